@@ -77,11 +77,19 @@
 //! of [NumberedIrep] and [NumberedString] it has already read from file.
 //! Dually to the serializer, it will only attempt to decode the contents of an
 //! object from the byte stream on the first occurrence.
-
-use crate::irep::{Irep, IrepId, Symbol, SymbolTable};
+//!
+//! The deserializer then rebuilds each [Irep] from its [NumberedIrep], caching
+//! the result by unique number, so that it can return the [GotoModel] stored
+//! in the file. Lifting this model back to the typesafe `goto_program`
+//! structures requires the from-irep conversions that are not implemented yet.
+
+use crate::irep::{
+    GotoFunction, GotoModel, Instruction, InstructionKind, Irep, IrepId, Symbol, SymbolTable,
+};
 use crate::{InternString, InternedString};
 #[cfg(not(test))]
 use fxhash::FxHashMap;
+use std::collections::BTreeMap;
 #[cfg(test)]
 use std::collections::HashMap;
 use std::fs::File;
@@ -102,17 +110,27 @@ pub fn write_goto_binary_file(filename: &Path, source: &crate::goto_program::Sym
     let mut writer = BufWriter::new(out_file);
     let mut serializer = GotoBinarySerializer::new(&mut writer);
     let irep_symbol_table = &source.to_irep();
-    serializer.write_file(irep_symbol_table);
+    serializer.write_file(irep_symbol_table, &BTreeMap::new());
+}
+
+/// Writes a goto model, including its GOTO functions, to a file in goto binary format in
+/// version 6. This is the inverse of [read_goto_binary_file].
+pub fn write_goto_model_file(filename: &Path, model: &GotoModel) {
+    let out_file = File::create(filename).unwrap();
+    let mut writer = BufWriter::new(out_file);
+    let mut serializer = GotoBinarySerializer::new(&mut writer);
+    serializer.write_file(&model.symbol_table, &model.functions);
 }
 
-/// Reads a symbol table from a file expected to be in goto binary format in version 6.
-//
+/// Reads a goto model from a file expected to be in goto binary format in version 6,
+/// e.g., a file produced by Kani, `goto-cc` or `goto-instrument`.
+///
 /// In CBMC, the deserialization rules are defined in :
 /// - src/goto-programs/read_goto_binary.h
 /// - src/util/irep_serialization.h
 /// - src/util/irep_hash_container.h
 /// - src/util/irep_hash.h
-pub fn read_goto_binary_file(filename: &Path) -> io::Result<()> {
+pub fn read_goto_binary_file(filename: &Path) -> io::Result<GotoModel> {
     let file = File::open(filename)?;
     let reader = BufReader::new(file);
     let mut deserializer = GotoBinaryDeserializer::new(reader);
@@ -453,29 +471,32 @@ fn write_numbered_string_ref(&mut self, numbered_string: &NumberedString) {
         self.write_usize_varenc(num);
         if self.is_first_write_string(num) {
             // first occurrence
-            numbered_string.string.map(|raw_str| {
-                for c in raw_str.chars() {
-                    if c.is_ascii() {
-                        // add escape character for backslashes and 0
-                        if c == '0' || c == '\\' {
-                            self.write_u8(b'\\');
-                        }
-                        self.write_u8(c as u8);
-                    } else {
-                        let mut buf = [0; 4];
-                        c.encode_utf8(&mut buf);
-                        for u in buf {
-                            if u == 0 {
-                                break;
-                            }
-                            self.write_u8(u);
-                        }
+            numbered_string.string.map(|raw_str| self.write_raw_string(raw_str));
+        }
+    }
+
+    /// Writes the characters of a string, followed by a 0 terminator.
+    fn write_raw_string(&mut self, raw_str: &str) {
+        for c in raw_str.chars() {
+            if c.is_ascii() {
+                // add escape character for backslashes and 0
+                if c == '0' || c == '\\' {
+                    self.write_u8(b'\\');
+                }
+                self.write_u8(c as u8);
+            } else {
+                let mut buf = [0; 4];
+                c.encode_utf8(&mut buf);
+                for u in buf {
+                    if u == 0 {
+                        break;
                     }
+                    self.write_u8(u);
                 }
-                // write terminator
-                self.write_u8(0u8);
-            });
+            }
         }
+        // write terminator
+        self.write_u8(0u8);
     }
 
     /// Writes a numbered irep to the buffer. Writes the unique number of the
@@ -563,10 +584,34 @@ fn write_symbol_table(&mut self, symbol_table: &SymbolTable) {
         }
     }
 
-    /// Writes an empty function map to the byte stream.
-    fn write_function_map(&mut self) {
-        // Write empty GOTO functions map
-        self.write_usize_varenc(0);
+    /// Writes a GOTO instruction to the byte stream.
+    fn write_instruction(&mut self, instruction: &Instruction) {
+        self.write_irep_ref(&instruction.code);
+        self.write_irep_ref(&instruction.location);
+        self.write_usize_varenc(instruction.kind as usize);
+        self.write_irep_ref(&instruction.condition);
+        self.write_usize_varenc(instruction.target_number);
+        self.write_usize_varenc(instruction.targets.len());
+        for target in &instruction.targets {
+            self.write_usize_varenc(*target);
+        }
+        self.write_usize_varenc(instruction.labels.len());
+        for label in &instruction.labels {
+            self.write_string_ref(label);
+        }
+    }
+
+    /// Writes the GOTO functions map to the byte stream.
+    fn write_function_map(&mut self, functions: &BTreeMap<InternedString, GotoFunction>) {
+        self.write_usize_varenc(functions.len());
+        for (name, function) in functions {
+            // Function names are written in full, without structural sharing.
+            name.map(|raw_str| self.write_raw_string(raw_str));
+            self.write_usize_varenc(function.instructions.len());
+            for instruction in &function.instructions {
+                self.write_instruction(instruction);
+            }
+        }
     }
 
     /// Writes a GOTO binary file header to the byte stream.
@@ -580,11 +625,16 @@ fn write_header(&mut self) {
         self.write_usize_varenc(6);
     }
 
-    /// Writes the symbol table using the GOTO binary file format to the byte stream.
-    fn write_file(&mut self, symbol_table: &SymbolTable) {
+    /// Writes the symbol table and the GOTO functions using the GOTO binary file format to the
+    /// byte stream.
+    fn write_file(
+        &mut self,
+        symbol_table: &SymbolTable,
+        functions: &BTreeMap<InternedString, GotoFunction>,
+    ) {
         self.write_header();
         self.write_symbol_table(symbol_table);
-        self.write_function_map();
+        self.write_function_map(functions);
     }
 }
 
@@ -612,6 +662,12 @@ struct GotoBinaryDeserializer<R>
     /// Maps the string number used in the binary stream to the new one generated
     /// by our own numbering.
     string_map: Vec<Option<usize>>,
+
+    /// The [Irep] rebuilt for each [NumberedIrep], indexed by our own numbering.
+    ireps: Vec<Option<Irep>>,
+
+    /// The [IrepId] of each [NumberedString], indexed by our own numbering.
+    irep_ids: Vec<Option<IrepId>>,
 }
 
 impl<R> GotoBinaryDeserializer<R>
@@ -629,6 +685,8 @@ fn new(reader: R) -> Self {
             string_map: Vec::new(),
             irep_count: Vec::new(),
             irep_map: Vec::new(),
+            ireps: Vec::new(),
+            irep_ids: Vec::new(),
         }
     }
 
@@ -734,59 +792,10 @@ fn read_numbered_string_ref(&mut self) -> io::Result<NumberedString> {
         let string_number_result = self.read_usize_varenc();
         let string_number = string_number_result?;
         if self.is_first_read_string(string_number) {
-            // read raw string
-            let mut string_buf: Vec<u8> = Vec::new();
-            loop {
-                match self.bytes.next() {
-                    Some(Ok(u)) => {
-                        match u {
-                            0 => {
-                                // Reached end of string
-                                match String::from_utf8(string_buf) {
-                                    Ok(str) => {
-                                        let numbered = self.numbering.number_string(&str.intern());
-                                        self.add_string_mapping(string_number, numbered.number);
-                                        return Ok(numbered);
-                                    }
-                                    Err(error) => {
-                                        return Err(Error::other(error.to_string()));
-                                    }
-                                }
-                            }
-                            b'\\' => {
-                                // Found escape symbol, read the next char
-                                match self.bytes.next() {
-                                    Some(Ok(c)) => {
-                                        string_buf.push(c);
-                                    }
-                                    Some(Err(error)) => {
-                                        return Err(error);
-                                    }
-                                    None => {
-                                        return Err(Error::other(
-                                            "Invalid goto binary input: unexpected end of input",
-                                        ));
-                                    }
-                                }
-                            }
-                            c => {
-                                // Found normal char, push to buffer
-                                string_buf.push(c);
-                            }
-                        }
-                    }
-                    Some(Err(error)) => {
-                        // Could not read from byte stream, propagate
-                        return Err(error);
-                    }
-                    None => {
-                        // No more bytes left
-                        return Err(Error::other(
-                            "Invalid goto binary input: unexpected end of input",
-                        ));
-                    }
-                }
-            }
+            let str = self.read_raw_string()?;
+            let numbered = self.numbering.number_string(&str.intern());
+            self.add_string_mapping(string_number, numbered.number);
+            Ok(numbered)
         } else {
             // We already read this irep, fetch it from the numbering
             Ok(self
@@ -796,6 +805,21 @@ fn read_numbered_string_ref(&mut self) -> io::Result<NumberedString> {
         }
     }
 
+    /// Reads the characters of a string until the 0 terminator.
+    fn read_raw_string(&mut self) -> io::Result<String> {
+        let mut string_buf: Vec<u8> = Vec::new();
+        loop {
+            match self.read_u8()? {
+                // Reached end of string
+                0 => return String::from_utf8(string_buf).map_err(Error::other),
+                // Found escape symbol, read the next char
+                b'\\' => string_buf.push(self.read_u8()?),
+                // Found normal char, push to buffer
+                c => string_buf.push(c),
+            }
+        }
+    }
+
     /// Reads a NumberedIrep from the byte stream.
     fn read_numbered_irep_ref(&mut self) -> io::Result<NumberedIrep> {
         let irep_number_result = self.read_usize_varenc();
@@ -853,19 +877,70 @@ fn read_numbered_irep_ref(&mut self) -> io::Result<NumberedIrep> {
         }
     }
 
+    /// Returns the [IrepId] of a [NumberedString].
+    fn irep_id(&mut self, numbered_string: &NumberedString) -> IrepId {
+        let number = numbered_string.number;
+        if number >= self.irep_ids.len() {
+            self.irep_ids.resize(number + 1, None);
+        }
+        self.irep_ids[number]
+            // Don't intern new strings while the interner is borrowed by `map`.
+            .get_or_insert_with(|| IrepId::from_cbmc_string(&numbered_string.string.to_string()))
+            .clone()
+    }
+
+    /// Rebuilds the [Irep] of a [NumberedIrep] from the numbering.
+    fn irep(&mut self, numbered_irep: &NumberedIrep) -> Irep {
+        let number = numbered_irep.number;
+        if let Some(Some(irep)) = self.ireps.get(number) {
+            return irep.clone();
+        }
+        let id = self.numbering.id(numbered_irep);
+        let id = self.irep_id(&id);
+        let sub = (0..self.numbering.nof_sub(numbered_irep))
+            .map(|sub_idx| {
+                let sub = self.numbering.sub(numbered_irep, sub_idx);
+                self.irep(&sub)
+            })
+            .collect();
+        let named_sub = (0..self.numbering.nof_named_sub(numbered_irep))
+            .map(|named_sub_idx| {
+                let (key, value) = self.numbering.named_sub(numbered_irep, named_sub_idx);
+                (self.irep_id(&key), self.irep(&value))
+            })
+            .collect();
+        let irep = Irep { id, sub, named_sub };
+        if number >= self.ireps.len() {
+            self.ireps.resize(number + 1, None);
+        }
+        self.ireps[number] = Some(irep.clone());
+        irep
+    }
+
+    /// Reads an Irep from the byte stream.
+    fn read_irep(&mut self) -> io::Result<Irep> {
+        let numbered_irep = self.read_numbered_irep_ref()?;
+        Ok(self.irep(&numbered_irep))
+    }
+
+    /// Reads a reference encoded string from the byte stream.
+    fn read_string(&mut self) -> io::Result<InternedString> {
+        Ok(self.read_numbered_string_ref()?.string)
+    }
+
     /// Reads a Symbol from the byte stream.
-    fn read_symbol(&mut self) -> io::Result<()> {
+    fn read_symbol(&mut self) -> io::Result<Symbol> {
         // Read Irep attributes of the symbol
-        let _typ = self.read_numbered_irep_ref()?;
-        let _value = self.read_numbered_irep_ref()?;
-        let _location = self.read_numbered_irep_ref()?;
+        let typ = self.read_irep()?;
+        let value = self.read_irep()?;
+        let location = self.read_irep()?;
 
         // Read string attributes of the symbol
-        let _name = self.read_numbered_string_ref()?;
-        let _module = self.read_numbered_string_ref()?;
-        let _base_name = self.read_numbered_string_ref()?;
-        let _mode = self.read_numbered_string_ref()?;
-        let _pretty_name = self.read_numbered_string_ref()?;
+        let name = self.read_string()?;
+        let module = self.read_string()?;
+        let base_name = self.read_string()?;
+        let mode = self.read_string()?;
+        let pretty_name = self.read_string()?;
 
         // obsolete: symordering
         let symordering = self.read_u8()?;
@@ -874,53 +949,106 @@ fn read_symbol(&mut self) -> io::Result<()> {
         // Decode the bit-packed flags and extract bits one by one
         let flags: usize = self.read_usize_varenc()?;
 
-        let _is_weak = (flags & (1 << 16)) != 0;
-        let _is_type = (flags & (1 << 15)) != 0;
-        let _is_property = (flags & (1 << 14)) != 0;
-        let _is_macro = (flags & (1 << 13)) != 0;
-        let _is_exported = (flags & (1 << 12)) != 0;
-        let _is_input = (flags & (1 << 11)) != 0;
-        let _is_output = (flags & (1 << 10)) != 0;
-        let _is_state_var = (flags & (1 << 9)) != 0;
-        let _is_parameter = (flags & (1 << 8)) != 0;
-        let _is_auxiliary = (flags & (1 << 7)) != 0;
+        let is_weak = (flags & (1 << 16)) != 0;
+        let is_type = (flags & (1 << 15)) != 0;
+        let is_property = (flags & (1 << 14)) != 0;
+        let is_macro = (flags & (1 << 13)) != 0;
+        let is_exported = (flags & (1 << 12)) != 0;
+        let is_input = (flags & (1 << 11)) != 0;
+        let is_output = (flags & (1 << 10)) != 0;
+        let is_state_var = (flags & (1 << 9)) != 0;
+        let is_parameter = (flags & (1 << 8)) != 0;
+        let is_auxiliary = (flags & (1 << 7)) != 0;
         // deprecated sym.binding but remains present for compatibility
         let _is_binding = (flags & (1 << 6)) != 0;
-        let _is_lvalue = (flags & (1 << 5)) != 0;
-        let _is_static_lifetime = (flags & (1 << 4)) != 0;
-        let _is_thread_local = (flags & (1 << 3)) != 0;
-        let _is_file_local = (flags & (1 << 2)) != 0;
-        let _is_extern = (flags & (1 << 1)) != 0;
-        let _is_volatile = (flags & 1) != 0;
+        let is_lvalue = (flags & (1 << 5)) != 0;
+        let is_static_lifetime = (flags & (1 << 4)) != 0;
+        let is_thread_local = (flags & (1 << 3)) != 0;
+        let is_file_local = (flags & (1 << 2)) != 0;
+        let is_extern = (flags & (1 << 1)) != 0;
+        let is_volatile = (flags & 1) != 0;
 
-        let shifted_flags = flags >> 16;
+        let shifted_flags = flags >> 17;
 
         if shifted_flags != 0 {
             return Err(Error::other(
                 "incorrect binary format: true bits remain in decoded symbol flags",
             ));
         }
-        Ok(())
+        Ok(Symbol {
+            typ,
+            value,
+            location,
+            name,
+            module,
+            base_name,
+            pretty_name,
+            mode,
+            is_type,
+            is_macro,
+            is_exported,
+            is_input,
+            is_output,
+            is_state_var,
+            is_property,
+            is_static_lifetime,
+            is_thread_local,
+            is_lvalue,
+            is_file_local,
+            is_extern,
+            is_volatile,
+            is_parameter,
+            is_auxiliary,
+            is_weak,
+        })
     }
 
     /// Reads a whole SymbolTable from the byte stream.
-    fn read_symbol_table(&mut self) -> io::Result<()> {
-        // Write symbol table size
+    fn read_symbol_table(&mut self) -> io::Result<SymbolTable> {
+        // Read symbol table size
         let symbol_table_len = self.read_usize_varenc()?;
 
-        // Write symbols
+        // Read symbols
+        let mut symbol_table = SymbolTable::new();
         for _ in 0..symbol_table_len {
-            self.read_symbol()?;
+            symbol_table.insert(self.read_symbol()?);
         }
 
-        Ok(())
-    }
-
-    /// Reads an empty function map from the byte stream.
-    fn read_function_map(&mut self) -> io::Result<()> {
+        Ok(symbol_table)
+    }
+
+    /// Reads a GOTO instruction from the byte stream.
+    fn read_instruction(&mut self) -> io::Result<Instruction> {
+        let code = self.read_irep()?;
+        let location = self.read_irep()?;
+        let kind = self.read_usize_varenc()?;
+        let kind = InstructionKind::from_usize(kind).ok_or_else(|| {
+            Error::other(format!("Invalid goto binary input: unknown instruction type {kind}"))
+        })?;
+        let condition = self.read_irep()?;
+        let target_number = self.read_usize_varenc()?;
+        let targets = (0..self.read_usize_varenc()?)
+            .map(|_| self.read_usize_varenc())
+            .collect::<io::Result<_>>()?;
+        let labels = (0..self.read_usize_varenc()?)
+            .map(|_| self.read_string())
+            .collect::<io::Result<_>>()?;
+        Ok(Instruction { code, location, kind, condition, target_number, targets, labels })
+    }
+
+    /// Reads the GOTO functions map from the byte stream.
+    fn read_function_map(&mut self) -> io::Result<BTreeMap<InternedString, GotoFunction>> {
         let goto_function_len = self.read_usize_varenc()?;
-        Self::expect(goto_function_len, 0)?;
-        Ok(())
+        let mut functions = BTreeMap::new();
+        for _ in 0..goto_function_len {
+            // Function names are written in full, without structural sharing.
+            let name = self.read_raw_string()?.intern();
+            let instructions = (0..self.read_usize_varenc()?)
+                .map(|_| self.read_instruction())
+                .collect::<io::Result<_>>()?;
+            functions.insert(name, GotoFunction { instructions });
+        }
+        Ok(functions)
     }
 
     /// Reads a GOTO binary header from the byte stream.
@@ -943,11 +1071,11 @@ fn read_header(&mut self) -> io::Result<()> {
     }
 
     /// Read a GOTO binary file from the byte stream.
-    fn read_file(&mut self) -> io::Result<()> {
+    fn read_file(&mut self) -> io::Result<GotoModel> {
         self.read_header()?;
-        self.read_symbol_table()?;
-        self.read_function_map()?;
-        Ok(())
+        let symbol_table = self.read_symbol_table()?;
+        let functions = self.read_function_map()?;
+        Ok(GotoModel { symbol_table, functions })
     }
 }
 
@@ -1219,6 +1347,7 @@ mod tests {
     use crate::irep::Irep;
     use crate::irep::IrepId;
     use crate::irep::goto_binary_serde::GotoBinaryDeserializer;
+    use crate::irep::{GotoFunction, GotoModel, Instruction, InstructionKind, Symbol};
     use crate::linear_map;
     use linear_map::LinearMap;
     use std::io::BufWriter;
@@ -1473,4 +1602,75 @@ fn test_write_read_irep_ref() {
             assert_eq!(irep1, irep6);
         }
     }
+
+    /// Utility function: creates a symbol with the given name, type and value.
+    fn make_symbol(name: &str, typ: Irep, value: Irep) -> Symbol {
+        Symbol {
+            typ,
+            value,
+            location: Irep::nil(),
+            name: name.intern(),
+            module: "".intern(),
+            base_name: name.intern(),
+            pretty_name: name.intern(),
+            mode: "C".intern(),
+            is_type: false,
+            is_macro: false,
+            is_exported: false,
+            is_input: false,
+            is_output: false,
+            is_state_var: false,
+            is_property: false,
+            is_static_lifetime: true,
+            is_thread_local: false,
+            is_lvalue: true,
+            is_file_local: false,
+            is_extern: false,
+            is_volatile: false,
+            is_parameter: false,
+            is_auxiliary: false,
+            is_weak: true,
+        }
+    }
+
+    #[test]
+    /// Write a goto model and check that reading it back rebuilds the same model.
+    fn test_write_read_goto_model() {
+        let mut model = GotoModel::default();
+        let identifiers = vec!["foo", "bar", "foo\\0", "baz"];
+        model.symbol_table.insert(make_symbol(
+            "foo",
+            make_symbol_expr("flag"),
+            fold_with_op(&identifiers, IrepId::And),
+        ));
+        model.symbol_table.insert(make_symbol("bar", Irep::nil(), make_symbol_expr("foo")));
+        let instruction = |kind, target_number, targets: Vec<usize>| Instruction {
+            code: make_symbol_expr("foo"),
+            location: Irep::nil(),
+            kind,
+            condition: fold_with_op(&identifiers, IrepId::Or),
+            target_number,
+            targets,
+            labels: vec!["label".intern()],
+        };
+        model.functions.insert(
+            "main".intern(),
+            GotoFunction {
+                instructions: vec![
+                    instruction(InstructionKind::Skip, 1, vec![]),
+                    instruction(InstructionKind::Goto, Instruction::NIL_TARGET, vec![1]),
+                    instruction(InstructionKind::EndFunction, Instruction::NIL_TARGET, vec![]),
+                ],
+            },
+        );
+
+        let mut vec: Vec<u8> = Vec::new();
+        {
+            let mut writer = BufWriter::new(&mut vec);
+            let mut serializer = GotoBinarySerializer::new(&mut writer);
+            serializer.write_file(&model.symbol_table, &model.functions);
+        }
+        let mut deserializer = GotoBinaryDeserializer::new(std::io::Cursor::new(vec));
+        assert_eq!(deserializer.read_file().unwrap(), model);
+    }
 }
@@ -0,0 +1,97 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+use super::{Irep, SymbolTable};
+use crate::InternedString;
+use std::collections::BTreeMap;
+
+/// A direct implementation of the CBMC goto model implemented in
+/// <https://github.com/diffblue/cbmc/blob/develop/src/goto-programs/goto_model.h>
+/// as it is stored in goto binary files, where only the functions with a body are kept.
+#[derive(Debug, Default, PartialEq)]
+pub struct GotoModel {
+    pub symbol_table: SymbolTable,
+    pub functions: BTreeMap<InternedString, GotoFunction>,
+}
+
+/// The body of a function, as implemented in
+/// <https://github.com/diffblue/cbmc/blob/develop/src/goto-programs/goto_program.h>
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GotoFunction {
+    pub instructions: Vec<Instruction>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Instruction {
+    pub code: Irep,
+    pub location: Irep,
+    pub kind: InstructionKind,
+    /// The condition of `GOTO`, `ASSUME` and `ASSERT` instructions, and `true` otherwise.
+    pub condition: Irep,
+    /// The number used by other instructions to jump to this one.
+    /// It is [Instruction::NIL_TARGET] if no instruction jumps to this one.
+    pub target_number: usize,
+    /// The target numbers of the instructions that this one may jump to.
+    pub targets: Vec<usize>,
+    pub labels: Vec<InternedString>,
+}
+
+impl Instruction {
+    /// The target number of instructions that are not the target of a jump.
+    pub const NIL_TARGET: usize = u32::MAX as usize;
+}
+
+/// The kinds of instructions, in the same order as `goto_program_instruction_typet` in
+/// <https://github.com/diffblue/cbmc/blob/develop/src/goto-programs/goto_instruction_code.h>
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InstructionKind {
+    NoInstructionType,
+    Goto,
+    Assume,
+    Assert,
+    Other,
+    Skip,
+    StartThread,
+    EndThread,
+    Location,
+    EndFunction,
+    AtomicBegin,
+    AtomicEnd,
+    SetReturnValue,
+    Assign,
+    Decl,
+    Dead,
+    FunctionCall,
+    Throw,
+    Catch,
+    IncompleteGoto,
+}
+
+impl InstructionKind {
+    const ALL: [InstructionKind; 20] = [
+        InstructionKind::NoInstructionType,
+        InstructionKind::Goto,
+        InstructionKind::Assume,
+        InstructionKind::Assert,
+        InstructionKind::Other,
+        InstructionKind::Skip,
+        InstructionKind::StartThread,
+        InstructionKind::EndThread,
+        InstructionKind::Location,
+        InstructionKind::EndFunction,
+        InstructionKind::AtomicBegin,
+        InstructionKind::AtomicEnd,
+        InstructionKind::SetReturnValue,
+        InstructionKind::Assign,
+        InstructionKind::Decl,
+        InstructionKind::Dead,
+        InstructionKind::FunctionCall,
+        InstructionKind::Throw,
+        InstructionKind::Catch,
+        InstructionKind::IncompleteGoto,
+    ];
+
+    /// Returns the kind encoded as `value` in goto binary files, if any.
+    pub fn from_usize(value: usize) -> Option<InstructionKind> {
+        InstructionKind::ALL.get(value).copied()
+    }
+}
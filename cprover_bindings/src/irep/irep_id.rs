@@ -10,833 +10,869 @@
 
 use std::borrow::Cow;
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug)]
-pub enum IrepId {
-    /// In addition to the standard enums defined below, CBMC also allows ids to be strings.
-    /// For e.g, to store the id of a variable. This enum variant captures those strings.
-    FreeformString(InternedString),
-    /// An integer, to be encoded as a decimal string
-    FreeformInteger(BigInt),
-    /// A constant, stored as a bit pattern (negative numbers in 2's complement).
-    FreeformBitPattern(BigUint),
-    EmptyString,
-    Let,
-    LetBinding,
-    Nil,
-    Type,
-    Bool,
-    CBool,
-    ProperBool,
-    Signedbv,
-    Unsignedbv,
-    VerilogSignedbv,
-    VerilogUnsignedbv,
-    Floatbv,
-    Fixedbv,
-    X86Extended,
-    CSourceLocation,
-    CEndLocation,
-    CIsPadding,
-    CDoNotDump,
-    File,
-    Line,
-    Column,
-    Comment,
-    Property,
-    PropertyClass,
-    PropertyId,
-    Function,
-    MathematicalFunction,
-    Code,
-    Typecast,
-    StaticCast,
-    DynamicCast,
-    ConstCast,
-    ReinterpretCast,
-    Index,
-    Ptrmember,
-    Member,
-    MemberName,
-    CMemberName,
-    Equal,
-    Implies,
-    And,
-    Nand,
-    Or,
-    Nor,
-    Xor,
-    Not,
-    Bitand,
-    Bitor,
-    Bitnot,
-    Bitxor,
-    Bitnand,
-    Bitnor,
-    Bitxnor,
-    Notequal,
-    If,
-    Symbol,
-    NextSymbol,
-    NondetSymbol,
-    PredicateSymbol,
-    PredicateNextSymbol,
-    NondetBool,
-    Empty,
-    SideEffect,
-    Statement,
-    StatementExpression,
-    Value,
-    Constant,
-    Block,
-    Decl,
-    Dead,
-    Assign,
-    AssignDiv,
-    AssignMult,
-    AssignPlus,
-    AssignMinus,
-    AssignMod,
-    AssignShl,
-    AssignShr,
-    AssignAshr,
-    AssignLshr,
-    AssignBitand,
-    AssignBitxor,
-    AssignBitor,
-    Assume,
-    Assert,
-    Assertion,
-    Precondition,
-    Postcondition,
-    PreconditionInstance,
-    Goto,
-    GccComputedGoto,
-    Ifthenelse,
-    Label,
-    Break,
-    Continue,
-    FunctionCall,
-    Return,
-    Skip,
-    Arguments,
-    Array,
-    Size,
-    FrontendPointer,
-    Pointer,
-    BlockPointer,
-    Switch,
-    SwitchCase,
-    GccSwitchCaseRange,
-    For,
-    While,
-    Dowhile,
-    Int,
-    Integer,
-    Natural,
-    Real,
-    Rational,
-    Complex,
-    Signed,
-    Unsigned,
-    Asm,
-    GccAsmInput,
-    GccAsmOutput,
-    GccAsmClobberedRegister,
-    Incomplete,
-    IncompleteClass,
-    CIncomplete,
-    Identifier,
-    Name,
-    InnerName,
-    CppName,
-    ComponentCppName,
-    CIdClass,
-    Declaration,
-    DeclarationList,
-    Declarator,
-    Struct,
-    CBitField,
-    Union,
-    Class,
-    MergedType,
-    Range,
-    From,
-    To,
-    Module,
-    Parameter,
-    ComponentName,
-    ComponentNumber,
-    Tag,
-    Default,
-    CDefaultValue,
-    BaseName,
-    CBaseName,
-    String,
-    CStringConstant,
-    StringConstant,
-    Width,
-    Components,
-    Bv,
-    F,
-    With,
-    Trans,
-    Throw,
-    TryCatch,
-    Noexcept,
-    CPROVERThrow,
-    CPROVERTryCatch,
-    CPROVERTryFinally,
-    Protection,
-    Private,
-    Public,
-    Protected,
-    Virtual,
-    Volatile,
-    Const,
-    Constexpr,
-    Inline,
-    Forall,
-    Exists,
-    Repeat,
-    Extractbit,
-    Extractbits,
-    CReference,
-    CRvalueReference,
-    True,
-    False,
-    AddressOf,
-    Dereference,
-    CLvalue,
-    CBase,
-    Destination,
-    Main,
-    Expression,
-    Allocate,
-    CCxxAllocType,
-    CppNew,
-    CppDelete,
-    CppNewArray,
-    CppDeleteArray,
-    JavaNew,
-    JavaNewArray,
-    JavaNewArrayData,
-    JavaStringLiteral,
-    Printf,
-    Input,
-    Output,
-    Nondet,
-    NULL,
-    Null,
-    Nullptr,
-    CEnum,
-    Enumeration,
-    Elements,
-    Unknown,
-    Uninitialized,
-    Invalid,
-    CInvalidObject,
-    PointerOffset,
-    PointerObject,
-    IsInvalidPointer,
-    IeeeFloatEqual,
-    IeeeFloatNotequal,
-    Isnan,
-    Lambda,
-    ArrayComprehension,
-    ArrayOf,
-    ArrayEqual,
-    ArraySet,
-    ArrayCopy,
-    ArrayList,
-    Mod,
-    Rem,
-    Shr,
-    Ashr,
-    Lshr,
-    Shl,
-    Rol,
-    Ror,
-    Comma,
-    Concatenation,
-    Infinity,
-    ReturnType,
-    Typedef,
-    TypedefType,
-    CTypedef,
-    Extern,
-    Static,
-    Auto,
-    Register,
-    ThreadLocal,
-    Thread,
-    CThreadLocal,
-    CStaticLifetime,
-    Mutable,
-    Void,
-    Int8,
-    Int16,
-    Int32,
-    Int64,
-    Ptr32,
-    Ptr64,
-    Char,
-    Short,
-    Long,
-    Float,
-    Float16,
-    Float128,
-    Double,
-    Byte,
-    Boolean,
-    LongDouble,
-    SignedChar,
-    UnsignedChar,
-    SignedInt,
-    UnsignedInt,
-    SignedLongInt,
-    UnsignedLongInt,
-    SignedShortInt,
-    UnsignedShortInt,
-    SignedLongLongInt,
-    UnsignedLongLongInt,
-    SignedInt128,
-    UnsignedInt128,
-    Case,
-    CInlined,
-    CHide,
-    Hide,
-    Abs,
-    Sign,
-    Access,
-    CAccess,
-    Postincrement,
-    Postdecrement,
-    Preincrement,
-    Predecrement,
-    IntegerBits,
-    KnR,
-    CKnR,
-    ConstraintSelectOne,
-    Cond,
-    BvLiterals,
-    IsFinite,
-    Isinf,
-    Isnormal,
-    Alignof,
-    ClangBuiltinConvertvector,
-    GccBuiltinVaArg,
-    GccBuiltinTypesCompatibleP,
-    VaStart,
-    GccFloat16,
-    GccFloat32,
-    GccFloat32x,
-    GccFloat64,
-    GccFloat64x,
-    GccFloat80,
-    GccFloat128,
-    GccFloat128x,
-    GccInt128,
-    GccDecimal32,
-    GccDecimal64,
-    GccDecimal128,
-    BuiltinOffsetof,
-    Id0,
-    Id1,
-    Sizeof,
-    TypeArg,
-    ExprArg,
-    ExpressionList,
-    InitializerList,
-    GccConditionalExpression,
-    GccLocalLabel,
-    Gcc,
-    Msc,
-    Typeof,
-    Ellipsis,
-    Flavor,
-    Ge,
-    Le,
-    Gt,
-    Lt,
-    Plus,
-    Minus,
-    UnaryMinus,
-    UnaryPlus,
-    Mult,
-    Div,
-    Power,
-    FactorialPower,
-    CPrettyName,
-    CClass,
-    CField,
-    CInterface,
-    DesignatedInitializer,
-    Designator,
-    MemberDesignator,
-    IndexDesignator,
-    CConstant,
-    CVolatile,
-    CRestricted,
-    CIdentifier,
-    CImplicit,
-    CPtr32,
-    CPtr64,
-    CAtomic,
-    Restrict,
-    ByteExtractBigEndian,
-    ByteExtractLittleEndian,
-    ByteUpdateBigEndian,
-    ByteUpdateLittleEndian,
-    Replication,
-    CproverAtomic,
-    Atomic,
-    AtomicTypeSpecifier,
-    AtomicBegin,
-    AtomicEnd,
-    StartThread,
-    EndThread,
-    CoverageCriterion,
-    Initializer,
-    Anonymous,
-    CIsAnonymous,
-    IsEnumConstant,
-    IsInline,
-    IsExtern,
-    IsSynchronized,
-    IsNativeMethod,
-    IsVarargsMethod,
-    IsGlobal,
-    IsThreadLocal,
-    IsParameter,
-    IsMember,
-    IsType,
-    IsRegister,
-    IsTypedef,
-    IsStatic,
-    IsTemplate,
-    IsStaticAssert,
-    IsVirtual,
-    CIsVirtual,
-    Literal,
-    MemberInitializers,
-    MemberInitializer,
-    MethodQualifier,
-    Methods,
-    StaticMembers,
-    Constructor,
-    Destructor,
-    Bases,
-    Base,
-    FromBase,
-    Operator,
-    Template,
-    TemplateClassInstance,
-    TemplateFunctionInstance,
-    TemplateType,
-    TemplateArgs,
-    TemplateParameter,
-    TemplateParameterSymbolType,
-    TemplateParameters,
-    CTemplate,
-    CTemplateArguments,
-    CTemplateCase,
-    Typename,
-    C,
-    Cpp,
-    Java,
-    DeclBlock,
-    DeclType,
-    Parameters,
-    WcharT,
-    Char16T,
-    Char32T,
-    SizeT,
-    SsizeT,
-    Mode,
-    This,
-    CThis,
-    ReductionAnd,
-    ReductionOr,
-    ReductionNand,
-    ReductionNor,
-    ReductionXor,
-    ReductionXnor,
-    CZeroInitializer,
-    Body,
-    TemporaryObject,
-    OverflowPlus,
-    OverflowMinus,
-    OverflowMult,
-    OverflowResultPlus,
-    OverflowResultMinus,
-    OverflowResultMult,
-    OverflowUnaryMinus,
-    ObjectDescriptor,
-    IsDynamicObject,
-    DynamicObject,
-    CDynamic,
-    ObjectSize,
-    GoodPointer,
-    IntegerAddress,
-    IntegerAddressObject,
-    NullObject,
-    StaticObject,
-    StackObject,
-    CIsFailedSymbol,
-    CFailedSymbol,
-    Friend,
-    CFriends,
-    Explicit,
-    StorageSpec,
-    MemberSpec,
-    MscDeclspec,
-    Packed,
-    CPacked,
-    TransparentUnion,
-    CTransparentUnion,
-    Aligned,
-    CAlignment,
-    FrontendVector,
-    Vector,
-    Abstract,
-    FunctionApplication,
-    CppDeclarator,
-    CppLinkageSpec,
-    CppNamespaceSpec,
-    CppStorageSpec,
-    CppUsing,
-    CppDeclaration,
-    CppStaticAssert,
-    CppMemberSpec,
-    CCType,
-    Namespace,
-    Linkage,
-    Decltype,
-    CTagOnlyDeclaration,
-    StructTag,
-    UnionTag,
-    CEnumTag,
-    VerilogCaseEquality,
-    VerilogCaseInequality,
-    UserSpecifiedPredicate,
-    UserSpecifiedParameterPredicates,
-    UserSpecifiedReturnPredicates,
-    Unassigned,
-    NewObject,
-    ComplexReal,
-    ComplexImag,
-    Imag,
-    MscTryExcept,
-    MscTryFinally,
-    MscLeave,
-    MscUuidof,
-    MscIfExists,
-    MscIfNotExists,
-    MscUnderlyingType,
-    MscBased,
-    Alias,
-    PtrObject,
-    CCSizeofType,
-    ArrayUpdate,
-    Update,
-    StaticAssert,
-    GccAttributeMode,
-    BuiltIn,
-    ExceptionList,
-    ExceptionId,
-    PredicatePassiveSymbol,
-    CwVaArgTypeof,
-    Fence,
-    Sync,
-    Lwsync,
-    Isync,
-    WRfence,
-    RRfence,
-    RWfence,
-    WWfence,
-    RRcumul,
-    RWcumul,
-    WWcumul,
-    WRcumul,
-    GenericSelection,
-    GenericAssociations,
-    GenericAssociation,
-    FloatbvPlus,
-    FloatbvMinus,
-    FloatbvMult,
-    FloatbvDiv,
-    FloatbvRem,
-    FloatbvTypecast,
-    CompoundLiteral,
-    CustomBv,
-    CustomUnsignedbv,
-    CustomSignedbv,
-    CustomFixedbv,
-    CustomFloatbv,
-    CSSASymbol,
-    L0,
-    L1,
-    L2,
-    L1ObjectIdentifier,
-    AlreadyTypechecked,
-    CVaArgType,
-    Smt2Symbol,
-    Onehot,
-    Onehot0,
-    Popcount,
-    CountLeadingZeros,
-    CountTrailingZeros,
-    EmptyUnion,
-    FunctionType,
-    Noreturn,
-    CNoreturn,
-    Weak,
-    IsWeak,
-    Used,
-    IsUsed,
-    CSpecLoopInvariant,
-    CSpecRequires,
-    CSpecEnsures,
-    CSpecAssigns,
-    VirtualFunction,
-    ElementType,
-    WorkingDirectory,
-    Section,
-    Bswap,
-    BitReverse,
-    JavaBytecodeIndex,
-    JavaInstanceof,
-    JavaSuperMethodCall,
-    JavaEnumStaticUnwind,
-    PushCatch,
-    PopCatch,
-    ExceptionLandingpad,
-    LengthUpperBound,
-    CproverAssociateArrayToPointerFunc,
-    CproverAssociateLengthToArrayFunc,
-    CproverCharLiteralFunc,
-    CproverStringLiteralFunc,
-    CproverStringCharAtFunc,
-    CproverStringCharSetFunc,
-    CproverStringCodePointAtFunc,
-    CproverStringCodePointBeforeFunc,
-    CproverStringCodePointCountFunc,
-    CproverStringOffsetByCodePointFunc,
-    CproverStringCompareToFunc,
-    CproverStringConcatFunc,
-    CproverStringConcatCharFunc,
-    CproverStringConcatCodePointFunc,
-    CproverStringConstrainCharactersFunc,
-    CproverStringContainsFunc,
-    CproverStringCopyFunc,
-    CproverStringDeleteFunc,
-    CproverStringDeleteCharAtFunc,
-    CproverStringEqualFunc,
-    CproverStringEqualsIgnoreCaseFunc,
-    CproverStringEmptyStringFunc,
-    CproverStringEndswithFunc,
-    CproverStringFormatFunc,
-    CproverStringIndexOfFunc,
-    CproverStringInsertFunc,
-    CproverStringIsPrefixFunc,
-    CproverStringIsSuffixFunc,
-    CproverStringIsEmptyFunc,
-    CproverStringLastIndexOfFunc,
-    CproverStringLengthFunc,
-    CproverStringOfIntFunc,
-    CproverStringOfIntHexFunc,
-    CproverStringOfLongFunc,
-    CproverStringOfFloatFunc,
-    CproverStringOfFloatScientificNotationFunc,
-    CproverStringOfDoubleFunc,
-    CproverStringParseIntFunc,
-    CproverStringIsValidIntFunc,
-    CproverStringIsValidLongFunc,
-    CproverStringReplaceFunc,
-    CproverStringSetLengthFunc,
-    CproverStringStartswithFunc,
-    CproverStringSubstringFunc,
-    CproverStringToLowerCaseFunc,
-    CproverStringToUpperCaseFunc,
-    CproverStringTrimFunc,
-    SkipInitialize,
-    BasicBlockCoveredLines,
-    BasicBlockSourceLines,
-    IsNondetNullable,
-    ArrayReplace,
-    SwitchCaseNumber,
-    JavaArrayAccess,
-    JavaMemberAccess,
-    CJavaGenericParameter,
-    CJavaGenericsClassType,
-    CJavaImplicitlyGenericClassType,
-    CJavaGenericSymbol,
-    GenericTypes,
-    ImplicitGenericTypes,
-    TypeVariables,
-    HandleType,
-    JavaLambdaMethodHandle,
-    JavaLambdaMethodHandleIndex,
-    JavaLambdaMethodHandles,
-    HavocObject,
-    OverflowShl,
-    CNoInitializationRequired,
-    CNoNondetInitialization,
-    OverlayClass,
-    OverlayMethod,
-    IgnoredMethod,
-    IsAnnotation,
-    CAnnotations,
-    Final,
-    BitsPerByte,
-    CAbstract,
-    Synthetic,
-    Interface,
-    CMustNotThrow,
-    IsInnerClass,
-    IsAnonymous,
-    OuterClass,
-    IsBridgeMethod,
-    CIsOperator,
-    CNotAccessible,
-    COverrideConstantness,
-    CBound,
-    CBoundsCheck,
-    CIsStatic,
-    CCallByValue,
-    CVirtualName,
-    CUnnamedObject,
-    CTemporaryAvoided,
-    CQualifier,
-    CArrayIni,
-    ROk,
-    WOk,
-    SuperClass,
-    ExceptionsThrownList,
-    CJavaMethodType,
-    Compiled,
-    PartialSpecializationArgs,
-    SpecializationOf,
-    InitArgs,
-    Ambiguous,
-    SpecializationTemplateArgs,
-    FullTemplateArgs,
-    InstantiatedWith,
-    TemplateMethods,
-    CppNotTypechecked,
-    Noaccess,
-    IsOperator,
-    IsCastOperator,
-    IsExplicit,
-    IsMutable,
-    VirtualName,
-    IsPureVirtual,
-    IsVtptr,
-    Prefix,
-    Cv,
-    CppDummyDestructor,
-    CastExpression,
-    PodConstructor,
-    TemplateDecls,
-    ThrowDecl,
-    Typeid,
-    CQuoted,
-    ToMember,
-    PointerToMember,
-    Tuple,
-    FunctionBody,
-    GetMay,
-    SetMay,
-    ClearMay,
-    GetMust,
-    SetMust,
-    ClearMust,
-    Pragma,
-    StatementList,
-    StatementListType,
-    StatementListFunction,
-    StatementListFunctionBlock,
-    StatementListMainFunction,
-    StatementListDataBlock,
-    StatementListVersion,
-    StatementListVarInput,
-    StatementListVarInout,
-    StatementListVarOutput,
-    StatementListVarConstant,
-    StatementListVarTemp,
-    StatementListVarStatic,
-    StatementListReturn,
-    StatementListReturnValueId,
-    StatementListVarEntry,
-    StatementListVarDecls,
-    StatementListNetwork,
-    StatementListNetworks,
-    StatementListTitle,
-    StatementListIdentifier,
-    StatementListLoad,
-    StatementListTransfer,
-    StatementListCall,
-    StatementListNop,
-    StatementListConstAdd,
-    StatementListAccuIntAdd,
-    StatementListAccuIntSub,
-    StatementListAccuIntMul,
-    StatementListAccuIntDiv,
-    StatementListAccuIntEq,
-    StatementListAccuIntNeq,
-    StatementListAccuIntGt,
-    StatementListAccuIntLt,
-    StatementListAccuIntGte,
-    StatementListAccuIntLte,
-    StatementListAccuRealAdd,
-    StatementListAccuRealSub,
-    StatementListAccuRealMul,
-    StatementListAccuRealDiv,
-    StatementListAccuRealEq,
-    StatementListAccuRealNeq,
-    StatementListAccuRealGt,
-    StatementListAccuRealLt,
-    StatementListAccuRealGte,
-    StatementListAccuRealLte,
-    StatementListAccuDintAdd,
-    StatementListAccuDintSub,
-    StatementListAccuDintMul,
-    StatementListAccuDintDiv,
-    StatementListAccuDintEq,
-    StatementListAccuDintNeq,
-    StatementListAccuDintGt,
-    StatementListAccuDintLt,
-    StatementListAccuDintGte,
-    StatementListAccuDintLte,
-    StatementListAnd,
-    StatementListAndNot,
-    StatementListOr,
-    StatementListOrNot,
-    StatementListXor,
-    StatementListXorNot,
-    StatementListAndNested,
-    StatementListAndNotNested,
-    StatementListOrNested,
-    StatementListOrNotNested,
-    StatementListXorNested,
-    StatementListXorNotNested,
-    StatementListNestingClosed,
-    StatementListAssign,
-    StatementListSetRlo,
-    StatementListClrRlo,
-    StatementListSet,
-    StatementListReset,
-    StatementListNot,
-    StatementListInstruction,
-    StatementListInstructions,
-    VectorEqual,
-    VectorNotequal,
-    VectorGe,
-    VectorLe,
-    VectorGt,
-    VectorLt,
-    FloatbvRoundToIntegral,
-    ShuffleVector,
+/// Declare [IrepId] and the conversions between its variants and their CBMC string from a single
+/// table, so the conversions are always the inverse of each other.
+macro_rules! irep_ids {
+    ($($id:ident => $name:literal,)*) => {
+        #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug)]
+        pub enum IrepId {
+            /// In addition to the standard enums defined below, CBMC also allows ids to be strings.
+            /// For e.g, to store the id of a variable. This enum variant captures those strings.
+            FreeformString(InternedString),
+            /// An integer, to be encoded as a decimal string
+            FreeformInteger(BigInt),
+            /// A constant, stored as a bit pattern (negative numbers in 2's complement).
+            FreeformBitPattern(BigUint),
+            $($id,)*
+        }
+
+        impl IrepId {
+            /// Every id with a fixed string.
+            #[cfg(test)]
+            const STATIC_IDS: &[IrepId] = &[$(IrepId::$id,)*];
+
+            /// Returns the [IrepId] whose string representation is `s`, e.g., when reading ids back
+            /// from a goto binary file. This is the inverse of [IrepId::to_string_cow], except that
+            /// integers, bit patterns and free-form strings that are also the string of another id
+            /// aren't distinguished, since CBMC only stores their string representation.
+            pub fn from_cbmc_string(s: &str) -> IrepId {
+                match s {
+                    $($name => IrepId::$id,)*
+                    _ => IrepId::FreeformString(s.into()),
+                }
+            }
+
+            fn to_static_string(&self) -> &'static str {
+                match self {
+                    IrepId::FreeformString(_)
+                    | IrepId::FreeformInteger(_)
+                    | IrepId::FreeformBitPattern { .. } => unreachable!(),
+                    $(IrepId::$id => $name,)*
+                }
+            }
+        }
+    };
+}
+
+irep_ids! {
+    EmptyString => "",
+    Let => "let",
+    LetBinding => "let_binding",
+    Nil => "nil",
+    Type => "type",
+    Bool => "bool",
+    CBool => "c_bool",
+    ProperBool => "proper_bool",
+    Signedbv => "signedbv",
+    Unsignedbv => "unsignedbv",
+    VerilogSignedbv => "verilog_signedbv",
+    VerilogUnsignedbv => "verilog_unsignedbv",
+    Floatbv => "floatbv",
+    Fixedbv => "fixedbv",
+    X86Extended => "x86_extended",
+    CSourceLocation => "#source_location",
+    CEndLocation => "#end_location",
+    CIsPadding => "#is_padding",
+    CDoNotDump => "C_do_not_dump",
+    File => "file",
+    Line => "line",
+    Column => "column",
+    Comment => "comment",
+    Property => "property",
+    PropertyClass => "property_class",
+    PropertyId => "property_id",
+    Function => "function",
+    MathematicalFunction => "mathematical_function",
+    Code => "code",
+    Typecast => "typecast",
+    StaticCast => "static_cast",
+    DynamicCast => "dynamic_cast",
+    ConstCast => "const_cast",
+    ReinterpretCast => "reinterpret_cast",
+    Index => "index",
+    Ptrmember => "ptrmember",
+    Member => "member",
+    MemberName => "member_name",
+    CMemberName => "#member_name",
+    Equal => "=",
+    Implies => "=>",
+    And => "and",
+    Nand => "nand",
+    Or => "or",
+    Nor => "nor",
+    Xor => "xor",
+    Not => "not",
+    Bitand => "bitand",
+    Bitor => "bitor",
+    Bitnot => "bitnot",
+    Bitxor => "bitxor",
+    Bitnand => "bitnand",
+    Bitnor => "bitnor",
+    Bitxnor => "bitxnor",
+    Notequal => "notequal",
+    If => "if",
+    Symbol => "symbol",
+    NextSymbol => "next_symbol",
+    NondetSymbol => "nondet_symbol",
+    PredicateSymbol => "predicate_symbol",
+    PredicateNextSymbol => "predicate_next_symbol",
+    NondetBool => "nondet_bool",
+    Empty => "empty",
+    SideEffect => "side_effect",
+    Statement => "statement",
+    StatementExpression => "statement_expression",
+    Value => "value",
+    Constant => "constant",
+    Block => "block",
+    Decl => "decl",
+    Dead => "dead",
+    Assign => "assign",
+    AssignDiv => "assign_div",
+    AssignMult => "assign*",
+    AssignPlus => "assign+",
+    AssignMinus => "assign-",
+    AssignMod => "assign_mod",
+    AssignShl => "assign_shl",
+    AssignShr => "assign_shr",
+    AssignAshr => "assign_ashr",
+    AssignLshr => "assign_lshr",
+    AssignBitand => "assign_bitand",
+    AssignBitxor => "assign_bitxor",
+    AssignBitor => "assign_bitor",
+    Assume => "assume",
+    Assert => "assert",
+    Assertion => "assertion",
+    Precondition => "precondition",
+    Postcondition => "postcondition",
+    PreconditionInstance => "precondition_instance",
+    Goto => "goto",
+    GccComputedGoto => "gcc_computed_goto",
+    Ifthenelse => "ifthenelse",
+    Label => "label",
+    Break => "break",
+    Continue => "continue",
+    FunctionCall => "function_call",
+    Return => "return",
+    Skip => "skip",
+    Arguments => "arguments",
+    Array => "array",
+    Size => "size",
+    FrontendPointer => "frontend_pointer",
+    Pointer => "pointer",
+    BlockPointer => "block_pointer",
+    Switch => "switch",
+    SwitchCase => "switch_case",
+    GccSwitchCaseRange => "gcc_switch_case_range",
+    For => "for",
+    While => "while",
+    Dowhile => "dowhile",
+    Int => "int",
+    Integer => "integer",
+    Natural => "natural",
+    Real => "real",
+    Rational => "rational",
+    Complex => "complex",
+    Signed => "signed",
+    Unsigned => "unsigned",
+    Asm => "asm",
+    GccAsmInput => "gcc_asm_input",
+    GccAsmOutput => "gcc_asm_output",
+    GccAsmClobberedRegister => "gcc_asm_clobbered_register",
+    Incomplete => "incomplete",
+    IncompleteClass => "incomplete_class",
+    CIncomplete => "#incomplete",
+    Identifier => "identifier",
+    Name => "name",
+    InnerName => "inner_name",
+    CppName => "cpp_name",
+    ComponentCppName => "component_cpp_name",
+    CIdClass => "#id_class",
+    Declaration => "declaration",
+    DeclarationList => "declaration_list",
+    Declarator => "declarator",
+    Struct => "struct",
+    CBitField => "c_bit_field",
+    Union => "union",
+    Class => "class",
+    MergedType => "merged_type",
+    Range => "range",
+    From => "from",
+    To => "to",
+    Module => "module",
+    Parameter => "parameter",
+    ComponentName => "component_name",
+    ComponentNumber => "component_number",
+    Tag => "tag",
+    Default => "default",
+    CDefaultValue => "#default_value",
+    BaseName => "base_name",
+    CBaseName => "#base_name",
+    String => "string",
+    CStringConstant => "#string_constant",
+    StringConstant => "string_constant",
+    Width => "width",
+    Components => "components",
+    Bv => "bv",
+    F => "f",
+    With => "with",
+    Trans => "trans",
+    Throw => "throw",
+    TryCatch => "try_catch",
+    Noexcept => "noexcept",
+    CPROVERThrow => "CPROVER_throw",
+    CPROVERTryCatch => "CPROVER_try_catch",
+    CPROVERTryFinally => "CPROVER_try_finally",
+    Protection => "protection",
+    Private => "private",
+    Public => "public",
+    Protected => "protected",
+    Virtual => "virtual",
+    Volatile => "volatile",
+    Const => "const",
+    Constexpr => "constexpr",
+    Inline => "inline",
+    Forall => "forall",
+    Exists => "exists",
+    Repeat => "repeat",
+    Extractbit => "extractbit",
+    Extractbits => "extractbits",
+    CReference => "#reference",
+    CRvalueReference => "#rvalue_reference",
+    True => "true",
+    False => "false",
+    AddressOf => "address_of",
+    Dereference => "dereference",
+    CLvalue => "#lvalue",
+    CBase => "#base",
+    Destination => "destination",
+    Main => "main",
+    Expression => "expression",
+    Allocate => "allocate",
+    CCxxAllocType => "#cxx_alloc_type",
+    CppNew => "cpp_new",
+    CppDelete => "cpp_delete",
+    CppNewArray => "cpp_new[]",
+    CppDeleteArray => "cpp_delete[]",
+    JavaNew => "java_new",
+    JavaNewArray => "java_new_array",
+    JavaNewArrayData => "java_new_array_data",
+    JavaStringLiteral => "java_string_literal",
+    Printf => "printf",
+    Input => "input",
+    Output => "output",
+    Nondet => "nondet",
+    NULL => "NULL",
+    Null => "null",
+    Nullptr => "nullptr",
+    CEnum => "c_enum",
+    Enumeration => "enumeration",
+    Elements => "elements",
+    Unknown => "unknown",
+    Uninitialized => "uninitialized",
+    Invalid => "invalid",
+    CInvalidObject => "#invalid_object",
+    PointerOffset => "pointer_offset",
+    PointerObject => "pointer_object",
+    IsInvalidPointer => "is_invalid_pointer",
+    IeeeFloatEqual => "ieee_float_equal",
+    IeeeFloatNotequal => "ieee_float_notequal",
+    Isnan => "isnan",
+    Lambda => "lambda",
+    ArrayComprehension => "array_comprehension",
+    ArrayOf => "array_of",
+    ArrayEqual => "array_equal",
+    ArraySet => "array_set",
+    ArrayCopy => "array_copy",
+    ArrayList => "array_list",
+    Mod => "mod",
+    Rem => "rem",
+    Shr => "shr",
+    Ashr => "ashr",
+    Lshr => "lshr",
+    Shl => "shl",
+    Rol => "rol",
+    Ror => "ror",
+    Comma => "comma",
+    Concatenation => "concatenation",
+    Infinity => "infinity",
+    ReturnType => "return_type",
+    Typedef => "typedef",
+    TypedefType => "typedef_type",
+    CTypedef => "#typedef",
+    Extern => "extern",
+    Static => "static",
+    Auto => "auto",
+    Register => "register",
+    ThreadLocal => "thread_local",
+    Thread => "thread",
+    CThreadLocal => "#thread_local",
+    CStaticLifetime => "#static_lifetime",
+    Mutable => "mutable",
+    Void => "void",
+    Int8 => "int8",
+    Int16 => "int16",
+    Int32 => "int32",
+    Int64 => "int64",
+    Ptr32 => "ptr32",
+    Ptr64 => "ptr64",
+    Char => "char",
+    Short => "short",
+    Long => "long",
+    Float => "float",
+    Float16 => "float16",
+    Float128 => "float128",
+    Double => "double",
+    Byte => "byte",
+    Boolean => "boolean",
+    LongDouble => "long_double",
+    SignedChar => "signed_char",
+    UnsignedChar => "unsigned_char",
+    SignedInt => "signed_int",
+    UnsignedInt => "unsigned_int",
+    SignedLongInt => "signed_long_int",
+    UnsignedLongInt => "unsigned_long_int",
+    SignedShortInt => "signed_short_int",
+    UnsignedShortInt => "unsigned_short_int",
+    SignedLongLongInt => "signed_long_long_int",
+    UnsignedLongLongInt => "unsigned_long_long_int",
+    SignedInt128 => "signed_int128",
+    UnsignedInt128 => "unsigned_int128",
+    Case => "case",
+    CInlined => "#inlined",
+    CHide => "#hide",
+    Hide => "hide",
+    Abs => "abs",
+    Sign => "sign",
+    Access => "access",
+    CAccess => "#access",
+    Postincrement => "postincrement",
+    Postdecrement => "postdecrement",
+    Preincrement => "preincrement",
+    Predecrement => "predecrement",
+    IntegerBits => "integer_bits",
+    KnR => "KnR",
+    CKnR => "#KnR",
+    ConstraintSelectOne => "constraint_select_one",
+    Cond => "cond",
+    BvLiterals => "bv_literals",
+    IsFinite => "isfinite",
+    Isinf => "isinf",
+    Isnormal => "isnormal",
+    Alignof => "alignof",
+    ClangBuiltinConvertvector => "clang_builtin_convertvector",
+    GccBuiltinVaArg => "gcc_builtin_va_arg",
+    GccBuiltinTypesCompatibleP => "gcc_builtin_types_compatible_p",
+    VaStart => "va_start",
+    GccFloat16 => "gcc_float16",
+    GccFloat32 => "gcc_float32",
+    GccFloat32x => "gcc_float32x",
+    GccFloat64 => "gcc_float64",
+    GccFloat64x => "gcc_float64x",
+    GccFloat80 => "gcc_float80",
+    GccFloat128 => "gcc_float128",
+    GccFloat128x => "gcc_float128x",
+    GccInt128 => "gcc_int128",
+    GccDecimal32 => "gcc_decimal32",
+    GccDecimal64 => "gcc_decimal64",
+    GccDecimal128 => "gcc_decimal128",
+    BuiltinOffsetof => "builtin_offsetof",
+    Id0 => "0",
+    Id1 => "1",
+    Sizeof => "sizeof",
+    TypeArg => "type_arg",
+    ExprArg => "expr_arg",
+    ExpressionList => "expression_list",
+    InitializerList => "initializer_list",
+    GccConditionalExpression => "gcc_conditional_expression",
+    GccLocalLabel => "gcc_local_label",
+    Gcc => "gcc",
+    Msc => "msc",
+    Typeof => "typeof",
+    Ellipsis => "ellipsis",
+    Flavor => "flavor",
+    Ge => ">=",
+    Le => "<=",
+    Gt => ">",
+    Lt => "<",
+    Plus => "+",
+    Minus => "-",
+    UnaryMinus => "unary-",
+    UnaryPlus => "unary+",
+    Mult => "*",
+    Div => "/",
+    Power => "**",
+    FactorialPower => "factorial_power",
+    CPrettyName => "#pretty_name",
+    CClass => "#class",
+    CField => "#field",
+    CInterface => "#interface",
+    DesignatedInitializer => "designated_initializer",
+    Designator => "designator",
+    MemberDesignator => "member_designator",
+    IndexDesignator => "index_designator",
+    CConstant => "#constant",
+    CVolatile => "#volatile",
+    CRestricted => "#restricted",
+    CIdentifier => "#identifier",
+    CImplicit => "#implicit",
+    CPtr32 => "#ptr32",
+    CPtr64 => "#ptr64",
+    CAtomic => "#atomic",
+    Restrict => "restrict",
+    ByteExtractBigEndian => "byte_extract_big_endian",
+    ByteExtractLittleEndian => "byte_extract_little_endian",
+    ByteUpdateBigEndian => "byte_update_big_endian",
+    ByteUpdateLittleEndian => "byte_update_little_endian",
+    Replication => "replication",
+    CproverAtomic => "cprover_atomic",
+    Atomic => "atomic",
+    AtomicTypeSpecifier => "atomic_type_specifier",
+    AtomicBegin => "atomic_begin",
+    AtomicEnd => "atomic_end",
+    StartThread => "start_thread",
+    EndThread => "end_thread",
+    CoverageCriterion => "coverage_criterion",
+    Initializer => "initializer",
+    Anonymous => "anonymous",
+    CIsAnonymous => "#is_anonymous",
+    IsEnumConstant => "is_enum_constant",
+    IsInline => "is_inline",
+    IsExtern => "is_extern",
+    IsSynchronized => "is_synchronized",
+    IsNativeMethod => "is_native_method",
+    IsVarargsMethod => "is_varargs_method",
+    IsGlobal => "is_global",
+    IsThreadLocal => "is_thread_local",
+    IsParameter => "is_parameter",
+    IsMember => "is_member",
+    IsType => "is_type",
+    IsRegister => "is_register",
+    IsTypedef => "is_typedef",
+    IsStatic => "is_static",
+    IsTemplate => "is_template",
+    IsStaticAssert => "is_static_assert",
+    IsVirtual => "is_virtual",
+    CIsVirtual => "#is_virtual",
+    Literal => "literal",
+    MemberInitializers => "member_initializers",
+    MemberInitializer => "member_initializer",
+    MethodQualifier => "method_qualifier",
+    Methods => "methods",
+    StaticMembers => "static_members",
+    Constructor => "constructor",
+    Destructor => "destructor",
+    Bases => "bases",
+    Base => "base",
+    FromBase => "from_base",
+    Operator => "operator",
+    Template => "template",
+    TemplateClassInstance => "template_class_instance",
+    TemplateFunctionInstance => "template_function_instance",
+    TemplateType => "template_type",
+    TemplateArgs => "template_args",
+    TemplateParameter => "template_parameter",
+    TemplateParameterSymbolType => "template_parameter_symbol_type",
+    TemplateParameters => "template_parameters",
+    CTemplate => "#template",
+    CTemplateArguments => "#template_arguments",
+    CTemplateCase => "#template_case",
+    Typename => "typename",
+    C => "C",
+    Cpp => "cpp",
+    Java => "java",
+    DeclBlock => "decl_block",
+    DeclType => "decl_type",
+    Parameters => "parameters",
+    WcharT => "wchar_t",
+    Char16T => "char16_t",
+    Char32T => "char32_t",
+    SizeT => "size_t",
+    SsizeT => "ssize_t",
+    Mode => "mode",
+    This => "this",
+    CThis => "#this",
+    ReductionAnd => "reduction_and",
+    ReductionOr => "reduction_or",
+    ReductionNand => "reduction_nand",
+    ReductionNor => "reduction_nor",
+    ReductionXor => "reduction_xor",
+    ReductionXnor => "reduction_xnor",
+    CZeroInitializer => "#zero_initializer",
+    Body => "body",
+    TemporaryObject => "temporary_object",
+    OverflowPlus => "overflow-+",
+    OverflowMinus => "overflow--",
+    OverflowMult => "overflow-*",
+    OverflowResultPlus => "overflow_result-+",
+    OverflowResultMinus => "overflow_result--",
+    OverflowResultMult => "overflow_result-*",
+    OverflowUnaryMinus => "overflow-unary-",
+    ObjectDescriptor => "object_descriptor",
+    IsDynamicObject => "is_dynamic_object",
+    DynamicObject => "dynamic_object",
+    CDynamic => "#dynamic",
+    ObjectSize => "object_size",
+    GoodPointer => "good_pointer",
+    IntegerAddress => "integer_address",
+    IntegerAddressObject => "integer_address_object",
+    NullObject => "NULL-object",
+    StaticObject => "static_object",
+    StackObject => "stack_object",
+    CIsFailedSymbol => "#is_failed_symbol",
+    CFailedSymbol => "#failed_symbol",
+    Friend => "friend",
+    CFriends => "#friends",
+    Explicit => "explicit",
+    StorageSpec => "storage_spec",
+    MemberSpec => "member_spec",
+    MscDeclspec => "msc_declspec",
+    Packed => "packed",
+    CPacked => "#packed",
+    TransparentUnion => "transparent_union",
+    CTransparentUnion => "#transparent_union",
+    Aligned => "aligned",
+    CAlignment => "#alignment",
+    FrontendVector => "frontend_vector",
+    Vector => "vector",
+    Abstract => "abstract",
+    FunctionApplication => "function_application",
+    CppDeclarator => "cpp_declarator",
+    CppLinkageSpec => "cpp_linkage_spec",
+    CppNamespaceSpec => "cpp_namespace_spec",
+    CppStorageSpec => "cpp_storage_spec",
+    CppUsing => "cpp_using",
+    CppDeclaration => "cpp_declaration",
+    CppStaticAssert => "cpp_static_assert",
+    CppMemberSpec => "cpp_member_spec",
+    CCType => "#c_type",
+    Namespace => "namespace",
+    Linkage => "linkage",
+    Decltype => "decltype",
+    CTagOnlyDeclaration => "#tag_only_declaration",
+    StructTag => "struct_tag",
+    UnionTag => "union_tag",
+    CEnumTag => "c_enum_tag",
+    VerilogCaseEquality => "verilog_case_equality",
+    VerilogCaseInequality => "verilog_case_inequality",
+    UserSpecifiedPredicate => "user_specified_predicate",
+    UserSpecifiedParameterPredicates => "user_specified_parameter_predicates",
+    UserSpecifiedReturnPredicates => "user_specified_return_predicates",
+    Unassigned => "unassigned",
+    NewObject => "new_object",
+    ComplexReal => "complex_real",
+    ComplexImag => "complex_imag",
+    Imag => "imag",
+    MscTryExcept => "msc_try_except",
+    MscTryFinally => "msc_try_finally",
+    MscLeave => "msc_leave",
+    MscUuidof => "msc_uuidof",
+    MscIfExists => "msc_if_exists",
+    MscIfNotExists => "msc_if_not_exists",
+    MscUnderlyingType => "msc_underlying_type",
+    MscBased => "msc_based",
+    Alias => "alias",
+    PtrObject => "ptr_object",
+    CCSizeofType => "#c_sizeof_type",
+    ArrayUpdate => "array_update",
+    Update => "update",
+    StaticAssert => "static_assert",
+    GccAttributeMode => "gcc_attribute_mode",
+    BuiltIn => "<built-in>",
+    ExceptionList => "exception_list",
+    ExceptionId => "exception_id",
+    PredicatePassiveSymbol => "predicate_passive_symbol",
+    CwVaArgTypeof => "cw_va_arg_typeof",
+    Fence => "fence",
+    Sync => "sync",
+    Lwsync => "lwsync",
+    Isync => "isync",
+    WRfence => "WRfence",
+    RRfence => "RRfence",
+    RWfence => "RWfence",
+    WWfence => "WWfence",
+    RRcumul => "RRcumul",
+    RWcumul => "RWcumul",
+    WWcumul => "WWcumul",
+    WRcumul => "WRcumul",
+    GenericSelection => "generic_selection",
+    GenericAssociations => "generic_associations",
+    GenericAssociation => "generic_association",
+    FloatbvPlus => "floatbv_plus",
+    FloatbvMinus => "floatbv_minus",
+    FloatbvMult => "floatbv_mult",
+    FloatbvDiv => "floatbv_div",
+    FloatbvRem => "floatbv_rem",
+    FloatbvTypecast => "floatbv_typecast",
+    CompoundLiteral => "compound_literal",
+    CustomBv => "custom_bv",
+    CustomUnsignedbv => "custom_unsignedbv",
+    CustomSignedbv => "custom_signedbv",
+    CustomFixedbv => "custom_fixedbv",
+    CustomFloatbv => "custom_floatbv",
+    CSSASymbol => "#SSA_symbol",
+    L0 => "L0",
+    L1 => "L1",
+    L2 => "L2",
+    L1ObjectIdentifier => "L1_object_identifier",
+    AlreadyTypechecked => "already_typechecked",
+    CVaArgType => "#va_arg_type",
+    Smt2Symbol => "smt2_symbol",
+    Onehot => "onehot",
+    Onehot0 => "onehot0",
+    Popcount => "popcount",
+    CountLeadingZeros => "count_leading_zeros",
+    CountTrailingZeros => "count_trailing_zeros",
+    EmptyUnion => "empty_union",
+    FunctionType => "function_type",
+    Noreturn => "noreturn",
+    CNoreturn => "#noreturn",
+    Weak => "weak",
+    IsWeak => "is_weak",
+    Used => "used",
+    IsUsed => "is_used",
+    CSpecLoopInvariant => "#spec_loop_invariant",
+    CSpecRequires => "#spec_requires",
+    CSpecEnsures => "#spec_ensures",
+    CSpecAssigns => "#spec_assigns",
+    VirtualFunction => "virtual_function",
+    ElementType => "element_type",
+    WorkingDirectory => "working_directory",
+    Section => "section",
+    Bswap => "bswap",
+    BitReverse => "bitreverse",
+    JavaBytecodeIndex => "java_bytecode_index",
+    JavaInstanceof => "java_instanceof",
+    JavaSuperMethodCall => "java_super_method_call",
+    JavaEnumStaticUnwind => "java_enum_static_unwind",
+    PushCatch => "push_catch",
+    PopCatch => "pop_catch",
+    ExceptionLandingpad => "exception_landingpad",
+    LengthUpperBound => "length_upper_bound",
+    CproverAssociateArrayToPointerFunc => "cprover_associate_array_to_pointer_func",
+    CproverAssociateLengthToArrayFunc => "cprover_associate_length_to_array_func",
+    CproverCharLiteralFunc => "cprover_char_literal_func",
+    CproverStringLiteralFunc => "cprover_string_literal_func",
+    CproverStringCharAtFunc => "cprover_string_char_at_func",
+    CproverStringCharSetFunc => "cprover_string_char_set_func",
+    CproverStringCodePointAtFunc => "cprover_string_code_point_at_func",
+    CproverStringCodePointBeforeFunc => "cprover_string_code_point_before_func",
+    CproverStringCodePointCountFunc => "cprover_string_code_point_count_func",
+    CproverStringOffsetByCodePointFunc => "cprover_string_offset_by_code_point_func",
+    CproverStringCompareToFunc => "cprover_string_compare_to_func",
+    CproverStringConcatFunc => "cprover_string_concat_func",
+    CproverStringConcatCharFunc => "cprover_string_concat_char_func",
+    CproverStringConcatCodePointFunc => "cprover_string_concat_code_point_func",
+    CproverStringConstrainCharactersFunc => "cprover_string_constrain_characters_func",
+    CproverStringContainsFunc => "cprover_string_contains_func",
+    CproverStringCopyFunc => "cprover_string_copy_func",
+    CproverStringDeleteFunc => "cprover_string_delete_func",
+    CproverStringDeleteCharAtFunc => "cprover_string_delete_char_at_func",
+    CproverStringEqualFunc => "cprover_string_equal_func",
+    CproverStringEqualsIgnoreCaseFunc => "cprover_string_equals_ignore_case_func",
+    CproverStringEmptyStringFunc => "cprover_string_empty_string_func",
+    CproverStringEndswithFunc => "cprover_string_endswith_func",
+    CproverStringFormatFunc => "cprover_string_format_func",
+    CproverStringIndexOfFunc => "cprover_string_index_of_func",
+    CproverStringInsertFunc => "cprover_string_insert_func",
+    CproverStringIsPrefixFunc => "cprover_string_is_prefix_func",
+    CproverStringIsSuffixFunc => "cprover_string_is_suffix_func",
+    CproverStringIsEmptyFunc => "cprover_string_is_empty_func",
+    CproverStringLastIndexOfFunc => "cprover_string_last_index_of_func",
+    CproverStringLengthFunc => "cprover_string_length_func",
+    CproverStringOfIntFunc => "cprover_string_of_int_func",
+    CproverStringOfIntHexFunc => "cprover_string_of_int_hex_func",
+    CproverStringOfLongFunc => "cprover_string_of_long_func",
+    CproverStringOfFloatFunc => "cprover_string_of_float_func",
+    CproverStringOfFloatScientificNotationFunc => "cprover_string_of_float_scientific_notation_func",
+    CproverStringOfDoubleFunc => "cprover_string_of_double_func",
+    CproverStringParseIntFunc => "cprover_string_parse_int_func",
+    CproverStringIsValidIntFunc => "cprover_string_is_valid_int_func",
+    CproverStringIsValidLongFunc => "cprover_string_is_valid_long_func",
+    CproverStringReplaceFunc => "cprover_string_replace_func",
+    CproverStringSetLengthFunc => "cprover_string_set_length_func",
+    CproverStringStartswithFunc => "cprover_string_startswith_func",
+    CproverStringSubstringFunc => "cprover_string_substring_func",
+    CproverStringToLowerCaseFunc => "cprover_string_to_lower_case_func",
+    CproverStringToUpperCaseFunc => "cprover_string_to_upper_case_func",
+    CproverStringTrimFunc => "cprover_string_trim_func",
+    SkipInitialize => "skip_initialize",
+    BasicBlockCoveredLines => "basic_block_covered_lines",
+    BasicBlockSourceLines => "basic_block_source_lines",
+    IsNondetNullable => "is_nondet_nullable",
+    ArrayReplace => "array_replace",
+    SwitchCaseNumber => "switch_case_number",
+    JavaArrayAccess => "java_array_access",
+    JavaMemberAccess => "java_member_access",
+    CJavaGenericParameter => "#java_generic_parameter",
+    CJavaGenericsClassType => "#java_generics_class_type",
+    CJavaImplicitlyGenericClassType => "#java_implicitly_generic_class_type",
+    CJavaGenericSymbol => "#java_generic_symbol",
+    GenericTypes => "generic_types",
+    ImplicitGenericTypes => "#implicit_generic_types",
+    TypeVariables => "type_variables",
+    HandleType => "handle_type",
+    JavaLambdaMethodHandle => "java_lambda_method_handle",
+    JavaLambdaMethodHandleIndex => "lambda_method_handle_index",
+    JavaLambdaMethodHandles => "lambda_method_handles",
+    HavocObject => "havoc_object",
+    OverflowShl => "overflow-shl",
+    CNoInitializationRequired => "#no_initialization_required",
+    CNoNondetInitialization => "#no_nondet_initialization",
+    OverlayClass => "java::org.cprover.OverlayClassImplementation",
+    OverlayMethod => "java::org.cprover.OverlayMethodImplementation",
+    IgnoredMethod => "java::org.cprover.IgnoredMethodImplementation",
+    IsAnnotation => "is_annotation",
+    CAnnotations => "#annotations",
+    Final => "final",
+    BitsPerByte => "bits_per_byte",
+    CAbstract => "#abstract",
+    Synthetic => "synthetic",
+    Interface => "interface",
+    CMustNotThrow => "#must_not_throw",
+    IsInnerClass => "is_inner_class",
+    IsAnonymous => "is_anonymous",
+    OuterClass => "outer_class",
+    IsBridgeMethod => "is_bridge_method",
+    CIsOperator => "#is_operator",
+    CNotAccessible => "#not_accessible",
+    COverrideConstantness => "#override_constantness",
+    CBound => "#bound",
+    CBoundsCheck => "#bounds_check",
+    CIsStatic => "#is_static",
+    CCallByValue => "#call_by_value",
+    CVirtualName => "#virtual_name",
+    CUnnamedObject => "#unnamed_object",
+    CTemporaryAvoided => "#temporary_avoided",
+    CQualifier => "#qualifier",
+    CArrayIni => "#array_ini",
+    ROk => "r_ok",
+    WOk => "w_ok",
+    SuperClass => "super_class",
+    ExceptionsThrownList => "exceptions_thrown_list",
+    CJavaMethodType => "#java_method_type",
+    Compiled => "compiled",
+    PartialSpecializationArgs => "partial_specialization_args",
+    SpecializationOf => "specialization_of",
+    InitArgs => "init_args",
+    Ambiguous => "ambiguous",
+    SpecializationTemplateArgs => "specialization_template_args",
+    FullTemplateArgs => "full_template_args",
+    InstantiatedWith => "instantiated_with",
+    TemplateMethods => "template_methods",
+    CppNotTypechecked => "cpp_not_typechecked",
+    Noaccess => "noaccess",
+    IsOperator => "is_operator",
+    IsCastOperator => "is_cast_operator",
+    IsExplicit => "is_explicit",
+    IsMutable => "is_mutable",
+    VirtualName => "virtual_name",
+    IsPureVirtual => "is_pure_virtual",
+    IsVtptr => "is_vtptr",
+    Prefix => "prefix",
+    Cv => "cv",
+    CppDummyDestructor => "cpp_dummy_destructor",
+    CastExpression => "cast_expression",
+    PodConstructor => "pod_constructor",
+    TemplateDecls => "template_decls",
+    ThrowDecl => "throw_decl",
+    Typeid => "typeid",
+    CQuoted => "#quoted",
+    ToMember => "to_member",
+    PointerToMember => "pointer_to_member",
+    Tuple => "tuple",
+    FunctionBody => "function_body",
+    GetMay => "get_may",
+    SetMay => "set_may",
+    ClearMay => "clear_may",
+    GetMust => "get_must",
+    SetMust => "set_must",
+    ClearMust => "clear_must",
+    Pragma => "pragma",
+    StatementList => "Statement List",
+    StatementListType => "statement_list_type",
+    StatementListFunction => "statement_list_function",
+    StatementListFunctionBlock => "statement_list_function_block",
+    StatementListMainFunction => "Main",
+    StatementListDataBlock => "statement_list_data_block",
+    StatementListVersion => "statement_list_version",
+    StatementListVarInput => "statement_list_var_input",
+    StatementListVarInout => "statement_list_var_inout",
+    StatementListVarOutput => "statement_list_var_output",
+    StatementListVarConstant => "statement_list_var_constant",
+    StatementListVarTemp => "statement_list_var_temp",
+    StatementListVarStatic => "statement_list_var_static",
+    StatementListReturn => "statement_list_return",
+    StatementListReturnValueId => "Ret_Val",
+    StatementListVarEntry => "statement_list_var_entry",
+    StatementListVarDecls => "statement_list_var_decls",
+    StatementListNetwork => "statement_list_network",
+    StatementListNetworks => "statement_list_networks",
+    StatementListTitle => "statement_list_title",
+    StatementListIdentifier => "statement_list_identifier",
+    StatementListLoad => "statement_list_load",
+    StatementListTransfer => "statement_list_transfer",
+    StatementListCall => "statement_list_call",
+    StatementListNop => "statement_list_nop",
+    StatementListConstAdd => "statement_list_const_add",
+    StatementListAccuIntAdd => "statement_list_accu_int_add",
+    StatementListAccuIntSub => "statement_list_accu_int_sub",
+    StatementListAccuIntMul => "statement_list_accu_int_mul",
+    StatementListAccuIntDiv => "statement_list_accu_int_div",
+    StatementListAccuIntEq => "statement_list_accu_int_eq",
+    StatementListAccuIntNeq => "statement_list_accu_int_neq",
+    StatementListAccuIntGt => "statement_list_accu_int_gt",
+    StatementListAccuIntLt => "statement_list_accu_int_lt",
+    StatementListAccuIntGte => "statement_list_accu_int_gte",
+    StatementListAccuIntLte => "statement_list_accu_int_lte",
+    StatementListAccuRealAdd => "statement_list_accu_real_add",
+    StatementListAccuRealSub => "statement_list_accu_real_sub",
+    StatementListAccuRealMul => "statement_list_accu_real_mul",
+    StatementListAccuRealDiv => "statement_list_accu_real_div",
+    StatementListAccuRealEq => "statement_list_accu_real_eq",
+    StatementListAccuRealNeq => "statement_list_accu_real_neq",
+    StatementListAccuRealGt => "statement_list_accu_real_gt",
+    StatementListAccuRealLt => "statement_list_accu_real_lt",
+    StatementListAccuRealGte => "statement_list_accu_real_gte",
+    StatementListAccuRealLte => "statement_list_accu_real_lte",
+    StatementListAccuDintAdd => "statement_list_accu_dint_add",
+    StatementListAccuDintSub => "statement_list_accu_dint_sub",
+    StatementListAccuDintMul => "statement_list_accu_dint_mul",
+    StatementListAccuDintDiv => "statement_list_accu_dint_div",
+    StatementListAccuDintEq => "statement_list_accu_dint_eq",
+    StatementListAccuDintNeq => "statement_list_accu_dint_neq",
+    StatementListAccuDintGt => "statement_list_accu_dint_gt",
+    StatementListAccuDintLt => "statement_list_accu_dint_lt",
+    StatementListAccuDintGte => "statement_list_accu_dint_gte",
+    StatementListAccuDintLte => "statement_list_accu_dint_lte",
+    StatementListAnd => "statement_list_and",
+    StatementListAndNot => "statement_list_and_not",
+    StatementListOr => "statement_list_or",
+    StatementListOrNot => "statement_list_or_not",
+    StatementListXor => "statement_list_xor",
+    StatementListXorNot => "statement_list_xor_not",
+    StatementListAndNested => "statement_list_and_nested",
+    StatementListAndNotNested => "statement_list_and_not_nested",
+    StatementListOrNested => "statement_list_or_nested",
+    StatementListOrNotNested => "statement_list_or_not_nested",
+    StatementListXorNested => "statement_list_xor_nested",
+    StatementListXorNotNested => "statement_list_xor_not_nested",
+    StatementListNestingClosed => "statement_list_nesting_closed",
+    StatementListAssign => "statement_list_assign",
+    StatementListSetRlo => "statement_list_set_rlo",
+    StatementListClrRlo => "statement_list_clr_rlo",
+    StatementListSet => "statement_list_set",
+    StatementListReset => "statement_list_reset",
+    StatementListNot => "statement_list_not",
+    StatementListInstruction => "statement_list_instruction",
+    StatementListInstructions => "statement_list_instructions",
+    VectorEqual => "vector-=",
+    VectorNotequal => "vector-!=",
+    VectorGe => "vector->=",
+    VectorLe => "vector-<=",
+    VectorGt => "vector->",
+    VectorLt => "vector-<",
+    FloatbvRoundToIntegral => "floatbv_round_to_integral",
+    ShuffleVector => "shuffle_vector",
 }
 
 impl IrepId {
@@ -903,838 +939,6 @@ fn to_owned_string(&self) -> Option<String> {
             _ => None,
         }
     }
-
-    fn to_static_string(&self) -> &'static str {
-        match self {
-            IrepId::FreeformString(_)
-            | IrepId::FreeformInteger(_)
-            | IrepId::FreeformBitPattern { .. } => unreachable!(),
-            IrepId::EmptyString => "",
-            IrepId::Let => "let",
-            IrepId::LetBinding => "let_binding",
-            IrepId::Nil => "nil",
-            IrepId::Type => "type",
-            IrepId::Bool => "bool",
-            IrepId::CBool => "c_bool",
-            IrepId::ProperBool => "proper_bool",
-            IrepId::Signedbv => "signedbv",
-            IrepId::Unsignedbv => "unsignedbv",
-            IrepId::VerilogSignedbv => "verilog_signedbv",
-            IrepId::VerilogUnsignedbv => "verilog_unsignedbv",
-            IrepId::Floatbv => "floatbv",
-            IrepId::Fixedbv => "fixedbv",
-            IrepId::X86Extended => "x86_extended",
-            IrepId::CSourceLocation => "#source_location",
-            IrepId::CEndLocation => "#end_location",
-            IrepId::CIsPadding => "#is_padding",
-            IrepId::CDoNotDump => "C_do_not_dump",
-            IrepId::File => "file",
-            IrepId::Line => "line",
-            IrepId::Column => "column",
-            IrepId::Comment => "comment",
-            IrepId::Property => "property",
-            IrepId::PropertyClass => "property_class",
-            IrepId::PropertyId => "property_id",
-            IrepId::Function => "function",
-            IrepId::MathematicalFunction => "mathematical_function",
-            IrepId::Code => "code",
-            IrepId::Typecast => "typecast",
-            IrepId::StaticCast => "static_cast",
-            IrepId::DynamicCast => "dynamic_cast",
-            IrepId::ConstCast => "const_cast",
-            IrepId::ReinterpretCast => "reinterpret_cast",
-            IrepId::Index => "index",
-            IrepId::Ptrmember => "ptrmember",
-            IrepId::Member => "member",
-            IrepId::MemberName => "member_name",
-            IrepId::CMemberName => "#member_name",
-            IrepId::Equal => "=",
-            IrepId::Implies => "=>",
-            IrepId::And => "and",
-            IrepId::Nand => "nand",
-            IrepId::Or => "or",
-            IrepId::Nor => "nor",
-            IrepId::Xor => "xor",
-            IrepId::Not => "not",
-            IrepId::Bitand => "bitand",
-            IrepId::Bitor => "bitor",
-            IrepId::Bitnot => "bitnot",
-            IrepId::Bitxor => "bitxor",
-            IrepId::Bitnand => "bitnand",
-            IrepId::Bitnor => "bitnor",
-            IrepId::Bitxnor => "bitxnor",
-            IrepId::Notequal => "notequal",
-            IrepId::If => "if",
-            IrepId::Symbol => "symbol",
-            IrepId::NextSymbol => "next_symbol",
-            IrepId::NondetSymbol => "nondet_symbol",
-            IrepId::PredicateSymbol => "predicate_symbol",
-            IrepId::PredicateNextSymbol => "predicate_next_symbol",
-            IrepId::NondetBool => "nondet_bool",
-            IrepId::Empty => "empty",
-            IrepId::SideEffect => "side_effect",
-            IrepId::Statement => "statement",
-            IrepId::StatementExpression => "statement_expression",
-            IrepId::Value => "value",
-            IrepId::Constant => "constant",
-            IrepId::Block => "block",
-            IrepId::Decl => "decl",
-            IrepId::Dead => "dead",
-            IrepId::Assign => "assign",
-            IrepId::AssignDiv => "assign_div",
-            IrepId::AssignMult => "assign*",
-            IrepId::AssignPlus => "assign+",
-            IrepId::AssignMinus => "assign-",
-            IrepId::AssignMod => "assign_mod",
-            IrepId::AssignShl => "assign_shl",
-            IrepId::AssignShr => "assign_shr",
-            IrepId::AssignAshr => "assign_ashr",
-            IrepId::AssignLshr => "assign_lshr",
-            IrepId::AssignBitand => "assign_bitand",
-            IrepId::AssignBitxor => "assign_bitxor",
-            IrepId::AssignBitor => "assign_bitor",
-            IrepId::Assume => "assume",
-            IrepId::Assert => "assert",
-            IrepId::Assertion => "assertion",
-            IrepId::Precondition => "precondition",
-            IrepId::Postcondition => "postcondition",
-            IrepId::PreconditionInstance => "precondition_instance",
-            IrepId::Goto => "goto",
-            IrepId::GccComputedGoto => "gcc_computed_goto",
-            IrepId::Ifthenelse => "ifthenelse",
-            IrepId::Label => "label",
-            IrepId::Break => "break",
-            IrepId::Continue => "continue",
-            IrepId::FunctionCall => "function_call",
-            IrepId::Return => "return",
-            IrepId::Skip => "skip",
-            IrepId::Arguments => "arguments",
-            IrepId::Array => "array",
-            IrepId::Size => "size",
-            IrepId::FrontendPointer => "frontend_pointer",
-            IrepId::Pointer => "pointer",
-            IrepId::BlockPointer => "block_pointer",
-            IrepId::Switch => "switch",
-            IrepId::SwitchCase => "switch_case",
-            IrepId::GccSwitchCaseRange => "gcc_switch_case_range",
-            IrepId::For => "for",
-            IrepId::While => "while",
-            IrepId::Dowhile => "dowhile",
-            IrepId::Int => "int",
-            IrepId::Integer => "integer",
-            IrepId::Natural => "natural",
-            IrepId::Real => "real",
-            IrepId::Rational => "rational",
-            IrepId::Complex => "complex",
-            IrepId::Signed => "signed",
-            IrepId::Unsigned => "unsigned",
-            IrepId::Asm => "asm",
-            IrepId::GccAsmInput => "gcc_asm_input",
-            IrepId::GccAsmOutput => "gcc_asm_output",
-            IrepId::GccAsmClobberedRegister => "gcc_asm_clobbered_register",
-            IrepId::Incomplete => "incomplete",
-            IrepId::IncompleteClass => "incomplete_class",
-            IrepId::CIncomplete => "#incomplete",
-            IrepId::Identifier => "identifier",
-            IrepId::Name => "name",
-            IrepId::InnerName => "inner_name",
-            IrepId::CppName => "cpp_name",
-            IrepId::ComponentCppName => "component_cpp_name",
-            IrepId::CIdClass => "#id_class",
-            IrepId::Declaration => "declaration",
-            IrepId::DeclarationList => "declaration_list",
-            IrepId::Declarator => "declarator",
-            IrepId::Struct => "struct",
-            IrepId::CBitField => "c_bit_field",
-            IrepId::Union => "union",
-            IrepId::Class => "class",
-            IrepId::MergedType => "merged_type",
-            IrepId::Range => "range",
-            IrepId::From => "from",
-            IrepId::To => "to",
-            IrepId::Module => "module",
-            IrepId::Parameter => "parameter",
-            IrepId::ComponentName => "component_name",
-            IrepId::ComponentNumber => "component_number",
-            IrepId::Tag => "tag",
-            IrepId::Default => "default",
-            IrepId::CDefaultValue => "#default_value",
-            IrepId::BaseName => "base_name",
-            IrepId::CBaseName => "#base_name",
-            IrepId::String => "string",
-            IrepId::CStringConstant => "#string_constant",
-            IrepId::StringConstant => "string_constant",
-            IrepId::Width => "width",
-            IrepId::Components => "components",
-            IrepId::Bv => "bv",
-            IrepId::F => "f",
-            IrepId::With => "with",
-            IrepId::Trans => "trans",
-            IrepId::Throw => "throw",
-            IrepId::TryCatch => "try_catch",
-            IrepId::Noexcept => "noexcept",
-            IrepId::CPROVERThrow => "CPROVER_throw",
-            IrepId::CPROVERTryCatch => "CPROVER_try_catch",
-            IrepId::CPROVERTryFinally => "CPROVER_try_finally",
-            IrepId::Protection => "protection",
-            IrepId::Private => "private",
-            IrepId::Public => "public",
-            IrepId::Protected => "protected",
-            IrepId::Virtual => "virtual",
-            IrepId::Volatile => "volatile",
-            IrepId::Const => "const",
-            IrepId::Constexpr => "constexpr",
-            IrepId::Inline => "inline",
-            IrepId::Forall => "forall",
-            IrepId::Exists => "exists",
-            IrepId::Repeat => "repeat",
-            IrepId::Extractbit => "extractbit",
-            IrepId::Extractbits => "extractbits",
-            IrepId::CReference => "#reference",
-            IrepId::CRvalueReference => "#rvalue_reference",
-            IrepId::True => "true",
-            IrepId::False => "false",
-            IrepId::AddressOf => "address_of",
-            IrepId::Dereference => "dereference",
-            IrepId::CLvalue => "#lvalue",
-            IrepId::CBase => "#base",
-            IrepId::Destination => "destination",
-            IrepId::Main => "main",
-            IrepId::Expression => "expression",
-            IrepId::Allocate => "allocate",
-            IrepId::CCxxAllocType => "#cxx_alloc_type",
-            IrepId::CppNew => "cpp_new",
-            IrepId::CppDelete => "cpp_delete",
-            IrepId::CppNewArray => "cpp_new[]",
-            IrepId::CppDeleteArray => "cpp_delete[]",
-            IrepId::JavaNew => "java_new",
-            IrepId::JavaNewArray => "java_new_array",
-            IrepId::JavaNewArrayData => "java_new_array_data",
-            IrepId::JavaStringLiteral => "java_string_literal",
-            IrepId::Printf => "printf",
-            IrepId::Input => "input",
-            IrepId::Output => "output",
-            IrepId::Nondet => "nondet",
-            IrepId::NULL => "NULL",
-            IrepId::Null => "null",
-            IrepId::Nullptr => "nullptr",
-            IrepId::CEnum => "c_enum",
-            IrepId::Enumeration => "enumeration",
-            IrepId::Elements => "elements",
-            IrepId::Unknown => "unknown",
-            IrepId::Uninitialized => "uninitialized",
-            IrepId::Invalid => "invalid",
-            IrepId::CInvalidObject => "#invalid_object",
-            IrepId::PointerOffset => "pointer_offset",
-            IrepId::PointerObject => "pointer_object",
-            IrepId::IsInvalidPointer => "is_invalid_pointer",
-            IrepId::IeeeFloatEqual => "ieee_float_equal",
-            IrepId::IeeeFloatNotequal => "ieee_float_notequal",
-            IrepId::Isnan => "isnan",
-            IrepId::Lambda => "lambda",
-            IrepId::ArrayComprehension => "array_comprehension",
-            IrepId::ArrayOf => "array_of",
-            IrepId::ArrayEqual => "array_equal",
-            IrepId::ArraySet => "array_set",
-            IrepId::ArrayCopy => "array_copy",
-            IrepId::ArrayList => "array_list",
-            IrepId::Mod => "mod",
-            IrepId::Rem => "rem",
-            IrepId::Shr => "shr",
-            IrepId::Ashr => "ashr",
-            IrepId::Lshr => "lshr",
-            IrepId::Shl => "shl",
-            IrepId::Rol => "rol",
-            IrepId::Ror => "ror",
-            IrepId::Comma => "comma",
-            IrepId::Concatenation => "concatenation",
-            IrepId::Infinity => "infinity",
-            IrepId::ReturnType => "return_type",
-            IrepId::Typedef => "typedef",
-            IrepId::TypedefType => "typedef_type",
-            IrepId::CTypedef => "#typedef",
-            IrepId::Extern => "extern",
-            IrepId::Static => "static",
-            IrepId::Auto => "auto",
-            IrepId::Register => "register",
-            IrepId::ThreadLocal => "thread_local",
-            IrepId::Thread => "thread",
-            IrepId::CThreadLocal => "#thread_local",
-            IrepId::CStaticLifetime => "#static_lifetime",
-            IrepId::Mutable => "mutable",
-            IrepId::Void => "void",
-            IrepId::Int8 => "int8",
-            IrepId::Int16 => "int16",
-            IrepId::Int32 => "int32",
-            IrepId::Int64 => "int64",
-            IrepId::Ptr32 => "ptr32",
-            IrepId::Ptr64 => "ptr64",
-            IrepId::Char => "char",
-            IrepId::Short => "short",
-            IrepId::Long => "long",
-            IrepId::Float => "float",
-            IrepId::Float16 => "float16",
-            IrepId::Float128 => "float128",
-            IrepId::Double => "double",
-            IrepId::Byte => "byte",
-            IrepId::Boolean => "boolean",
-            IrepId::LongDouble => "long_double",
-            IrepId::SignedChar => "signed_char",
-            IrepId::UnsignedChar => "unsigned_char",
-            IrepId::SignedInt => "signed_int",
-            IrepId::UnsignedInt => "unsigned_int",
-            IrepId::SignedLongInt => "signed_long_int",
-            IrepId::UnsignedLongInt => "unsigned_long_int",
-            IrepId::SignedShortInt => "signed_short_int",
-            IrepId::UnsignedShortInt => "unsigned_short_int",
-            IrepId::SignedLongLongInt => "signed_long_long_int",
-            IrepId::UnsignedLongLongInt => "unsigned_long_long_int",
-            IrepId::SignedInt128 => "signed_int128",
-            IrepId::UnsignedInt128 => "unsigned_int128",
-            IrepId::Case => "case",
-            IrepId::CInlined => "#inlined",
-            IrepId::CHide => "#hide",
-            IrepId::Hide => "hide",
-            IrepId::Abs => "abs",
-            IrepId::Sign => "sign",
-            IrepId::Access => "access",
-            IrepId::CAccess => "#access",
-            IrepId::Postincrement => "postincrement",
-            IrepId::Postdecrement => "postdecrement",
-            IrepId::Preincrement => "preincrement",
-            IrepId::Predecrement => "predecrement",
-            IrepId::IntegerBits => "integer_bits",
-            IrepId::KnR => "KnR",
-            IrepId::CKnR => "#KnR",
-            IrepId::ConstraintSelectOne => "constraint_select_one",
-            IrepId::Cond => "cond",
-            IrepId::BvLiterals => "bv_literals",
-            IrepId::IsFinite => "isfinite",
-            IrepId::Isinf => "isinf",
-            IrepId::Isnormal => "isnormal",
-            IrepId::Alignof => "alignof",
-            IrepId::ClangBuiltinConvertvector => "clang_builtin_convertvector",
-            IrepId::GccBuiltinVaArg => "gcc_builtin_va_arg",
-            IrepId::GccBuiltinTypesCompatibleP => "gcc_builtin_types_compatible_p",
-            IrepId::VaStart => "va_start",
-            IrepId::GccFloat16 => "gcc_float16",
-            IrepId::GccFloat32 => "gcc_float32",
-            IrepId::GccFloat32x => "gcc_float32x",
-            IrepId::GccFloat64 => "gcc_float64",
-            IrepId::GccFloat64x => "gcc_float64x",
-            IrepId::GccFloat80 => "gcc_float80",
-            IrepId::GccFloat128 => "gcc_float128",
-            IrepId::GccFloat128x => "gcc_float128x",
-            IrepId::GccInt128 => "gcc_int128",
-            IrepId::GccDecimal32 => "gcc_decimal32",
-            IrepId::GccDecimal64 => "gcc_decimal64",
-            IrepId::GccDecimal128 => "gcc_decimal128",
-            IrepId::BuiltinOffsetof => "builtin_offsetof",
-            IrepId::Id0 => "0",
-            IrepId::Id1 => "1",
-            IrepId::Sizeof => "sizeof",
-            IrepId::TypeArg => "type_arg",
-            IrepId::ExprArg => "expr_arg",
-            IrepId::ExpressionList => "expression_list",
-            IrepId::InitializerList => "initializer_list",
-            IrepId::GccConditionalExpression => "gcc_conditional_expression",
-            IrepId::GccLocalLabel => "gcc_local_label",
-            IrepId::Gcc => "gcc",
-            IrepId::Msc => "msc",
-            IrepId::Typeof => "typeof",
-            IrepId::Ellipsis => "ellipsis",
-            IrepId::Flavor => "flavor",
-            IrepId::Ge => ">=",
-            IrepId::Le => "<=",
-            IrepId::Gt => ">",
-            IrepId::Lt => "<",
-            IrepId::Plus => "+",
-            IrepId::Minus => "-",
-            IrepId::UnaryMinus => "unary-",
-            IrepId::UnaryPlus => "unary+",
-            IrepId::Mult => "*",
-            IrepId::Div => "/",
-            IrepId::Power => "**",
-            IrepId::FactorialPower => "factorial_power",
-            IrepId::CPrettyName => "#pretty_name",
-            IrepId::CClass => "#class",
-            IrepId::CField => "#field",
-            IrepId::CInterface => "#interface",
-            IrepId::DesignatedInitializer => "designated_initializer",
-            IrepId::Designator => "designator",
-            IrepId::MemberDesignator => "member_designator",
-            IrepId::IndexDesignator => "index_designator",
-            IrepId::CConstant => "#constant",
-            IrepId::CVolatile => "#volatile",
-            IrepId::CRestricted => "#restricted",
-            IrepId::CIdentifier => "#identifier",
-            IrepId::CImplicit => "#implicit",
-            IrepId::CPtr32 => "#ptr32",
-            IrepId::CPtr64 => "#ptr64",
-            IrepId::CAtomic => "#atomic",
-            IrepId::Restrict => "restrict",
-            IrepId::ByteExtractBigEndian => "byte_extract_big_endian",
-            IrepId::ByteExtractLittleEndian => "byte_extract_little_endian",
-            IrepId::ByteUpdateBigEndian => "byte_update_big_endian",
-            IrepId::ByteUpdateLittleEndian => "byte_update_little_endian",
-            IrepId::Replication => "replication",
-            IrepId::CproverAtomic => "cprover_atomic",
-            IrepId::Atomic => "atomic",
-            IrepId::AtomicTypeSpecifier => "atomic_type_specifier",
-            IrepId::AtomicBegin => "atomic_begin",
-            IrepId::AtomicEnd => "atomic_end",
-            IrepId::StartThread => "start_thread",
-            IrepId::EndThread => "end_thread",
-            IrepId::CoverageCriterion => "coverage_criterion",
-            IrepId::Initializer => "initializer",
-            IrepId::Anonymous => "anonymous",
-            IrepId::CIsAnonymous => "#is_anonymous",
-            IrepId::IsEnumConstant => "is_enum_constant",
-            IrepId::IsInline => "is_inline",
-            IrepId::IsExtern => "is_extern",
-            IrepId::IsSynchronized => "is_synchronized",
-            IrepId::IsNativeMethod => "is_native_method",
-            IrepId::IsVarargsMethod => "is_varargs_method",
-            IrepId::IsGlobal => "is_global",
-            IrepId::IsThreadLocal => "is_thread_local",
-            IrepId::IsParameter => "is_parameter",
-            IrepId::IsMember => "is_member",
-            IrepId::IsType => "is_type",
-            IrepId::IsRegister => "is_register",
-            IrepId::IsTypedef => "is_typedef",
-            IrepId::IsStatic => "is_static",
-            IrepId::IsTemplate => "is_template",
-            IrepId::IsStaticAssert => "is_static_assert",
-            IrepId::IsVirtual => "is_virtual",
-            IrepId::CIsVirtual => "#is_virtual",
-            IrepId::Literal => "literal",
-            IrepId::MemberInitializers => "member_initializers",
-            IrepId::MemberInitializer => "member_initializer",
-            IrepId::MethodQualifier => "method_qualifier",
-            IrepId::Methods => "methods",
-            IrepId::StaticMembers => "static_members",
-            IrepId::Constructor => "constructor",
-            IrepId::Destructor => "destructor",
-            IrepId::Bases => "bases",
-            IrepId::Base => "base",
-            IrepId::FromBase => "from_base",
-            IrepId::Operator => "operator",
-            IrepId::Template => "template",
-            IrepId::TemplateClassInstance => "template_class_instance",
-            IrepId::TemplateFunctionInstance => "template_function_instance",
-            IrepId::TemplateType => "template_type",
-            IrepId::TemplateArgs => "template_args",
-            IrepId::TemplateParameter => "template_parameter",
-            IrepId::TemplateParameterSymbolType => "template_parameter_symbol_type",
-            IrepId::TemplateParameters => "template_parameters",
-            IrepId::CTemplate => "#template",
-            IrepId::CTemplateArguments => "#template_arguments",
-            IrepId::CTemplateCase => "#template_case",
-            IrepId::Typename => "typename",
-            IrepId::C => "C",
-            IrepId::Cpp => "cpp",
-            IrepId::Java => "java",
-            IrepId::DeclBlock => "decl_block",
-            IrepId::DeclType => "decl_type",
-            IrepId::Parameters => "parameters",
-            IrepId::WcharT => "wchar_t",
-            IrepId::Char16T => "char16_t",
-            IrepId::Char32T => "char32_t",
-            IrepId::SizeT => "size_t",
-            IrepId::SsizeT => "ssize_t",
-            IrepId::Mode => "mode",
-            IrepId::This => "this",
-            IrepId::CThis => "#this",
-            IrepId::ReductionAnd => "reduction_and",
-            IrepId::ReductionOr => "reduction_or",
-            IrepId::ReductionNand => "reduction_nand",
-            IrepId::ReductionNor => "reduction_nor",
-            IrepId::ReductionXor => "reduction_xor",
-            IrepId::ReductionXnor => "reduction_xnor",
-            IrepId::CZeroInitializer => "#zero_initializer",
-            IrepId::Body => "body",
-            IrepId::TemporaryObject => "temporary_object",
-            IrepId::OverflowPlus => "overflow-+",
-            IrepId::OverflowMinus => "overflow--",
-            IrepId::OverflowMult => "overflow-*",
-            IrepId::OverflowResultPlus => "overflow_result-+",
-            IrepId::OverflowResultMinus => "overflow_result--",
-            IrepId::OverflowResultMult => "overflow_result-*",
-            IrepId::OverflowUnaryMinus => "overflow-unary-",
-            IrepId::ObjectDescriptor => "object_descriptor",
-            IrepId::IsDynamicObject => "is_dynamic_object",
-            IrepId::DynamicObject => "dynamic_object",
-            IrepId::CDynamic => "#dynamic",
-            IrepId::ObjectSize => "object_size",
-            IrepId::GoodPointer => "good_pointer",
-            IrepId::IntegerAddress => "integer_address",
-            IrepId::IntegerAddressObject => "integer_address_object",
-            IrepId::NullObject => "NULL-object",
-            IrepId::StaticObject => "static_object",
-            IrepId::StackObject => "stack_object",
-            IrepId::CIsFailedSymbol => "#is_failed_symbol",
-            IrepId::CFailedSymbol => "#failed_symbol",
-            IrepId::Friend => "friend",
-            IrepId::CFriends => "#friends",
-            IrepId::Explicit => "explicit",
-            IrepId::StorageSpec => "storage_spec",
-            IrepId::MemberSpec => "member_spec",
-            IrepId::MscDeclspec => "msc_declspec",
-            IrepId::Packed => "packed",
-            IrepId::CPacked => "#packed",
-            IrepId::TransparentUnion => "transparent_union",
-            IrepId::CTransparentUnion => "#transparent_union",
-            IrepId::Aligned => "aligned",
-            IrepId::CAlignment => "#alignment",
-            IrepId::FrontendVector => "frontend_vector",
-            IrepId::Vector => "vector",
-            IrepId::Abstract => "abstract",
-            IrepId::FunctionApplication => "function_application",
-            IrepId::CppDeclarator => "cpp_declarator",
-            IrepId::CppLinkageSpec => "cpp_linkage_spec",
-            IrepId::CppNamespaceSpec => "cpp_namespace_spec",
-            IrepId::CppStorageSpec => "cpp_storage_spec",
-            IrepId::CppUsing => "cpp_using",
-            IrepId::CppDeclaration => "cpp_declaration",
-            IrepId::CppStaticAssert => "cpp_static_assert",
-            IrepId::CppMemberSpec => "cpp_member_spec",
-            IrepId::CCType => "#c_type",
-            IrepId::Namespace => "namespace",
-            IrepId::Linkage => "linkage",
-            IrepId::Decltype => "decltype",
-            IrepId::CTagOnlyDeclaration => "#tag_only_declaration",
-            IrepId::StructTag => "struct_tag",
-            IrepId::UnionTag => "union_tag",
-            IrepId::CEnumTag => "c_enum_tag",
-            IrepId::VerilogCaseEquality => "verilog_case_equality",
-            IrepId::VerilogCaseInequality => "verilog_case_inequality",
-            IrepId::UserSpecifiedPredicate => "user_specified_predicate",
-            IrepId::UserSpecifiedParameterPredicates => "user_specified_parameter_predicates",
-            IrepId::UserSpecifiedReturnPredicates => "user_specified_return_predicates",
-            IrepId::Unassigned => "unassigned",
-            IrepId::NewObject => "new_object",
-            IrepId::ComplexReal => "complex_real",
-            IrepId::ComplexImag => "complex_imag",
-            IrepId::Imag => "imag",
-            IrepId::MscTryExcept => "msc_try_except",
-            IrepId::MscTryFinally => "msc_try_finally",
-            IrepId::MscLeave => "msc_leave",
-            IrepId::MscUuidof => "msc_uuidof",
-            IrepId::MscIfExists => "msc_if_exists",
-            IrepId::MscIfNotExists => "msc_if_not_exists",
-            IrepId::MscUnderlyingType => "msc_underlying_type",
-            IrepId::MscBased => "msc_based",
-            IrepId::Alias => "alias",
-            IrepId::PtrObject => "ptr_object",
-            IrepId::CCSizeofType => "#c_sizeof_type",
-            IrepId::ArrayUpdate => "array_update",
-            IrepId::Update => "update",
-            IrepId::StaticAssert => "static_assert",
-            IrepId::GccAttributeMode => "gcc_attribute_mode",
-            IrepId::BuiltIn => "<built-in>",
-            IrepId::ExceptionList => "exception_list",
-            IrepId::ExceptionId => "exception_id",
-            IrepId::PredicatePassiveSymbol => "predicate_passive_symbol",
-            IrepId::CwVaArgTypeof => "cw_va_arg_typeof",
-            IrepId::Fence => "fence",
-            IrepId::Sync => "sync",
-            IrepId::Lwsync => "lwsync",
-            IrepId::Isync => "isync",
-            IrepId::WRfence => "WRfence",
-            IrepId::RRfence => "RRfence",
-            IrepId::RWfence => "RWfence",
-            IrepId::WWfence => "WWfence",
-            IrepId::RRcumul => "RRcumul",
-            IrepId::RWcumul => "RWcumul",
-            IrepId::WWcumul => "WWcumul",
-            IrepId::WRcumul => "WRcumul",
-            IrepId::GenericSelection => "generic_selection",
-            IrepId::GenericAssociations => "generic_associations",
-            IrepId::GenericAssociation => "generic_association",
-            IrepId::FloatbvPlus => "floatbv_plus",
-            IrepId::FloatbvMinus => "floatbv_minus",
-            IrepId::FloatbvMult => "floatbv_mult",
-            IrepId::FloatbvDiv => "floatbv_div",
-            IrepId::FloatbvRem => "floatbv_rem",
-            IrepId::FloatbvTypecast => "floatbv_typecast",
-            IrepId::CompoundLiteral => "compound_literal",
-            IrepId::CustomBv => "custom_bv",
-            IrepId::CustomUnsignedbv => "custom_unsignedbv",
-            IrepId::CustomSignedbv => "custom_signedbv",
-            IrepId::CustomFixedbv => "custom_fixedbv",
-            IrepId::CustomFloatbv => "custom_floatbv",
-            IrepId::CSSASymbol => "#SSA_symbol",
-            IrepId::L0 => "L0",
-            IrepId::L1 => "L1",
-            IrepId::L2 => "L2",
-            IrepId::L1ObjectIdentifier => "L1_object_identifier",
-            IrepId::AlreadyTypechecked => "already_typechecked",
-            IrepId::CVaArgType => "#va_arg_type",
-            IrepId::Smt2Symbol => "smt2_symbol",
-            IrepId::Onehot => "onehot",
-            IrepId::Onehot0 => "onehot0",
-            IrepId::Popcount => "popcount",
-            IrepId::CountLeadingZeros => "count_leading_zeros",
-            IrepId::CountTrailingZeros => "count_trailing_zeros",
-            IrepId::EmptyUnion => "empty_union",
-            IrepId::FunctionType => "function_type",
-            IrepId::Noreturn => "noreturn",
-            IrepId::CNoreturn => "#noreturn",
-            IrepId::Weak => "weak",
-            IrepId::IsWeak => "is_weak",
-            IrepId::Used => "used",
-            IrepId::IsUsed => "is_used",
-            IrepId::CSpecLoopInvariant => "#spec_loop_invariant",
-            IrepId::CSpecRequires => "#spec_requires",
-            IrepId::CSpecEnsures => "#spec_ensures",
-            IrepId::CSpecAssigns => "#spec_assigns",
-            IrepId::VirtualFunction => "virtual_function",
-            IrepId::ElementType => "element_type",
-            IrepId::WorkingDirectory => "working_directory",
-            IrepId::Section => "section",
-            IrepId::Bswap => "bswap",
-            IrepId::BitReverse => "bitreverse",
-            IrepId::JavaBytecodeIndex => "java_bytecode_index",
-            IrepId::JavaInstanceof => "java_instanceof",
-            IrepId::JavaSuperMethodCall => "java_super_method_call",
-            IrepId::JavaEnumStaticUnwind => "java_enum_static_unwind",
-            IrepId::PushCatch => "push_catch",
-            IrepId::PopCatch => "pop_catch",
-            IrepId::ExceptionLandingpad => "exception_landingpad",
-            IrepId::LengthUpperBound => "length_upper_bound",
-            IrepId::CproverAssociateArrayToPointerFunc => "cprover_associate_array_to_pointer_func",
-            IrepId::CproverAssociateLengthToArrayFunc => "cprover_associate_length_to_array_func",
-            IrepId::CproverCharLiteralFunc => "cprover_char_literal_func",
-            IrepId::CproverStringLiteralFunc => "cprover_string_literal_func",
-            IrepId::CproverStringCharAtFunc => "cprover_string_char_at_func",
-            IrepId::CproverStringCharSetFunc => "cprover_string_char_set_func",
-            IrepId::CproverStringCodePointAtFunc => "cprover_string_code_point_at_func",
-            IrepId::CproverStringCodePointBeforeFunc => "cprover_string_code_point_before_func",
-            IrepId::CproverStringCodePointCountFunc => "cprover_string_code_point_count_func",
-            IrepId::CproverStringOffsetByCodePointFunc => {
-                "cprover_string_offset_by_code_point_func"
-            }
-            IrepId::CproverStringCompareToFunc => "cprover_string_compare_to_func",
-            IrepId::CproverStringConcatFunc => "cprover_string_concat_func",
-            IrepId::CproverStringConcatCharFunc => "cprover_string_concat_char_func",
-            IrepId::CproverStringConcatCodePointFunc => "cprover_string_concat_code_point_func",
-            IrepId::CproverStringConstrainCharactersFunc => {
-                "cprover_string_constrain_characters_func"
-            }
-            IrepId::CproverStringContainsFunc => "cprover_string_contains_func",
-            IrepId::CproverStringCopyFunc => "cprover_string_copy_func",
-            IrepId::CproverStringDeleteFunc => "cprover_string_delete_func",
-            IrepId::CproverStringDeleteCharAtFunc => "cprover_string_delete_char_at_func",
-            IrepId::CproverStringEqualFunc => "cprover_string_equal_func",
-            IrepId::CproverStringEqualsIgnoreCaseFunc => "cprover_string_equals_ignore_case_func",
-            IrepId::CproverStringEmptyStringFunc => "cprover_string_empty_string_func",
-            IrepId::CproverStringEndswithFunc => "cprover_string_endswith_func",
-            IrepId::CproverStringFormatFunc => "cprover_string_format_func",
-            IrepId::CproverStringIndexOfFunc => "cprover_string_index_of_func",
-            IrepId::CproverStringInsertFunc => "cprover_string_insert_func",
-            IrepId::CproverStringIsPrefixFunc => "cprover_string_is_prefix_func",
-            IrepId::CproverStringIsSuffixFunc => "cprover_string_is_suffix_func",
-            IrepId::CproverStringIsEmptyFunc => "cprover_string_is_empty_func",
-            IrepId::CproverStringLastIndexOfFunc => "cprover_string_last_index_of_func",
-            IrepId::CproverStringLengthFunc => "cprover_string_length_func",
-            IrepId::CproverStringOfIntFunc => "cprover_string_of_int_func",
-            IrepId::CproverStringOfIntHexFunc => "cprover_string_of_int_hex_func",
-            IrepId::CproverStringOfLongFunc => "cprover_string_of_long_func",
-            IrepId::CproverStringOfFloatFunc => "cprover_string_of_float_func",
-            IrepId::CproverStringOfFloatScientificNotationFunc => {
-                "cprover_string_of_float_scientific_notation_func"
-            }
-            IrepId::CproverStringOfDoubleFunc => "cprover_string_of_double_func",
-            IrepId::CproverStringParseIntFunc => "cprover_string_parse_int_func",
-            IrepId::CproverStringIsValidIntFunc => "cprover_string_is_valid_int_func",
-            IrepId::CproverStringIsValidLongFunc => "cprover_string_is_valid_long_func",
-            IrepId::CproverStringReplaceFunc => "cprover_string_replace_func",
-            IrepId::CproverStringSetLengthFunc => "cprover_string_set_length_func",
-            IrepId::CproverStringStartswithFunc => "cprover_string_startswith_func",
-            IrepId::CproverStringSubstringFunc => "cprover_string_substring_func",
-            IrepId::CproverStringToLowerCaseFunc => "cprover_string_to_lower_case_func",
-            IrepId::CproverStringToUpperCaseFunc => "cprover_string_to_upper_case_func",
-            IrepId::CproverStringTrimFunc => "cprover_string_trim_func",
-            IrepId::SkipInitialize => "skip_initialize",
-            IrepId::BasicBlockCoveredLines => "basic_block_covered_lines",
-            IrepId::BasicBlockSourceLines => "basic_block_source_lines",
-            IrepId::IsNondetNullable => "is_nondet_nullable",
-            IrepId::ArrayReplace => "array_replace",
-            IrepId::SwitchCaseNumber => "switch_case_number",
-            IrepId::JavaArrayAccess => "java_array_access",
-            IrepId::JavaMemberAccess => "java_member_access",
-            IrepId::CJavaGenericParameter => "#java_generic_parameter",
-            IrepId::CJavaGenericsClassType => "#java_generics_class_type",
-            IrepId::CJavaImplicitlyGenericClassType => "#java_implicitly_generic_class_type",
-            IrepId::CJavaGenericSymbol => "#java_generic_symbol",
-            IrepId::GenericTypes => "generic_types",
-            IrepId::ImplicitGenericTypes => "#implicit_generic_types",
-            IrepId::TypeVariables => "type_variables",
-            IrepId::HandleType => "handle_type",
-            IrepId::JavaLambdaMethodHandle => "java_lambda_method_handle",
-            IrepId::JavaLambdaMethodHandleIndex => "lambda_method_handle_index",
-            IrepId::JavaLambdaMethodHandles => "lambda_method_handles",
-            IrepId::HavocObject => "havoc_object",
-            IrepId::OverflowShl => "overflow-shl",
-            IrepId::CNoInitializationRequired => "#no_initialization_required",
-            IrepId::CNoNondetInitialization => "#no_nondet_initialization",
-            IrepId::OverlayClass => "java::org.cprover.OverlayClassImplementation",
-            IrepId::OverlayMethod => "java::org.cprover.OverlayMethodImplementation",
-            IrepId::IgnoredMethod => "java::org.cprover.IgnoredMethodImplementation",
-            IrepId::IsAnnotation => "is_annotation",
-            IrepId::CAnnotations => "#annotations",
-            IrepId::Final => "final",
-            IrepId::BitsPerByte => "bits_per_byte",
-            IrepId::CAbstract => "#abstract",
-            IrepId::Synthetic => "synthetic",
-            IrepId::Interface => "interface",
-            IrepId::CMustNotThrow => "#must_not_throw",
-            IrepId::IsInnerClass => "is_inner_class",
-            IrepId::IsAnonymous => "is_anonymous",
-            IrepId::OuterClass => "outer_class",
-            IrepId::IsBridgeMethod => "is_bridge_method",
-            IrepId::CIsOperator => "#is_operator",
-            IrepId::CNotAccessible => "#not_accessible",
-            IrepId::COverrideConstantness => "#override_constantness",
-            IrepId::CBound => "#bound",
-            IrepId::CBoundsCheck => "#bounds_check",
-            IrepId::CIsStatic => "#is_static",
-            IrepId::CCallByValue => "#call_by_value",
-            IrepId::CVirtualName => "#virtual_name",
-            IrepId::CUnnamedObject => "#unnamed_object",
-            IrepId::CTemporaryAvoided => "#temporary_avoided",
-            IrepId::CQualifier => "#qualifier",
-            IrepId::CArrayIni => "#array_ini",
-            IrepId::ROk => "r_ok",
-            IrepId::WOk => "w_ok",
-            IrepId::SuperClass => "super_class",
-            IrepId::ExceptionsThrownList => "exceptions_thrown_list",
-            IrepId::CJavaMethodType => "#java_method_type",
-            IrepId::Compiled => "compiled",
-            IrepId::PartialSpecializationArgs => "partial_specialization_args",
-            IrepId::SpecializationOf => "specialization_of",
-            IrepId::InitArgs => "init_args",
-            IrepId::Ambiguous => "ambiguous",
-            IrepId::SpecializationTemplateArgs => "specialization_template_args",
-            IrepId::FullTemplateArgs => "full_template_args",
-            IrepId::InstantiatedWith => "instantiated_with",
-            IrepId::TemplateMethods => "template_methods",
-            IrepId::CppNotTypechecked => "cpp_not_typechecked",
-            IrepId::Noaccess => "noaccess",
-            IrepId::IsOperator => "is_operator",
-            IrepId::IsCastOperator => "is_cast_operator",
-            IrepId::IsExplicit => "is_explicit",
-            IrepId::IsMutable => "is_mutable",
-            IrepId::VirtualName => "virtual_name",
-            IrepId::IsPureVirtual => "is_pure_virtual",
-            IrepId::IsVtptr => "is_vtptr",
-            IrepId::Prefix => "prefix",
-            IrepId::Cv => "cv",
-            IrepId::CppDummyDestructor => "cpp_dummy_destructor",
-            IrepId::CastExpression => "cast_expression",
-            IrepId::PodConstructor => "pod_constructor",
-            IrepId::TemplateDecls => "template_decls",
-            IrepId::ThrowDecl => "throw_decl",
-            IrepId::Typeid => "typeid",
-            IrepId::CQuoted => "#quoted",
-            IrepId::ToMember => "to_member",
-            IrepId::PointerToMember => "pointer_to_member",
-            IrepId::Tuple => "tuple",
-            IrepId::FunctionBody => "function_body",
-            IrepId::GetMay => "get_may",
-            IrepId::SetMay => "set_may",
-            IrepId::ClearMay => "clear_may",
-            IrepId::GetMust => "get_must",
-            IrepId::SetMust => "set_must",
-            IrepId::ClearMust => "clear_must",
-            IrepId::Pragma => "pragma",
-            IrepId::StatementList => "Statement List",
-            IrepId::StatementListType => "statement_list_type",
-            IrepId::StatementListFunction => "statement_list_function",
-            IrepId::StatementListFunctionBlock => "statement_list_function_block",
-            IrepId::StatementListMainFunction => "Main",
-            IrepId::StatementListDataBlock => "statement_list_data_block",
-            IrepId::StatementListVersion => "statement_list_version",
-            IrepId::StatementListVarInput => "statement_list_var_input",
-            IrepId::StatementListVarInout => "statement_list_var_inout",
-            IrepId::StatementListVarOutput => "statement_list_var_output",
-            IrepId::StatementListVarConstant => "statement_list_var_constant",
-            IrepId::StatementListVarTemp => "statement_list_var_temp",
-            IrepId::StatementListVarStatic => "statement_list_var_static",
-            IrepId::StatementListReturn => "statement_list_return",
-            IrepId::StatementListReturnValueId => "Ret_Val",
-            IrepId::StatementListVarEntry => "statement_list_var_entry",
-            IrepId::StatementListVarDecls => "statement_list_var_decls",
-            IrepId::StatementListNetwork => "statement_list_network",
-            IrepId::StatementListNetworks => "statement_list_networks",
-            IrepId::StatementListTitle => "statement_list_title",
-            IrepId::StatementListIdentifier => "statement_list_identifier",
-            IrepId::StatementListLoad => "statement_list_load",
-            IrepId::StatementListTransfer => "statement_list_transfer",
-            IrepId::StatementListCall => "statement_list_call",
-            IrepId::StatementListNop => "statement_list_nop",
-            IrepId::StatementListConstAdd => "statement_list_const_add",
-            IrepId::StatementListAccuIntAdd => "statement_list_accu_int_add",
-            IrepId::StatementListAccuIntSub => "statement_list_accu_int_sub",
-            IrepId::StatementListAccuIntMul => "statement_list_accu_int_mul",
-            IrepId::StatementListAccuIntDiv => "statement_list_accu_int_div",
-            IrepId::StatementListAccuIntEq => "statement_list_accu_int_eq",
-            IrepId::StatementListAccuIntNeq => "statement_list_accu_int_neq",
-            IrepId::StatementListAccuIntGt => "statement_list_accu_int_gt",
-            IrepId::StatementListAccuIntLt => "statement_list_accu_int_lt",
-            IrepId::StatementListAccuIntGte => "statement_list_accu_int_gte",
-            IrepId::StatementListAccuIntLte => "statement_list_accu_int_lte",
-            IrepId::StatementListAccuRealAdd => "statement_list_accu_real_add",
-            IrepId::StatementListAccuRealSub => "statement_list_accu_real_sub",
-            IrepId::StatementListAccuRealMul => "statement_list_accu_real_mul",
-            IrepId::StatementListAccuRealDiv => "statement_list_accu_real_div",
-            IrepId::StatementListAccuRealEq => "statement_list_accu_real_eq",
-            IrepId::StatementListAccuRealNeq => "statement_list_accu_real_neq",
-            IrepId::StatementListAccuRealGt => "statement_list_accu_real_gt",
-            IrepId::StatementListAccuRealLt => "statement_list_accu_real_lt",
-            IrepId::StatementListAccuRealGte => "statement_list_accu_real_gte",
-            IrepId::StatementListAccuRealLte => "statement_list_accu_real_lte",
-            IrepId::StatementListAccuDintAdd => "statement_list_accu_dint_add",
-            IrepId::StatementListAccuDintSub => "statement_list_accu_dint_sub",
-            IrepId::StatementListAccuDintMul => "statement_list_accu_dint_mul",
-            IrepId::StatementListAccuDintDiv => "statement_list_accu_dint_div",
-            IrepId::StatementListAccuDintEq => "statement_list_accu_dint_eq",
-            IrepId::StatementListAccuDintNeq => "statement_list_accu_dint_neq",
-            IrepId::StatementListAccuDintGt => "statement_list_accu_dint_gt",
-            IrepId::StatementListAccuDintLt => "statement_list_accu_dint_lt",
-            IrepId::StatementListAccuDintGte => "statement_list_accu_dint_gte",
-            IrepId::StatementListAccuDintLte => "statement_list_accu_dint_lte",
-            IrepId::StatementListAnd => "statement_list_and",
-            IrepId::StatementListAndNot => "statement_list_and_not",
-            IrepId::StatementListOr => "statement_list_or",
-            IrepId::StatementListOrNot => "statement_list_or_not",
-            IrepId::StatementListXor => "statement_list_xor",
-            IrepId::StatementListXorNot => "statement_list_xor_not",
-            IrepId::StatementListAndNested => "statement_list_and_nested",
-            IrepId::StatementListAndNotNested => "statement_list_and_not_nested",
-            IrepId::StatementListOrNested => "statement_list_or_nested",
-            IrepId::StatementListOrNotNested => "statement_list_or_not_nested",
-            IrepId::StatementListXorNested => "statement_list_xor_nested",
-            IrepId::StatementListXorNotNested => "statement_list_xor_not_nested",
-            IrepId::StatementListNestingClosed => "statement_list_nesting_closed",
-            IrepId::StatementListAssign => "statement_list_assign",
-            IrepId::StatementListSetRlo => "statement_list_set_rlo",
-            IrepId::StatementListClrRlo => "statement_list_clr_rlo",
-            IrepId::StatementListSet => "statement_list_set",
-            IrepId::StatementListReset => "statement_list_reset",
-            IrepId::StatementListNot => "statement_list_not",
-            IrepId::StatementListInstruction => "statement_list_instruction",
-            IrepId::StatementListInstructions => "statement_list_instructions",
-            IrepId::VectorEqual => "vector-=",
-            IrepId::VectorNotequal => "vector-!=",
-            IrepId::VectorGe => "vector->=",
-            IrepId::VectorLe => "vector-<=",
-            IrepId::VectorGt => "vector->",
-            IrepId::VectorLt => "vector-<",
-            IrepId::FloatbvRoundToIntegral => "floatbv_round_to_integral",
-            IrepId::ShuffleVector => "shuffle_vector",
-        }
-    }
 }
 
 #[cfg(test)]
@@ -1777,6 +981,15 @@ mod tests {
     //     IrepId::FreeformHexInteger { value: BigInt::from(-12), width: 4, signed: true }.to_string();
     // }
 
+    #[test]
+    fn test_from_cbmc_string() {
+        for id in IrepId::STATIC_IDS {
+            assert_eq!(&IrepId::from_cbmc_string(&id.to_string()), id);
+        }
+        let id = IrepId::from_string("foo");
+        assert_eq!(IrepId::from_cbmc_string(&id.to_string()), id);
+    }
+
     #[test]
     fn test_hex_id() {
         // For positive numbers, should just give the smallest representation.
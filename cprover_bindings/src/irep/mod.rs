@@ -15,6 +15,7 @@
 //! TODO: Investigate memory usage, and consider using sharing to reduce memory usage
 
 pub mod goto_binary_serde;
+mod goto_model;
 #[allow(clippy::module_inception)]
 mod irep;
 mod irep_id;
@@ -23,6 +24,7 @@
 mod symbol_table;
 mod to_irep;
 
+pub use goto_model::{GotoFunction, GotoModel, Instruction, InstructionKind};
 pub use irep::Irep;
 pub use irep_id::IrepId;
 pub use symbol::Symbol;
@@ -1,6 +1,7 @@
 // Copyright Kani Contributors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use crate::kani_middle::transform::mutation::MutantId;
 use strum_macros::{AsRefStr, Display, EnumString, VariantNames};
 use tracing_subscriber::filter::Directive;
 
@@ -40,6 +41,9 @@ pub struct Arguments {
     /// Option used to disable asserting function contracts.
     #[clap(long)]
     pub no_assert_contracts: bool,
+    /// Option used to inject the type invariants of arguments and return values into function contracts.
+    #[clap(long)]
+    pub contract_invariants: bool,
     /// Option name used to enable assertion reachability checks.
     #[clap(long = "assertion-reach-checks")]
     pub check_assertion_reachability: bool,
@@ -49,6 +53,9 @@ pub struct Arguments {
     /// Option name used to dump function pointer restrictions.
     #[clap(long = "restrict-vtable-fn-ptrs")]
     pub emit_vtable_restrictions: bool,
+    /// Option name used to record the items reachable from each harness in the metadata.
+    #[clap(long)]
+    pub emit_reachable_items: bool,
     /// Option name used to use json pretty-print for output files.
     #[clap(long = "pretty-json-files")]
     pub output_pretty_json: bool,
@@ -111,6 +118,18 @@ pub struct Arguments {
     /// See kani_driver::autoharness_args for documentation.
     #[arg(long = "autoharness-exclude-pattern", num_args(1))]
     pub autoharness_excluded_patterns: Vec<String>,
+    /// If we are running the autoharness subcommand, the types to instantiate generic functions with.
+    /// See kani_driver::autoharness_args for documentation.
+    #[arg(long = "autoharness-generic-type", num_args(1))]
+    pub autoharness_generic_types: Vec<String>,
+    /// If we are running the mutate subcommand, record the mutants of the functions reachable
+    /// from each harness in the metadata.
+    #[clap(long)]
+    pub list_mutants: bool,
+    /// If we are running the mutate subcommand, the mutant to apply in the format
+    /// `<mangled_name>#<index>`.
+    #[clap(long)]
+    pub mutant: Option<MutantId>,
 }
 
 #[derive(Debug, Clone, Copy, AsRefStr, EnumString, VariantNames, PartialEq, Eq)]
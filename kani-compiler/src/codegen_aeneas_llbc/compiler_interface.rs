@@ -19,6 +19,7 @@
 use charon_lib::transform::TransformCtx;
 use charon_lib::transform::ctx::{TransformOptions, TransformPass};
 use kani_metadata::ArtifactType;
+use kani_metadata::artifact::convert_type;
 use kani_metadata::{AssignsContract, CompilerArtifactStub};
 use rustc_codegen_ssa::back::archive::{
     ArArchiveBuilder, ArchiveBuilder, ArchiveBuilderBuilder, DEFAULT_OBJECT_READER,
@@ -69,6 +70,7 @@ fn codegen_items(
         tcx: TyCtxt,
         starting_items: &[MonoItem],
         llbc_file: &Path,
+        crate_name: &str,
         _check_contract: Option<InternalDefId>,
         mut transformer: BodyTransformation,
     ) -> (Vec<MonoItem>, Option<AssignsContract>) {
@@ -107,7 +109,7 @@ fn codegen_items(
         // https://rustc-dev-guide.rust-lang.org/conventions.html#naming-conventions
 
         // Create a Charon transformation context that will be populated with translation results
-        let mut ccx = create_charon_transformation_context(tcx);
+        let mut ccx = create_charon_transformation_context(crate_name);
         let mut id_map: FxHashMap<DefId, AnyTransId> = FxHashMap::default();
 
         // Translate all the items
@@ -177,8 +179,7 @@ fn codegen_items(
         if !tcx.sess.opts.unstable_opts.no_codegen && tcx.sess.opts.output_types.should_codegen() {
             // # Final step: generate the files.
             // `crate_data` is set by our callbacks when there is no fatal error.
-            let mut pb = llbc_file.to_path_buf();
-            pb.set_extension("llbc");
+            let pb = convert_type(llbc_file, ArtifactType::SymTabGoto, ArtifactType::Llbc);
             println!("Writing LLBC file to {}", pb.display());
             if let Err(()) = crate_data.serialize_to_file(&pb) {
                 tcx.sess.dcx().err("Failed to write LLBC file");
@@ -228,12 +229,14 @@ fn codegen_crate(&self, tcx: TyCtxt) -> Box<dyn Any> {
                         let mut transformer = BodyTransformation::new(&queries, tcx, &unit);
                         for harness in &unit.harnesses {
                             let model_path = units.harness_model_path(*harness).unwrap();
+                            let crate_name = units.harness_metadata(*harness).lean_namespace();
                             let contract_metadata =
                                 contract_metadata_for_harness(tcx, harness.def.def_id()).unwrap();
                             let (_items, contract_info) = self.codegen_items(
                                 tcx,
                                 &[MonoItem::Fn(*harness)],
                                 model_path,
+                                &crate_name,
                                 contract_metadata
                                     .map(|def| rustc_internal::internal(tcx, def.def_id())),
                                 transformer,
@@ -269,6 +272,7 @@ fn codegen_crate(&self, tcx: TyCtxt) -> Box<dyn Any> {
                         tcx,
                         &local_reachable,
                         &model_path,
+                        tcx.crate_name(LOCAL_CRATE).as_str(),
                         Default::default(),
                         transformer,
                     );
@@ -435,9 +439,11 @@ fn get_transform_options(tcx: &TranslatedCrate, error_ctx: &mut ErrorCtx) -> Tra
     }
 }
 
-fn create_charon_transformation_context(tcx: TyCtxt) -> TransformCtx {
-    let crate_name = tcx.crate_name(LOCAL_CRATE).as_str().into();
-    let translated = TranslatedCrate { crate_name, ..TranslatedCrate::default() };
+/// `crate_name` is the name of the crate in the LLBC file, which Aeneas uses as the namespace of
+/// the generated Lean code.
+fn create_charon_transformation_context(crate_name: &str) -> TransformCtx {
+    let translated =
+        TranslatedCrate { crate_name: crate_name.into(), ..TranslatedCrate::default() };
     let mut errors = ErrorCtx::new(true, false);
     let options = get_transform_options(&translated, &mut errors);
     TransformCtx { options, translated, errors }
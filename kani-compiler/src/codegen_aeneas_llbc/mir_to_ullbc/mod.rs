@@ -59,8 +59,8 @@
 use rustc_public::mir::mono::{Instance, InstanceDef};
 use rustc_public::mir::{
     AggregateKind, BasicBlock, BinOp, Body, BorrowKind, CastKind, ConstOperand, Local, Mutability,
-    Operand, Place, ProjectionElem, Rvalue, Statement, StatementKind, SwitchTargets, Terminator,
-    TerminatorKind, UnOp, VarDebugInfoContents,
+    Operand, Place, ProjectionElem, RawPtrKind, Rvalue, Statement, StatementKind, SwitchTargets,
+    Terminator, TerminatorKind, UnOp, VarDebugInfoContents,
 };
 use rustc_public::rustc_internal;
 use rustc_public::ty::{
@@ -121,6 +121,16 @@ fn continue_on_failure(&self) -> bool {
         self.errors.continue_on_failure()
     }
 
+    /// Report a MIR construct that the LLBC backend cannot translate yet. The
+    /// construct is replaced by an error statement so that the rest of the body
+    /// can still be translated.
+    fn unsupported(&mut self, span: Span, what: &str) -> CharonRawStatement {
+        let msg = format!("Kani's LLBC backend does not support {what} yet");
+        let span = self.translate_span(span);
+        self.span_err(span, &msg);
+        CharonRawStatement::Error(msg)
+    }
+
     fn translate_traitdecl(&mut self, trait_def: TraitDef) -> CharonTraitDeclId {
         let trait_def_id = trait_def.def_id();
         let trait_decl_id = self.register_trait_decl_id(trait_def_id);
@@ -1081,7 +1091,9 @@ fn adtdef_to_name(&mut self, def: AdtDef) -> Result<CharonName, CharonError> {
                     found_crate_name = true;
                     name.push(CharonPathElem::Ident(crate_name.clone(), disambiguator));
                 }
-                DefPathData::Impl => todo!(),
+                DefPathData::Impl => {
+                    // As in `def_to_name`, impl blocks do not contribute to the name
+                }
                 DefPathData::OpaqueTy => {
                     // TODO: do nothing for now
                 }
@@ -1469,10 +1481,12 @@ fn translate_block(&mut self, bb: &BasicBlock) -> CharonBlockData {
 
     fn translate_statement(&mut self, stmt: &Statement) -> Option<CharonStatement> {
         let content = match &stmt.kind {
-            StatementKind::Assign(place, rhs) => Some(CharonRawStatement::Assign(
-                self.translate_place(&place),
-                self.translate_rvalue(&rhs),
-            )),
+            StatementKind::Assign(place, rhs) => match self.translate_rvalue(&rhs) {
+                Ok(rvalue) => {
+                    Some(CharonRawStatement::Assign(self.translate_place(&place), rvalue))
+                }
+                Err(what) => Some(self.unsupported(stmt.span, &what)),
+            },
             StatementKind::SetDiscriminant { place, variant_index } => {
                 Some(CharonRawStatement::SetDiscriminant(
                     self.translate_place(&place),
@@ -1483,8 +1497,16 @@ fn translate_statement(&mut self, stmt: &Statement) -> Option<CharonStatement> {
             StatementKind::StorageDead(local) => {
                 Some(CharonRawStatement::StorageDead(CharonVarId::from_usize(*local)))
             }
-            StatementKind::Nop => None,
-            _ => todo!(),
+            StatementKind::FakeRead(..)
+            | StatementKind::Retag(..)
+            | StatementKind::PlaceMention(_)
+            | StatementKind::AscribeUserType { .. }
+            | StatementKind::Coverage(_)
+            | StatementKind::ConstEvalCounter
+            | StatementKind::Nop => None,
+            StatementKind::Deinit(_) | StatementKind::Intrinsic(_) => {
+                Some(self.unsupported(stmt.span, &format!("statement `{:?}`", stmt.kind)))
+            }
         };
         if let Some(content) = content {
             let span = self.translate_span(stmt.span);
@@ -1517,37 +1539,47 @@ fn translate_terminator(
             TerminatorKind::Call { func, args, destination, target, .. } => {
                 debug!("translate_call: {func:?} {args:?} {destination:?} {target:?}");
                 let fn_ty = func.ty(self.instance.body().unwrap().locals()).unwrap();
-                let fn_ptr = match fn_ty.kind() {
-                    TyKind::RigidTy(RigidTy::FnDef(def, genarg)) => {
-                        let instance = Instance::resolve(def, &genarg).unwrap();
-                        let def_id = instance.def.def_id();
-                        let fid = self.register_fun_decl_id(def_id);
-                        let genarg_resolve = match instance.ty().kind() {
-                            TyKind::RigidTy(RigidTy::FnDef(_, ga)) => ga,
-                            _ => panic!("Expected a function type"),
-                        };
-                        let funcid = CharonFunIdOrTraitMethodRef::Fun(CharonFunId::Regular(fid));
-                        let generics = self.translate_generic_args(genarg_resolve, def_id);
-                        CharonFnPtr { func: funcid, generics }
+                let c_func_op = match (fn_ty.kind(), func) {
+                    (TyKind::RigidTy(RigidTy::FnDef(def, genarg)), _) => {
+                        Some(CharonFnOperand::Regular(self.translate_fn_ptr(def, genarg)))
                     }
-                    TyKind::RigidTy(RigidTy::FnPtr(..)) => todo!(),
-                    x => unreachable!(
+                    (
+                        TyKind::RigidTy(RigidTy::FnPtr(..)),
+                        Operand::Copy(place) | Operand::Move(place),
+                    ) => Some(CharonFnOperand::Move(self.translate_place(place))),
+                    (TyKind::RigidTy(RigidTy::FnPtr(..)), Operand::Constant(_)) => None,
+                    (x, _) => unreachable!(
                         "Function call where the function was of unexpected type: {:?}",
                         x
                     ),
                 };
-                let c_func_op = CharonFnOperand::Regular(fn_ptr);
-                let call = CharonCall {
-                    func: c_func_op,
-                    args: args.iter().map(|arg| self.translate_operand(arg)).collect(),
-                    dest: self.translate_place(destination),
+                let statement = match c_func_op {
+                    Some(c_func_op) => CharonRawStatement::Call(CharonCall {
+                        func: c_func_op,
+                        args: args.iter().map(|arg| self.translate_operand(arg)).collect(),
+                        dest: self.translate_place(destination),
+                    }),
+                    None => {
+                        self.unsupported(terminator.span, "calls to constant function pointers")
+                    }
+                };
+                // Calls to diverging functions (e.g., `panic!`) have no target
+                let c_terminator = match target {
+                    Some(target) => {
+                        CharonRawTerminator::Goto { target: CharonBlockId::from_usize(*target) }
+                    }
+                    None => {
+                        // The name of functions called through a pointer is unknown
+                        let name = match fn_ty.kind() {
+                            TyKind::RigidTy(RigidTy::FnDef(def, _)) => {
+                                self.defid_to_name(def.def_id()).unwrap()
+                            }
+                            _ => CharonName { name: Vec::new() },
+                        };
+                        CharonRawTerminator::Abort(CharonAbortKind::Panic(name))
+                    }
                 };
-                (
-                    Some(CharonRawStatement::Call(call)),
-                    CharonRawTerminator::Goto {
-                        target: CharonBlockId::from_usize(target.unwrap()),
-                    },
-                )
+                (Some(statement), c_terminator)
             }
             TerminatorKind::Assert { cond, expected, msg: _, target, .. } => (
                 Some(CharonRawStatement::Assert(CharonAssert {
@@ -1556,7 +1588,15 @@ fn translate_terminator(
                 })),
                 CharonRawTerminator::Goto { target: CharonBlockId::from_usize(*target) },
             ),
-            _ => todo!(),
+            TerminatorKind::Resume | TerminatorKind::Abort | TerminatorKind::InlineAsm { .. } => {
+                (
+                    Some(self.unsupported(
+                        terminator.span,
+                        &format!("terminator `{:?}`", terminator.kind),
+                    )),
+                    CharonRawTerminator::Abort(CharonAbortKind::UndefinedBehavior),
+                )
+            }
         };
         (
             statement.map(|statement| CharonStatement { span, content: statement }),
@@ -1583,22 +1623,40 @@ fn place_ty(&self, place: &Place) -> Ty {
         ty
     }
 
-    fn translate_rvalue(&mut self, rvalue: &Rvalue) -> CharonRvalue {
+    /// Translate an rvalue, or return a description of the construct that is not
+    /// supported yet.
+    fn translate_rvalue(&mut self, rvalue: &Rvalue) -> Result<CharonRvalue, String> {
         trace!("translate_rvalue: {rvalue:?}");
-        match rvalue {
+        let rvalue = match rvalue {
             Rvalue::Use(operand) => CharonRvalue::Use(self.translate_operand(operand)),
-            Rvalue::Repeat(_operand, _) => todo!(),
+            Rvalue::Repeat(operand, count) => {
+                let Ok(len) = count.eval_target_usize() else {
+                    return Err(format!("array repeat expressions of length `{count:?}`"));
+                };
+                let ty = operand.ty(self.instance.body().unwrap().locals()).unwrap();
+                let c_ty = self.translate_ty(ty);
+                let c_operand = self.translate_operand(operand);
+                let cg =
+                    CharonConstGeneric::Value(CharonLiteral::Scalar(CharonScalarValue::Usize(len)));
+                CharonRvalue::Repeat(c_operand, c_ty, cg)
+            }
             Rvalue::Ref(_region, kind, place) => {
                 CharonRvalue::Ref(self.translate_place(&place), translate_borrow_kind(kind))
             }
-            Rvalue::AddressOf(_, _) => todo!(),
+            Rvalue::AddressOf(kind, place) => {
+                let ref_kind = match kind {
+                    RawPtrKind::Mut => CharonRefKind::Mut,
+                    RawPtrKind::Const | RawPtrKind::FakeForPtrMetadata => CharonRefKind::Shared,
+                };
+                CharonRvalue::RawPtr(self.translate_place(&place), ref_kind)
+            }
             Rvalue::Len(place) => CharonRvalue::Len(
                 self.translate_place(&place),
                 self.translate_ty(rvalue.ty(self.instance.body().unwrap().locals()).unwrap()),
                 None,
             ),
             Rvalue::Cast(kind, operand, ty) => CharonRvalue::UnaryOp(
-                CharonUnOp::Cast(self.translate_cast(*kind, operand, *ty)),
+                CharonUnOp::Cast(self.translate_cast(*kind, operand, *ty)?),
                 self.translate_operand(operand),
             ),
             Rvalue::BinaryOp(bin_op, lhs, rhs) => CharonRvalue::BinaryOp(
@@ -1622,7 +1680,7 @@ fn translate_rvalue(&mut self, rvalue: &Rvalue) -> CharonRvalue {
                     CharonTyKind::Adt(CharonTypeId::Adt(c_typedeclid), _) => {
                         CharonRvalue::Discriminant(c_place, *c_typedeclid)
                     }
-                    _ => todo!("Not yet implemented:{:?}", c_ty.kind()),
+                    _ => return Err(format!("discriminants of `{ty:?}`")),
                 }
             }
 
@@ -1667,7 +1725,7 @@ fn translate_rvalue(&mut self, rvalue: &Rvalue) -> CharonRvalue {
                                 );
                                 CharonRvalue::Aggregate(c_agg_kind, c_operands)
                             }
-                            _ => todo!(),
+                            AdtKind::Union => return Err("union aggregates".to_string()),
                         }
                     }
                     AggregateKind::Tuple => CharonRvalue::Aggregate(
@@ -1686,15 +1744,18 @@ fn translate_rvalue(&mut self, rvalue: &Rvalue) -> CharonRvalue {
                         ));
                         CharonRvalue::Aggregate(CharonAggregateKind::Array(c_ty, cg), c_operands)
                     }
-                    _ => todo!(),
+                    _ => return Err(format!("aggregate `{akind:?}`")),
                 }
             }
 
-            Rvalue::ShallowInitBox(_, _) => todo!(),
-            Rvalue::CopyForDeref(_) => todo!(),
-            Rvalue::ThreadLocalRef(_) => todo!(),
-            _ => todo!(),
-        }
+            Rvalue::CopyForDeref(place) => {
+                CharonRvalue::Use(CharonOperand::Copy(self.translate_place(place)))
+            }
+            Rvalue::ShallowInitBox(..) | Rvalue::ThreadLocalRef(_) | Rvalue::NullaryOp(..) => {
+                return Err(format!("rvalue `{rvalue:?}`"));
+            }
+        };
+        Ok(rvalue)
     }
 
     fn translate_operand(&mut self, operand: &Operand) -> CharonOperand {
@@ -1717,7 +1778,12 @@ fn translate_constant_value(&mut self, constant: &MirConst) -> CharonRawConstant
         match constant.kind() {
             ConstantKind::Allocated(alloc) => self.translate_allocation(alloc, constant.ty()),
             ConstantKind::Ty(_) => todo!(),
-            ConstantKind::ZeroSized => todo!(),
+            ConstantKind::ZeroSized => match constant.ty().kind() {
+                TyKind::RigidTy(RigidTy::FnDef(def, genarg)) => {
+                    CharonRawConstantExpr::FnPtr(self.translate_fn_ptr(def, genarg))
+                }
+                _ => CharonRawConstantExpr::Adt(None, Vec::new()),
+            },
             ConstantKind::Unevaluated(uc) => {
                 let defid = uc.def.def_id();
                 let c_defid = self.register_global_decl_id(defid);
@@ -1769,8 +1835,34 @@ fn translate_allocation(&self, alloc: &Allocation, ty: Ty) -> CharonRawConstantE
         }
     }
 
-    fn translate_cast(&self, _kind: CastKind, _operand: &Operand, _ty: Ty) -> CharonCastKind {
-        todo!()
+    fn translate_cast(
+        &mut self,
+        kind: CastKind,
+        operand: &Operand,
+        ty: Ty,
+    ) -> Result<CharonCastKind, String> {
+        let src_ty = self.translate_ty(operand.ty(self.instance.body().unwrap().locals()).unwrap());
+        let dst_ty = self.translate_ty(ty);
+        match (kind, src_ty.kind(), dst_ty.kind()) {
+            (CastKind::IntToInt, CharonTyKind::Literal(src), CharonTyKind::Literal(dst)) => {
+                Ok(CharonCastKind::Scalar(*src, *dst))
+            }
+            _ => Err(format!("`{kind:?}` casts")),
+        }
+    }
+
+    /// Translate a reference to the function `def` instantiated with `genarg`
+    fn translate_fn_ptr(&mut self, def: FnDef, genarg: GenericArgs) -> CharonFnPtr {
+        let instance = Instance::resolve(def, &genarg).unwrap();
+        let def_id = instance.def.def_id();
+        let fid = self.register_fun_decl_id(def_id);
+        let genarg_resolve = match instance.ty().kind() {
+            TyKind::RigidTy(RigidTy::FnDef(_, ga)) => ga,
+            _ => panic!("Expected a function type"),
+        };
+        let funcid = CharonFunIdOrTraitMethodRef::Fun(CharonFunId::Regular(fid));
+        let generics = self.translate_generic_args(genarg_resolve, def_id);
+        CharonFnPtr { func: funcid, generics }
     }
 
     fn translate_switch_targets(
@@ -1970,8 +2062,8 @@ fn translate_bin_op(bin_op: BinOp) -> CharonBinOp {
         BinOp::Ne => CharonBinOp::Ne,
         BinOp::Ge => CharonBinOp::Ge,
         BinOp::Gt => CharonBinOp::Gt,
-        BinOp::Cmp => todo!(),
-        BinOp::Offset => todo!(),
+        BinOp::Cmp => CharonBinOp::Cmp,
+        BinOp::Offset => CharonBinOp::Offset,
     }
 }
 
@@ -1979,7 +2071,7 @@ fn translate_un_op(un_op: UnOp) -> CharonUnOp {
     match un_op {
         UnOp::Not => CharonUnOp::Not,
         UnOp::Neg => CharonUnOp::Neg,
-        UnOp::PtrMetadata => todo!(),
+        UnOp::PtrMetadata => CharonUnOp::PtrMetadata,
     }
 }
 
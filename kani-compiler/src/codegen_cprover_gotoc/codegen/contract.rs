@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 use crate::codegen_cprover_gotoc::{GotocCtx, codegen::ty_stable::pointee_type_stable};
 use crate::kani_middle::attributes::KaniAttributes;
+use crate::kani_middle::stable_fn_def;
 use cbmc::goto_program::FunctionContract;
 use cbmc::goto_program::{Expr, Lambda, Location, Type};
 use kani_metadata::AssignsContract;
@@ -32,12 +33,19 @@ pub fn handle_check_contract(
         items: &[MonoItem],
     ) -> AssignsContract {
         let tcx = self.tcx;
+        // A function may inherit its contract from a trait method declaration, in which case the
+        // contract is implemented by the instance of a provided method that wraps it.
+        let inherited = KaniAttributes::for_item(tcx, function_under_contract).inherited_contract();
         let modify = items
             .iter()
             .find_map(|item| {
                 // Find the instance under contract
                 let MonoItem::Fn(instance) = *item else { return None };
-                if rustc_internal::internal(tcx, instance.def.def_id()) == function_under_contract {
+                let def_id = rustc_internal::internal(tcx, instance.def.def_id());
+                if def_id == function_under_contract
+                    || (Some(def_id) == inherited
+                        && self.wrapped_method(instance) == Some(function_under_contract))
+                {
                     self.find_modifies(instance)
                 } else {
                     None
@@ -85,6 +93,16 @@ fn find_recursion_tracker(&mut self, items: &[MonoItem]) -> Option<String> {
         recursion_tracker
     }
 
+    /// Return the method wrapped by this instance of a provided method generated from a trait
+    /// contract.
+    fn wrapped_method(&self, instance: Instance) -> Option<InternalDefId> {
+        let attributes = KaniAttributes::for_instance(self.tcx, instance);
+        let decl = stable_fn_def(self.tcx, attributes.trait_contract_decl()?)?;
+        let method = Instance::resolve(decl, &instance.args()).ok()?;
+        let (method, _) = method.ty().kind().fn_def()?;
+        Some(rustc_internal::internal(self.tcx, method.def_id()))
+    }
+
     /// Find the modifies recursively since we may have a recursion wrapper.
     /// I.e.: [recursion_wrapper ->]? check -> modifies.
     fn find_modifies(&mut self, instance: Instance) -> Option<Instance> {
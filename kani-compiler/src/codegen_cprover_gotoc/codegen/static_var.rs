@@ -4,6 +4,7 @@
 //! This file contains functions related to codegenning MIR static variables into gotoc
 
 use crate::codegen_cprover_gotoc::GotocCtx;
+use crate::codegen_cprover_gotoc::overrides::declare_static_shadow_map;
 use crate::kani_middle::is_interior_mut;
 use rustc_public::CrateDef;
 use rustc_public::mir::mono::{Instance, StaticDef};
@@ -46,5 +47,6 @@ pub fn declare_static(&mut self, def: StaticDef) {
         self.ensure_global_var(symbol_name, false, typ, location)
             .set_is_hidden(false) // Static items are always user defined.
             .set_pretty_name(pretty_name);
+        declare_static_shadow_map(self, def);
     }
 }
@@ -29,6 +29,7 @@
     Ty as StableTy,
 };
 use rustc_span::def_id::DefId;
+use std::collections::BTreeMap;
 use tracing::{debug, trace, warn};
 
 /// Map the unit type to an empty struct
@@ -1496,6 +1497,28 @@ pub fn codegen_enum_discr_typ(&self, ty: Ty<'tcx>) -> Ty<'tcx> {
         }
     }
 
+    /// The names of the variants of an enum indexed by their discriminant, if the enum stores its
+    /// discriminant in the `case` field of its goto type (i.e., it uses a direct tag encoding).
+    ///
+    /// This is used to print the variant of an enum value in a counterexample trace.
+    pub fn enum_variant_names(&self, ty: Ty<'tcx>) -> Option<BTreeMap<String, String>> {
+        let TyKind::Adt(def, _) = ty.kind() else { return None };
+        let Variants::Multiple { tag_encoding: TagEncoding::Direct, .. } =
+            &self.layout_of(ty).variants
+        else {
+            return None;
+        };
+        Some(
+            def.variants()
+                .iter_enumerated()
+                .map(|(idx, variant)| {
+                    let discr = ty.discriminant_for_variant(self.tcx, idx).unwrap();
+                    (discr.to_string(), variant.name.to_string())
+                })
+                .collect(),
+        )
+    }
+
     /// Codegen the type for each variant represented in this enum.
     /// As an optimization, we ignore the ones that don't have any field, since they
     /// are only manipulated via discriminant operations.
@@ -11,6 +11,7 @@
 use crate::kani_middle::attributes::KaniAttributes;
 use crate::kani_middle::check_reachable_items;
 use crate::kani_middle::codegen_units::{CodegenUnit, CodegenUnits};
+use crate::kani_middle::metadata::{gen_mutants_metadata, gen_reachable_items_metadata};
 use crate::kani_middle::provide;
 use crate::kani_middle::reachability::{collect_reachable_items, filter_crate_items};
 use crate::kani_middle::transform::{BodyTransformation, GlobalPasses};
@@ -206,6 +207,13 @@ fn codegen_items<'tcx>(
         let type_map: BTreeMap<InternedString, InternedString> =
             BTreeMap::from_iter(gcx.type_map.iter().map(|(k, v)| (*k, v.to_string().into())));
 
+        // Map the enum types to the names of their variants
+        let enum_map: BTreeMap<InternedString, BTreeMap<String, String>> = gcx
+            .type_map
+            .iter()
+            .filter_map(|(k, v)| Some((*k, gcx.enum_variant_names(*v)?)))
+            .collect();
+
         // Get the vtable function pointer restrictions if requested
         let vtable_restrictions = if gcx.vtable_ctx.emit_vtable_restrictions {
             Some(gcx.vtable_ctx.get_virtual_function_restrictions())
@@ -230,6 +238,7 @@ fn codegen_items<'tcx>(
                 symbol_table,
                 vtable_restrictions,
                 type_map,
+                enum_map,
                 pretty_name_map,
                 pretty,
             };
@@ -388,6 +397,8 @@ fn codegen_crate(&self, tcx: TyCtxt) -> Box<dyn Any> {
                     let mut units = CodegenUnits::new(&queries, tcx);
                     let mut modifies_instances = vec![];
                     let mut loop_contracts_instances = vec![];
+                    let mut reachable_items = vec![];
+                    let mut mutants = vec![];
 
                     // We know the # of harnesses here, so provide them to the thread_pool size calculation.
                     let num_harnesses: usize = units.iter().map(|unit| unit.harnesses.len()).sum();
@@ -422,6 +433,13 @@ fn codegen_crate(&self, tcx: TyCtxt) -> Box<dyn Any> {
                             if min_gcx.has_loop_contracts {
                                 loop_contracts_instances.push(*harness);
                             }
+                            if queries.args().emit_reachable_items {
+                                reachable_items
+                                    .push((*harness, gen_reachable_items_metadata(tcx, &items)));
+                            }
+                            if queries.args().list_mutants {
+                                mutants.push((*harness, gen_mutants_metadata(tcx, &items)));
+                            }
                             results.extend(min_gcx, items, None);
                             if let Some(assigns_contract) = contract_info {
                                 modifies_instances.push((*harness, assigns_contract));
@@ -430,6 +448,8 @@ fn codegen_crate(&self, tcx: TyCtxt) -> Box<dyn Any> {
                     }
                     units.store_modifies(&modifies_instances);
                     units.store_loop_contracts(&loop_contracts_instances);
+                    units.store_reachable_items(reachable_items);
+                    units.store_mutants(mutants);
                     units.write_metadata(&queries, tcx);
                 }
                 ReachabilityType::None => unreachable!(),
@@ -471,7 +491,7 @@ fn codegen_crate(&self, tcx: TyCtxt) -> Box<dyn Any> {
 
             if reachability != ReachabilityType::None {
                 // Print compilation report.
-                results.print_report(tcx);
+                results.print_report(tcx, &queries);
 
                 if reachability != ReachabilityType::Harnesses
                     && reachability != ReachabilityType::AllFns
@@ -726,7 +746,7 @@ fn extend(
     }
 
     /// Prints a report at the end of the compilation.
-    fn print_report(&self, tcx: TyCtxt) {
+    fn print_report(&self, tcx: TyCtxt, queries: &QueryDb) {
         // Print all unsupported constructs.
         if !self.unsupported_constructs.is_empty() {
             // Sort alphabetically.
@@ -745,7 +765,9 @@ fn print_report(&self, tcx: TyCtxt) {
             tcx.dcx().warn(msg);
         }
 
-        if !self.concurrent_constructs.is_empty() {
+        // With `-Z concurrency`, the user opted into the thread model, which handles these.
+        let concurrency = queries.args().unstable_features.contains(&"concurrency".to_string());
+        if !self.concurrent_constructs.is_empty() && !concurrency {
             let mut msg = String::from(
                 "Kani currently does not support concurrency. The following constructs will be treated \
                 as sequential operations:\n",
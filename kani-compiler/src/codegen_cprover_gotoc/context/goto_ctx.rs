@@ -17,7 +17,7 @@
 use super::current_fn::CurrentFnCtx;
 use super::vtable_ctx::VtableCtx;
 use crate::codegen_cprover_gotoc::UnsupportedConstructs;
-use crate::codegen_cprover_gotoc::overrides::{GotocHooks, fn_hooks};
+use crate::codegen_cprover_gotoc::overrides::{GotocHooks, ShadowMap, fn_hooks};
 use crate::codegen_cprover_gotoc::utils::full_crate_name;
 use crate::kani_middle::transform::BodyTransformation;
 use crate::kani_queries::QueryDb;
@@ -98,6 +98,8 @@ pub struct GotocCtx<'tcx> {
     pub has_loop_contracts: bool,
     /// Track loop assign clause
     pub current_loop_modifies: Vec<Expr>,
+    /// The shadow memory maps of `kani::shadow` created by the codegen items, by id.
+    pub shadow_maps: FxHashMap<u64, ShadowMap>,
 }
 
 /// Constructor
@@ -129,6 +131,7 @@ pub fn new(
             transformer,
             has_loop_contracts: false,
             current_loop_modifies: Vec::new(),
+            shadow_maps: FxHashMap::default(),
         }
     }
 
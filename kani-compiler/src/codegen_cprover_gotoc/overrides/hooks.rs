@@ -15,13 +15,14 @@
 use crate::unwrap_or_return_codegen_unimplemented_stmt;
 use cbmc::goto_program::CIntType;
 use cbmc::goto_program::Symbol as GotoSymbol;
-use cbmc::goto_program::{BuiltinFn, Expr, Location, Stmt, Type};
+use cbmc::goto_program::{BuiltinFn, Expr, ExprValue, Location, Stmt, Type};
 use rustc_middle::ty::TyCtxt;
-use rustc_public::mir::mono::Instance;
+use rustc_public::mir::mono::{Instance, StaticDef};
 use rustc_public::mir::{BasicBlockIdx, Place};
 use rustc_public::rustc_internal;
 use rustc_public::ty::ClosureKind;
 use rustc_public::ty::RigidTy;
+use rustc_public::ty::{GenericArgKind, GenericArgs, TyKind};
 use rustc_public::{CrateDef, ty::Span};
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -774,6 +775,387 @@ fn handle(
     }
 }
 
+/// A hook for `kani::internal::start_thread(run, arg)`, which runs `run(arg)` in a new thread.
+///
+/// When `-Z concurrency` is enabled, this is encoded with CBMC's thread creation label:
+/// ```c
+/// __CPROVER_ASYNC_<N>: run(arg);
+/// ```
+/// which CBMC converts into a `START_THREAD` / `END_THREAD` block, so the thread may be interleaved
+/// with the other threads of the harness. Otherwise, the thread runs to completion in place, which
+/// is one of the possible schedules.
+struct StartThread;
+
+impl GotocHook for StartThread {
+    fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
+        unreachable!("{UNEXPECTED_CALL}")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        mut fargs: Vec<Expr>,
+        _assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 2);
+        let loc = gcx.codegen_span_stable(span);
+        let arg = fargs.pop().unwrap();
+        let run = fargs.pop().unwrap();
+        let call = run.dereference().call(vec![arg]).as_stmt(loc);
+        let thread = if gcx.queries.args().unstable_features.contains(&"concurrency".to_string()) {
+            let id = gcx.current_fn_mut().get_and_incr_counter();
+            call.with_label(format!("__CPROVER_ASYNC_{id}"))
+        } else {
+            call
+        };
+        Stmt::block(vec![thread, Stmt::goto(bb_label(target.unwrap()), loc)], loc)
+    }
+}
+
+/// A hook for `kani::internal::is_concurrent()`, which is true if `-Z concurrency` is enabled.
+struct IsConcurrent;
+
+impl GotocHook for IsConcurrent {
+    fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
+        unreachable!("{UNEXPECTED_CALL}")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        _instance: Instance,
+        fargs: Vec<Expr>,
+        assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert!(fargs.is_empty());
+        let loc = gcx.codegen_caller_span_stable(span);
+        let ret_place = unwrap_or_return_codegen_unimplemented_stmt!(
+            gcx,
+            gcx.codegen_place_stable(assign_to, loc)
+        );
+        let enabled = gcx.queries.args().unstable_features.contains(&"concurrency".to_string());
+        Stmt::block(
+            vec![
+                ret_place.goto_expr.assign(Expr::c_bool_constant(enabled), loc),
+                Stmt::goto(bb_label(target.unwrap()), loc),
+            ],
+            loc,
+        )
+    }
+}
+
+/// A shadow memory map of `kani::shadow`, i.e., a `ShadowMem` with a given id.
+///
+/// Each byte of the values of a map is backed by its own CBMC shadow memory field, which must be
+/// declared exactly once with the corresponding byte of the initial value of the map. CBMC
+/// collects the field declarations from the whole program before symbolic execution, so the
+/// initial value must be known during codegen.
+#[derive(Debug)]
+pub struct ShadowMap {
+    /// The bytes of the value of the locations that were never set.
+    init: Vec<u8>,
+    /// Whether the declarations of the fields were emitted.
+    declared: bool,
+}
+
+/// The name of the CBMC shadow memory field that stores the byte `byte` of the values of the map
+/// with id `map`.
+fn shadow_field(map: u64, byte: usize) -> Expr {
+    Expr::string_constant(format!("kani_shadow_{map}_{byte}"))
+}
+
+/// The id of the map of a `ShadowMem` type, or of a shadow memory hook instance.
+fn shadow_map_id(args: &GenericArgs, index: usize) -> u64 {
+    let GenericArgKind::Const(map) = &args.0[index] else {
+        unreachable!("expected the id of a shadow memory map, found {args:?}")
+    };
+    map.eval_target_usize().unwrap()
+}
+
+/// The size of the values of a `ShadowMem` type, or of a shadow memory hook instance, which is
+/// the number of fields of its map.
+fn shadow_value_size(args: &GenericArgs, index: usize) -> usize {
+    let GenericArgKind::Type(ty) = &args.0[index] else {
+        unreachable!("expected the value type of a shadow memory map, found {args:?}")
+    };
+    ty.layout().unwrap().shape().size.bytes()
+}
+
+/// The declarations of the shadow memory fields of `map`, with the bytes of its initial value.
+fn shadow_field_decls(map: u64, init: &[u8], loc: Location) -> Vec<Stmt> {
+    init.iter()
+        .enumerate()
+        .flat_map(|(byte, init)| {
+            ["__CPROVER_field_decl_global", "__CPROVER_field_decl_local"].map(|decl| {
+                Expr::symbol_expression(
+                    decl,
+                    Type::code(
+                        vec![
+                            Type::c_char().to_pointer().as_parameter(None, Some("field".into())),
+                            Type::unsigned_int(8).as_parameter(None, Some("init".into())),
+                        ],
+                        Type::empty(),
+                    ),
+                )
+                .call(vec![
+                    shadow_field(map, byte),
+                    Expr::int_constant(*init, Type::unsigned_int(8)),
+                ])
+                .as_stmt(loc)
+            })
+        })
+        .collect()
+}
+
+/// The bytes of a constant of a primitive type, or `None` if `value` is not one.
+fn constant_bytes(value: &Expr, size: usize) -> Option<Vec<u8>> {
+    let mut bytes = match value.value() {
+        ExprValue::BoolConstant(value) | ExprValue::CBoolConstant(value) => vec![*value as u8],
+        ExprValue::IntConstant(value) => value.to_signed_bytes_le(),
+        ExprValue::FloatConstant(value) => value.to_le_bytes().to_vec(),
+        ExprValue::DoubleConstant(value) => value.to_le_bytes().to_vec(),
+        _ => return None,
+    };
+    // Sign-extend negative integers to the size of their type.
+    let fill = if bytes.last().is_some_and(|byte| *byte >= 0x80) { 0xff } else { 0 };
+    bytes.resize(size, fill);
+    Some(bytes)
+}
+
+/// Register the shadow memory map of a static `ShadowMem`, if `def` is one.
+///
+/// Statics are declared before any function is codegen, so the maps of static variables are
+/// known by the time their `get` and `set` calls are codegen.
+pub fn declare_static_shadow_map(gcx: &mut GotocCtx, def: StaticDef) {
+    let Some(shadow_new) = gcx.queries.kani_functions().get(&KaniHook::ShadowNew.into()) else {
+        return;
+    };
+    let TyKind::RigidTy(RigidTy::Adt(shadow_mem, _)) = shadow_new.fn_sig().value.output().kind()
+    else {
+        unreachable!("expected `ShadowMem::new` to return a `ShadowMem`")
+    };
+    let TyKind::RigidTy(RigidTy::Adt(adt, args)) = def.ty().kind() else { return };
+    if adt != shadow_mem {
+        return;
+    }
+    let map = shadow_map_id(&args, 1);
+    // A `ShadowMem` only contains its initial value, so their bytes are the same.
+    let init =
+        def.eval_initializer().unwrap().bytes.iter().map(|byte| byte.unwrap_or_default()).collect();
+    if gcx.shadow_maps.insert(map, ShadowMap { init, declared: false }).is_some() {
+        utils::span_err(gcx.tcx, def.span(), duplicate_shadow_map(map));
+    }
+}
+
+fn duplicate_shadow_map(map: u64) -> String {
+    format!(
+        "the shadow memory map id `{map}` is used by more than one `ShadowMem`; \
+        give each map a different id"
+    )
+}
+
+/// The declarations of the shadow memory fields of the map that a `shadow_get` or `shadow_set`
+/// call refers to, if they weren't emitted yet.
+///
+/// The fields of a map created at runtime are declared where the map is created, see
+/// [ShadowNew].
+fn declare_shadow_fields(gcx: &mut GotocCtx, map: u64, loc: Location) -> Vec<Stmt> {
+    match gcx.shadow_maps.get_mut(&map) {
+        Some(shadow_map) if !shadow_map.declared => {
+            shadow_map.declared = true;
+            shadow_field_decls(map, &shadow_map.init, loc)
+        }
+        _ => vec![],
+    }
+}
+
+/// A pointer to the byte `byte` of `value`, which must be an lvalue.
+fn value_byte(value: &Expr, byte: usize) -> Expr {
+    value
+        .clone()
+        .address_of()
+        .cast_to(Type::unsigned_int(8).to_pointer())
+        .plus(Expr::int_constant(byte, Type::size_t()))
+}
+
+/// A hook for `kani::shadow::ShadowMem::new(init)` calls that are executed at runtime.
+///
+/// The shadow memory fields of the map are declared here with `init`, which must be a constant
+/// of a primitive type. A map id can only be created once, so this reports an error if the map
+/// was created elsewhere, and a failure if this call is executed more than once.
+struct ShadowNew;
+
+impl GotocHook for ShadowNew {
+    fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
+        unreachable!("{UNEXPECTED_CALL}")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        instance: Instance,
+        mut fargs: Vec<Expr>,
+        assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 1);
+        let value = fargs.pop().unwrap();
+        let map = shadow_map_id(&instance.args(), 1);
+        let size = shadow_value_size(&instance.args(), 0);
+        let loc = gcx.codegen_caller_span_stable(span);
+        let init = constant_bytes(&value, size).unwrap_or_else(|| {
+            utils::span_err(
+                gcx.tcx,
+                span,
+                "the initial value of a `ShadowMem` created at runtime must be a constant of a \
+                primitive type"
+                    .to_string(),
+            );
+            vec![0; size]
+        });
+        let decls = shadow_field_decls(map, &init, loc);
+        if gcx.shadow_maps.insert(map, ShadowMap { init, declared: true }).is_some() {
+            utils::span_err(gcx.tcx, span, duplicate_shadow_map(map));
+        }
+        let ret_place = unwrap_or_return_codegen_unimplemented_stmt!(
+            gcx,
+            gcx.codegen_place_stable(assign_to, loc)
+        );
+        let created = gcx
+            .ensure_global_var_init(
+                format!("kani_shadow_{map}_created"),
+                false,
+                false,
+                Type::c_bool(),
+                loc,
+                |_, _| Expr::c_false(),
+            )
+            .to_expr();
+        let mut stmts = decls;
+        stmts.extend([
+            gcx.codegen_assert_assume(
+                created.clone().not(),
+                PropertyClass::SafetyCheck,
+                &format!("the shadow memory map `{map}` is created more than once"),
+                loc,
+            ),
+            created.assign(Expr::c_true(), loc),
+            ret_place.goto_expr.clone().assign(
+                Expr::struct_expr_from_values(
+                    ret_place.goto_expr.typ().clone(),
+                    vec![value],
+                    &gcx.symbol_table,
+                ),
+                loc,
+            ),
+            Stmt::goto(bb_label(target.unwrap()), loc),
+        ]);
+        Stmt::block(stmts, loc)
+    }
+}
+
+/// Encodes `__CPROVER_get_field(ptr, field)` for each byte of the value returned by the shadow
+/// memory map of `kani::shadow`.
+struct ShadowGet;
+
+impl GotocHook for ShadowGet {
+    fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
+        unreachable!("{UNEXPECTED_CALL}")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        instance: Instance,
+        mut fargs: Vec<Expr>,
+        assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 1);
+        let ptr = fargs.pop().unwrap().cast_to(Type::unsigned_int(8).to_pointer());
+        let map = shadow_map_id(&instance.args(), 0);
+        let size = shadow_value_size(&instance.args(), 1);
+        let loc = gcx.codegen_caller_span_stable(span);
+        let ret_place = unwrap_or_return_codegen_unimplemented_stmt!(
+            gcx,
+            gcx.codegen_place_stable(assign_to, loc)
+        );
+        let get = Expr::symbol_expression(
+            "__CPROVER_get_field",
+            Type::code(
+                vec![
+                    Type::unsigned_int(8).to_pointer().as_parameter(None, Some("ptr".into())),
+                    Type::c_char().to_pointer().as_parameter(None, Some("field".into())),
+                ],
+                Type::unsigned_int(8),
+            ),
+        );
+        let mut stmts = declare_shadow_fields(gcx, map, loc);
+        stmts.extend((0..size).map(|byte| {
+            value_byte(&ret_place.goto_expr, byte)
+                .dereference()
+                .assign(get.clone().call(vec![ptr.clone(), shadow_field(map, byte)]), loc)
+        }));
+        stmts.push(Stmt::goto(bb_label(target.unwrap()), loc));
+        Stmt::block(stmts, loc)
+    }
+}
+
+/// Encodes `__CPROVER_set_field(ptr, field, value)` for each byte of the value stored by the
+/// shadow memory map of `kani::shadow`.
+struct ShadowSet;
+
+impl GotocHook for ShadowSet {
+    fn hook_applies(&self, _tcx: TyCtxt, _instance: Instance) -> bool {
+        unreachable!("{UNEXPECTED_CALL}")
+    }
+
+    fn handle(
+        &self,
+        gcx: &mut GotocCtx,
+        instance: Instance,
+        mut fargs: Vec<Expr>,
+        _assign_to: &Place,
+        target: Option<BasicBlockIdx>,
+        span: Span,
+    ) -> Stmt {
+        assert_eq!(fargs.len(), 2);
+        let value = fargs.pop().unwrap();
+        let ptr = fargs.pop().unwrap().cast_to(Type::unsigned_int(8).to_pointer());
+        let map = shadow_map_id(&instance.args(), 0);
+        let size = shadow_value_size(&instance.args(), 1);
+        let loc = gcx.codegen_caller_span_stable(span);
+        let set = Expr::symbol_expression(
+            "__CPROVER_set_field",
+            Type::code(
+                vec![
+                    Type::unsigned_int(8).to_pointer().as_parameter(None, Some("ptr".into())),
+                    Type::c_char().to_pointer().as_parameter(None, Some("field".into())),
+                    Type::unsigned_int(8).as_parameter(None, Some("value".into())),
+                ],
+                Type::empty(),
+            ),
+        );
+        // The value is stored in a variable to read its bytes.
+        let (value, decl) = gcx.decl_temp_variable(value.typ().clone(), Some(value), loc);
+        let mut stmts = declare_shadow_fields(gcx, map, loc);
+        stmts.push(decl);
+        stmts.extend((0..size).map(|byte| {
+            let value = value_byte(&value, byte).dereference();
+            set.clone().call(vec![ptr.clone(), shadow_field(map, byte), value]).as_stmt(loc)
+        }));
+        stmts.push(Stmt::goto(bb_label(target.unwrap()), loc));
+        Stmt::block(stmts, loc)
+    }
+}
+
 struct Forall;
 struct Exists;
 
@@ -920,7 +1302,12 @@ pub fn fn_hooks() -> GotocHooks {
         (KaniHook::AnyRaw, Rc::new(Nondet)),
         (KaniHook::SafetyCheck, Rc::new(SafetyCheck)),
         (KaniHook::SafetyCheckNoAssume, Rc::new(SafetyCheckNoAssume)),
+        (KaniHook::ShadowGet, Rc::new(ShadowGet)),
+        (KaniHook::ShadowNew, Rc::new(ShadowNew)),
+        (KaniHook::ShadowSet, Rc::new(ShadowSet)),
+        (KaniHook::StartThread, Rc::new(StartThread)),
         (KaniHook::IsAllocated, Rc::new(IsAllocated)),
+        (KaniHook::IsConcurrent, Rc::new(IsConcurrent)),
         (KaniHook::PointerObject, Rc::new(PointerObject)),
         (KaniHook::PointerOffset, Rc::new(PointerOffset)),
         (KaniHook::UnsupportedCheck, Rc::new(UnsupportedCheck)),
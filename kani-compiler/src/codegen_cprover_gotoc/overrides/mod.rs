@@ -8,4 +8,4 @@
 
 mod hooks;
 
-pub use hooks::{GotocHooks, fn_hooks};
+pub use hooks::{GotocHooks, ShadowMap, declare_static_shadow_map, fn_hooks};
@@ -21,6 +21,7 @@ pub(crate) struct FileDataToWrite {
     pub symbol_table: cbmc::goto_program::SymbolTable,
     pub vtable_restrictions: Option<kani_metadata::VtableCtxResults>,
     pub type_map: BTreeMap<InternedString, InternedString>,
+    pub enum_map: BTreeMap<InternedString, BTreeMap<String, String>>,
     pub pretty_name_map: BTreeMap<InternedString, Option<InternedString>>,
     pub pretty: bool,
 }
@@ -96,6 +97,7 @@ fn handle_file(
         symbol_table,
         vtable_restrictions,
         type_map,
+        enum_map,
         pretty_name_map,
         pretty,
     }: FileDataToWrite,
@@ -103,6 +105,7 @@ fn handle_file(
     write_file(&symtab_goto, ArtifactType::PrettyNameMap, &pretty_name_map, pretty);
     write_goto_binary_file(&symtab_goto, &symbol_table);
     write_file(&symtab_goto, ArtifactType::TypeMap, &type_map, pretty);
+    write_file(&symtab_goto, ArtifactType::EnumMap, &enum_map, pretty);
     // If they exist, write out vtable virtual call function pointer restrictions
     if let Some(restrictions) = vtable_restrictions {
         write_file(&symtab_goto, ArtifactType::VTableRestriction, &restrictions, pretty);
@@ -20,7 +20,7 @@
 use crate::codegen_aeneas_llbc::LlbcCodegenBackend;
 #[cfg(feature = "cprover")]
 use crate::codegen_cprover_gotoc::GotocCodegenBackend;
-use crate::kani_middle::check_crate_items;
+use crate::kani_middle::{check_crate_items, playback};
 use crate::kani_queries::QueryDb;
 use crate::session::init_session;
 use clap::Parser;
@@ -40,6 +40,13 @@ pub fn run(args: Vec<String>) {
     kani_compiler.run(args);
 }
 
+/// Compile concrete playback tests with the regular rustc backend, applying the stubs and
+/// contracts of the harnesses they play back.
+pub fn run_playback(args: Vec<String>) {
+    debug!(?args, "run_playback");
+    run_compiler(&args, &mut PlaybackCompiler);
+}
+
 /// Configure the LLBC backend (Aeneas's IR).
 #[allow(unused)]
 fn llbc_backend(_queries: Arc<Mutex<QueryDb>>) -> Box<dyn CodegenBackend> {
@@ -135,3 +142,22 @@ fn after_analysis(
         Compilation::Continue
     }
 }
+
+/// This object controls the compiler behavior when compiling concrete playback tests.
+struct PlaybackCompiler;
+
+impl Callbacks for PlaybackCompiler {
+    fn config(&mut self, config: &mut Config) {
+        config.override_queries = Some(playback::provide);
+    }
+
+    /// Collect the configuration of the played back harnesses before code generation.
+    fn after_analysis(
+        &mut self,
+        _compiler: &rustc_interface::interface::Compiler,
+        tcx: TyCtxt<'_>,
+    ) -> Compilation {
+        rustc_internal::run(tcx, || playback::collect_playback_config(tcx)).unwrap();
+        Compilation::Continue
+    }
+}
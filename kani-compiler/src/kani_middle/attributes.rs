@@ -13,11 +13,11 @@
     def::DefKind,
     def_id::{DefId, LocalDefId},
 };
-use rustc_middle::ty::{Instance, TyCtxt, TyKind};
+use rustc_middle::ty::{Instance, TyCtxt, TyKind, TypeVisitableExt};
 use rustc_public::crate_def::Attribute as AttributeStable;
 use rustc_public::mir::mono::Instance as InstanceStable;
 use rustc_public::rustc_internal;
-use rustc_public::ty::FnDef as FnDefStable;
+use rustc_public::ty::{FnDef as FnDefStable, Ty as TyStable};
 use rustc_public::{CrateDef, DefId as DefIdStable, Symbol as SymbolStable};
 use rustc_session::Session;
 use rustc_span::{Span, Symbol};
@@ -27,7 +27,7 @@
 use syn::punctuated::Punctuated;
 use syn::{Expr, ExprLit, Lit, PathSegment, TypePath};
 
-use super::resolve::{FnResolution, ResolveError, resolve_fn_path};
+use super::resolve::{FnResolution, ResolveError, resolve_fn_path, resolve_ty};
 use tracing::{debug, trace};
 
 #[derive(Debug, Clone, Copy, AsRefStr, EnumString, PartialEq, Eq, PartialOrd, Ord)]
@@ -60,6 +60,16 @@ enum KaniAttributeKind {
     /// Attribute on a function with a contract that identifies the code
     /// implementing the recursive check for the harness.
     RecursionCheck,
+    /// Attribute on a trait method declaration that identifies the provided
+    /// method generated from its contract, which is inherited by every
+    /// implementation of the method.
+    TraitContract,
+    /// Attribute on a function with a contract that holds the text of one of
+    /// its `requires` clauses.
+    ContractRequires,
+    /// Attribute on a function with a contract that holds the text of one of
+    /// its `ensures` clauses.
+    ContractEnsures,
     /// Attribute on a function that was auto-generated from expanding a
     /// function contract.
     IsContractGenerated,
@@ -81,6 +91,9 @@ enum KaniAttributeKind {
     /// Used to mark functions where generating automatic pointer checks should be disabled. This is
     /// used later to automatically attach pragma statements to locations.
     DisableChecks,
+    /// Attribute on a generic function that lists the types the `autoharness` subcommand should
+    /// instantiate it with.
+    AutoharnessTypes,
 }
 
 impl KaniAttributeKind {
@@ -100,11 +113,15 @@ pub fn is_harness_only(self) -> bool {
             | KaniAttributeKind::RecursionTracker
             | KaniAttributeKind::ReplacedWith
             | KaniAttributeKind::RecursionCheck
+            | KaniAttributeKind::TraitContract
             | KaniAttributeKind::CheckedWith
             | KaniAttributeKind::ModifiesWrapper
             | KaniAttributeKind::AssertedWith
+            | KaniAttributeKind::ContractRequires
+            | KaniAttributeKind::ContractEnsures
             | KaniAttributeKind::IsContractGenerated
-            | KaniAttributeKind::DisableChecks => false,
+            | KaniAttributeKind::DisableChecks
+            | KaniAttributeKind::AutoharnessTypes => false,
         }
     }
 
@@ -220,6 +237,53 @@ pub fn interpret_stub_verified_attribute(&self) -> Vec<FnDefStable> {
             .collect()
     }
 
+    /// Parse and resolve the types listed in the `autoharness_types(TYPE, ...)` attribute.
+    ///
+    /// Any error is emitted and the type that caused it is filtered out.
+    pub fn autoharness_types(&self) -> Option<Vec<TyStable>> {
+        let attr = self.expect_maybe_one(KaniAttributeKind::AutoharnessTypes)?;
+        let current_module =
+            self.tcx.parent_module_from_def_id(self.item.expect_local()).to_local_def_id();
+        let parser = Punctuated::<syn::Type, syn::Token![,]>::parse_terminated;
+        let types = match syn_attr(self.tcx, attr).parse_args_with(parser) {
+            Ok(types) => types,
+            Err(err) => {
+                self.tcx.dcx().span_err(
+                    attr.span(),
+                    format!(
+                        "attribute `kani::{}` takes a list of types; {err}",
+                        KaniAttributeKind::AutoharnessTypes.as_ref()
+                    ),
+                );
+                return None;
+            }
+        };
+        let resolved = types
+            .iter()
+            .filter_map(|typ| match resolve_ty(self.tcx, current_module, typ) {
+                Ok(ty) if rustc_internal::internal(self.tcx, ty).has_non_region_param() => {
+                    self.tcx.dcx().span_err(
+                        attr.span(),
+                        format!(
+                            "`{}` is not a concrete type; generic type arguments are not supported",
+                            typ.to_token_stream()
+                        ),
+                    );
+                    None
+                }
+                Ok(ty) => Some(ty),
+                Err(err) => {
+                    self.tcx.dcx().span_err(
+                        attr.span(),
+                        format!("failed to resolve `{}`: {err}", typ.to_token_stream()),
+                    );
+                    None
+                }
+            })
+            .collect();
+        Some(resolved)
+    }
+
     pub(crate) fn has_recursion(&self) -> bool {
         self.map.contains_key(&KaniAttributeKind::Recursion)
     }
@@ -278,6 +342,65 @@ pub fn contract_attributes(&self) -> Option<ContractAttributes> {
         })
     }
 
+    /// Return the provided method generated from the contract of this trait method declaration.
+    pub fn trait_contract(&self) -> Option<DefId> {
+        let name = self.attribute_value(KaniAttributeKind::TraitContract)?;
+        let trait_id = self.tcx.trait_of_assoc(self.item)?;
+        self.tcx
+            .associated_items(trait_id)
+            .filter_by_name_unhygienic(name)
+            .find(|item| item.is_fn())
+            .map(|item| item.def_id)
+    }
+
+    /// Return the text of the `requires` and `ensures` clauses of this function's contract, in
+    /// the order they were written.
+    pub fn contract_clauses(&self) -> (Vec<String>, Vec<String>) {
+        // Each contract attribute is recorded on top of the ones expanded after it.
+        let clauses = |kind| {
+            self.map
+                .get(&kind)
+                .map_or([].as_slice(), Vec::as_slice)
+                .iter()
+                .rev()
+                .filter_map(|attr| expect_key_string_value(self.tcx.sess, attr).ok())
+                .map(|clause| clause.to_string())
+                .collect()
+        };
+        (clauses(KaniAttributeKind::ContractRequires), clauses(KaniAttributeKind::ContractEnsures))
+    }
+
+    /// Return the trait method declaration whose contract is implemented by this item, if this
+    /// is a provided method generated from the contract of a declaration.
+    pub fn trait_contract_decl(&self) -> Option<DefId> {
+        if !self.is_contract_generated() {
+            return None;
+        }
+        let trait_id = self.tcx.trait_of_assoc(self.item)?;
+        let name = self.item_name();
+        self.tcx.associated_item_def_ids(trait_id).iter().copied().find(|def_id| {
+            KaniAttributes::for_item(self.tcx, *def_id)
+                .attribute_value(KaniAttributeKind::TraitContract)
+                .is_some_and(|wrapper| wrapper == name)
+        })
+    }
+
+    /// Return the provided method implementing the contract this item inherits from a trait
+    /// method declaration, if any.
+    ///
+    /// The contract of an implementation takes precedence over the one of its declaration, so
+    /// nothing is inherited by implementations that have a contract of their own.
+    pub fn inherited_contract(&self) -> Option<DefId> {
+        if self.has_contract() {
+            return None;
+        }
+        let trait_item = self.tcx.opt_associated_item(self.item)?.trait_item_def_id;
+        match trait_item {
+            Some(decl) => KaniAttributes::for_item(self.tcx, decl).trait_contract(),
+            None => self.trait_contract(),
+        }
+    }
+
     // Is this a function inserted by Kani instrumentation?
     pub fn is_kani_instrumentation(&self) -> bool {
         self.fn_marker().is_some() || self.is_contract_generated()
@@ -318,7 +441,7 @@ pub(super) fn check_attributes(&self) {
                 KaniAttributeKind::ShouldPanic => {
                     expect_single(self.tcx, kind, attrs);
                     attrs.iter().for_each(|attr| {
-                        expect_no_args(self.tcx, kind, attr);
+                        parse_should_panic(self.tcx, attr);
                     })
                 }
                 KaniAttributeKind::Recursion => {
@@ -375,10 +498,16 @@ pub(super) fn check_attributes(&self) {
                 | KaniAttributeKind::CheckedWith
                 | KaniAttributeKind::ModifiesWrapper
                 | KaniAttributeKind::RecursionCheck
+                | KaniAttributeKind::TraitContract
                 | KaniAttributeKind::AssertedWith
                 | KaniAttributeKind::ReplacedWith => {
                     self.attribute_value(kind);
                 }
+                KaniAttributeKind::ContractRequires | KaniAttributeKind::ContractEnsures => {
+                    attrs.iter().for_each(|attr| {
+                        let _ = expect_key_string_value(self.tcx.sess, attr);
+                    })
+                }
                 KaniAttributeKind::IsContractGenerated => {
                     // Ignored here because this is only used by the proc macros
                     // to communicate with one another. So by the time it gets
@@ -391,6 +520,16 @@ pub(super) fn check_attributes(&self) {
                     // Ignored here, because it should be an internal attribute. Actual validation
                     // happens when pragmas are generated.
                 }
+                KaniAttributeKind::AutoharnessTypes => {
+                    expect_single(self.tcx, kind, attrs);
+                    if !self.tcx.generics_of(self.item).requires_monomorphization(self.tcx) {
+                        local_error(format!(
+                            "the `{}` attribute can only be applied to generic functions",
+                            kind.as_ref()
+                        ));
+                    }
+                    self.autoharness_types();
+                }
             }
         }
     }
@@ -466,7 +605,7 @@ fn report_unstable_forbidden(&self, unstable_attr: &UnstableAttribute) -> ErrorG
 
     /// Is this item a harness? (either `proof` or `proof_for_contract`
     /// attribute are present)
-    fn is_proof_harness(&self) -> bool {
+    pub(crate) fn is_proof_harness(&self) -> bool {
         self.map.contains_key(&KaniAttributeKind::Proof)
             || self.map.contains_key(&KaniAttributeKind::ProofForContract)
     }
@@ -513,7 +652,10 @@ pub fn harness_attributes(&self) -> HarnessAttributes {
         };
         self.map.iter().fold(harness_attrs, |mut harness, (kind, attributes)| {
             match kind {
-                KaniAttributeKind::ShouldPanic => harness.should_panic = true,
+                KaniAttributeKind::ShouldPanic => {
+                    harness.should_panic = true;
+                    harness.expected_panic = parse_should_panic(self.tcx, attributes[0]);
+                }
                 KaniAttributeKind::Recursion => {
                     self.tcx.dcx().span_err(self.tcx.def_span(self.item), "The attribute `kani::recursion` should only be used in combination with function contracts.");
                 }
@@ -538,7 +680,10 @@ pub fn harness_attributes(&self) -> HarnessAttributes {
                 | KaniAttributeKind::ModifiesWrapper
                 | KaniAttributeKind::RecursionCheck
                 | KaniAttributeKind::RecursionTracker
+                | KaniAttributeKind::TraitContract
                 | KaniAttributeKind::AssertedWith
+                | KaniAttributeKind::ContractRequires
+                | KaniAttributeKind::ContractEnsures
                 | KaniAttributeKind::ReplacedWith => {
                     self.tcx.dcx().span_err(self.tcx.def_span(self.item), format!("Contracts are not supported on harnesses. (Found the kani-internal contract attribute `{}`)", kind.as_ref()));
                 }
@@ -546,7 +691,7 @@ pub fn harness_attributes(&self) -> HarnessAttributes {
                     // Internal attribute which shouldn't exist here.
                     unreachable!()
                 }
-                KaniAttributeKind::FnMarker => {
+                KaniAttributeKind::FnMarker | KaniAttributeKind::AutoharnessTypes => {
                     /* no-op */
                 }
             };
@@ -560,7 +705,26 @@ fn handle_proof_for_contract(&self, attr: &Attribute) {
             Some(def) => def,
         };
         let target_attributes = KaniAttributes::for_def_id(self.tcx, target_def.def_id());
-        if target_attributes.contract_attributes().is_none() {
+        if target_attributes.trait_contract().is_some() {
+            let trait_id = self.tcx.trait_of_assoc(target_attributes.item).unwrap();
+            self.tcx
+                .dcx()
+                .struct_span_err(
+                    attr.span(),
+                    format!(
+                        "Failed to check contract: `{}` is a trait method declaration.",
+                        target_def.name(),
+                    ),
+                )
+                .with_help(format!(
+                    "Use `<T as {}>::{}` to check the contract of the implementation for `T`.",
+                    self.tcx.def_path_str(trait_id),
+                    target_attributes.item_name(),
+                ))
+                .emit();
+        } else if target_attributes.contract_attributes().is_none()
+            && target_attributes.inherited_contract().is_none()
+        {
             self.tcx
                 .dcx()
                 .struct_span_err(
@@ -595,9 +759,9 @@ fn check_stub_verified(&self, attr: &Attribute) {
             } else {
                 seen.insert(stub_target);
             }
-            if KaniAttributes::for_def_id(self.tcx, stub_target.def_id())
-                .contract_attributes()
-                .is_none()
+            let target_attributes = KaniAttributes::for_def_id(self.tcx, stub_target.def_id());
+            if target_attributes.contract_attributes().is_none()
+                && target_attributes.inherited_contract().is_none()
             {
                 dcx.struct_span_err(
                     attr.span(),
@@ -940,6 +1104,37 @@ fn expect_no_args(tcx: TyCtxt, kind: KaniAttributeKind, attr: &Attribute) {
     }
 }
 
+/// Return the expected panic message from the given `should_panic` attribute, if any.
+///
+/// The attribute can either have no arguments, or a single `expected = "<message>"` argument.
+fn parse_should_panic(tcx: TyCtxt, attr: &Attribute) -> Option<String> {
+    const ATTRIBUTE: &str = "#[kani::should_panic]";
+    if attr.is_word() {
+        return None;
+    }
+    let attr_args = attr.meta_item_list().unwrap_or_default();
+    let expected = match attr_args.as_slice() {
+        [] => return None,
+        [arg] => arg.meta_item().and_then(|meta_item| match &meta_item.kind {
+            MetaItemKind::NameValue(lit) if meta_item.has_name(rustc_span::sym::expected) => {
+                match lit.kind {
+                    LitKind::Str(message, _) => Some(message.to_string()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }),
+        _ => None,
+    };
+    if expected.is_none() {
+        tcx.dcx()
+            .struct_span_err(attr.span(), format!("invalid argument for `{ATTRIBUTE}` attribute"))
+            .with_help("expected `#[kani::should_panic]` or `#[kani::should_panic(expected = \"<message>\")]`")
+            .emit();
+    }
+    expected
+}
+
 /// Return the unwind value from the given attribute.
 fn parse_unwind(tcx: TyCtxt, attr: &Attribute) -> Option<u32> {
     // Get Attribute value and if it's not none, assign it to the metadata
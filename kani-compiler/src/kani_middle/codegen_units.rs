@@ -7,6 +7,8 @@
 //! Today, only stub / contracts can affect the harness codegen. Thus, we group the harnesses
 //! according to their stub configuration.
 
+#[cfg(feature = "llbc")]
+use crate::args::BackendOption;
 use crate::args::{Arguments, ReachabilityType};
 use crate::kani_middle::attributes::{KaniAttributes, is_proof_harness};
 use crate::kani_middle::kani_functions::{KaniIntrinsic, KaniModel};
@@ -14,17 +16,19 @@
     gen_automatic_proof_metadata, gen_contracts_metadata, gen_proof_metadata,
 };
 use crate::kani_middle::reachability::filter_crate_items;
+use crate::kani_middle::resolve::resolve_ty_str;
 use crate::kani_middle::stubbing::{check_compatibility, harness_stub_map};
 use crate::kani_middle::{can_derive_arbitrary, implements_arbitrary};
 use crate::kani_queries::QueryDb;
 use fxhash::{FxHashMap, FxHashSet};
+use itertools::Itertools;
 use kani_metadata::{
     ArtifactType, AssignsContract, AutoHarnessMetadata, AutoHarnessSkipReason, HarnessMetadata,
-    KaniMetadata, find_proof_harnesses,
+    KaniMetadata, Mutant, ReachableItem, find_proof_harnesses,
 };
 use regex::RegexSet;
-use rustc_hir::def_id::DefId;
-use rustc_middle::ty::TyCtxt;
+use rustc_hir::def_id::{CRATE_DEF_ID, DefId};
+use rustc_middle::ty::{self, GenericParamDefKind, TyCtxt, TypeVisitableExt};
 use rustc_public::mir::mono::Instance;
 use rustc_public::rustc_internal;
 use rustc_public::ty::{FnDef, GenericArgKind, GenericArgs, RigidTy, Ty, TyKind};
@@ -156,6 +160,20 @@ pub fn store_modifies(&mut self, harness_modifies: &[(Harness, AssignsContract)]
         }
     }
 
+    /// We store the items that are reachable from each harness.
+    pub fn store_reachable_items(&mut self, harness_items: Vec<(Harness, Vec<ReachableItem>)>) {
+        for (harness, items) in harness_items {
+            self.harness_info.get_mut(&harness).unwrap().reachable_items = items;
+        }
+    }
+
+    /// We store the mutants of the functions that are reachable from each harness.
+    pub fn store_mutants(&mut self, harness_mutants: Vec<(Harness, Vec<Mutant>)>) {
+        for (harness, mutants) in harness_mutants {
+            self.harness_info.get_mut(&harness).unwrap().mutants = mutants;
+        }
+    }
+
     /// We flag that the harness contains usage of loop contracts.
     pub fn store_loop_contracts(&mut self, harnesses: &[Harness]) {
         for harness in harnesses {
@@ -166,7 +184,12 @@ pub fn store_loop_contracts(&mut self, harnesses: &[Harness]) {
 
     /// Write compilation metadata into a file.
     pub fn write_metadata(&self, queries: &QueryDb, tcx: TyCtxt) {
-        let metadata = self.generate_metadata(tcx);
+        // The theorem stubs of the Lean backend state the contracts of the crate.
+        #[cfg(feature = "llbc")]
+        let with_specs = queries.args().backend == BackendOption::Llbc;
+        #[cfg(not(feature = "llbc"))]
+        let with_specs = false;
+        let metadata = self.generate_metadata(tcx, with_specs);
         let outpath = metadata_output_path(tcx);
         store_metadata(queries, &metadata, &outpath);
     }
@@ -175,8 +198,13 @@ pub fn harness_model_path(&self, harness: Harness) -> Option<&PathBuf> {
         self.harness_info[&harness].goto_file.as_ref()
     }
 
+    #[cfg(feature = "llbc")]
+    pub fn harness_metadata(&self, harness: Harness) -> &HarnessMetadata {
+        &self.harness_info[&harness]
+    }
+
     /// Generate [KaniMetadata] for the target crate.
-    fn generate_metadata(&self, tcx: TyCtxt) -> KaniMetadata {
+    fn generate_metadata(&self, tcx: TyCtxt, with_specs: bool) -> KaniMetadata {
         let (proof_harnesses, test_harnesses) =
             self.harness_info.values().cloned().partition(|md| md.attributes.is_proof_harness());
         KaniMetadata {
@@ -184,7 +212,7 @@ fn generate_metadata(&self, tcx: TyCtxt) -> KaniMetadata {
             proof_harnesses,
             unsupported_features: vec![],
             test_harnesses,
-            contracted_functions: gen_contracts_metadata(tcx, &self.harness_info),
+            contracted_functions: gen_contracts_metadata(tcx, &self.harness_info, with_specs),
             autoharness_md: AUTOHARNESS_MD.get().cloned(),
         }
     }
@@ -428,25 +456,15 @@ fn automatic_harness_partition(
 
     let included_set = make_regex_set(args.autoharness_included_patterns.clone());
     let excluded_set = make_regex_set(args.autoharness_excluded_patterns.clone());
+    let generic_types = resolve_generic_types(tcx, &args.autoharness_generic_types);
 
     // Cache whether a type implements or can derive Arbitrary
     let mut ty_arbitrary_cache: FxHashMap<Ty, bool> = FxHashMap::default();
 
-    // If `func` is not eligible for an automatic harness, return the reason why; if it is eligible, return None.
+    // If `instance` is not eligible for an automatic harness, return the reason why; if it is eligible, return None.
     // Note that we only return one reason for ineligiblity, when there could be multiple;
     // we can revisit this implementation choice in the future if users request more verbose output.
-    let mut skip_reason = |fn_item: CrateItem| -> Option<AutoHarnessSkipReason> {
-        if KaniAttributes::for_def_id(tcx, fn_item.def_id()).is_kani_instrumentation() {
-            return Some(AutoHarnessSkipReason::KaniImpl);
-        }
-
-        let instance = match Instance::try_from(fn_item) {
-            Ok(inst) => inst,
-            Err(_) => {
-                return Some(AutoHarnessSkipReason::GenericFn);
-            }
-        };
-
+    let mut skip_reason = |instance: Instance| -> Option<AutoHarnessSkipReason> {
         if !instance.has_body() {
             return Some(AutoHarnessSkipReason::NoBody);
         }
@@ -502,16 +520,118 @@ fn automatic_harness_partition(
     let mut skipped = BTreeMap::new();
 
     for func in crate_fns {
-        if let Some(reason) = skip_reason(func) {
+        let attributes = KaniAttributes::for_def_id(tcx, func.def_id());
+        if attributes.is_kani_instrumentation() {
+            skipped.insert(func.name(), AutoHarnessSkipReason::KaniImpl);
+            continue;
+        }
+
+        let Ok(instance) = Instance::try_from(func) else {
+            // The types provided via the attribute take precedence over the ones provided via the command line.
+            let candidates =
+                attributes.autoharness_types().unwrap_or_else(|| generic_types.clone());
+            match instantiate_generic_fn(tcx, func, &candidates) {
+                Some(instances) if !instances.is_empty() => {
+                    // Report each instantiation under its own name, e.g. `foo::<u8>`.
+                    for instance in instances {
+                        if let Some(reason) = skip_reason(instance) {
+                            skipped.insert(instance.name(), reason);
+                        } else {
+                            chosen.push(instance);
+                        }
+                    }
+                }
+                Some(_) if !candidates.is_empty() => {
+                    skipped.insert(func.name(), AutoHarnessSkipReason::NoValidInstantiation);
+                }
+                _ => {
+                    skipped.insert(func.name(), AutoHarnessSkipReason::GenericFn);
+                }
+            }
+            continue;
+        };
+
+        if let Some(reason) = skip_reason(instance) {
             skipped.insert(func.name(), reason);
         } else {
-            chosen.push(Instance::try_from(func).unwrap());
+            chosen.push(instance);
         }
     }
 
     (chosen, skipped)
 }
 
+/// Resolve the types provided via `--autoharness-generic-type` relative to the crate root.
+fn resolve_generic_types(tcx: TyCtxt, types: &[String]) -> Vec<Ty> {
+    let resolved = types
+        .iter()
+        .filter_map(|typ| match resolve_ty_str(tcx, CRATE_DEF_ID, typ) {
+            Ok(ty) if rustc_internal::internal(tcx, ty).has_non_region_param() => {
+                tcx.dcx().err(format!(
+                    "`{typ}` is not a concrete type; generic type arguments are not supported"
+                ));
+                None
+            }
+            Ok(ty) => Some(ty),
+            Err(err) => {
+                tcx.dcx().err(format!("failed to resolve autoharness generic type `{typ}`: {err}"));
+                None
+            }
+        })
+        .collect();
+    tcx.dcx().abort_if_errors();
+    resolved
+}
+
+/// Instantiate the generic function `fn_item` with every combination of `candidates` for its type parameters,
+/// and return the instances whose trait bounds are satisfied.
+/// Return `None` if `fn_item` has const generic parameters, since we can only substitute types.
+fn instantiate_generic_fn(
+    tcx: TyCtxt,
+    fn_item: CrateItem,
+    candidates: &[Ty],
+) -> Option<Vec<Instance>> {
+    let def_id = rustc_internal::internal(tcx, fn_item.def_id());
+
+    // Collect the parameters of the function, including the ones inherited from its parent (e.g., an impl block).
+    let mut params = vec![];
+    let mut generics = Some(tcx.generics_of(def_id));
+    while let Some(current) = generics {
+        params.extend(current.own_params.iter().map(|param| &param.kind));
+        generics = current.parent.map(|parent| tcx.generics_of(parent));
+    }
+    if params.iter().any(|kind| matches!(kind, GenericParamDefKind::Const { .. })) {
+        return None;
+    }
+    let num_types =
+        params.iter().filter(|kind| matches!(kind, GenericParamDefKind::Type { .. })).count();
+
+    let instances = (0..num_types)
+        .map(|_| candidates.iter())
+        .multi_cartesian_product()
+        .filter_map(|types| {
+            let mut types = types.into_iter();
+            let args = ty::GenericArgs::for_item(tcx, def_id, |param, _| match param.kind {
+                GenericParamDefKind::Lifetime => tcx.lifetimes.re_erased.into(),
+                GenericParamDefKind::Type { .. } => {
+                    rustc_internal::internal(tcx, *types.next().unwrap()).into()
+                }
+                GenericParamDefKind::Const { .. } => unreachable!(),
+            });
+            // Skip instantiations that do not satisfy the function's trait bounds.
+            if tcx.instantiate_and_check_impossible_predicates((def_id, args)) {
+                return None;
+            }
+            let fn_ty = rustc_internal::stable(ty::Ty::new_fn_def(tcx, def_id, args));
+            let TyKind::RigidTy(RigidTy::FnDef(fn_def, args)) = fn_ty.kind() else {
+                unreachable!("Expected function type, but found: {fn_ty}")
+            };
+            Instance::resolve(fn_def, &args).ok()
+        })
+        .collect();
+    Some(instances)
+}
+
 #[cfg(test)]
 mod autoharness_filter_tests {
     use super::*;
@@ -4,15 +4,23 @@
 //! given function.
 
 use std::collections::HashMap;
+use std::hash::Hash;
 use std::path::Path;
 
 use crate::kani_middle::codegen_units::Harness;
+use crate::kani_middle::transform::mutation::{MutantId, mutation_sites};
 use crate::kani_middle::{KaniAttributes, SourceLocation};
-use kani_metadata::ContractedFunction;
-use kani_metadata::{ArtifactType, HarnessAttributes, HarnessKind, HarnessMetadata};
+use kani_metadata::{
+    ArtifactType, HarnessAttributes, HarnessKind, HarnessMetadata, Mutant, ReachableItem,
+};
+use kani_metadata::{ContractSpec, ContractedFunction};
+use rustc_data_structures::fingerprint::Fingerprint;
+use rustc_data_structures::stable_hasher::StableHasher;
 use rustc_middle::ty::TyCtxt;
-use rustc_public::mir::mono::Instance;
-use rustc_public::{CrateDef, CrateItems, DefId};
+use rustc_public::mir::mono::{Instance, MonoItem};
+use rustc_public::rustc_internal;
+use rustc_public::{CrateDef, CrateItem, CrateItems, DefId};
+use rustc_span::{FileName, RealFileName};
 
 /// Create the harness metadata for a proof harness for a given function.
 pub fn gen_proof_metadata(tcx: TyCtxt, instance: Instance, base_name: &Path) -> HarnessMetadata {
@@ -40,16 +48,20 @@ pub fn gen_proof_metadata(tcx: TyCtxt, instance: Instance, base_name: &Path) ->
         contract: Default::default(),
         has_loop_contracts: false,
         is_automatically_generated: false,
+        reachable_items: vec![],
+        mutants: vec![],
     }
 }
 
 /// Collects contract and contract harness metadata.
 ///
 /// For each function with contracts (or that is a target of a contract harness),
-/// construct a `ContractedFunction` object for it.
+/// construct a `ContractedFunction` object for it. The clauses of the contracts are only
+/// collected `with_specs`.
 pub fn gen_contracts_metadata(
     tcx: TyCtxt,
     harness_info: &HashMap<Harness, HarnessMetadata>,
+    with_specs: bool,
 ) -> Vec<ContractedFunction> {
     // We work with `rustc_public::CrateItem` instead of `rustc_public::Instance` to include generic items
     let crate_items: CrateItems = rustc_public::all_local_items();
@@ -61,9 +73,16 @@ pub fn gen_contracts_metadata(
         let file = SourceLocation::new(item.span()).filename;
         let attributes = KaniAttributes::for_def_id(tcx, item.def_id());
 
-        if attributes.has_contract() {
-            fn_to_data
-                .insert(item.def_id(), ContractedFunction { function, file, harnesses: vec![] });
+        if attributes.trait_contract_decl().is_some() {
+            // The implementations that inherit the contract of a trait method are listed instead.
+            continue;
+        }
+        if attributes.has_contract() || attributes.inherited_contract().is_some() {
+            let spec = with_specs.then(|| contract_spec(&attributes, item));
+            fn_to_data.insert(
+                item.def_id(),
+                ContractedFunction { function, file, harnesses: vec![], spec },
+            );
         // This logic finds manual contract harnesses only (automatic harnesses are a Kani intrinsic, not crate items annotated with the proof_for_contract attribute).
         } else if let Some(def) = attributes.interpret_for_contract_attribute() {
             let target_def_id = def.def_id();
@@ -78,6 +97,7 @@ pub fn gen_contracts_metadata(
                         function: item.name(),
                         file,
                         harnesses: vec![function],
+                        spec: None,
                     },
                 );
             }
@@ -105,6 +125,29 @@ pub fn gen_contracts_metadata(
     fn_to_data.into_values().collect()
 }
 
+/// Collect the arguments of `item` and the clauses of its contract.
+fn contract_spec(attributes: &KaniAttributes, item: CrateItem) -> ContractSpec {
+    let args = item.body().map_or_else(Vec::new, |body| {
+        body.arg_locals()
+            .iter()
+            .enumerate()
+            .map(|(idx, arg)| {
+                // Note that rustc_public argument indices start at 1.
+                let name = body
+                    .var_debug_info
+                    .iter()
+                    .find(|var| {
+                        var.argument_index.is_some_and(|arg_idx| idx + 1 == usize::from(arg_idx))
+                    })
+                    .map_or("_".to_string(), |debug_info| debug_info.name.to_string());
+                (name, arg.ty.to_string())
+            })
+            .collect()
+    });
+    let (requires, ensures) = attributes.contract_clauses();
+    ContractSpec { args, requires, ensures }
+}
+
 /// Generate metadata for automatically generated harnesses.
 /// For now, we just use the data from the function we are verifying; since we only generate one automatic harness per function,
 /// the metdata from that function uniquely identifies the harness.
@@ -148,5 +191,84 @@ pub fn gen_automatic_proof_metadata(
         contract: Default::default(),
         has_loop_contracts: false,
         is_automatically_generated: true,
+        reachable_items: vec![],
+        mutants: vec![],
     }
 }
+
+/// Summarize the items reachable from a harness, so the driver can determine whether the harness
+/// is affected by a code change.
+///
+/// Only items defined in local source files are included, since the items from the standard
+/// library can only change together with the toolchain.
+pub fn gen_reachable_items_metadata(tcx: TyCtxt, items: &[MonoItem]) -> Vec<ReachableItem> {
+    let source_map = tcx.sess.source_map();
+    items
+        .iter()
+        .filter_map(|item| {
+            let instance = match item {
+                MonoItem::Fn(instance) => *instance,
+                MonoItem::Static(def) => Instance::from(*def),
+                MonoItem::GlobalAsm(_) => return None,
+            };
+            let body = instance.body()?;
+            let FileName::Real(RealFileName::LocalPath(path)) =
+                source_map.span_to_filename(rustc_internal::internal(tcx, body.span))
+            else {
+                return None;
+            };
+            // The MIR dump doesn't include source locations, so moving an item around doesn't
+            // change its fingerprint.
+            let mut mir = vec![];
+            body.dump(&mut mir, &instance.mangled_name()).ok()?;
+            let mut hasher = StableHasher::new();
+            mir.hash(&mut hasher);
+            let loc = SourceLocation::new(body.span);
+            Some(ReachableItem {
+                name: instance.name(),
+                mangled_name: instance.mangled_name(),
+                file: path.canonicalize().unwrap_or(path).to_string_lossy().into_owned(),
+                start_line: loc.start_line,
+                end_line: loc.end_line,
+                fingerprint: hasher.finish::<Fingerprint>().to_hex(),
+            })
+        })
+        .collect()
+}
+
+/// Enumerate the mutants of the local functions that are reachable from a harness.
+///
+/// We skip the harnesses, their closures and the functions generated by Kani, since mutating them
+/// doesn't tell anything about the code under verification.
+pub fn gen_mutants_metadata(tcx: TyCtxt, items: &[MonoItem]) -> Vec<Mutant> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            MonoItem::Fn(instance) if instance.def.krate().is_local => Some(*instance),
+            _ => None,
+        })
+        .filter(|instance| {
+            let def_id = rustc_internal::internal(tcx, instance.def.def_id());
+            let attributes = KaniAttributes::for_item(tcx, tcx.typeck_root_def_id(def_id));
+            instance.has_body()
+                && !attributes.is_kani_instrumentation()
+                && !attributes.is_proof_harness()
+        })
+        .flat_map(|instance| {
+            let function = instance.name();
+            let mangled_name = instance.mangled_name();
+            mutation_sites(&instance.body().unwrap()).into_iter().enumerate().map(
+                move |(index, site)| {
+                    let loc = SourceLocation::new(site.span);
+                    Mutant {
+                        id: MutantId { mangled_name: mangled_name.clone(), index }.to_string(),
+                        function: function.clone(),
+                        description: site.description,
+                        file: loc.filename,
+                        line: loc.start_line,
+                    }
+                },
+            )
+        })
+        .collect()
+}
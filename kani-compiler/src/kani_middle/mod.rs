@@ -29,6 +29,7 @@
 mod intrinsics;
 pub mod kani_functions;
 pub mod metadata;
+pub mod playback;
 pub mod points_to;
 pub mod provide;
 pub mod reachability;
@@ -92,10 +93,15 @@ fn visit_ty(&mut self, ty: &Ty) -> ControlFlow<Self::Break> {
     }
 }
 
+/// The function that every entry point of the standard library that spawns a thread calls, e.g.,
+/// `std::thread::spawn` and `std::thread::scope`.
+const STD_SPAWN: &str = "std::thread::Builder::spawn_unchecked_";
+
 /// Check that all given items are supported and there's no misconfiguration.
 /// This method will exhaustively print any error / warning and it will abort at the end if any
 /// error was found.
 pub fn check_reachable_items(tcx: TyCtxt, queries: &QueryDb, items: &[MonoItem]) {
+    let concurrency = queries.args().unstable_features.contains(&"concurrency".to_string());
     // Avoid printing the same error multiple times for different instantiations of the same item.
     let mut def_ids = HashSet::new();
     let reachable_functions: HashSet<DefId> = items
@@ -114,6 +120,13 @@ pub fn check_reachable_items(tcx: TyCtxt, queries: &QueryDb, items: &[MonoItem])
             }
         };
         if !def_ids.contains(&def_id) {
+            if concurrency && def_id.name() == STD_SPAWN {
+                tcx.dcx().err(
+                    "spawning a thread with the standard library is not supported with \
+                    `-Z concurrency`, because Kani would run it sequentially. Use \
+                    `kani::thread::spawn` instead.",
+                );
+            }
             let attributes = KaniAttributes::for_def_id(tcx, def_id);
             // Check if any unstable attribute was reached.
             attributes.check_unstable_features(&queries.args().unstable_features);
@@ -192,12 +205,11 @@ fn implements_arbitrary(
         return false;
     }
 
+    // A reference is generated by borrowing an owned arbitrary value of the referenced type,
+    // so it suffices for the referenced type to implement Arbitrary or to be able to derive it.
     if let TyKind::RigidTy(RigidTy::Ref(_, inner_ty, _)) = ty.kind() {
-        if let TyKind::RigidTy(RigidTy::Adt(..)) = inner_ty.kind() {
-            return can_derive_arbitrary(inner_ty, kani_any_def, ty_arbitrary_cache);
-        } else {
-            return implements_arbitrary(inner_ty, kani_any_def, ty_arbitrary_cache);
-        }
+        return implements_arbitrary(inner_ty, kani_any_def, ty_arbitrary_cache)
+            || can_derive_arbitrary(inner_ty, kani_any_def, ty_arbitrary_cache);
     }
 
     let kani_any_body =
@@ -235,7 +247,8 @@ fn can_derive_arbitrary(
             for ty in fields.iter().map(|field| field.ty_with_args(&args)) {
                 if let TyKind::RigidTy(RigidTy::Adt(..)) = ty.kind() {
                     fields_impl_arbitrary &=
-                        can_derive_arbitrary(ty, kani_any_def, ty_arbitrary_cache);
+                        implements_arbitrary(ty, kani_any_def, ty_arbitrary_cache)
+                            || can_derive_arbitrary(ty, kani_any_def, ty_arbitrary_cache);
                 } else {
                     fields_impl_arbitrary &=
                         implements_arbitrary(ty, kani_any_def, ty_arbitrary_cache);
@@ -0,0 +1,414 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! This module applies the stubs and the contracts of proof harnesses to their concrete playback
+//! tests.
+//!
+//! Playback tests are compiled by the regular rustc backend, so the Kani transformation passes,
+//! which work on rustc_public bodies, cannot be used. Instead, we override the `optimized_mir`
+//! query, and we redirect calls in rustc's internal MIR:
+//! - Calls to a function stubbed by a harness are redirected to its stub.
+//! - Functions checked or stubbed by their contract run the corresponding contract closure.
+//!   Inside these closures, `kani::any_modifies()` and `kani::internal::write_any()` are
+//!   redirected to their models, so they draw values from the recorded byte stream, like
+//!   `kani::any()` does.
+//!
+//! The harnesses that are played back are the ones that a function of the crate passes to
+//! `kani::concrete_playback_run` or `kani::concrete_playback_run_fixtures`.
+//!
+//! Stubs are scoped to the harness that declares them. Each harness with stubs gets an id, and
+//! its playback calls are replaced by the Kani functions that record this id while the harness
+//! runs. Calls to a stubbed function then dispatch on the id of the running harness, and call
+//! the original function for the harnesses that don't stub it.
+//! Contracts, however, apply to the entire crate, so the harnesses played back together cannot
+//! use the contract of a function differently.
+//!
+//! Note that calls are only redirected in functions that are code generated as part of the
+//! crate being played back, that calls through function pointers are not redirected, and that
+//! stubs only apply to the thread that runs the harness.
+
+use crate::kani_middle::attributes::KaniAttributes;
+use crate::kani_middle::kani_functions::{
+    KaniFunction, KaniIntrinsic, KaniModel, find_kani_functions,
+};
+use crate::kani_middle::stubbing::update_stub_mapping;
+use crate::kani_middle::transform::ContractMode;
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_middle::mir::{
+    BasicBlock, BasicBlockData, Body, CallSource, Const, ConstOperand, LocalDecl, Operand, Rvalue,
+    Statement, StatementKind, SwitchTargets, Terminator, TerminatorKind,
+};
+use rustc_middle::ty::{self, GenericArgsRef, TyCtxt, TypingEnv};
+use rustc_middle::util::Providers;
+use rustc_public::CrateDef;
+use rustc_public::rustc_internal;
+use rustc_session::Session;
+use rustc_span::source_map::Spanned;
+use rustc_span::{DUMMY_SP, Symbol};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use tracing::debug;
+
+/// The configuration of the harnesses played back in the crate being compiled.
+static CONFIG: OnceLock<PlaybackConfig> = OnceLock::new();
+
+#[derive(Debug, Default)]
+struct PlaybackConfig {
+    /// Map each stubbed function to the id of the harnesses that stub it, and their stub.
+    stubs: HashMap<DefId, Vec<(u32, DefId)>>,
+    /// The id of the harnesses with stubs.
+    harness_ids: HashMap<DefId, u32>,
+    /// Map the playback functions of Kani to the ones that record the harness id.
+    playback_fns: HashMap<DefId, DefId>,
+    /// The Kani function that returns the id of the running harness.
+    playback_harness: Option<DefId>,
+    /// The contract mode of the functions that are checked or stubbed by their contract.
+    modes: HashMap<DefId, ContractMode>,
+    /// The Kani functions used to run contracts.
+    kani_fns: HashMap<KaniFunction, DefId>,
+}
+
+/// Sets up rustc's query mechanism to apply the configuration of the played back harnesses.
+pub fn provide(_sess: &Session, providers: &mut Providers) {
+    providers.optimized_mir = run_playback_pass;
+    providers.extern_queries.optimized_mir = run_playback_pass_extern;
+}
+
+/// Collect the stubs and contracts of the harnesses played back in the local crate.
+///
+/// This must be called before code generation, and it requires a rustc_public context.
+pub fn collect_playback_config(tcx: TyCtxt) {
+    let mut config = PlaybackConfig::default();
+    for harness in played_back_harnesses(tcx) {
+        let attributes = KaniAttributes::for_item(tcx, harness.to_def_id());
+        let mut stubs = HashMap::new();
+        for stub in &attributes.harness_attributes().stubs {
+            update_stub_mapping(tcx, harness, stub, &mut stubs);
+        }
+        if !stubs.is_empty() {
+            let id = config.harness_ids.len() as u32 + 1;
+            config.harness_ids.insert(harness.to_def_id(), id);
+            for (original, stub) in stubs {
+                config.stubs.entry(original).or_default().push((id, stub));
+            }
+        }
+        if let Some(target) = attributes.interpret_for_contract_attribute() {
+            let target = rustc_internal::internal(tcx, target.def_id());
+            let mode = if KaniAttributes::for_item(tcx, target).has_recursion() {
+                ContractMode::RecursiveCheck
+            } else {
+                ContractMode::SimpleCheck
+            };
+            config.set_mode(tcx, harness, target, mode);
+        }
+        for target in attributes.interpret_stub_verified_attribute() {
+            let target = rustc_internal::internal(tcx, target.def_id());
+            config.set_mode(tcx, harness, target, ContractMode::Replace);
+        }
+    }
+    if !config.stubs.is_empty() {
+        let kani_fn = |name| kani_playback_fns(tcx, &[name]).pop();
+        config.playback_harness = kani_fn("playback_harness");
+        config.playback_fns = [
+            ("concrete_playback_run", "concrete_playback_run_harness"),
+            ("concrete_playback_run_fixtures", "concrete_playback_run_fixtures_harness"),
+        ]
+        .into_iter()
+        .filter_map(|(playback_fn, harness_fn)| Some((kani_fn(playback_fn)?, kani_fn(harness_fn)?)))
+        .collect();
+    }
+    if !config.modes.is_empty() {
+        config.kani_fns = find_kani_functions()
+            .into_iter()
+            .map(|(function, def)| (function, rustc_internal::internal(tcx, def.def_id())))
+            .collect();
+    }
+    debug!(?config, "collect_playback_config");
+    CONFIG.set(config).expect("playback configuration should only be collected once");
+}
+
+/// Return the harnesses that are passed to the playback functions of Kani in the local crate.
+fn played_back_harnesses(tcx: TyCtxt) -> Vec<LocalDefId> {
+    let playback_fns =
+        kani_playback_fns(tcx, &["concrete_playback_run", "concrete_playback_run_fixtures"]);
+    if playback_fns.is_empty() {
+        return vec![];
+    }
+    let mut harnesses = tcx
+        .hir_body_owners()
+        .filter(|owner| tcx.def_kind(*owner).is_fn_like())
+        .flat_map(|owner| {
+            let results = tcx.typeck(owner);
+            results
+                .node_types()
+                .items_in_stable_order()
+                .into_iter()
+                .filter_map(|(_, ty)| match ty.kind() {
+                    ty::FnDef(def_id, args) if playback_fns.contains(def_id) => {
+                        match args.type_at(0).kind() {
+                            ty::FnDef(harness, _) => harness.as_local(),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        })
+        .filter(|harness| KaniAttributes::for_item(tcx, harness.to_def_id()).is_proof_harness())
+        .collect::<Vec<_>>();
+    harnesses.sort_by_key(|harness| harness.local_def_index);
+    harnesses.dedup();
+    harnesses
+}
+
+/// Find the functions of the Kani library with the given `names`, if the crate depends on it.
+fn kani_playback_fns(tcx: TyCtxt, names: &[&str]) -> Vec<DefId> {
+    let Some(kani) = tcx.crates(()).iter().find(|krate| tcx.crate_name(**krate).as_str() == "kani")
+    else {
+        return vec![];
+    };
+    tcx.module_children(kani.as_def_id())
+        .iter()
+        .filter(|child| names.contains(&child.ident.name.as_str()))
+        .filter_map(|child| child.res.opt_def_id())
+        .collect()
+}
+
+impl PlaybackConfig {
+    /// Set the contract mode of `target`, as required by `harness`.
+    fn set_mode(&mut self, tcx: TyCtxt, harness: LocalDefId, target: DefId, mode: ContractMode) {
+        if !KaniAttributes::for_item(tcx, target).has_contract() {
+            tcx.dcx().span_warn(
+                tcx.def_span(harness),
+                format!(
+                    "The contract of `{}` is not applied to the playback tests of this harness, \
+                     since it is inherited from a trait method declaration.",
+                    tcx.def_path_str(target)
+                ),
+            );
+            return;
+        }
+        let other = self.modes.insert(target, mode);
+        if other.is_some_and(|other| other != mode) {
+            tcx.dcx()
+                .struct_span_err(
+                    tcx.def_span(harness),
+                    format!(
+                        "The harnesses played back in this crate use the contract of `{}` \
+                         differently.",
+                        tcx.def_path_str(target)
+                    ),
+                )
+                .with_help(
+                    "A function can only be either checked or stubbed by its contract in the \
+                     playback tests of a crate. Play back these harnesses separately.",
+                )
+                .emit();
+        }
+    }
+
+    /// Redirect the calls in `body`, which belongs to `def_id`.
+    fn redirect_calls<'tcx>(&self, tcx: TyCtxt<'tcx>, def_id: DefId, body: &mut Body<'tcx>) {
+        // The contract closures are defined inside the function with the contract.
+        let mode = self.modes.get(&tcx.typeck_root_def_id(def_id)).copied();
+        let local_decls = body.local_decls.clone();
+        for bb in body.basic_blocks.indices() {
+            let block = &mut body.basic_blocks.as_mut()[bb];
+            let terminator = block.terminator();
+            let source_info = terminator.source_info;
+            let TerminatorKind::Call { func, destination, target, .. } = &terminator.kind else {
+                continue;
+            };
+            let ty::FnDef(callee, args) = *func.ty(&local_decls, tcx).kind() else { continue };
+            if let Some(mode) = mode
+                && is_marker(tcx, callee, "kani_contract_mode")
+            {
+                // Replace the call by the mode used in the playback tests.
+                let mode = Operand::Constant(Box::new(ConstOperand {
+                    span: source_info.span,
+                    user_ty: None,
+                    const_: Const::from_bits(
+                        tcx,
+                        mode as u128,
+                        TypingEnv::fully_monomorphized(),
+                        tcx.types.u8,
+                    ),
+                }));
+                let assign = StatementKind::Assign(Box::new((*destination, Rvalue::Use(mode))));
+                let target = target.unwrap();
+                block.statements.push(Statement::new(source_info, assign));
+                block.terminator_mut().kind = TerminatorKind::Goto { target };
+            } else if let Some(stubs) = self.stubs.get(&callee) {
+                self.dispatch_stubs(tcx, body, bb, stubs, args);
+            } else if let Some(run_harness) = self.playback_fns.get(&callee)
+                && let ty::FnDef(harness, _) = args.type_at(0).kind()
+                && let Some(id) = self.harness_ids.get(harness)
+            {
+                // Record the id of the harness while it is played back.
+                let id = Operand::Constant(Box::new(ConstOperand {
+                    span: source_info.span,
+                    user_ty: None,
+                    const_: Const::from_bits(
+                        tcx,
+                        *id as u128,
+                        TypingEnv::fully_monomorphized(),
+                        tcx.types.u32,
+                    ),
+                }));
+                let TerminatorKind::Call { func, args: call_args, .. } =
+                    &mut block.terminator_mut().kind
+                else {
+                    unreachable!()
+                };
+                *func = Operand::function_handle(tcx, *run_harness, args, source_info.span);
+                let mut new_args = call_args.to_vec();
+                new_args.push(Spanned { node: id, span: source_info.span });
+                *call_args = new_args.into_boxed_slice();
+            } else if let Some((new_callee, new_args)) = self.new_callee(tcx, mode, callee, args) {
+                debug!(?callee, ?new_callee, "redirect_calls");
+                let TerminatorKind::Call { func, .. } = &mut block.terminator_mut().kind else {
+                    unreachable!()
+                };
+                *func = Operand::function_handle(tcx, new_callee, new_args, source_info.span);
+            }
+        }
+    }
+
+    /// Replace the call in the terminator of `bb` to a function stubbed by some harnesses with a
+    /// dispatch on the harness being played back:
+    ///
+    /// ```text
+    /// bb: harness = kani::playback_harness() -> bb_switch
+    /// bb_switch: switchInt(harness) -> [id_0: bb_stub_0, .., otherwise: bb_original]
+    /// bb_stub_i: destination = stub_i(args) -> target
+    /// bb_original: destination = original(args) -> target
+    /// ```
+    fn dispatch_stubs<'tcx>(
+        &self,
+        tcx: TyCtxt<'tcx>,
+        body: &mut Body<'tcx>,
+        bb: BasicBlock,
+        stubs: &[(u32, DefId)],
+        args: GenericArgsRef<'tcx>,
+    ) {
+        let Some(playback_harness) = self.playback_harness else { return };
+        let original = body.basic_blocks[bb].terminator().clone();
+        let is_cleanup = body.basic_blocks[bb].is_cleanup;
+        let source_info = original.source_info;
+        let TerminatorKind::Call { unwind, fn_span, .. } = original.kind else { unreachable!() };
+        let harness = body.local_decls.push(LocalDecl::new(tcx.types.u32, DUMMY_SP));
+
+        let mut new_block = |kind| {
+            let terminator = Terminator { source_info, kind };
+            body.basic_blocks_mut().push(BasicBlockData::new(Some(terminator), is_cleanup))
+        };
+        let branches = stubs
+            .iter()
+            .map(|(id, stub)| {
+                let mut kind = original.kind.clone();
+                let TerminatorKind::Call { func, .. } = &mut kind else { unreachable!() };
+                *func = Operand::function_handle(
+                    tcx,
+                    *stub,
+                    stub_args(tcx, *stub, args),
+                    source_info.span,
+                );
+                (*id as u128, new_block(kind))
+            })
+            .collect::<Vec<_>>();
+        let otherwise = new_block(original.kind.clone());
+        let switch = new_block(TerminatorKind::SwitchInt {
+            discr: Operand::Copy(harness.into()),
+            targets: SwitchTargets::new(branches.into_iter(), otherwise),
+        });
+        debug!(?bb, ?stubs, "dispatch_stubs");
+        body.basic_blocks_mut()[bb].terminator_mut().kind = TerminatorKind::Call {
+            func: Operand::function_handle(
+                tcx,
+                playback_harness,
+                ty::List::empty(),
+                source_info.span,
+            ),
+            args: Box::new([]),
+            destination: harness.into(),
+            target: Some(switch),
+            unwind,
+            call_source: CallSource::Misc,
+            fn_span,
+        };
+    }
+
+    /// Return the function that should be called instead of `callee`, if any.
+    fn new_callee<'tcx>(
+        &self,
+        tcx: TyCtxt<'tcx>,
+        mode: Option<ContractMode>,
+        callee: DefId,
+        args: GenericArgsRef<'tcx>,
+    ) -> Option<(DefId, GenericArgsRef<'tcx>)> {
+        mode?;
+        let kani_fn = |function: KaniFunction| self.kani_fns.get(&function).copied();
+        if Some(callee) == kani_fn(KaniIntrinsic::AnyModifies.into()) {
+            Some((kani_fn(KaniModel::Any.into())?, args))
+        } else if Some(callee) == kani_fn(KaniIntrinsic::WriteAny.into()) {
+            match args.type_at(0).kind() {
+                ty::Slice(elem) => Some((
+                    kani_fn(KaniModel::WriteAnySlice.into())?,
+                    tcx.mk_args(&[(*elem).into()]),
+                )),
+                ty::Str => Some((kani_fn(KaniModel::WriteAnyStr.into())?, ty::List::empty())),
+                _ => Some((kani_fn(KaniModel::WriteAnySlim.into())?, args)),
+            }
+        } else if is_marker(tcx, callee, "kani_register_contract") {
+            Some((kani_fn(KaniModel::RunContract.into())?, args))
+        } else {
+            None
+        }
+    }
+}
+
+/// The generic arguments of a call to `stub` that replaces a call with `args`.
+fn stub_args<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    stub: DefId,
+    args: GenericArgsRef<'tcx>,
+) -> GenericArgsRef<'tcx> {
+    // Stubs of trait methods don't take the `Self` type as an argument.
+    let count = tcx.generics_of(stub).count();
+    if args.len() > count { tcx.mk_args(&args[args.len() - count..]) } else { args }
+}
+
+/// Check whether the function `def_id` is marked with `#[kanitool::fn_marker = marker]`.
+fn is_marker(tcx: TyCtxt, def_id: DefId, marker: &str) -> bool {
+    KaniAttributes::for_item(tcx, def_id).fn_marker() == Some(Symbol::intern(marker))
+}
+
+/// Returns the optimized code for the external function associated with `def_id` with the calls
+/// redirected according to the played back harnesses.
+fn run_playback_pass_extern(tcx: TyCtxt<'_>, def_id: DefId) -> &Body<'_> {
+    let body = (rustc_interface::DEFAULT_QUERY_PROVIDERS.extern_queries.optimized_mir)(tcx, def_id);
+    redirect_calls(tcx, def_id, body)
+}
+
+/// Returns the optimized code for the local function associated with `def_id` with the calls
+/// redirected according to the played back harnesses.
+fn run_playback_pass(tcx: TyCtxt<'_>, def_id: LocalDefId) -> &Body<'_> {
+    let body = (rustc_interface::DEFAULT_QUERY_PROVIDERS.optimized_mir)(tcx, def_id);
+    redirect_calls(tcx, def_id.to_def_id(), body)
+}
+
+fn redirect_calls<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def_id: DefId,
+    body: &'tcx Body<'tcx>,
+) -> &'tcx Body<'tcx> {
+    // The configuration is only available after analysis. Bodies needed before that, e.g., for
+    // constant evaluation, are left unchanged.
+    match CONFIG.get() {
+        Some(config) if !config.stubs.is_empty() || !config.modes.is_empty() => {
+            let mut transformed_body = body.clone();
+            config.redirect_calls(tcx, def_id, &mut transformed_body);
+            tcx.arena.alloc(transformed_body)
+        }
+        _ => body,
+    }
+}
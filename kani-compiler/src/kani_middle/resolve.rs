@@ -28,6 +28,8 @@
 
 mod type_resolution;
 
+pub use type_resolution::resolve_ty;
+
 macro_rules! validate_kind {
     ($tcx:ident, $id:ident, $expected:literal, $kind:pat) => {{
         let def_kind = $tcx.def_kind($id);
@@ -115,6 +117,20 @@ pub fn resolve_fn<'tcx>(
     Ok(rustc_internal::internal(tcx, result.def().def_id()))
 }
 
+/// Attempts to resolve a type (in the form of a string) to a `Ty`.
+/// The current module is provided as an argument in order to resolve relative paths.
+pub fn resolve_ty_str<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    current_module: LocalDefId,
+    ty_str: &str,
+) -> Result<Ty, ResolveError<'tcx>> {
+    let _span = debug_span!("resolve_ty_str", ?ty_str, ?current_module).entered();
+    let typ = syn::parse_str(ty_str).map_err(|err| ResolveError::InvalidPath {
+        msg: format!("Expected a type, but found `{ty_str}`. {err}"),
+    })?;
+    resolve_ty(tcx, current_module, &typ)
+}
+
 /// Attempts to resolve a simple path (in the form of a string) to a `DefId`.
 /// The current module is provided as an argument in order to resolve relative
 /// paths.
@@ -20,7 +20,7 @@
 use rustc_public::ty::{FnDef, GenericArgs, RigidTy, TyKind};
 use rustc_public::{CrateDef, CrateItem};
 
-use self::annotations::update_stub_mapping;
+pub use self::annotations::update_stub_mapping;
 
 /// Collects the stubs from the harnesses in a crate.
 pub fn harness_stub_map(
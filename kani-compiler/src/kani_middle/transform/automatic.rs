@@ -23,7 +23,8 @@
     Place, Rvalue, SwitchTargets, Terminator, TerminatorKind,
 };
 use rustc_public::ty::{
-    AdtDef, AdtKind, FnDef, GenericArgKind, GenericArgs, RigidTy, Ty, TyKind, UintTy, VariantDef,
+    AdtDef, AdtKind, FnDef, GenericArgKind, GenericArgs, Region, RegionKind, RigidTy, Ty, TyKind,
+    UintTy, VariantDef,
 };
 use rustc_public_bridge::IndexedVal;
 use tracing::debug;
@@ -287,6 +288,9 @@ pub struct AutomaticHarnessPass {
     kani_any: FnDef,
     init_contracts_hook: Instance,
     kani_autoharness_intrinsic: FnDef,
+    /// The intrinsic used to assume the type invariant of each argument, if the user asked for
+    /// type invariants to be injected into contracts.
+    assume_invariant: Option<FnDef>,
 }
 
 impl AutomaticHarnessPass {
@@ -298,7 +302,41 @@ pub fn new(query_db: &QueryDb) -> Self {
         let init_contracts_hook = *kani_fns.get(&KaniHook::InitContracts.into()).unwrap();
         let init_contracts_hook =
             Instance::resolve(init_contracts_hook, &GenericArgs(vec![])).unwrap();
-        Self { kani_any, init_contracts_hook, kani_autoharness_intrinsic }
+        let assume_invariant = query_db
+            .args()
+            .contract_invariants
+            .then(|| *kani_fns.get(&KaniIntrinsic::AssumeInvariant.into()).unwrap());
+        Self { kani_any, init_contracts_hook, kani_autoharness_intrinsic, assume_invariant }
+    }
+
+    /// Insert `kani::internal::assume_invariant(&arg)` in `body`.
+    fn assume_invariant(&self, body: &mut MutableBody, arg: Local, source: &mut SourceInstruction) {
+        let Some(assume_invariant) = self.assume_invariant else { return };
+        let span = source.span(body.blocks());
+        let arg_ty = body.locals()[arg].ty;
+        let region = Region { kind: RegionKind::ReErased };
+        let arg_ref = body.new_local(
+            Ty::new_ref(region.clone(), arg_ty, Mutability::Not),
+            span,
+            Mutability::Not,
+        );
+        body.assign_to(
+            Place::from(arg_ref),
+            Rvalue::Ref(region, BorrowKind::Shared, Place::from(arg)),
+            source,
+            InsertPosition::Before,
+        );
+        let assume_invariant =
+            Instance::resolve(assume_invariant, &GenericArgs(vec![GenericArgKind::Type(arg_ty)]))
+                .unwrap();
+        let unit = body.new_local(Ty::new_tuple(&[]), span, Mutability::Not);
+        body.insert_call(
+            &assume_invariant,
+            source,
+            InsertPosition::Before,
+            vec![Operand::Move(Place::from(arg_ref))],
+            Place::from(unit),
+        );
     }
 }
 
@@ -354,6 +392,8 @@ fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, B
 
         // For each argument of `fn_to_verify`, create a nondeterministic value of its type
         // by generating a kani::any() call and saving the result in `arg_local`.
+        // For reference arguments (including `&self` and `&mut self` receivers), this creates an
+        // owned arbitrary value on the stack and passes a borrow of it, c.f. `call_kani_any_for_ty`.
         let arg_locals = fn_to_verify_body
             .arg_locals()
             .iter()
@@ -368,6 +408,11 @@ fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, B
             })
             .collect::<Vec<_>>();
 
+        // Constrain the arguments to values that satisfy their type invariant, if requested.
+        for arg_local in &arg_locals {
+            self.assume_invariant(&mut harness_body, *arg_local, &mut source);
+        }
+
         let func_to_verify_ret = fn_to_verify_body.ret_local();
         let ret_place = Place::from(harness_body.new_local(
             func_to_verify_ret.ty,
@@ -376,11 +421,12 @@ fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, B
         ));
 
         // Call `fn_to_verify` on the nondeterministic arguments generated above.
+        // Each argument local is only used once, so we move it; this is required for `&mut` borrows.
         harness_body.insert_call(
             &fn_to_verify,
             &mut source,
             InsertPosition::Before,
-            arg_locals.iter().map(|lcl| Operand::Copy(Place::from(*lcl))).collect::<Vec<_>>(),
+            arg_locals.iter().map(|lcl| Operand::Move(Place::from(*lcl))).collect::<Vec<_>>(),
             ret_place,
         );
 
@@ -2,23 +2,31 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 //! This module contains code related to the MIR-to-MIR pass to enable contracts.
 use crate::args::ReachabilityType;
+use crate::kani_middle::abi::LayoutOf;
 use crate::kani_middle::attributes::KaniAttributes;
 use crate::kani_middle::codegen_units::CodegenUnit;
-use crate::kani_middle::kani_functions::{KaniIntrinsic, KaniModel};
-use crate::kani_middle::transform::body::{InsertPosition, MutableBody, SourceInstruction};
+use crate::kani_middle::kani_functions::{KaniHook, KaniIntrinsic, KaniModel};
+use crate::kani_middle::stable_fn_def;
+use crate::kani_middle::transform::body::{
+    InsertPosition, MutMirVisitor, MutableBody, SourceInstruction,
+};
 use crate::kani_middle::transform::{TransformPass, TransformationType};
 use crate::kani_queries::QueryDb;
 use cbmc::{InternString, InternedString};
 use rustc_middle::ty::TyCtxt;
 use rustc_public::CrateDef;
-use rustc_public::mir::mono::Instance;
+use rustc_public::mir::mono::{Instance, InstanceKind};
 use rustc_public::mir::{
-    Body, ConstOperand, Operand, Rvalue, Terminator, TerminatorKind, VarDebugInfoContents,
+    Body, BorrowKind, ConstOperand, LocalDecl, Mutability, Operand, Place, ProjectionElem, Rvalue,
+    Terminator, TerminatorKind, VarDebugInfoContents,
 };
 use rustc_public::rustc_internal;
-use rustc_public::ty::{ClosureDef, FnDef, MirConst, RigidTy, TyKind, TypeAndMut, UintTy};
+use rustc_public::ty::{
+    ClosureDef, FnDef, GenericArgKind, GenericArgs, MirConst, RigidTy, Ty, TyKind, TypeAndMut,
+    UintTy,
+};
 use rustc_span::Symbol;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use tracing::{debug, trace};
 
@@ -265,6 +273,16 @@ fn any_body(&self, tcx: TyCtxt, mut body: Body) -> (bool, Body) {
 ///    - Replace the non-used generated closures body with unreachable.
 /// 3. Replace the body of `kani_register_contract` by `kani::internal::run_contract_fn` to
 ///    invoke the closure.
+///
+/// If the user enabled `--contract-invariants`, this pass also replaces the calls to
+/// `kani::internal::{assume, assert}_invariant`, which the contract closures use to check the
+/// type invariants of the arguments and the result, by the corresponding check of
+/// `kani::Invariant::is_safe`.
+///
+/// Contracts of trait method declarations are implemented by a provided method
+/// `__kani_trait_contract_<fn_name>` with the structure above, which calls the declared method.
+/// Each instance of this method selects its mode according to the implementation it wraps, and
+/// calls to implementations that inherit the contract are redirected to it.
 #[derive(Debug, Default, Clone)]
 pub struct FunctionWithContractPass {
     /// Function that is being checked, if any.
@@ -277,8 +295,16 @@ pub struct FunctionWithContractPass {
     /// are not to be used in this harness.
     /// In order to avoid bringing unnecessary logic, we clear their body.
     unused_closures: HashSet<ClosureDef>,
+    /// The closures of provided methods generated from trait contracts are shared by all
+    /// implementations, so we only clear their body for the instances with the given
+    /// generic arguments.
+    unused_trait_closures: Vec<(ClosureDef, GenericArgs)>,
+    /// Cache the provided method generated from the contract of a trait method declaration.
+    trait_contracts: HashMap<FnDef, Option<FnDef>>,
     /// Cache KaniRunContract function used to implement contracts.
     run_contract_fn: Option<FnDef>,
+    /// Functions used to inject type invariants, if enabled.
+    invariants: Option<ContractInvariants>,
 }
 
 impl TransformPass for FunctionWithContractPass {
@@ -299,10 +325,10 @@ fn is_enabled(&self, _query_db: &QueryDb) -> bool
     /// Transform the function body by replacing it with the stub body.
     fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, Body) {
         trace!(function=?instance.name(), "FunctionWithContractPass::transform");
-        match instance.ty().kind().rigid().unwrap() {
+        let (changed, body) = match instance.ty().kind().rigid().unwrap() {
             RigidTy::FnDef(def, args) => {
-                if let Some(mode) = self.contract_mode(tcx, *def) {
-                    self.mark_unused(tcx, *def, &body, mode);
+                if let Some(mode) = self.contract_mode(tcx, *def, args) {
+                    self.mark_unused(tcx, *def, args, &body, mode);
                     let new_body = self.set_mode(tcx, body, mode);
                     (true, new_body)
                 } else if KaniAttributes::for_instance(tcx, instance).fn_marker()
@@ -315,8 +341,8 @@ fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, B
                     (false, body)
                 }
             }
-            RigidTy::Closure(def, _args) => {
-                if self.unused_closures.contains(def) {
+            RigidTy::Closure(def, args) => {
+                if self.is_unused_closure(*def, args) {
                     // Delete body and mark it as unreachable.
                     let mut new_body = MutableBody::from(body);
                     new_body.clear_body(TerminatorKind::Unreachable);
@@ -330,6 +356,19 @@ fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, B
                 /* static variables case */
                 (false, body)
             }
+        };
+        let (changed, body) =
+            if self.redirects_trait_calls() && !is_trait_contract_body(tcx, instance) {
+                let (redirected, body) = self.redirect_trait_calls(tcx, body);
+                (changed || redirected, body)
+            } else {
+                (changed, body)
+            };
+        if let Some(invariants) = &self.invariants {
+            let (injected, body) = invariants.inject(body);
+            (changed || injected, body)
+        } else {
+            (changed, body)
         }
     }
 }
@@ -367,7 +406,10 @@ pub fn new(tcx: TyCtxt, queries: &QueryDb, unit: &CodegenUnit) -> FunctionWithCo
                 replace_fns,
                 assert_contracts: !queries.args().no_assert_contracts,
                 unused_closures: Default::default(),
+                unused_trait_closures: Default::default(),
+                trait_contracts: Default::default(),
                 run_contract_fn,
+                invariants: ContractInvariants::new(queries),
             }
         } else {
             // If reachability mode is PubFns or Tests, we just remove any contract logic.
@@ -457,8 +499,20 @@ fn set_mode(&self, tcx: TyCtxt, body: Body, mode: ContractMode) -> Body {
     /// Note that the Check and Replace modes take precedence over the Assert mode.
     /// This precedence ensures that a given `target` of a proof_for_contract(target) or stub_verified(target)
     /// use their Check or Replace closures, respectively, rather than the Assert closure.
-    fn contract_mode(&self, tcx: TyCtxt, fn_def: FnDef) -> Option<ContractMode> {
+    fn contract_mode(
+        &self,
+        tcx: TyCtxt,
+        fn_def: FnDef,
+        args: &GenericArgs,
+    ) -> Option<ContractMode> {
         let kani_attributes = KaniAttributes::for_def_id(tcx, fn_def.def_id());
+        if let Some(decl) = kani_attributes.trait_contract_decl() {
+            // The mode of a trait contract depends on the implementation it wraps.
+            let decl = stable_fn_def(tcx, decl).unwrap();
+            let method = Instance::resolve(decl, args).unwrap();
+            let (method, _) = method.ty().kind().fn_def().unwrap();
+            return Some(self.inherited_contract_mode(tcx, decl, fn_def, method));
+        }
         kani_attributes.has_contract().then(|| {
             if self.check_fn == Some(fn_def) {
                 if kani_attributes.has_recursion() {
@@ -476,51 +530,310 @@ fn contract_mode(&self, tcx: TyCtxt, fn_def: FnDef) -> Option<ContractMode> {
         })
     }
 
+    /// Return which contract mode to use for the contract that `method` inherits from the trait
+    /// method declaration `decl`, which is implemented by the provided method `trait_contract`.
+    ///
+    /// The contract can be selected either for the implementation or for the declaration, which
+    /// covers every implementation. An implementation that has a contract of its own doesn't
+    /// inherit anything.
+    fn inherited_contract_mode(
+        &self,
+        tcx: TyCtxt,
+        decl: FnDef,
+        trait_contract: FnDef,
+        method: FnDef,
+    ) -> ContractMode {
+        if KaniAttributes::for_def_id(tcx, method.def_id()).has_contract() {
+            ContractMode::Original
+        } else if self.check_fn == Some(method) {
+            if KaniAttributes::for_def_id(tcx, trait_contract.def_id()).has_recursion() {
+                ContractMode::RecursiveCheck
+            } else {
+                ContractMode::SimpleCheck
+            }
+        } else if self.replace_fns.contains(&method) || self.replace_fns.contains(&decl) {
+            ContractMode::Replace
+        } else if self.assert_contracts {
+            ContractMode::Assert
+        } else {
+            ContractMode::Original
+        }
+    }
+
     /// Select any unused closure for body deletion.
-    fn mark_unused(&mut self, tcx: TyCtxt, fn_def: FnDef, body: &Body, mode: ContractMode) {
-        let contract =
-            KaniAttributes::for_def_id(tcx, fn_def.def_id()).contract_attributes().unwrap();
+    fn mark_unused(
+        &mut self,
+        tcx: TyCtxt,
+        fn_def: FnDef,
+        args: &GenericArgs,
+        body: &Body,
+        mode: ContractMode,
+    ) {
+        let kani_attributes = KaniAttributes::for_def_id(tcx, fn_def.def_id());
+        let contract = kani_attributes.contract_attributes().unwrap();
         let recursion_closure = find_closure(tcx, fn_def, body, contract.recursion_check.as_str());
         let check_closure = find_closure(tcx, fn_def, body, contract.checked_with.as_str());
         let replace_closure = find_closure(tcx, fn_def, body, contract.replaced_with.as_str());
         let assert_closure = find_closure(tcx, fn_def, body, contract.asserted_with.as_str());
-        match mode {
+        let unused = match mode {
             ContractMode::Original => {
                 // No contract instrumentation needed. Add all closures to the list of unused.
-                self.unused_closures.insert(recursion_closure);
-                self.unused_closures.insert(check_closure);
-                self.unused_closures.insert(replace_closure);
-                self.unused_closures.insert(assert_closure);
-            }
-            ContractMode::RecursiveCheck => {
-                self.unused_closures.insert(replace_closure);
-                self.unused_closures.insert(check_closure);
-                self.unused_closures.insert(assert_closure);
-            }
-            ContractMode::SimpleCheck => {
-                self.unused_closures.insert(replace_closure);
-                self.unused_closures.insert(recursion_closure);
-                self.unused_closures.insert(assert_closure);
-            }
-            ContractMode::Replace => {
-                self.unused_closures.insert(recursion_closure);
-                self.unused_closures.insert(check_closure);
-                self.unused_closures.insert(assert_closure);
+                vec![recursion_closure, check_closure, replace_closure, assert_closure]
             }
-            ContractMode::Assert => {
-                self.unused_closures.insert(recursion_closure);
-                self.unused_closures.insert(check_closure);
-                self.unused_closures.insert(replace_closure);
+            ContractMode::RecursiveCheck => vec![replace_closure, check_closure, assert_closure],
+            ContractMode::SimpleCheck => vec![replace_closure, recursion_closure, assert_closure],
+            ContractMode::Replace => vec![recursion_closure, check_closure, assert_closure],
+            ContractMode::Assert => vec![recursion_closure, check_closure, replace_closure],
+        };
+        if kani_attributes.trait_contract_decl().is_some() {
+            self.unused_trait_closures
+                .extend(unused.into_iter().map(|closure| (closure, args.clone())));
+        } else {
+            self.unused_closures.extend(unused);
+        }
+    }
+
+    /// Is this closure part of a contract that is not used?
+    ///
+    /// The generic arguments of a closure start with the ones of the function that defines it.
+    fn is_unused_closure(&self, def: ClosureDef, args: &GenericArgs) -> bool {
+        self.unused_closures.contains(&def)
+            || self
+                .unused_trait_closures
+                .iter()
+                .any(|(closure, parent_args)| *closure == def && args.0.starts_with(&parent_args.0))
+    }
+
+    /// Whether any call may have to be redirected to the contract of a trait method declaration.
+    fn redirects_trait_calls(&self) -> bool {
+        self.assert_contracts || self.check_fn.is_some() || !self.replace_fns.is_empty()
+    }
+
+    /// Return the provided method generated from the contract of the trait method declaration
+    /// `decl`, if any.
+    fn trait_contract(&mut self, tcx: TyCtxt, decl: FnDef) -> Option<FnDef> {
+        *self.trait_contracts.entry(decl).or_insert_with(|| {
+            let trait_contract = KaniAttributes::for_def_id(tcx, decl.def_id()).trait_contract()?;
+            stable_fn_def(tcx, trait_contract)
+        })
+    }
+
+    /// Redirect the calls to methods that inherit the contract of their trait method declaration
+    /// to the provided method that implements it, whenever the contract is used.
+    ///
+    /// Calls are always made to the declaration, e.g. `<T as Trait>::method`, even when the
+    /// implementation is statically known. Since virtual calls are only resolved at runtime, we
+    /// redirect them whenever a contract may be used, and let the provided method select the
+    /// mode of the implementation.
+    fn redirect_trait_calls(&mut self, tcx: TyCtxt, body: Body) -> (bool, Body) {
+        let mut new_body = MutableBody::from(body);
+        let locals = new_body.locals().to_vec();
+        let mut visitor = TraitContractVisitor { tcx, pass: self, locals, changed: false };
+        visitor.visit_body(&mut new_body);
+        (visitor.changed, new_body.into())
+    }
+}
+
+/// Visitor that replaces constants of declarations with a contract by the provided method that
+/// implements it.
+struct TraitContractVisitor<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    pass: &'a mut FunctionWithContractPass,
+    locals: Vec<LocalDecl>,
+    changed: bool,
+}
+
+impl MutMirVisitor for TraitContractVisitor<'_, '_> {
+    fn visit_operand(&mut self, operand: &mut Operand) {
+        let Operand::Constant(ConstOperand { span, .. }) = operand else { return };
+        let span = *span;
+        let TyKind::RigidTy(RigidTy::FnDef(decl, args)) = operand.ty(&self.locals).unwrap().kind()
+        else {
+            return;
+        };
+        let Some(trait_contract) = self.pass.trait_contract(self.tcx, decl) else { return };
+        let redirect = match Instance::resolve(decl, &args) {
+            Ok(instance) if matches!(instance.kind, InstanceKind::Virtual { .. }) => true,
+            Ok(instance) => {
+                let (method, _) = instance.ty().kind().fn_def().unwrap();
+                self.pass.inherited_contract_mode(self.tcx, decl, trait_contract, method)
+                    != ContractMode::Original
             }
+            Err(_) => false,
+        };
+        if redirect {
+            let ty = Ty::from_rigid_kind(RigidTy::FnDef(trait_contract, args));
+            let literal = MirConst::try_new_zero_sized(ty).unwrap();
+            *operand = Operand::Constant(ConstOperand { span, user_ty: None, const_: literal });
+            self.changed = true;
+        }
+    }
+}
+
+/// The functions used to inject type invariants into contracts.
+#[derive(Debug, Clone)]
+struct ContractInvariants {
+    assume_invariant: FnDef,
+    assert_invariant: FnDef,
+    /// Model that calls `Invariant::is_safe`, used to resolve its implementation for a type.
+    is_safe_model: FnDef,
+    assume_hook: Instance,
+    assert_hook: Instance,
+}
+
+impl ContractInvariants {
+    /// Collect the functions used to inject type invariants, if the user enabled them.
+    ///
+    /// The `Invariant` trait is only defined in the `kani` crate, so there is nothing to inject
+    /// if we are using `kani_core`.
+    fn new(queries: &QueryDb) -> Option<ContractInvariants> {
+        if !queries.args().contract_invariants {
+            return None;
         }
+        let kani_fns = queries.kani_functions();
+        let hook = |hook: KaniHook| {
+            Instance::resolve(*kani_fns.get(&hook.into()).unwrap(), &GenericArgs(vec![])).unwrap()
+        };
+        Some(ContractInvariants {
+            assume_invariant: *kani_fns.get(&KaniIntrinsic::AssumeInvariant.into())?,
+            assert_invariant: *kani_fns.get(&KaniIntrinsic::AssertInvariant.into())?,
+            is_safe_model: *kani_fns.get(&KaniModel::InvariantIsSafe.into())?,
+            assume_hook: hook(KaniHook::Assume),
+            assert_hook: hook(KaniHook::Assert),
+        })
     }
+
+    /// Replace each call to `kani::internal::{assume, assert}_invariant::<T>(value, [msg])`
+    /// where `T` is `U`, `&U` or `&mut U` (at any depth) and `U` implements `Invariant` by:
+    ///
+    /// ```ignore
+    /// let holds = <U as kani::Invariant>::is_safe(&**value);
+    /// kani::assume(holds); // or kani::assert(holds, msg);
+    /// ```
+    ///
+    /// Calls for types that do not implement `Invariant` are kept, and they are no-ops.
+    fn inject(&self, body: Body) -> (bool, Body) {
+        let mut new_body = MutableBody::from(body);
+        let calls: Vec<_> = new_body
+            .blocks()
+            .iter()
+            .enumerate()
+            .filter_map(|(bb, block)| {
+                let TerminatorKind::Call { func, args, destination, target, .. } =
+                    &block.terminator.kind
+                else {
+                    return None;
+                };
+                let TyKind::RigidTy(RigidTy::FnDef(def, generic_args)) =
+                    func.ty(new_body.locals()).ok()?.kind()
+                else {
+                    return None;
+                };
+                let hook = if def == self.assume_invariant {
+                    &self.assume_hook
+                } else if def == self.assert_invariant {
+                    &self.assert_hook
+                } else {
+                    return None;
+                };
+                let mut derefs = vec![ProjectionElem::Deref];
+                let mut target_ty = *generic_args.0[0].expect_ty();
+                while let TyKind::RigidTy(RigidTy::Ref(_, inner_ty, _)) = target_ty.kind() {
+                    target_ty = inner_ty;
+                    derefs.push(ProjectionElem::Deref);
+                }
+                let is_safe = resolve_is_safe(self.is_safe_model, target_ty)?;
+                Some((bb, is_safe, *hook, args.clone(), destination.clone(), *target, derefs))
+            })
+            .collect();
+        let changed = !calls.is_empty();
+        for (bb, is_safe, hook, mut args, destination, target, derefs) in calls {
+            let mut source = SourceInstruction::Terminator { bb };
+            let span = source.span(new_body.blocks());
+            let value = args.remove(0);
+            let value_ty = value.ty(new_body.locals()).unwrap();
+            let value_local = new_body.new_local(value_ty, span, Mutability::Not);
+            new_body.assign_to(
+                Place::from(value_local),
+                Rvalue::Use(value),
+                &mut source,
+                InsertPosition::Before,
+            );
+            let TyKind::RigidTy(RigidTy::Ref(region, _, _)) = value_ty.kind() else {
+                unreachable!()
+            };
+            let target_ty = is_safe.fn_abi().unwrap().args[0].ty;
+            let target_ref = new_body.new_local(target_ty, span, Mutability::Not);
+            new_body.assign_to(
+                Place::from(target_ref),
+                Rvalue::Ref(
+                    region,
+                    BorrowKind::Shared,
+                    Place { local: value_local, projection: derefs },
+                ),
+                &mut source,
+                InsertPosition::Before,
+            );
+            let holds = new_body.new_local(Ty::bool_ty(), span, Mutability::Not);
+            new_body.insert_call(
+                &is_safe,
+                &mut source,
+                InsertPosition::Before,
+                vec![Operand::Move(Place::from(target_ref))],
+                Place::from(holds),
+            );
+            // The remaining argument is the message of the assertion, if any.
+            let hook_args =
+                std::iter::once(Operand::Move(Place::from(holds))).chain(args).collect();
+            new_body.insert_call(
+                &hook,
+                &mut source,
+                InsertPosition::Before,
+                hook_args,
+                destination,
+            );
+            new_body.replace_terminator(
+                &source,
+                Terminator { kind: TerminatorKind::Goto { target: target.unwrap() }, span },
+            );
+        }
+        (changed, new_body.into())
+    }
+}
+
+/// Resolve the implementation of `Invariant::is_safe` for `ty`, if there's one.
+///
+/// The `is_safe_model` calls `Invariant::is_safe`, so we look for that call in its body and try
+/// to resolve it for `ty`.
+fn resolve_is_safe(is_safe_model: FnDef, ty: Ty) -> Option<Instance> {
+    if !LayoutOf::new(ty).is_sized() {
+        return None;
+    }
+    let model_body = Instance::resolve(is_safe_model, &GenericArgs(vec![GenericArgKind::Type(ty)]))
+        .ok()?
+        .body()?;
+    model_body.blocks.iter().find_map(|bb| {
+        let TerminatorKind::Call { func, .. } = &bb.terminator.kind else { return None };
+        let TyKind::RigidTy(RigidTy::FnDef(def, args)) = func.ty(model_body.locals()).ok()?.kind()
+        else {
+            return None;
+        };
+        Instance::resolve(def, &args).ok()
+    })
+}
+
+/// Is this the body of a provided method generated from a trait contract, or one of its
+/// closures? These are the only places where the declared method must still be called.
+fn is_trait_contract_body(tcx: TyCtxt, instance: Instance) -> bool {
+    let def_id = rustc_internal::internal(tcx, instance.def.def_id());
+    KaniAttributes::for_item(tcx, tcx.typeck_root_def_id(def_id)).trait_contract_decl().is_some()
 }
 
 /// Enumeration that store the value of which implementation should be selected.
 ///
 /// Keep the discriminant values in sync with [kani::internal::mode].
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-enum ContractMode {
+pub(crate) enum ContractMode {
     Original = 0,
     RecursiveCheck = 1,
     SimpleCheck = 2,
@@ -74,6 +74,13 @@ fn transform(&mut self, tcx: TyCtxt, body: Body, instance: Instance) -> (bool, B
                 KaniIntrinsic::CheckedSizeOf => (true, self.checked_size_of(body, instance)),
                 KaniIntrinsic::IsInitialized => (true, self.is_initialized_body(body)),
                 KaniIntrinsic::ValidValue => (true, self.valid_value_body(body)),
+                // Calls to these are replaced in the contracts pass if type invariants are
+                // injected into contracts. Otherwise, they are no-ops.
+                KaniIntrinsic::AssertInvariant | KaniIntrinsic::AssumeInvariant => {
+                    let mut new_body = MutableBody::from(body);
+                    new_body.clear_body(TerminatorKind::Return);
+                    (true, new_body.into())
+                }
                 // The former two are handled in contracts pass for now, while the latter is handled in the the automatic harness pass.
                 KaniIntrinsic::WriteAny
                 | KaniIntrinsic::AnyModifies
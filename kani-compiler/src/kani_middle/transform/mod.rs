@@ -25,6 +25,7 @@
 use crate::kani_middle::transform::contracts::{AnyModifiesPass, FunctionWithContractPass};
 use crate::kani_middle::transform::kani_intrinsics::IntrinsicGeneratorPass;
 use crate::kani_middle::transform::loop_contracts::LoopContractPass;
+use crate::kani_middle::transform::mutation::MutationPass;
 use crate::kani_middle::transform::stubs::{ExternFnStubPass, FnStubPass};
 use crate::kani_queries::QueryDb;
 use automatic::{AutomaticArbitraryPass, AutomaticHarnessPass};
@@ -36,6 +37,7 @@
 use std::fmt::Debug;
 
 use crate::kani_middle::transform::rustc_intrinsics::RustcIntrinsicsPass;
+pub(crate) use contracts::ContractMode;
 pub use internal_mir::RustcInternalMir;
 
 mod automatic;
@@ -47,6 +49,7 @@
 mod internal_mir;
 mod kani_intrinsics;
 mod loop_contracts;
+pub(crate) mod mutation;
 mod rustc_intrinsics;
 mod stubs;
 
@@ -75,7 +78,10 @@ pub fn new(queries: &QueryDb, tcx: TyCtxt, unit: &CodegenUnit) -> Self {
         };
         let safety_check_type = CheckType::new_safety_check_assert_assume(queries);
         let unsupported_check_type = CheckType::new_unsupported_check_assert_assume_false(queries);
-        // This has to come first, since creating harnesses affects later stubbing and contract passes.
+        // This has to come first, since the mutation sites are enumerated over the original body.
+        transformer.add_pass(queries, MutationPass::new(queries));
+        // This has to come before other passes, since creating harnesses affects later stubbing
+        // and contract passes.
         transformer.add_pass(queries, AutomaticHarnessPass::new(queries));
         transformer.add_pass(queries, AutomaticArbitraryPass::new(unit, queries));
         transformer.add_pass(queries, FnStubPass::new(&unit.stubs));
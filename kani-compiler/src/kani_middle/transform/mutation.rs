@@ -0,0 +1,328 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! This module contains the MIR-to-MIR pass that applies a mutation to a function body.
+//!
+//! Mutations are small syntactic changes, such as flipping a comparison operator or removing a
+//! statement, which are used to measure how strong a set of proof harnesses is: a harness that
+//! still succeeds after a function it covers was mutated is not checking that behavior.
+//!
+//! Each function body has a list of mutation sites that is enumerated in a deterministic order.
+//! The driver selects one mutant at a time by its [MutantId], and recompiles the crate so this
+//! pass can replace the selected site.
+use crate::kani_middle::transform::{TransformPass, TransformationType};
+use crate::kani_queries::QueryDb;
+use rustc_middle::ty::TyCtxt;
+use rustc_public::CrateDef;
+use rustc_public::mir::mono::Instance;
+use rustc_public::mir::{
+    BasicBlockIdx, BinOp, Body, ConstOperand, Operand, Rvalue, StatementKind, TerminatorKind,
+};
+use rustc_public::target::{Endian, MachineInfo};
+use rustc_public::ty::{ConstantKind, MirConst, RigidTy, Span, TyKind};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use tracing::{debug, trace};
+
+/// Identify a mutant by the mangled name of the mutated function and the index of the mutation
+/// site in its body, formatted as `<mangled_name>#<index>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MutantId {
+    pub mangled_name: String,
+    pub index: usize,
+}
+
+impl FromStr for MutantId {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (mangled_name, index) = value
+            .rsplit_once('#')
+            .ok_or_else(|| format!("expected `<mangled_name>#<index>`, but found `{value}`"))?;
+        let index = index.parse().map_err(|_| format!("invalid mutation index `{index}`"))?;
+        Ok(MutantId { mangled_name: mangled_name.to_string(), index })
+    }
+}
+
+impl Display for MutantId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}#{}", self.mangled_name, self.index)
+    }
+}
+
+/// Apply the mutation selected by the user to the body of the mutated function.
+#[derive(Debug, Clone)]
+pub struct MutationPass {
+    mutant: Option<MutantId>,
+}
+
+impl TransformPass for MutationPass {
+    fn transformation_type() -> TransformationType
+    where
+        Self: Sized,
+    {
+        TransformationType::Stubbing
+    }
+
+    fn is_enabled(&self, _query_db: &QueryDb) -> bool
+    where
+        Self: Sized,
+    {
+        self.mutant.is_some()
+    }
+
+    /// Replace the selected mutation site if this is the mutated function.
+    fn transform(&mut self, _tcx: TyCtxt, mut body: Body, instance: Instance) -> (bool, Body) {
+        trace!(function=?instance.name(), "transform");
+        let mutant = self.mutant.as_ref().unwrap();
+        if instance.mangled_name() != mutant.mangled_name {
+            return (false, body);
+        }
+        let Some(site) = mutation_sites(&body).into_iter().nth(mutant.index) else {
+            debug!(?mutant, "MutationPass::transform: site not found");
+            return (false, body);
+        };
+        debug!(function=?instance.name(), mutation=%site.description, "MutationPass::transform");
+        site.apply(&mut body);
+        (true, body)
+    }
+}
+
+impl MutationPass {
+    pub fn new(queries: &QueryDb) -> MutationPass {
+        MutationPass { mutant: queries.args().mutant.clone() }
+    }
+}
+
+/// A location in the body that can be mutated, and how to mutate it.
+#[derive(Debug, Clone)]
+pub struct MutationSite {
+    pub bb: BasicBlockIdx,
+    /// The index of the statement, or `None` if this is the block terminator.
+    pub stmt: Option<usize>,
+    pub kind: MutationKind,
+    pub span: Span,
+    /// A user-friendly description of the mutation.
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MutationKind {
+    /// Replace the operator of a binary operation.
+    ReplaceBinOp(BinOp),
+    /// Replace the constant that is the n-th operand of an rvalue.
+    ReplaceConstant { operand: usize, new_const: MirConst },
+    /// Remove an assignment.
+    RemoveAssignment,
+    /// Remove a call to a function that returns unit.
+    RemoveCall,
+}
+
+impl MutationSite {
+    fn apply(self, body: &mut Body) {
+        let block = &mut body.blocks[self.bb];
+        match (self.kind, self.stmt) {
+            (MutationKind::ReplaceBinOp(new_op), Some(idx)) => {
+                if let StatementKind::Assign(_, rvalue) = &mut block.statements[idx].kind
+                    && let Rvalue::BinaryOp(op, ..) | Rvalue::CheckedBinaryOp(op, ..) = rvalue
+                {
+                    *op = new_op;
+                }
+            }
+            (MutationKind::ReplaceConstant { operand, new_const }, Some(idx)) => {
+                if let StatementKind::Assign(_, rvalue) = &mut block.statements[idx].kind
+                    && let Some(Operand::Constant(constant)) = rvalue_operands(rvalue).nth(operand)
+                {
+                    constant.const_ = new_const;
+                }
+            }
+            (MutationKind::RemoveAssignment, Some(idx)) => {
+                block.statements[idx].kind = StatementKind::Nop;
+            }
+            (MutationKind::RemoveCall, None) => {
+                if let TerminatorKind::Call { target: Some(target), .. } = block.terminator.kind {
+                    block.terminator.kind = TerminatorKind::Goto { target };
+                }
+            }
+            (kind, stmt) => unreachable!("Unexpected mutation `{kind:?}` for statement {stmt:?}"),
+        }
+    }
+}
+
+/// Enumerate all the mutation sites of a function body.
+///
+/// The order of the sites only depends on the body, so the index of a site can be used to
+/// identify it across compilations.
+pub fn mutation_sites(body: &Body) -> Vec<MutationSite> {
+    let mut sites = vec![];
+    for (bb, block) in body.blocks.iter().enumerate() {
+        for (idx, stmt) in block.statements.iter().enumerate() {
+            let StatementKind::Assign(place, rvalue) = &stmt.kind else { continue };
+            let mut push = |kind, description| {
+                sites.push(MutationSite { bb, stmt: Some(idx), kind, span: stmt.span, description })
+            };
+            if let Rvalue::BinaryOp(op, ..) | Rvalue::CheckedBinaryOp(op, ..) = rvalue
+                && let Some(new_op) = mutate_bin_op(*op)
+            {
+                let description =
+                    format!("replace `{}` with `{}`", bin_op_str(*op), bin_op_str(new_op));
+                push(MutationKind::ReplaceBinOp(new_op), description);
+            }
+            let mut rvalue = rvalue.clone();
+            for (operand, op) in rvalue_operands(&mut rvalue).enumerate() {
+                if let Operand::Constant(ConstOperand { const_, .. }) = op
+                    && let Some((new_const, description)) = mutate_constant(const_)
+                {
+                    push(MutationKind::ReplaceConstant { operand, new_const }, description);
+                }
+            }
+            // Only remove assignments to projections, e.g. fields or dereferences, since removing
+            // the assignment to a local would most likely leave it uninitialized.
+            if !place.projection.is_empty() {
+                push(MutationKind::RemoveAssignment, "remove assignment".to_string());
+            }
+        }
+        let terminator = &block.terminator;
+        if let TerminatorKind::Call { func, destination, target: Some(_), .. } = &terminator.kind
+            && destination.ty(body.locals()).is_ok_and(|ty| ty.kind().is_unit())
+        {
+            let callee = match func.ty(body.locals()).map(|ty| ty.kind()) {
+                Ok(TyKind::RigidTy(RigidTy::FnDef(def, _))) => format!("`{}`", def.name()),
+                _ => "function pointer".to_string(),
+            };
+            sites.push(MutationSite {
+                bb,
+                stmt: None,
+                kind: MutationKind::RemoveCall,
+                span: terminator.span,
+                description: format!("remove call to {callee}"),
+            });
+        }
+    }
+    sites
+}
+
+/// The operands of an rvalue that may contain a constant that can be mutated.
+fn rvalue_operands(rvalue: &mut Rvalue) -> impl Iterator<Item = &mut Operand> {
+    match rvalue {
+        Rvalue::Use(op) => vec![op],
+        Rvalue::BinaryOp(_, lhs, rhs) | Rvalue::CheckedBinaryOp(_, lhs, rhs) => vec![lhs, rhs],
+        _ => vec![],
+    }
+    .into_iter()
+}
+
+/// Return the operator that replaces the given one, if any.
+fn mutate_bin_op(op: BinOp) -> Option<BinOp> {
+    let new_op = match op {
+        BinOp::Lt => BinOp::Le,
+        BinOp::Le => BinOp::Lt,
+        BinOp::Gt => BinOp::Ge,
+        BinOp::Ge => BinOp::Gt,
+        BinOp::Eq => BinOp::Ne,
+        BinOp::Ne => BinOp::Eq,
+        BinOp::Add => BinOp::Sub,
+        BinOp::Sub => BinOp::Add,
+        BinOp::Mul => BinOp::Add,
+        BinOp::Div => BinOp::Mul,
+        BinOp::Rem => BinOp::Div,
+        BinOp::BitAnd => BinOp::BitOr,
+        BinOp::BitOr => BinOp::BitAnd,
+        BinOp::BitXor => BinOp::BitAnd,
+        BinOp::Shl => BinOp::Shr,
+        BinOp::Shr => BinOp::Shl,
+        // Unchecked operations have extra safety requirements, and the remaining operations
+        // don't have a meaningful replacement.
+        BinOp::AddUnchecked
+        | BinOp::SubUnchecked
+        | BinOp::MulUnchecked
+        | BinOp::ShlUnchecked
+        | BinOp::ShrUnchecked
+        | BinOp::Cmp
+        | BinOp::Offset => return None,
+    };
+    Some(new_op)
+}
+
+fn bin_op_str(op: BinOp) -> &'static str {
+    match op {
+        BinOp::Add | BinOp::AddUnchecked => "+",
+        BinOp::Sub | BinOp::SubUnchecked => "-",
+        BinOp::Mul | BinOp::MulUnchecked => "*",
+        BinOp::Div => "/",
+        BinOp::Rem => "%",
+        BinOp::BitXor => "^",
+        BinOp::BitAnd => "&",
+        BinOp::BitOr => "|",
+        BinOp::Shl | BinOp::ShlUnchecked => "<<",
+        BinOp::Shr | BinOp::ShrUnchecked => ">>",
+        BinOp::Eq => "==",
+        BinOp::Lt => "<",
+        BinOp::Le => "<=",
+        BinOp::Ne => "!=",
+        BinOp::Ge => ">=",
+        BinOp::Gt => ">",
+        BinOp::Cmp => "cmp",
+        BinOp::Offset => "offset",
+    }
+}
+
+/// Increment integer constants and negate boolean constants.
+///
+/// Integers wrap around, which is the same as incrementing their bytes with carry.
+fn mutate_constant(constant: &MirConst) -> Option<(MirConst, String)> {
+    let ConstantKind::Allocated(alloc) = constant.kind() else { return None };
+    if !alloc.provenance.ptrs.is_empty() {
+        return None;
+    }
+    let mut new_alloc = alloc.clone();
+    let description = match constant.ty().kind().rigid()? {
+        RigidTy::Bool => {
+            let value = alloc.read_bool().ok()?;
+            new_alloc.bytes = vec![Some(u8::from(!value))];
+            format!("replace `{value}` with `{}`", !value)
+        }
+        RigidTy::Int(_) | RigidTy::Uint(_) => {
+            let little_endian = match MachineInfo::target_endianness() {
+                Endian::Little => new_alloc.bytes.iter_mut().collect::<Vec<_>>(),
+                Endian::Big => new_alloc.bytes.iter_mut().rev().collect(),
+            };
+            for byte in little_endian {
+                let (value, overflow) = byte.as_mut()?.overflowing_add(1);
+                *byte = Some(value);
+                if !overflow {
+                    break;
+                }
+            }
+            if matches!(constant.ty().kind().rigid()?, RigidTy::Int(_)) {
+                format!(
+                    "replace `{}` with `{}`",
+                    alloc.read_int().ok()?,
+                    new_alloc.read_int().ok()?
+                )
+            } else {
+                format!(
+                    "replace `{}` with `{}`",
+                    alloc.read_uint().ok()?,
+                    new_alloc.read_uint().ok()?
+                )
+            }
+        }
+        _ => return None,
+    };
+    let new_const = MirConst::new(ConstantKind::Allocated(new_alloc), constant.ty(), constant.id);
+    Some((new_const, description))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MutantId;
+
+    #[test]
+    fn check_parse_mutant_id() {
+        let id: MutantId = "_RNvCs1234_5krate3abs#12".parse().unwrap();
+        assert_eq!(id, MutantId { mangled_name: "_RNvCs1234_5krate3abs".to_string(), index: 12 });
+        assert_eq!(id.to_string(), "_RNvCs1234_5krate3abs#12");
+        assert!("_RNvCs1234_5krate3abs".parse::<MutantId>().is_err());
+        assert!("_RNvCs1234_5krate3abs#first".parse::<MutantId>().is_err());
+    }
+}
@@ -60,10 +60,13 @@
 fn main() {
     session::init_panic_hook();
     let (kani_compiler, rustc_args) = is_kani_compiler(env::args().collect());
+    let (kani_playback, rustc_args) = remove_flag(rustc_args, "--kani-playback");
 
     // Configure and run compiler.
     if kani_compiler {
         kani_compiler::run(rustc_args);
+    } else if kani_playback {
+        kani_compiler::run_playback(rustc_args);
     } else {
         let mut callbacks = TimePassesCallbacks::default();
         run_compiler(&rustc_args, &mut callbacks);
@@ -83,18 +86,25 @@ fn main() {
 /// The build script should be compiled with normal rustc, not the Kani compiler.
 pub fn is_kani_compiler(args: Vec<String>) -> (bool, Vec<String>) {
     assert!(!args.is_empty(), "Arguments should always include executable name");
-    const KANI_COMPILER: &str = "--kani-compiler";
-    let mut has_kani_compiler = false;
+    remove_flag(args, "--kani-compiler")
+}
+
+/// Return whether `flag` is in the arguments, and the arguments without it.
+///
+/// `kani-driver` adds a `--kani-playback` argument when it compiles concrete playback tests, so
+/// the stubs and contracts of the harnesses are applied to the tests.
+fn remove_flag(args: Vec<String>, flag: &str) -> (bool, Vec<String>) {
+    let mut has_flag = false;
     let new_args = args
         .into_iter()
         .filter(|arg| {
-            if arg == KANI_COMPILER {
-                has_kani_compiler = true;
+            if arg == flag {
+                has_flag = true;
                 false
             } else {
                 true
             }
         })
         .collect();
-    (has_kani_compiler, new_args)
+    (has_flag, new_args)
 }
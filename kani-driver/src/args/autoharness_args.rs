@@ -23,6 +23,13 @@ pub struct CommonAutoharnessArgs {
     #[arg(long = "exclude-pattern", num_args(1), value_name = "PATTERN")]
     pub exclude_pattern: Vec<String>,
 
+    /// Instantiate generic functions with the given type and generate one automatic harness per instantiation.
+    /// Pass this option multiple times to provide multiple types; Kani instantiates each type parameter of a generic
+    /// function with every provided type and skips instantiations that do not satisfy the function's trait bounds.
+    /// The `#[kani::autoharness_types(...)]` attribute overrides these types for a specific function.
+    #[arg(long = "generic-type", num_args(1), value_name = "TYPE")]
+    pub generic_type: Vec<String>,
+
     /// Run the `list` subcommand after generating the automatic harnesses. Note that this option implies --only-codegen.
     #[arg(long)]
     pub list: bool,
@@ -84,6 +91,8 @@ fn validate(&self) -> Result<(), Error> {
             }
         }
 
+        validate_generic_types(&self.generic_type)?;
+
         for include_pattern in self.include_pattern.iter() {
             for exclude_pattern in self.exclude_pattern.iter() {
                 // Check if include pattern contains exclude pattern
@@ -102,6 +111,22 @@ fn validate(&self) -> Result<(), Error> {
     }
 }
 
+/// Error gracefully if a type contains whitespace, since rustc_driver arguments cannot contain whitespace.
+pub fn validate_generic_types(types: &[String]) -> Result<(), Error> {
+    for typ in types {
+        if typ.contains(char::is_whitespace) {
+            return Err(Error::raw(
+                ErrorKind::InvalidValue,
+                format!(
+                    "The `--generic-type` option does not support types with whitespace, but found `{typ}`. \
+                        Remove the whitespace instead, e.g., `[u8;4]` or `(u8,i32)`."
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
 impl ValidateArgs for CargoAutoharnessArgs {
     fn validate(&self) -> Result<(), Error> {
         self.common_autoharness_args.validate()?;
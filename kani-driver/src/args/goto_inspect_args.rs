@@ -0,0 +1,102 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Implements the subcommand handling of the goto-inspect subcommand
+
+use std::path::PathBuf;
+
+use crate::args::common::UnstableFeature;
+use crate::args::{CommonArgs, ValidateArgs};
+use clap::{Error, Parser, ValueEnum, error::ErrorKind};
+
+/// Inspect the goto model of a harness, e.g., a `.out` file kept with `--keep-temps`.
+#[derive(Debug, Parser)]
+pub struct GotoInspectArgs {
+    /// The goto binary file to inspect.
+    #[arg(required = true)]
+    pub input: PathBuf,
+
+    /// The function to inspect the model from. By default, this is the harness of the file
+    /// according to the Kani metadata next to it, or the entry point of CBMC.
+    #[arg(long)]
+    pub entry: Option<String>,
+
+    /// The query to answer. This option can be repeated. All queries are answered by default.
+    #[arg(long = "query", value_enum)]
+    pub queries: Vec<Query>,
+
+    /// The unwind bound of the loops of a harness without an unwind attribute, as when
+    /// verifying it with `--default-unwind`.
+    #[arg(long, value_name = "UNWIND")]
+    pub default_unwind: Option<u32>,
+
+    /// The unwind bound of specific loops, as in the `--unwindset` option of CBMC, e.g.,
+    /// `--unwindset harness.0:3,callee.1:5`. These bounds take precedence over the others.
+    #[arg(long, value_name = "LOOP:UNWIND", value_delimiter = ',', value_parser = parse_unwindset)]
+    pub unwindset: Vec<(String, u32)>,
+
+    /// Output format
+    #[arg(long, default_value = "pretty")]
+    pub format: Format,
+
+    #[command(flatten)]
+    pub common_args: CommonArgs,
+}
+
+/// The queries that the subcommand can answer about a goto model.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, strum_macros::Display)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Query {
+    /// The functions reachable from the entry point.
+    Reachable,
+    /// The reachable functions that have no body, and the functions replaced by a stub.
+    Stubs,
+    /// The loops of the reachable functions, and whether they are bounded.
+    Loops,
+    /// The size of the symbols in the model.
+    Sizes,
+    /// The constructs that Kani does not support in the reachable functions.
+    Unsupported,
+}
+
+/// Output formats available for the subcommand.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, strum_macros::Display)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Format {
+    /// Print tables to the terminal.
+    Pretty,
+    /// Print JSON to the terminal.
+    Json,
+}
+
+/// Parse a `LOOP:UNWIND` entry of `--unwindset`.
+fn parse_unwindset(entry: &str) -> Result<(String, u32), String> {
+    let (name, unwind) =
+        entry.rsplit_once(':').ok_or(format!("expected `LOOP:UNWIND`, found `{entry}`"))?;
+    let unwind = unwind.parse().map_err(|err| format!("invalid unwind bound `{unwind}`: {err}"))?;
+    Ok((name.to_string(), unwind))
+}
+
+impl ValidateArgs for GotoInspectArgs {
+    fn validate(&self) -> Result<(), Error> {
+        self.common_args.validate()?;
+        if !self.common_args.unstable_features.contains(UnstableFeature::UnstableOptions) {
+            return Err(Error::raw(
+                ErrorKind::MissingRequiredArgument,
+                format!(
+                    "The `goto-inspect` subcommand is unstable and requires -Z {}",
+                    UnstableFeature::UnstableOptions
+                ),
+            ));
+        }
+        if !self.input.is_file() {
+            return Err(Error::raw(
+                ErrorKind::InvalidValue,
+                format!(
+                    "Invalid argument: Input invalid. `{}` is not a regular file.",
+                    self.input.display()
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Implements the subcommand handling of the merge-results subcommand
+
+use std::path::PathBuf;
+
+use crate::args::common::UnstableFeature;
+use crate::args::{CommonArgs, ValidateArgs};
+use clap::{Error, Parser, error::ErrorKind};
+
+/// Merge the JSON reports of several verification runs into a single summary and exit code.
+#[derive(Debug, Parser)]
+pub struct MergeResultsArgs {
+    /// The reports to merge, generated with `--report-format json`.
+    #[arg(required = true)]
+    pub reports: Vec<PathBuf>,
+
+    #[command(flatten)]
+    pub common_args: CommonArgs,
+}
+
+impl ValidateArgs for MergeResultsArgs {
+    fn validate(&self) -> Result<(), Error> {
+        self.common_args.validate()?;
+        if !self.common_args.unstable_features.contains(UnstableFeature::UnstableOptions) {
+            return Err(Error::raw(
+                ErrorKind::MissingRequiredArgument,
+                format!(
+                    "The `merge-results` subcommand is unstable and requires -Z {}",
+                    UnstableFeature::UnstableOptions
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -5,7 +5,10 @@
 pub mod autoharness_args;
 pub mod cargo;
 pub mod common;
+pub mod goto_inspect_args;
 pub mod list_args;
+pub mod merge_results_args;
+pub mod mutate_args;
 pub mod playback_args;
 pub mod std_args;
 
@@ -169,8 +172,15 @@ pub struct StandaloneArgs {
 pub enum StandaloneSubcommand {
     /// Create and run harnesses automatically for eligible functions. Implies -Z function-contracts and -Z loop-contracts.
     Autoharness(Box<autoharness_args::StandaloneAutoharnessArgs>),
+    /// Inspect the goto model of a harness.
+    GotoInspect(Box<goto_inspect_args::GotoInspectArgs>),
     /// List contracts and harnesses.
     List(Box<list_args::StandaloneListArgs>),
+    /// Merge the JSON reports of several verification runs, e.g. of different shards.
+    MergeResults(Box<merge_results_args::MergeResultsArgs>),
+    /// Verify the harnesses against mutated versions of the functions they reach, and report the
+    /// mutants that were not detected.
+    Mutate(Box<mutate_args::StandaloneMutateArgs>),
     /// Execute concrete playback testcases of a local crate.
     Playback(Box<playback_args::KaniPlaybackArgs>),
     /// Verify the rust standard library.
@@ -199,9 +209,19 @@ pub enum CargoKaniSubcommand {
     /// See https://model-checking.github.io/kani/reference/experimental/autoharness.html for documentation.
     Autoharness(Box<autoharness_args::CargoAutoharnessArgs>),
 
+    /// Inspect the goto model of a harness.
+    GotoInspect(Box<goto_inspect_args::GotoInspectArgs>),
+
     /// List contracts and harnesses.
     List(Box<list_args::CargoListArgs>),
 
+    /// Merge the JSON reports of several verification runs, e.g. of different shards.
+    MergeResults(Box<merge_results_args::MergeResultsArgs>),
+
+    /// Verify the harnesses against mutated versions of the functions they reach, and report the
+    /// mutants that were not detected.
+    Mutate(Box<mutate_args::CargoMutateArgs>),
+
     /// Execute concrete playback testcases of a local package.
     Playback(Box<playback_args::CargoPlaybackArgs>),
 }
@@ -227,9 +247,22 @@ pub struct VerificationArgs {
     // consumes everything
     pub cbmc_args: Vec<OsString>,
 
+    /// Only verify the harnesses affected by the changes since the given git revision.
+    /// This feature is unstable and it requires `-Z unstable-options` to be used
+    #[arg(long, value_name = "REV", hide_short_help = true)]
+    pub changed_since: Option<String>,
+
+    /// Only verify the harnesses affected by the changes since the fingerprints saved to the
+    /// given file with `--save-fingerprints`.
+    /// This feature is unstable and it requires `-Z unstable-options` to be used
+    #[arg(long, value_name = "FILE", hide_short_help = true, conflicts_with = "changed_since")]
+    pub changed_since_fingerprints: Option<PathBuf>,
+
     /// Generate concrete playback unit test.
     /// If value supplied is 'print', Kani prints the unit test to stdout.
     /// If value supplied is 'inplace', Kani automatically adds the unit test to your source code.
+    /// If value supplied is 'fixture', Kani saves each counterexample under `kani-counterexamples/`
+    /// and adds a test that plays back every saved counterexample of the harness.
     /// This option does not work with `--output-format old`.
     #[arg(long, ignore_case = true, value_enum)]
     pub concrete_playback: Option<ConcretePlaybackMode>,
@@ -293,6 +326,17 @@ pub struct VerificationArgs {
     #[arg(long, hide_short_help = true)]
     pub keep_temps: bool,
 
+    /// Directory where the Lean project is generated from the LLBC of each harness.
+    /// Defaults to `lean` in the current directory. This requires the `-Z lean` option.
+    #[arg(long, value_name = "DIR", hide_short_help = true)]
+    pub lean_dir: Option<PathBuf>,
+
+    /// Assume and assert the `kani::Invariant` of the arguments and return value of every function
+    /// with a contract, and assume it for the inputs of automatic harnesses.
+    /// Requires -Z function-contracts.
+    #[arg(long, hide_short_help = true)]
+    pub contract_invariants: bool,
+
     /// Do not assert the function contracts of dependencies. Requires -Z function-contracts.
     #[arg(long, hide_short_help = true)]
     pub no_assert_contracts: bool,
@@ -342,6 +386,19 @@ pub struct VerificationArgs {
     #[arg(long)]
     pub randomize_layout: Option<Option<u64>>,
 
+    /// Write a machine-readable report of the verification results to `kani-report.<ext>` in the
+    /// `kani` folder of the cargo target directory, or in the output directory of a standalone
+    /// file. This option can be provided multiple times to write several formats.
+    /// This feature is unstable and it requires `-Z unstable-options` to be used
+    #[arg(
+        long = "report-format",
+        num_args(1),
+        ignore_case = true,
+        value_enum,
+        hide_short_help = true
+    )]
+    pub report_formats: Vec<ReportFormat>,
+
     /// Restrict the targets of virtual table function pointer calls.
     /// This feature is unstable and it requires `-Z restrict-vtable` to be used
     #[arg(long, hide = true, conflicts_with = "no_restrict_vtable")]
@@ -351,6 +408,26 @@ pub struct VerificationArgs {
     #[arg(long, hide_short_help = true)]
     pub run_sanity_checks: bool,
 
+    /// Save the fingerprints of the items reachable from each harness that passed verification to
+    /// the given file, so a later run can only verify the affected harnesses with
+    /// `--changed-since-fingerprints <FILE>`.
+    /// This feature is unstable and it requires `-Z unstable-options` to be used
+    #[arg(long, value_name = "FILE", hide_short_help = true)]
+    pub save_fingerprints: Option<PathBuf>,
+
+    /// Only verify the harnesses assigned to the given shard, e.g. `--shard 2/4`. The harnesses
+    /// are deterministically split among the shards, so the results of every shard can be
+    /// combined with `kani merge-results`.
+    /// This feature is unstable and it requires `-Z unstable-options` to be used
+    #[arg(long, value_name = "INDEX/COUNT", hide_short_help = true)]
+    pub shard: Option<Shard>,
+
+    /// Print a table of the N harnesses that took the longest to verify, with the resources
+    /// consumed by CBMC to verify them.
+    /// This feature is unstable and it requires `-Z unstable-options` to be used
+    #[arg(long, value_name = "N", hide_short_help = true)]
+    pub slowest_harnesses: Option<usize>,
+
     /// Specify the CBMC solver to use. Overrides the harness `solver` attribute.
     /// If no solver is specified (with --solver or harness attribute), Kani will use CaDiCaL.
     #[arg(long, value_parser = CbmcSolverValueParser::new(CbmcSolver::VARIANTS))]
@@ -373,10 +450,22 @@ pub struct VerificationArgs {
     #[arg(long)]
     pub tests: bool,
 
+    /// Print the counterexample trace of every failed check, with the values assigned to user
+    /// variables at each step.
+    /// This feature is unstable and it requires `-Z unstable-options` to be used
+    #[arg(long, hide_short_help = true)]
+    pub trace: bool,
+
     /// Specify the value used for loop unwinding for the specified harness in CBMC
     #[arg(long, requires("harnesses"))]
     pub unwind: Option<u32>,
 
+    /// Reuse the results of previous runs for harnesses whose goto binary and CBMC flags haven't
+    /// changed. Results are stored in the target directory.
+    /// This feature is unstable and it requires `-Z unstable-options` to be used
+    #[arg(long, hide_short_help = true)]
+    pub verification_cache: bool,
+
     /// Write the GotoC symbol table to a file in JSON format instead of goto binary format.
     #[arg(long, hide = true)]
     pub write_json_symtab: bool,
@@ -496,6 +585,7 @@ pub enum ConcretePlaybackMode {
     // Otherwise clap will default to `in-place`
     #[value(name = "inplace")]
     InPlace,
+    Fixture,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, ValueEnum)]
@@ -505,6 +595,49 @@ pub enum OutputFormat {
     Old,
 }
 
+/// Machine-readable formats for the report of verification results.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, strum_macros::Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum ReportFormat {
+    /// JSON, with the outcome and the resources consumed by each harness.
+    Json,
+    /// JUnit XML, where each harness is a test case.
+    Junit,
+    /// SARIF, where each failed property is a result.
+    Sarif,
+}
+
+/// A subset of the harnesses selected with `--shard <index>/<count>`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Shard {
+    /// The 1-based index of this shard.
+    pub index: usize,
+    /// The total number of shards.
+    pub count: usize,
+}
+
+impl FromStr for Shard {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            || format!("expected `<index>/<count>` with 1 <= index <= count, got `{value}`");
+        let (index, count) = value.split_once('/').ok_or_else(invalid)?;
+        let index: usize = index.trim().parse().map_err(|_| invalid())?;
+        let count: usize = count.trim().parse().map_err(|_| invalid())?;
+        if index == 0 || index > count {
+            return Err(invalid());
+        }
+        Ok(Shard { index, count })
+    }
+}
+
+impl std::fmt::Display for Shard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.index, self.count)
+    }
+}
+
 #[derive(Debug, clap::Args)]
 #[clap(next_help_heading = "Memory Checks")]
 pub struct CheckArgs {
@@ -568,6 +701,9 @@ fn validate(&self) -> Result<(), Error> {
             Some(StandaloneSubcommand::VerifyStd(args)) => args.validate()?,
             Some(StandaloneSubcommand::List(args)) => args.validate()?,
             Some(StandaloneSubcommand::Autoharness(args)) => args.validate()?,
+            Some(StandaloneSubcommand::GotoInspect(args)) => args.validate()?,
+            Some(StandaloneSubcommand::MergeResults(args)) => args.validate()?,
+            Some(StandaloneSubcommand::Mutate(args)) => args.validate()?,
             // TODO: Invoke PlaybackArgs::validate()
             None | Some(StandaloneSubcommand::Playback(..)) => {}
         };
@@ -612,8 +748,11 @@ impl ValidateArgs for CargoKaniSubcommand {
     fn validate(&self) -> Result<(), Error> {
         match self {
             CargoKaniSubcommand::Autoharness(autoharness) => autoharness.validate(),
+            CargoKaniSubcommand::GotoInspect(inspect) => inspect.validate(),
             CargoKaniSubcommand::Playback(playback) => playback.validate(),
             CargoKaniSubcommand::List(list) => list.validate(),
+            CargoKaniSubcommand::MergeResults(merge) => merge.validate(),
+            CargoKaniSubcommand::Mutate(mutate) => mutate.validate(),
         }
     }
 }
@@ -713,6 +852,11 @@ fn validate(&self) -> Result<(), Error> {
                 "print-llbc",
                 UnstableFeature::Lean,
             )?;
+            self.common_args.check_unstable(
+                self.lean_dir.is_some(),
+                "lean-dir",
+                UnstableFeature::Lean,
+            )?;
             self.common_args.check_unstable(
                 self.harness_timeout.is_some(),
                 "harness-timeout",
@@ -723,6 +867,23 @@ fn validate(&self) -> Result<(), Error> {
                 "no-assert",
                 UnstableFeature::FunctionContracts,
             )?;
+            self.common_args.check_unstable(
+                self.contract_invariants,
+                "contract-invariants",
+                UnstableFeature::FunctionContracts,
+            )?;
+
+            self.common_args.check_unstable(
+                self.changed_since.is_some(),
+                "changed-since",
+                UnstableFeature::UnstableOptions,
+            )?;
+
+            self.common_args.check_unstable(
+                self.changed_since_fingerprints.is_some(),
+                "changed-since-fingerprints",
+                UnstableFeature::UnstableOptions,
+            )?;
 
             self.common_args.check_unstable(
                 self.prove_safety_only,
@@ -730,6 +891,42 @@ fn validate(&self) -> Result<(), Error> {
                 UnstableFeature::UnstableOptions,
             )?;
 
+            self.common_args.check_unstable(
+                !self.report_formats.is_empty(),
+                "report-format",
+                UnstableFeature::UnstableOptions,
+            )?;
+
+            self.common_args.check_unstable(
+                self.save_fingerprints.is_some(),
+                "save-fingerprints",
+                UnstableFeature::UnstableOptions,
+            )?;
+
+            self.common_args.check_unstable(
+                self.shard.is_some(),
+                "shard",
+                UnstableFeature::UnstableOptions,
+            )?;
+
+            self.common_args.check_unstable(
+                self.slowest_harnesses.is_some(),
+                "slowest-harnesses",
+                UnstableFeature::UnstableOptions,
+            )?;
+
+            self.common_args.check_unstable(
+                self.trace,
+                "trace",
+                UnstableFeature::UnstableOptions,
+            )?;
+
+            self.common_args.check_unstable(
+                self.verification_cache,
+                "verification-cache",
+                UnstableFeature::UnstableOptions,
+            )?;
+
             Ok(())
         };
 
@@ -1103,6 +1300,77 @@ fn check_disable_slicing_unstable() {
         check_unstable_flag!("--no-slice-formula", no_slice_formula);
     }
 
+    #[test]
+    fn check_report_format_unstable() {
+        check_opt!(
+            "--report-format junit --report-format sarif --report-format json",
+            Some(UnstableFeature::UnstableOptions),
+            report_formats,
+            vec![ReportFormat::Junit, ReportFormat::Sarif, ReportFormat::Json]
+        );
+    }
+
+    #[test]
+    fn check_changed_since_unstable() {
+        check_opt!(
+            "--changed-since main",
+            Some(UnstableFeature::UnstableOptions),
+            changed_since,
+            Some("main".to_string())
+        );
+        check_opt!(
+            "--changed-since-fingerprints fingerprints.json",
+            Some(UnstableFeature::UnstableOptions),
+            changed_since_fingerprints,
+            Some(PathBuf::from("fingerprints.json"))
+        );
+        check_opt!(
+            "--save-fingerprints fingerprints.json",
+            Some(UnstableFeature::UnstableOptions),
+            save_fingerprints,
+            Some(PathBuf::from("fingerprints.json"))
+        );
+    }
+
+    #[test]
+    fn check_shard_unstable() {
+        check_opt!(
+            "--shard 2/4",
+            Some(UnstableFeature::UnstableOptions),
+            shard,
+            Some(Shard { index: 2, count: 4 })
+        );
+    }
+
+    #[test]
+    fn check_invalid_shard() {
+        for shard in ["0/4", "5/4", "1", "a/b"] {
+            let args = ["kani", "file.rs", "-Z", "unstable-options", "--shard", shard];
+            let err = StandaloneArgs::try_parse_from(args).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ValueValidation);
+        }
+    }
+
+    #[test]
+    fn check_slowest_harnesses_unstable() {
+        check_opt!(
+            "--slowest-harnesses 5",
+            Some(UnstableFeature::UnstableOptions),
+            slowest_harnesses,
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn check_trace_unstable() {
+        check_unstable_flag!("--trace", trace);
+    }
+
+    #[test]
+    fn check_verification_cache_unstable() {
+        check_unstable_flag!("--verification-cache", verification_cache);
+    }
+
     #[test]
     fn check_concrete_playback_unstable() {
         let check = |input: &str| {
@@ -1116,6 +1384,7 @@ fn check_concrete_playback_unstable() {
 
         check("kani file.rs --concrete-playback=inplace");
         check("kani file.rs --concrete-playback=print");
+        check("kani file.rs --concrete-playback=fixture");
     }
 
     /// Check if parsing the given argument string results in the given error.
@@ -1211,4 +1480,24 @@ fn check_no_assert_contracts() {
         let err = StandaloneArgs::try_parse_from(args).unwrap().validate().unwrap_err();
         assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
     }
+
+    #[test]
+    fn check_contract_invariants() {
+        check_opt!(
+            "--contract-invariants",
+            Some(UnstableFeature::FunctionContracts),
+            contract_invariants,
+            true
+        );
+    }
+
+    #[test]
+    fn check_lean_dir() {
+        check_opt!(
+            "--lean-dir proofs",
+            Some(UnstableFeature::Lean),
+            lean_dir,
+            Some("proofs".into())
+        );
+    }
 }
@@ -0,0 +1,75 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Implements the subcommand handling of the mutate subcommand
+
+use std::path::PathBuf;
+
+use crate::args::common::UnstableFeature;
+use crate::args::{ValidateArgs, VerificationArgs};
+use clap::{Error, Parser, error::ErrorKind};
+
+/// Measure the strength of the proof harnesses of a crate by verifying them against mutated
+/// versions of the functions they reach.
+#[derive(Debug, Parser)]
+pub struct CargoMutateArgs {
+    #[command(flatten)]
+    pub verify_opts: VerificationArgs,
+}
+
+/// Measure the strength of the proof harnesses of a file by verifying them against mutated
+/// versions of the functions they reach.
+#[derive(Debug, Parser)]
+pub struct StandaloneMutateArgs {
+    /// Rust crate's top file location.
+    #[arg(required = true)]
+    pub input: PathBuf,
+
+    #[arg(long, hide = true)]
+    pub crate_name: Option<String>,
+
+    #[command(flatten)]
+    pub verify_opts: VerificationArgs,
+}
+
+impl ValidateArgs for CargoMutateArgs {
+    fn validate(&self) -> Result<(), Error> {
+        self.verify_opts.validate()?;
+        validate_mutate_opts(&self.verify_opts)
+    }
+}
+
+impl ValidateArgs for StandaloneMutateArgs {
+    fn validate(&self) -> Result<(), Error> {
+        self.verify_opts.validate()?;
+        validate_mutate_opts(&self.verify_opts)?;
+        if !self.input.is_file() {
+            return Err(Error::raw(
+                ErrorKind::InvalidValue,
+                format!(
+                    "Invalid argument: Input invalid. `{}` is not a regular file.",
+                    self.input.display()
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn validate_mutate_opts(verify_opts: &VerificationArgs) -> Result<(), Error> {
+    if !verify_opts.common_args.unstable_features.contains(UnstableFeature::UnstableOptions) {
+        return Err(Error::raw(
+            ErrorKind::MissingRequiredArgument,
+            format!(
+                "The `mutate` subcommand is unstable and requires -Z {}",
+                UnstableFeature::UnstableOptions
+            ),
+        ));
+    }
+    if verify_opts.common_args.unstable_features.contains(UnstableFeature::ConcretePlayback) {
+        return Err(Error::raw(
+            ErrorKind::ArgumentConflict,
+            "The mutate subcommand does not support concrete playback",
+        ));
+    }
+    Ok(())
+}
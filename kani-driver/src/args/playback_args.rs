@@ -46,11 +46,33 @@ pub struct PlaybackArgs {
     #[arg(long, default_value = "human")]
     pub message_format: MessageFormat,
 
+    /// Build the test with debug information and run it under the given debugger, with a
+    /// breakpoint at the failing check. This requires the name of the test to debug, and any
+    /// other argument after `--` is passed to the test executable.
+    #[arg(long, value_name = "DEBUGGER", num_args(0..=1), default_missing_value = "gdb")]
+    pub debugger: Option<Debugger>,
+
+    /// Instead of running the tests, export the values of every concrete playback test and
+    /// fixture to the given directory, as a corpus that can seed `cargo fuzz`.
+    /// The corpus of each harness is saved in a sub-directory named after it.
+    #[arg(long, value_name = "DIR", conflicts_with_all(["only_codegen", "debugger"]))]
+    pub export_corpus: Option<PathBuf>,
+
     /// Arguments to be passed to the test binary.
     #[arg(num_args(0..), last = true)]
     pub test_args: Vec<String>,
 }
 
+/// Debuggers that can run a playback test.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, strum_macros::Display)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Debugger {
+    Gdb,
+    Lldb,
+    /// Record the test with `rr`, and replay the recording with `gdb`.
+    Rr,
+}
+
 /// Message formats available for the subcommand.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, strum_macros::Display)]
 #[strum(serialize_all = "kebab-case")]
@@ -94,6 +116,21 @@ fn validate(&self) -> Result<(), Error> {
                 to be used.",
             ));
         }
+        if self.debugger.is_some() {
+            if self.only_codegen {
+                return Err(Error::raw(
+                    ErrorKind::ArgumentConflict,
+                    "Conflicting options: `--debugger` cannot be used with `--only-codegen`.",
+                ));
+            }
+            if self.test_args.first().is_none_or(|test| test.starts_with('-')) {
+                return Err(Error::raw(
+                    ErrorKind::MissingRequiredArgument,
+                    "The `--debugger` option requires the name of the test to debug, \
+                    e.g. `-- kani_concrete_playback_check_1234`.",
+                ));
+            }
+        }
         Ok(())
     }
 }
@@ -148,6 +185,54 @@ fn check_kani_parse_extra_args() {
         assert_eq!(args.playback.test_args, ["TEST_NAME", "--exact", "--nocapture"])
     }
 
+    #[test]
+    fn check_parse_debugger_works() {
+        let input = "playback -Z concrete-playback --debugger -- TEST_NAME".split_whitespace();
+        let args = CargoPlaybackArgs::try_parse_from(input).unwrap();
+        args.validate().unwrap();
+        assert_eq!(args.playback.debugger, Some(Debugger::Gdb));
+
+        let input = "playback -Z concrete-playback --debugger lldb -- TEST_NAME".split_whitespace();
+        let args = CargoPlaybackArgs::try_parse_from(input).unwrap();
+        assert_eq!(args.playback.debugger, Some(Debugger::Lldb));
+
+        // `--debug` is the debug output option that is common to all subcommands.
+        let input = "playback -Z concrete-playback --debug -- TEST_NAME".split_whitespace();
+        let args = CargoPlaybackArgs::try_parse_from(input).unwrap();
+        assert!(args.playback.common_opts.debug);
+        assert_eq!(args.playback.debugger, None);
+    }
+
+    #[test]
+    fn check_debugger_extra_args() {
+        let input = "playback -Z concrete-playback --debugger -- TEST_NAME --show-output"
+            .split_whitespace();
+        let args = CargoPlaybackArgs::try_parse_from(input).unwrap();
+        args.validate().unwrap();
+        assert_eq!(args.playback.test_args, ["TEST_NAME", "--show-output"]);
+    }
+
+    #[test]
+    fn check_debugger_requires_test() {
+        let input = "playback -Z concrete-playback --debugger".split_whitespace();
+        let args = CargoPlaybackArgs::try_parse_from(input).unwrap();
+        let err = args.validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    }
+
+    #[test]
+    fn check_parse_export_corpus_works() {
+        let input = "playback -Z concrete-playback --export-corpus fuzz/corpus".split_whitespace();
+        let args = CargoPlaybackArgs::try_parse_from(input).unwrap();
+        args.validate().unwrap();
+        assert_eq!(args.playback.export_corpus, Some(PathBuf::from("fuzz/corpus")));
+
+        let input =
+            "playback -Z concrete-playback --export-corpus corpus --debugger".split_whitespace();
+        let err = CargoPlaybackArgs::try_parse_from(input).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    }
+
     #[test]
     fn check_kani_no_unstable_fails() {
         let input = "playback input.rs".split_whitespace();
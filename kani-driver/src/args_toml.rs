@@ -69,8 +69,12 @@ fn merge_args(
 fn cargo_locate_project(input_args: &[OsString]) -> Result<PathBuf> {
     // Try parsing our command line arguments as they presently look, to see if a "manifest-path" has been given.
     let current_args = crate::args::CargoKaniArgs::parse_from(input_args);
+    locate_manifest(current_args.verify_opts.cargo.manifest_path)
+}
 
-    if let Some(path) = current_args.verify_opts.cargo.manifest_path {
+/// Return the given manifest path, or ask cargo to locate the project's manifest if none was given.
+fn locate_manifest(manifest_path: Option<PathBuf>) -> Result<PathBuf> {
+    if let Some(path) = manifest_path {
         Ok(path)
     } else {
         let cmd =
@@ -85,6 +89,54 @@ fn cargo_locate_project(input_args: &[OsString]) -> Result<PathBuf> {
     }
 }
 
+/// Extract the types used to instantiate generic functions in the `autoharness` subcommand.
+///
+/// Subcommand arguments can't be injected via `flags`, since those are inserted before the
+/// subcommand. Instead, the types are listed in an `autoharness` sub-table, e.g.:
+/// ```toml
+/// [package.metadata.kani.autoharness]
+/// generic-types = ["u8", "i32"]
+/// ```
+pub fn autoharness_generic_types(manifest_path: Option<PathBuf>) -> Result<Vec<String>> {
+    let Ok(toml_path) = locate_manifest(manifest_path) else {
+        // We're not inside a Cargo project, so there's nothing to extract.
+        return Ok(vec![]);
+    };
+    let file = std::fs::read_to_string(toml_path)?;
+    toml_to_generic_types(&file)
+}
+
+/// Parse a config toml string and extract the `autoharness.generic-types` entries.
+/// We support the same tables as [toml_to_args].
+fn toml_to_generic_types(tomldata: &str) -> Result<Vec<String>> {
+    let config = tomldata.parse::<Value>()?;
+    let tables = ["workspace.metadata.kani", "package.metadata.kani", "kani"];
+    let mut types = Vec::new();
+
+    for table in tables {
+        let Some(entry) = get_table(&config, table)
+            .and_then(|table| table.get("autoharness")?.get("generic-types"))
+        else {
+            continue;
+        };
+        let Some(values) = entry.as_array() else {
+            bail!(
+                "Expected `autoharness.generic-types` to be an array of strings, but found `{entry}`"
+            );
+        };
+        for value in values {
+            let Some(typ) = value.as_str() else {
+                bail!("`autoharness.generic-types` contains non-string value `{value}`");
+            };
+            if !types.iter().any(|t| t == typ) {
+                types.push(typ.to_string());
+            }
+        }
+    }
+
+    Ok(types)
+}
+
 /// Parse a config toml string and extract the cargo-kani arguments we should try injecting.
 /// This returns two different vectors since all cbmc-args have to be at the end.
 /// We currently support the following entries:
@@ -235,6 +287,20 @@ fn check_toml_parsing() {
         assert_eq!(b.1, vec!["--cbmc-args", "--fake"]);
     }
 
+    #[test]
+    fn check_toml_generic_types() {
+        let a = "[workspace.metadata.kani.autoharness]
+                      generic-types = [\"u8\", \"i32\"]
+                 [package.metadata.kani.autoharness]
+                      generic-types = [\"i32\", \"[u8;4]\"]";
+        let b = toml_to_generic_types(a).unwrap();
+        assert_eq!(b, vec!["u8", "i32", "[u8;4]"]);
+
+        let c = "[package.metadata.kani.autoharness]
+                      generic-types = \"u8\"";
+        assert!(toml_to_generic_types(c).is_err());
+    }
+
     #[test]
     fn check_merge_args_with_only_command_line_args() {
         let cmd_args: Vec<OsString> =
@@ -5,9 +5,10 @@
 
 use crate::args::Timeout;
 use crate::args::autoharness_args::{
-    CargoAutoharnessArgs, CommonAutoharnessArgs, StandaloneAutoharnessArgs,
+    CargoAutoharnessArgs, CommonAutoharnessArgs, StandaloneAutoharnessArgs, validate_generic_types,
 };
 use crate::args::common::UnstableFeature;
+use crate::args_toml::autoharness_generic_types;
 use crate::call_cbmc::VerificationStatus;
 use crate::harness_runner::HarnessResult;
 use crate::list::collect_metadata::process_metadata;
@@ -22,7 +23,16 @@
 const AUTOHARNESS_TIMEOUT: &str = "60s";
 const LOOP_UNWIND_DEFAULT: u32 = 20;
 
-pub fn autoharness_cargo(args: CargoAutoharnessArgs) -> Result<()> {
+pub fn autoharness_cargo(mut args: CargoAutoharnessArgs) -> Result<()> {
+    // Types provided in the Cargo.toml are used in addition to the ones provided on the command line.
+    let toml_types = autoharness_generic_types(args.verify_opts.cargo.manifest_path.clone())?;
+    for typ in toml_types {
+        if !args.common_autoharness_args.generic_type.contains(&typ) {
+            args.common_autoharness_args.generic_type.push(typ);
+        }
+    }
+    validate_generic_types(&args.common_autoharness_args.generic_type)?;
+
     let mut session = KaniSession::new(args.verify_opts)?;
     setup_session(&mut session, &args.common_autoharness_args);
 
@@ -57,6 +67,7 @@ fn setup_session(session: &mut KaniSession, common_autoharness_args: &CommonAuto
     session.add_auto_harness_args(
         &common_autoharness_args.include_pattern,
         &common_autoharness_args.exclude_pattern,
+        &common_autoharness_args.generic_type,
     );
 }
 
@@ -108,7 +119,8 @@ fn print_autoharness_metadata(metadata: Vec<KaniMetadata>) {
                 ]),
                 AutoHarnessSkipReason::GenericFn
                 | AutoHarnessSkipReason::NoBody
-                | AutoHarnessSkipReason::UserFilter => {
+                | AutoHarnessSkipReason::UserFilter
+                | AutoHarnessSkipReason::NoValidInstantiation => {
                     Some(vec![md.crate_name.clone(), func, reason.to_string()])
                 }
                 // We don't report Kani implementations to the user to avoid exposing Kani functions we insert during instrumentation.
@@ -161,7 +173,12 @@ pub fn enable_autoharness(&mut self) {
     }
 
     /// Add the compiler arguments specific to the `autoharness` subcommand.
-    pub fn add_auto_harness_args(&mut self, included: &[String], excluded: &[String]) {
+    pub fn add_auto_harness_args(
+        &mut self,
+        included: &[String],
+        excluded: &[String],
+        generic_types: &[String],
+    ) {
         let mut args = vec![];
         for pattern in included {
             args.push(format!("--autoharness-include-pattern {pattern}"));
@@ -169,6 +186,9 @@ pub fn add_auto_harness_args(&mut self, included: &[String], excluded: &[String]
         for pattern in excluded {
             args.push(format!("--autoharness-exclude-pattern {pattern}"));
         }
+        for typ in generic_types {
+            args.push(format!("--autoharness-generic-type {typ}"));
+        }
         self.autoharness_compiler_flags = Some(args);
     }
 
@@ -0,0 +1,381 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Generate a Lean project from the LLBC files produced by the Lean backend.
+
+use crate::project::Project;
+use crate::session::KaniSession;
+use crate::util::warning;
+use anyhow::Result;
+use kani_metadata::{ArtifactType, ContractedFunction, HarnessMetadata};
+use std::collections::BTreeMap;
+use std::env::current_dir;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The Lean library that contains the code translated by Aeneas and the theorem stubs.
+const LEAN_LIB: &str = "Proofs";
+
+/// The module with the theorem stubs of a harness. It lives next to the modules generated by
+/// Aeneas for the same harness.
+const SPEC_MODULE: &str = "Spec";
+
+impl KaniSession {
+    /// Translate the LLBC file of each harness to Lean with Aeneas, and generate a lake project
+    /// with one theorem stub per harness and per contract checked by the harnesses.
+    ///
+    /// The code generated by Aeneas is overwritten on every run, but the theorem stubs are only
+    /// generated once, so users can fill in the proofs. If `aeneas` is not installed, only the
+    /// LLBC files are generated.
+    pub fn generate_lean_project(
+        &self,
+        project: &Project,
+        harnesses: &[&HarnessMetadata],
+    ) -> Result<()> {
+        let Ok(aeneas) = which::which("aeneas") else {
+            warning(
+                "Failed to find `aeneas` in your `PATH`. Skipping the generation of the Lean \
+                 project.",
+            );
+            return Ok(());
+        };
+        let lean_dir = match &self.args.lean_dir {
+            Some(dir) => dir.clone(),
+            None => current_dir()?.join("lean"),
+        };
+
+        // Each contract gets a single theorem stub, which goes with the first harness that
+        // checks it.
+        let mut contracts = BTreeMap::<&str, Vec<&ContractedFunction>>::new();
+        for contract in project.metadata.iter().flat_map(|metadata| &metadata.contracted_functions)
+        {
+            if let Some(harness) =
+                harnesses.iter().find(|harness| contract.harnesses.contains(&harness.pretty_name))
+            {
+                contracts.entry(&harness.pretty_name).or_default().push(contract);
+            }
+        }
+
+        for harness in harnesses {
+            let llbc = project.get_harness_artifact(harness, ArtifactType::Llbc).unwrap();
+            let module_dir = lean_dir
+                .join(LEAN_LIB)
+                .join(&harness.crate_name)
+                .join(harness.pretty_name.replace("::", "_"));
+            fs::create_dir_all(&module_dir)?;
+
+            let mut cmd = Command::new(&aeneas);
+            cmd.args(["-backend", "lean", "-dest"]).arg(&module_dir).arg(llbc.as_os_str());
+            self.run_suppress(cmd)?;
+
+            let spec = module_dir.join(SPEC_MODULE).with_extension("lean");
+            if !spec.exists() {
+                let imports = generated_modules(&lean_dir, &module_dir)?;
+                let contracts = contracts.get(harness.pretty_name.as_str());
+                fs::write(&spec, spec_file(harness, &imports, contracts.map_or(&[], |c| c)))?;
+            }
+        }
+
+        write_lake_files(&lean_dir, &aeneas)?;
+        if !self.args.common_args.quiet {
+            println!("Generated Lean project in `{}`", lean_dir.display());
+        }
+        Ok(())
+    }
+}
+
+/// Write the lake configuration if it doesn't exist yet, and the root module of the library,
+/// which imports the theorem stubs of every harness.
+fn write_lake_files(lean_dir: &Path, aeneas: &Path) -> Result<()> {
+    let lakefile = lean_dir.join("lakefile.lean");
+    if !lakefile.exists() {
+        // Prefer the Lean library of the local Aeneas installation, so the generated code and the
+        // library always match.
+        let require = match aeneas_lean_lib(aeneas) {
+            Some(lib) => {
+                let toolchain = lean_dir.join("lean-toolchain");
+                if !toolchain.exists() {
+                    fs::copy(lib.join("lean-toolchain"), toolchain)?;
+                }
+                format!("require aeneas from \"{}\"", lib.display())
+            }
+            None => {
+                "require aeneas from git\n  \"https://github.com/AeneasVerif/aeneas\" @ \"main\" \
+                     / \"backends/lean\""
+                    .to_string()
+            }
+        };
+        let package = LEAN_LIB.to_lowercase();
+        fs::write(
+            lakefile,
+            format!(
+                "import Lake\nopen Lake DSL\n\n{require}\n\npackage {package}\n\n\
+                 @[default_target]\nlean_lib {LEAN_LIB}\n"
+            ),
+        )?;
+    }
+
+    let mut specs = Vec::new();
+    find_specs(&lean_dir.join(LEAN_LIB), &mut specs)?;
+    let imports = specs
+        .iter()
+        .map(|spec| format!("import {}\n", module_name(lean_dir, spec)))
+        .collect::<Vec<_>>();
+    fs::write(lean_dir.join(LEAN_LIB).with_extension("lean"), imports.concat())?;
+    Ok(())
+}
+
+/// The Lean library that comes with `aeneas`, if it runs from a build of the Aeneas repository.
+fn aeneas_lean_lib(aeneas: &Path) -> Option<PathBuf> {
+    let root = aeneas.canonicalize().ok()?.parent()?.parent()?.to_path_buf();
+    let lib = root.join("backends").join("lean");
+    lib.join("lean-toolchain").exists().then_some(lib)
+}
+
+/// Collect the theorem stubs in `dir` and its subdirectories, in a deterministic order.
+fn find_specs(dir: &Path, specs: &mut Vec<PathBuf>) -> Result<()> {
+    let mut entries =
+        fs::read_dir(dir)?.map(|entry| Ok(entry?.path())).collect::<Result<Vec<_>>>()?;
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            find_specs(&path, specs)?;
+        } else if path.file_stem().is_some_and(|stem| stem == SPEC_MODULE) {
+            specs.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// The modules that Aeneas generated in `module_dir`.
+fn generated_modules(lean_dir: &Path, module_dir: &Path) -> Result<Vec<String>> {
+    let mut modules = fs::read_dir(module_dir)?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == "lean")
+                && path.file_stem().is_some_and(|stem| stem != SPEC_MODULE)
+        })
+        .map(|path| module_name(lean_dir, &path))
+        .collect::<Vec<_>>();
+    modules.sort();
+    Ok(modules)
+}
+
+/// The name of the Lean module defined by `file`, relative to the root of the project.
+fn module_name(lean_dir: &Path, file: &Path) -> String {
+    let relative = file.strip_prefix(lean_dir).unwrap().with_extension("");
+    relative.iter().map(|component| component.to_string_lossy()).collect::<Vec<_>>().join(".")
+}
+
+/// Generate the theorem stubs for `harness` and the functions with a contract in `contracts`.
+///
+/// The stubs live in the namespace of the code that Aeneas generated for the harness, where the
+/// names of the crate items are qualified by the crate name.
+fn spec_file(
+    harness: &HarnessMetadata,
+    imports: &[String],
+    contracts: &[&ContractedFunction],
+) -> String {
+    let HarnessMetadata { crate_name, pretty_name, .. } = harness;
+    let namespace = harness.lean_namespace();
+    let mut spec = format!(
+        "-- Theorem stubs for the harness `{pretty_name}`, generated by Kani.\n\
+         -- Kani never overwrites this file, so the proofs can be filled in here.\n\
+         import Aeneas\n"
+    );
+    for import in imports {
+        spec.push_str(&format!("import {import}\n"));
+    }
+    spec.push_str(&format!("open Aeneas.Std\n\nnamespace {namespace}\n"));
+    spec.push_str(&format!(
+        "\n/-- The harness `{pretty_name}` does not fail. -/\ntheorem {}_spec : {crate_name}.{} = \
+         .ok () := by\n  sorry\n",
+        pretty_name.replace("::", "_"),
+        pretty_name.replace("::", "."),
+    ));
+    for contract in contracts {
+        spec.push_str(&contract_spec(crate_name, contract));
+    }
+    spec.push_str(&format!("\nend {namespace}\n"));
+    spec
+}
+
+/// Generate the theorem stub of a contract: the function returns a value for any arguments that
+/// satisfy its preconditions, and that value satisfies its postconditions.
+///
+/// Kani doesn't translate the clauses to Lean. Each one is quoted next to a `True` placeholder,
+/// which is left for the user to replace.
+fn contract_spec(crate_name: &str, contract: &ContractedFunction) -> String {
+    let function = &contract.function;
+    let spec = contract.spec.clone().unwrap_or_default();
+    let mut doc = format!("The contract of `{function}`:");
+    for clause in &spec.requires {
+        doc.push_str(&format!("\n  `#[kani::requires({clause})]`"));
+    }
+    for clause in &spec.ensures {
+        doc.push_str(&format!("\n  `#[kani::ensures({clause})]`"));
+    }
+    doc.push_str("\nReplace each `True` with the Lean statement of the clause next to it.");
+
+    // Arguments bound by a pattern have no name.
+    let args = spec
+        .args
+        .iter()
+        .enumerate()
+        .map(
+            |(idx, (name, ty))| {
+                if name == "_" { (format!("arg{idx}"), ty) } else { (name.clone(), ty) }
+            },
+        )
+        .collect::<Vec<_>>();
+    let mut params =
+        args.iter().map(|(name, ty)| format!(" ({name} : {})", lean_type(ty))).collect::<String>();
+    for (idx, clause) in spec.requires.iter().enumerate() {
+        params.push_str(&format!("\n    (h{idx} : True /- {clause} -/)"));
+    }
+    let args = args.iter().map(|(name, _)| format!(" {name}")).collect::<String>();
+    let post =
+        spec.ensures.iter().map(|clause| format!(" ∧ True /- {clause} -/")).collect::<String>();
+    format!(
+        "\n/-- {doc} -/\ntheorem {}_spec{params} :\n    ∃ result, {crate_name}.{}{args} = .ok result{post} \
+         := by\n  sorry\n",
+        function.replace("::", "_"),
+        function.replace("::", "."),
+    )
+}
+
+/// The name that Aeneas gives to a Rust type, if it is a primitive type. Lean infers the other
+/// types from the call to the function.
+fn lean_type(ty: &str) -> &str {
+    match ty {
+        "bool" => "Bool",
+        "char" => "Char",
+        "u8" => "U8",
+        "u16" => "U16",
+        "u32" => "U32",
+        "u64" => "U64",
+        "u128" => "U128",
+        "usize" => "Usize",
+        "i8" => "I8",
+        "i16" => "I16",
+        "i32" => "I32",
+        "i64" => "I64",
+        "i128" => "I128",
+        "isize" => "Isize",
+        _ => "_",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::tests::mock_proof_harness;
+    use kani_metadata::ContractSpec;
+
+    #[test]
+    fn check_spec_file() {
+        let harness = mock_proof_harness("proofs::check_gcd", None, Some("gcd"), None);
+        let imports = vec!["Proofs.gcd.proofs_check_gcd.Gcd_proofs_check_gcd".to_string()];
+        let contract = ContractedFunction {
+            function: "gcd".to_string(),
+            file: "src/lib.rs".to_string(),
+            harnesses: vec!["proofs::check_gcd".to_string()],
+            spec: Some(ContractSpec {
+                args: vec![
+                    ("x".to_string(), "u8".to_string()),
+                    ("y".to_string(), "u8".to_string()),
+                ],
+                requires: vec!["max(x, y) <= 255".to_string(), "x != 0".to_string()],
+                ensures: vec!["|result| *result != 0".to_string()],
+            }),
+        };
+        let spec = spec_file(&harness, &imports, &[&contract]);
+        assert!(spec.contains("import Aeneas\nimport Proofs.gcd.proofs_check_gcd.Gcd_proofs"));
+        assert!(spec.contains("namespace gcd_proofs_check_gcd\n"));
+        assert!(
+            spec.contains("theorem proofs_check_gcd_spec : gcd.proofs.check_gcd = .ok () := by")
+        );
+        assert!(spec.contains(
+            "\n  `#[kani::requires(max(x, y) <= 255)]`\n  `#[kani::requires(x != 0)]`\n  \
+             `#[kani::ensures(|result| *result != 0)]`\n"
+        ));
+        assert!(spec.contains(
+            "theorem gcd_spec (x : U8) (y : U8)\n    (h0 : True /- max(x, y) <= 255 -/)\n    \
+             (h1 : True /- x != 0 -/) :\n    ∃ result, gcd.gcd x y = .ok result \
+             ∧ True /- |result| *result != 0 -/ := by\n  sorry\n"
+        ));
+        assert!(spec.ends_with("end gcd_proofs_check_gcd\n"));
+    }
+
+    #[test]
+    fn check_spec_file_without_contracts() {
+        let harness = mock_proof_harness("check_gcd_zero", None, Some("gcd"), None);
+        let spec = spec_file(&harness, &[], &[]);
+        assert_eq!(spec.matches("theorem ").count(), 1);
+    }
+
+    #[test]
+    fn check_contract_spec_arguments() {
+        let contract = ContractedFunction {
+            function: "ops::swap".to_string(),
+            file: "src/lib.rs".to_string(),
+            harnesses: vec![],
+            spec: Some(ContractSpec {
+                args: vec![("_".to_string(), "(u32, u32)".to_string())],
+                requires: vec![],
+                ensures: vec![],
+            }),
+        };
+        assert!(contract_spec("pair", &contract).contains(
+            "theorem ops_swap_spec (arg0 : _) :\n    ∃ result, pair.ops.swap arg0 = .ok result := by"
+        ));
+    }
+
+    #[test]
+    fn check_module_name() {
+        let lean_dir = Path::new("/tmp/lean");
+        let file = lean_dir.join("Proofs").join("gcd").join("check_gcd").join("Spec.lean");
+        assert_eq!(module_name(lean_dir, &file), "Proofs.gcd.check_gcd.Spec");
+    }
+
+    #[test]
+    fn check_write_lake_files() {
+        let lean_dir = tempfile::tempdir().unwrap();
+        let lean_dir = lean_dir.path();
+        for harness in ["check_one", "check_two"] {
+            let module_dir = lean_dir.join(LEAN_LIB).join("gcd").join(harness);
+            fs::create_dir_all(&module_dir).unwrap();
+            fs::write(module_dir.join("Spec.lean"), "").unwrap();
+            fs::write(module_dir.join("Gcd.lean"), "").unwrap();
+        }
+        // Without a local Aeneas build, the library is fetched from GitHub.
+        write_lake_files(lean_dir, Path::new("/nonexistent/bin/aeneas")).unwrap();
+        let lakefile = fs::read_to_string(lean_dir.join("lakefile.lean")).unwrap();
+        assert!(lakefile.contains("require aeneas from git"));
+        assert!(lakefile.contains("lean_lib Proofs"));
+        let root = fs::read_to_string(lean_dir.join("Proofs.lean")).unwrap();
+        assert_eq!(root, "import Proofs.gcd.check_one.Spec\nimport Proofs.gcd.check_two.Spec\n");
+
+        // An existing lake configuration is kept.
+        fs::write(lean_dir.join("lakefile.lean"), "-- custom").unwrap();
+        write_lake_files(lean_dir, Path::new("/nonexistent/bin/aeneas")).unwrap();
+        assert_eq!(fs::read_to_string(lean_dir.join("lakefile.lean")).unwrap(), "-- custom");
+    }
+
+    #[test]
+    fn check_generated_modules() {
+        let lean_dir = tempfile::tempdir().unwrap();
+        let lean_dir = lean_dir.path();
+        let module_dir = lean_dir.join(LEAN_LIB).join("gcd").join("check_gcd");
+        fs::create_dir_all(&module_dir).unwrap();
+        for file in ["Spec.lean", "Types.lean", "Funs.lean", "Funs.olean"] {
+            fs::write(module_dir.join(file), "").unwrap();
+        }
+        assert_eq!(
+            generated_modules(lean_dir, &module_dir).unwrap(),
+            ["Proofs.gcd.check_gcd.Funs", "Proofs.gcd.check_gcd.Types"]
+        );
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use anyhow::{Result, bail};
-use kani_metadata::{CbmcSolver, HarnessMetadata};
+use kani_metadata::{CbmcSolver, HarnessAttributes, HarnessMetadata};
 use regex::Regex;
 use rustc_demangle::demangle;
 use std::collections::BTreeMap;
@@ -20,9 +20,12 @@
 use crate::cbmc_output_parser::{
     CheckStatus, Property, VerificationOutput, extract_results, process_cbmc_output,
 };
-use crate::cbmc_property_renderer::{format_coverage, format_result, kani_cbmc_output_filter};
+use crate::cbmc_property_renderer::{
+    TraceContext, format_coverage, format_result, kani_cbmc_output_filter,
+};
 use crate::coverage::cov_results::{CoverageCheck, CoverageResults};
 use crate::coverage::cov_results::{CoverageRegion, CoverageTerm};
+use crate::resource_usage::{PeakMemoryMonitor, ResourceUsage};
 use crate::session::KaniSession;
 use crate::util::render_command;
 
@@ -44,6 +47,8 @@ pub enum FailedProperties {
     None,
     // One or more panic-related failures
     PanicsOnly,
+    // One or more panic-related failures, but some don't match the expected panic message
+    UnexpectedPanics,
     // One or more failures that aren't panic-related
     Other,
 }
@@ -75,6 +80,8 @@ pub struct VerificationResult {
     pub generated_concrete_test: bool,
     /// The coverage results
     pub coverage_results: Option<CoverageResults>,
+    /// The resources consumed by CBMC.
+    pub resources: ResourceUsage,
 }
 
 impl KaniSession {
@@ -119,6 +126,7 @@ async fn run_cbmc_piped(
             .map_err(|_| anyhow::Error::msg("Failed to run cbmc"))?;
 
         let start_time = Instant::now();
+        let memory_monitor = cbmc_process.id().map(PeakMemoryMonitor::start);
 
         let res = if let Some(timeout) = self.args.harness_timeout {
             tokio::time::timeout(
@@ -145,7 +153,7 @@ async fn run_cbmc_piped(
             .await)
         };
 
-        let verification_results = if res.is_err() {
+        let mut verification_results = if res.is_err() {
             // An error occurs if the timeout was reached
 
             // Kill the process
@@ -158,12 +166,15 @@ async fn run_cbmc_piped(
                 runtime: start_time.elapsed(),
                 generated_concrete_test: false,
                 coverage_results: None,
+                resources: ResourceUsage::default(),
             }
         } else {
             // The timeout wasn't reached
             let output = res.unwrap()?;
-            VerificationResult::from(output, harness.attributes.should_panic, start_time)
+            VerificationResult::from(output, &harness.attributes, start_time)
         };
+        verification_results.resources.peak_memory =
+            memory_monitor.and_then(PeakMemoryMonitor::stop);
 
         Ok(verification_results)
     }
@@ -197,7 +208,7 @@ pub fn cbmc_flags(
             args.push("--slice-formula".into());
         }
 
-        if self.args.concrete_playback.is_some() {
+        if self.args.concrete_playback.is_some() || self.args.trace {
             args.push("--trace".into());
         }
 
@@ -324,23 +335,17 @@ impl VerificationResult {
     ///      (Do not mistake lack of results for success: report it as failure.)
     fn from(
         output: VerificationOutput,
-        should_panic: bool,
+        attributes: &HarnessAttributes,
         start_time: Instant,
     ) -> VerificationResult {
         let runtime = start_time.elapsed();
-        let (_, results) = extract_results(output.processed_items);
+        let (messages, results) = extract_results(output.processed_items);
+        let resources = ResourceUsage::from_messages(&messages);
 
         if let Some(results) = results {
-            let (status, failed_properties) =
-                verification_outcome_from_properties(&results, should_panic);
-            let coverage_results = coverage_results_from_properties(&results);
             VerificationResult {
-                status,
-                failed_properties,
-                results: Ok(results),
-                runtime,
-                generated_concrete_test: false,
-                coverage_results,
+                resources,
+                ..VerificationResult::from_properties(results, attributes, runtime)
             }
         } else {
             // We never got results from CBMC - something went wrong (e.g. crash) so it's failure
@@ -356,10 +361,32 @@ fn from(
                 runtime,
                 generated_concrete_test: false,
                 coverage_results: None,
+                resources,
             }
         }
     }
 
+    /// Computes a `VerificationResult` from the properties reported by CBMC, e.g. the ones
+    /// stored in the verification cache.
+    pub fn from_properties(
+        results: Vec<Property>,
+        attributes: &HarnessAttributes,
+        runtime: Duration,
+    ) -> VerificationResult {
+        let (status, failed_properties) =
+            verification_outcome_from_properties(&results, attributes);
+        let coverage_results = coverage_results_from_properties(&results);
+        VerificationResult {
+            status,
+            failed_properties,
+            results: Ok(results),
+            runtime,
+            generated_concrete_test: false,
+            coverage_results,
+            resources: ResourceUsage::default(),
+        }
+    }
+
     pub fn mock_success() -> VerificationResult {
         VerificationResult {
             status: VerificationStatus::Success,
@@ -368,6 +395,7 @@ pub fn mock_success() -> VerificationResult {
             runtime: Duration::from_secs(0),
             generated_concrete_test: false,
             coverage_results: None,
+            resources: ResourceUsage::default(),
         }
     }
 
@@ -382,10 +410,16 @@ fn mock_failure() -> VerificationResult {
             runtime: Duration::from_secs(0),
             generated_concrete_test: false,
             coverage_results: None,
+            resources: ResourceUsage::default(),
         }
     }
 
-    pub fn render(&self, output_format: &OutputFormat, should_panic: bool) -> String {
+    pub fn render(
+        &self,
+        output_format: &OutputFormat,
+        should_panic: bool,
+        trace_context: Option<&TraceContext>,
+    ) -> String {
         match &self.results {
             Ok(results) => {
                 let status = self.status;
@@ -400,9 +434,17 @@ pub fn render(&self, output_format: &OutputFormat, should_panic: bool) -> String
                         should_panic,
                         failed_properties,
                         show_checks,
+                        trace_context,
                     )
                 } else {
-                    format_result(results, status, should_panic, failed_properties, show_checks)
+                    format_result(
+                        results,
+                        status,
+                        should_panic,
+                        failed_properties,
+                        show_checks,
+                        trace_context,
+                    )
                 };
                 writeln!(result, "Verification Time: {}s", self.runtime.as_secs_f32()).unwrap();
                 result
@@ -438,25 +480,36 @@ pub fn render(&self, output_format: &OutputFormat, should_panic: bool) -> String
 /// We decide if verification succeeded based on properties, not (typically) on exit code
 fn verification_outcome_from_properties(
     properties: &[Property],
-    should_panic: bool,
+    attributes: &HarnessAttributes,
 ) -> (VerificationStatus, FailedProperties) {
-    let failed_properties = determine_failed_properties(properties);
-    let status = if should_panic {
+    let failed_properties =
+        determine_failed_properties(properties, attributes.expected_panic.as_deref());
+    let status = if attributes.should_panic {
         match failed_properties {
-            FailedProperties::None | FailedProperties::Other => VerificationStatus::Failure,
+            FailedProperties::None
+            | FailedProperties::UnexpectedPanics
+            | FailedProperties::Other => VerificationStatus::Failure,
             FailedProperties::PanicsOnly => VerificationStatus::Success,
         }
     } else {
         match failed_properties {
             FailedProperties::None => VerificationStatus::Success,
-            FailedProperties::PanicsOnly | FailedProperties::Other => VerificationStatus::Failure,
+            FailedProperties::PanicsOnly
+            | FailedProperties::UnexpectedPanics
+            | FailedProperties::Other => VerificationStatus::Failure,
         }
     };
     (status, failed_properties)
 }
 
-/// Determines the `FailedProperties` variant that corresponds to an array of properties
-fn determine_failed_properties(properties: &[Property]) -> FailedProperties {
+/// Determines the `FailedProperties` variant that corresponds to an array of properties.
+///
+/// If an expected panic message is given, panics are only accepted if their description
+/// contains it.
+fn determine_failed_properties(
+    properties: &[Property],
+    expected_panic: Option<&str>,
+) -> FailedProperties {
     let failed_properties: Vec<&Property> =
         properties.iter().filter(|prop| prop.status == CheckStatus::Failure).collect();
     // Return `FAILURE` if there isn't at least one failed property
@@ -467,10 +520,14 @@ fn determine_failed_properties(properties: &[Property]) -> FailedProperties {
         // Note: Panics caused by `panic!` and `assert!` fall into this class.
         let all_failed_checks_are_panics =
             failed_properties.iter().all(|prop| prop.property_class() == "assertion");
-        if all_failed_checks_are_panics {
-            FailedProperties::PanicsOnly
-        } else {
+        if !all_failed_checks_are_panics {
             FailedProperties::Other
+        } else if let Some(expected) = expected_panic
+            && !failed_properties.iter().all(|prop| prop.description.contains(expected))
+        {
+            FailedProperties::UnexpectedPanics
+        } else {
+            FailedProperties::PanicsOnly
         }
     }
 }
@@ -586,4 +643,49 @@ fn resolve(args: &[&str], harness: &HarnessMetadata) -> Option<u32> {
         assert_eq!(resolve(&args_only_harness, &harness_some), Some(1));
         assert_eq!(resolve(&args_both, &harness_some), Some(1));
     }
+
+    fn mock_property(class: &str, description: &str) -> Property {
+        Property {
+            description: description.to_string(),
+            property_id: crate::cbmc_output_parser::PropertyId {
+                fn_name: Some("check".to_string()),
+                class: class.to_string(),
+                id: 1,
+            },
+            source_location: crate::cbmc_output_parser::SourceLocation {
+                column: None,
+                file: None,
+                function: None,
+                line: None,
+            },
+            status: CheckStatus::Failure,
+            reach: None,
+            trace: None,
+        }
+    }
+
+    #[test]
+    fn check_expected_panic_outcome() {
+        let mut attributes = mock_proof_harness("check", None, None, None).attributes;
+        attributes.should_panic = true;
+        let panic = [mock_property("assertion", "index out of bounds: the length is less than 4")];
+        let overflow = [mock_property("arithmetic_overflow", "attempt to add with overflow")];
+        let outcome = |properties: &[Property], attributes: &HarnessAttributes| {
+            verification_outcome_from_properties(properties, attributes).0
+        };
+
+        assert_eq!(outcome(&panic, &attributes), VerificationStatus::Success);
+        assert_eq!(outcome(&overflow, &attributes), VerificationStatus::Failure);
+
+        attributes.expected_panic = Some("index out of bounds".to_string());
+        assert_eq!(outcome(&panic, &attributes), VerificationStatus::Success);
+        assert_eq!(outcome(&[], &attributes), VerificationStatus::Failure);
+
+        attributes.expected_panic = Some("division by zero".to_string());
+        assert_eq!(outcome(&panic, &attributes), VerificationStatus::Failure);
+        assert!(matches!(
+            verification_outcome_from_properties(&panic, &attributes).1,
+            FailedProperties::UnexpectedPanics
+        ));
+    }
 }
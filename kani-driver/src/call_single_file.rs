@@ -140,6 +140,10 @@ pub fn kani_compiler_local_flags(&self) -> Vec<KaniArg> {
             flags.push("--coverage-checks".into());
         }
 
+        if self.needs_reachable_items() {
+            flags.push("--emit-reachable-items".into());
+        }
+
         if self.args.common_args.unstable_features.contains(UnstableFeature::ValidValueChecks) {
             flags.push("--ub-check=validity".into())
         }
@@ -163,6 +167,10 @@ pub fn kani_compiler_local_flags(&self) -> Vec<KaniArg> {
             flags.push("--no-assert-contracts".into());
         }
 
+        if self.args.contract_invariants {
+            flags.push("--contract-invariants".into());
+        }
+
         for harness in &self.args.harnesses {
             flags.push(format!("--harness {harness}").into());
         }
@@ -175,6 +183,8 @@ pub fn kani_compiler_local_flags(&self) -> Vec<KaniArg> {
             flags.extend(args.into_iter().map(KaniArg::from));
         }
 
+        flags.extend(self.mutation_compiler_flags.iter().cloned().map(KaniArg::from));
+
         if self.args.prove_safety_only {
             flags.push("--prove-safety-only".into());
         }
@@ -233,6 +243,11 @@ pub fn kani_rustc_flags(&self, lib_config: LibConfig) -> Vec<RustcArg> {
             flags.push("debug-assertions=off".into());
         }
 
+        // The contract macros only emit the type invariant checks if this is set.
+        if self.args.contract_invariants {
+            flags.push("--cfg=kani_contract_invariants".into());
+        }
+
         // This argument will select the Kani flavour of the compiler. It will be removed before
         // rustc driver is invoked.
         flags.push("--kani-compiler".into());
@@ -255,6 +270,7 @@ pub fn base_rustc_flags(lib_config: LibConfig) -> Vec<RustcArg> {
         "-Z",
         "always-encode-mir",
         "--cfg=kani",
+        "--check-cfg=cfg(kani_contract_invariants)",
         "-Z",
         "crate-attr=feature(register_tool)",
         "-Z",
@@ -216,7 +216,7 @@ fn deserialize<D>(d: D) -> Result<Self, D::Error>
 ///
 /// Source locations may be completely empty, which is why
 /// all members are optional.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SourceLocation {
     pub column: Option<String>,
     pub file: Option<String>,
@@ -296,7 +296,7 @@ pub struct TraceItem {
 /// Struct that represents a trace value.
 ///
 /// Note: this struct can have a lot of different fields depending on the value type.
-/// The fields included right now are relevant to primitive types and arrays.
+/// The fields included right now are relevant to primitive types, arrays and structs.
 #[derive(Clone, Debug, Deserialize)]
 pub struct TraceValue {
     pub binary: Option<String>,
@@ -304,6 +304,8 @@ pub struct TraceValue {
     pub width: Option<u32>,
     // Invariant: elements is Some iff binary, data, and width are None.
     pub elements: Option<Vec<TraceArrayValue>>,
+    // Invariant: members is Some iff binary, data, width and elements are None.
+    pub members: Option<Vec<TraceStructMember>>,
 }
 
 /// Struct that represents an element of an array in a trace.
@@ -312,6 +314,13 @@ pub struct TraceArrayValue {
     pub value: TraceValue,
 }
 
+/// Struct that represents a member of a struct in a trace.
+#[derive(Clone, Debug, Deserialize)]
+pub struct TraceStructMember {
+    pub name: String,
+    pub value: TraceValue,
+}
+
 /// Enum that represents a trace data item.
 #[derive(Clone, Debug, Deserialize)]
 #[serde(untagged)]
@@ -3,13 +3,21 @@
 
 use crate::args::OutputFormat;
 use crate::call_cbmc::{FailedProperties, VerificationStatus};
-use crate::cbmc_output_parser::{CheckStatus, ParserItem, Property, TraceItem};
+use crate::cbmc_output_parser::{
+    CheckStatus, ParserItem, Property, SourceLocation, TraceItem, TraceValue,
+};
 use crate::coverage::cov_results::CoverageResults;
+use cbmc::InternedString;
+use cbmc::irep::goto_binary_serde::read_goto_binary_file;
+use cbmc::irep::{Irep, IrepId, SymbolTable};
 use console::style;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rustc_demangle::demangle;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
 
 type CbmcAltDescriptions = HashMap<&'static str, Vec<(&'static str, Option<&'static str>)>>;
 
@@ -248,6 +256,7 @@ pub fn format_result(
     should_panic: bool,
     failed_properties: FailedProperties,
     show_checks: bool,
+    trace_context: Option<&TraceContext>,
 ) -> String {
     let mut result_str = String::new();
     let mut number_checks_failed = 0;
@@ -381,6 +390,9 @@ pub fn format_result(
     for prop in failed_tests {
         let failure_message = build_failure_message(prop.description.clone(), &prop.trace.clone());
         result_str.push_str(&failure_message);
+        if let (Some(context), Some(trace)) = (trace_context, &prop.trace) {
+            result_str.push_str(&format_trace(trace, context));
+        }
     }
 
     let verification_result = if status == VerificationStatus::Success {
@@ -392,6 +404,9 @@ pub fn format_result(
         match failed_properties {
             FailedProperties::None => " (encountered no panics, but at least one was expected)",
             FailedProperties::PanicsOnly => " (encountered one or more panics as expected)",
+            FailedProperties::UnexpectedPanics => {
+                " (encountered one or more panics that don't match the expected message)"
+            }
             FailedProperties::Other => {
                 " (encountered failures other than panics, which were unexpected)"
             }
@@ -434,12 +449,19 @@ pub fn format_coverage(
     should_panic: bool,
     failed_properties: FailedProperties,
     show_checks: bool,
+    trace_context: Option<&TraceContext>,
 ) -> String {
     let (_coverage_checks, non_coverage_checks): (Vec<Property>, Vec<Property>) =
         properties.iter().cloned().partition(|x| x.property_class() == "code_coverage");
 
-    let verification_output =
-        format_result(&non_coverage_checks, status, should_panic, failed_properties, show_checks);
+    let verification_output = format_result(
+        &non_coverage_checks,
+        status,
+        should_panic,
+        failed_properties,
+        show_checks,
+        trace_context,
+    );
     let cov_results_intro = "Source-based code coverage results:";
     let result = format!("{verification_output}\n{cov_results_intro}\n\n{cov_results}");
 
@@ -475,6 +497,235 @@ fn build_failure_message(description: String, trace: &Option<Vec<TraceItem>>) ->
     backup_failure_message
 }
 
+/// Information about the harness under verification used to render its counterexample traces.
+pub struct TraceContext {
+    /// The directory of the Kani library sources. Steps in these files are omitted from traces.
+    kani_library: PathBuf,
+    /// The symbols of the goto model, used to find the type of the variables.
+    symbols: SymbolTable,
+    /// The names of the variable symbols, indexed by each suffix of their name that follows a
+    /// `::`. This avoids scanning the symbol table for each trace step.
+    variables: HashMap<String, Vec<InternedString>>,
+    /// The names of the variants of each enum type, indexed by their discriminant.
+    enum_map: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl TraceContext {
+    /// Load the symbols of the goto model and the variants of the enums generated for it.
+    ///
+    /// This information is optional: if it cannot be loaded, values are rendered without their
+    /// types, and enums are printed as their discriminant.
+    pub fn new(kani_library: PathBuf, goto_model: &Path, enum_map: Option<&Path>) -> Self {
+        let symbols =
+            read_goto_binary_file(goto_model).map(|model| model.symbol_table).unwrap_or_default();
+        let enum_map = enum_map
+            .and_then(|path| File::open(path).ok())
+            .and_then(|file| serde_json::from_reader(BufReader::new(file)).ok())
+            .unwrap_or_default();
+        Self::with_symbols(kani_library, symbols, enum_map)
+    }
+
+    fn with_symbols(
+        kani_library: PathBuf,
+        symbols: SymbolTable,
+        enum_map: BTreeMap<String, BTreeMap<String, String>>,
+    ) -> Self {
+        let mut variables = HashMap::<String, Vec<InternedString>>::new();
+        for symbol in symbols.symbol_table.values().filter(|symbol| !symbol.is_type) {
+            let name = symbol.name.to_string();
+            for (index, _) in name.match_indices("::") {
+                variables.entry(name[index + 2..].to_string()).or_default().push(symbol.name);
+            }
+        }
+        TraceContext { kani_library, symbols, variables, enum_map }
+    }
+
+    /// Whether the location corresponds to the user code, as opposed to the standard library or
+    /// the Kani library.
+    fn is_user_location(&self, location: &SourceLocation) -> bool {
+        let (Some(file), Some(function)) = (&location.file, &location.function) else {
+            return false;
+        };
+        !file.starts_with("/rustc/")
+            && !Path::new(file).starts_with(&self.kani_library)
+            && !function.starts_with("kani::")
+    }
+
+    /// The type of the variable assigned in a trace step, if it can be determined.
+    ///
+    /// CBMC may print the variable with its symbol name, or with a suffix of it (e.g.,
+    /// `harness::1::var_1::x` for a symbol whose name starts with the mangled function name).
+    /// Variables that match several symbols with different types are not resolved.
+    fn variable_type(&self, variable: &str) -> Option<&Irep> {
+        let symbols = &self.symbols.symbol_table;
+        if let Some(symbol) = symbols.get(&InternedString::from(variable)) {
+            return Some(&symbol.typ);
+        }
+        // The index is keyed by the part of the variable after its first `::`, if any, and the
+        // candidates are then filtered by the whole suffix.
+        let (key, suffix) = match variable.split_once("::") {
+            Some((_, key)) => (key.to_string(), variable.to_string()),
+            None => (variable.to_string(), format!("::{variable}")),
+        };
+        let mut types = self
+            .variables
+            .get(&key)?
+            .iter()
+            .filter(|name| name.to_string().ends_with(&suffix))
+            .filter_map(|name| symbols.get(name))
+            .map(|symbol| &symbol.typ);
+        let typ = types.next()?;
+        types.all(|other| other == typ).then_some(typ)
+    }
+
+    /// Resolve a struct tag to the struct definition.
+    fn follow_tag<'a>(&'a self, typ: &'a Irep) -> &'a Irep {
+        match typ.id {
+            IrepId::StructTag => typ
+                .lookup(IrepId::Identifier)
+                .and_then(|tag| {
+                    self.symbols.symbol_table.get(&InternedString::from(tag.id.to_string()))
+                })
+                .map_or(typ, |symbol| &symbol.typ),
+            _ => typ,
+        }
+    }
+
+    /// The type of a field of a struct.
+    fn field_type<'a>(&'a self, typ: &'a Irep, field: &str) -> Option<&'a Irep> {
+        self.follow_tag(typ)
+            .lookup(IrepId::Components)?
+            .sub
+            .iter()
+            .find(|component| component.lookup_as_string(IrepId::Name).as_deref() == Some(field))?
+            .lookup(IrepId::Type)
+    }
+
+    /// The type of the elements of an array.
+    fn element_type<'a>(&'a self, typ: &'a Irep) -> Option<&'a Irep> {
+        if typ.id == IrepId::Array { typ.sub.first() } else { None }
+    }
+
+    /// The name of the variant of an enum with the given discriminant.
+    fn variant_name(&self, typ: &Irep, discriminant: &str) -> Option<&str> {
+        let tag = typ.lookup_as_string(IrepId::Identifier)?;
+        self.enum_map.get(&tag)?.get(discriminant).map(String::as_str)
+    }
+}
+
+/// Renders the steps of a counterexample trace that assign values to user variables, in the
+/// order in which they were executed.
+///
+/// Steps in the standard library, in the Kani library, and assignments to temporaries
+/// introduced by the compiler (e.g., `var_2`) are omitted.
+fn format_trace(trace: &[TraceItem], context: &TraceContext) -> String {
+    let mut result = String::from(" Trace:\n");
+    let mut steps = 0;
+    for item in trace {
+        let (Some(lhs), Some(location), Some(value)) =
+            (&item.lhs, &item.source_location, &item.value)
+        else {
+            continue;
+        };
+        if item.step_type != "assignment" || !context.is_user_location(location) {
+            continue;
+        }
+        let (variable, path) = lhs.split_at(lhs.find(['.', '[']).unwrap_or(lhs.len()));
+        // Variables may be printed with their fully qualified name, e.g. `harness::1::var_1::x`.
+        let name = variable.rsplit("::").next().unwrap();
+        if !is_user_variable(name) || path.contains('$') {
+            continue;
+        }
+        let typ = context.variable_type(variable);
+        // Setting the variant of an enum assigns its discriminant, e.g. `mode.case = 5`.
+        let variant = path.strip_suffix(".case").and_then(|enum_path| {
+            let enum_type = path_type(context, typ?, enum_path)?;
+            let discriminant = format_value(value, None, context);
+            Some((enum_path, context.variant_name(enum_type, &discriminant)?))
+        });
+        let assignment = match variant {
+            Some((enum_path, variant)) => format!("{name}{enum_path} = {variant}"),
+            None => {
+                let typ = typ.and_then(|typ| path_type(context, typ, path));
+                format!("{name}{path} = {}", format_value(value, typ, context))
+            }
+        };
+        steps += 1;
+        result.push_str(&format!("  {steps}: {location}\n     {assignment}\n"));
+    }
+    if steps == 0 {
+        result.push_str("  No assignment to user variables found\n");
+    }
+    result
+}
+
+/// The type of the part of a variable selected by a path of field accesses and array indices,
+/// e.g. `.x[1].y`.
+fn path_type<'a>(context: &'a TraceContext, typ: &'a Irep, path: &str) -> Option<&'a Irep> {
+    let mut typ = typ;
+    let mut rest = path;
+    while !rest.is_empty() {
+        if let Some(field) = rest.strip_prefix('.') {
+            let end = field.find(['.', '[']).unwrap_or(field.len());
+            typ = context.field_type(typ, &field[..end])?;
+            rest = &field[end..];
+        } else {
+            let end = rest.find(']')?;
+            typ = context.element_type(typ)?;
+            rest = &rest[end + 1..];
+        }
+    }
+    Some(typ)
+}
+
+/// Whether the variable was declared by the user, as opposed to a temporary introduced by the
+/// compiler (e.g., `var_2`) or an auxiliary variable introduced by CBMC
+/// (e.g., `goto_symex$$return_value`).
+fn is_user_variable(name: &str) -> bool {
+    let is_temporary =
+        name.strip_prefix("var_").is_some_and(|idx| idx.chars().all(|c| c.is_ascii_digit()));
+    !name.is_empty() && !is_temporary && !name.contains('$') && !name.starts_with("__")
+}
+
+/// Renders a trace value using Rust syntax.
+///
+/// Integers and booleans are printed as CBMC reports them, and structs and tuples as a list of
+/// their fields. Enums are printed as the name of their active variant when their type is known,
+/// and as their discriminant otherwise. The fields of the active variant are not printed, since
+/// they cannot be determined from the trace alone.
+fn format_value(value: &TraceValue, typ: Option<&Irep>, context: &TraceContext) -> String {
+    if let Some(members) = &value.members {
+        if let Some(case) = members.iter().find(|member| member.name == "case") {
+            let discriminant = format_value(&case.value, None, context);
+            return match typ.and_then(|typ| context.variant_name(typ, &discriminant)) {
+                Some(variant) => variant.to_string(),
+                None => format!("variant #{discriminant}"),
+            };
+        }
+        let fields: Vec<_> = members
+            .iter()
+            .filter(|member| !member.name.starts_with("$pad"))
+            .map(|member| {
+                let field_type = typ.and_then(|typ| context.field_type(typ, &member.name));
+                format!("{}: {}", member.name, format_value(&member.value, field_type, context))
+            })
+            .collect();
+        return format!("{{ {} }}", fields.join(", "));
+    }
+    if let Some(elements) = &value.elements {
+        let element_type = typ.and_then(|typ| context.element_type(typ));
+        let elements: Vec<_> = elements
+            .iter()
+            .map(|element| format_value(&element.value, element_type, context))
+            .collect();
+        return format!("[{}]", elements.join(", "));
+    }
+    match &value.data {
+        Some(data) => data.to_string(),
+        None => value.binary.clone().unwrap_or_else(|| "<unknown>".to_string()),
+    }
+}
+
 /// Edits an error message.
 ///
 /// At present, we only know one case where CBMC emits an error message, related
@@ -803,3 +1054,174 @@ fn annotate_properties_with_reach_results(
     }
     properties
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cbmc_output_parser::{TraceData, TraceStructMember};
+    use cbmc::irep::Symbol;
+
+    fn context() -> TraceContext {
+        TraceContext::with_symbols(
+            PathBuf::from("/kani/library"),
+            SymbolTable::new(),
+            BTreeMap::new(),
+        )
+    }
+
+    fn variable_symbol(name: &str, typ: Irep) -> Symbol {
+        Symbol {
+            typ,
+            value: Irep::nil(),
+            location: Irep::nil(),
+            name: name.into(),
+            module: "".into(),
+            base_name: name.rsplit("::").next().unwrap().into(),
+            pretty_name: name.into(),
+            mode: "C".into(),
+            is_type: false,
+            is_macro: false,
+            is_exported: false,
+            is_input: false,
+            is_output: false,
+            is_state_var: false,
+            is_property: false,
+            is_static_lifetime: false,
+            is_thread_local: false,
+            is_lvalue: true,
+            is_file_local: false,
+            is_extern: false,
+            is_volatile: false,
+            is_parameter: false,
+            is_auxiliary: false,
+            is_weak: false,
+        }
+    }
+
+    fn location(file: &str, function: &str, line: &str) -> SourceLocation {
+        SourceLocation {
+            column: None,
+            file: Some(file.to_string()),
+            function: Some(function.to_string()),
+            line: Some(line.to_string()),
+        }
+    }
+
+    fn scalar(data: &str) -> TraceValue {
+        TraceValue {
+            binary: None,
+            data: Some(TraceData::NonBool(data.to_string())),
+            width: None,
+            elements: None,
+            members: None,
+        }
+    }
+
+    fn assignment(lhs: &str, location: SourceLocation, value: TraceValue) -> TraceItem {
+        TraceItem {
+            step_type: "assignment".to_string(),
+            lhs: Some(lhs.to_string()),
+            source_location: Some(location),
+            value: Some(value),
+        }
+    }
+
+    #[test]
+    fn check_format_trace() {
+        let point = TraceValue {
+            binary: None,
+            data: None,
+            width: None,
+            elements: None,
+            members: Some(vec![
+                TraceStructMember { name: "x".to_string(), value: scalar("1") },
+                TraceStructMember { name: "$pad1".to_string(), value: scalar("0") },
+                TraceStructMember { name: "y".to_string(), value: scalar("2") },
+            ]),
+        };
+        let trace = vec![
+            assignment(
+                "goto_symex$$return_value",
+                location("/kani/library/kani/src/lib.rs", "kani::any", "1"),
+                scalar("1"),
+            ),
+            assignment("var_3", location("src/lib.rs", "check", "3"), scalar("7")),
+            assignment("check::1::var_1::p", location("src/lib.rs", "check", "4"), point),
+            assignment(
+                "len",
+                location("/rustc/abc/library/core/src/lib.rs", "len", "5"),
+                scalar("0"),
+            ),
+        ];
+        assert_eq!(
+            format_trace(&trace, &context()),
+            " Trace:\n  1: src/lib.rs:4 in function check\n     p = { x: 1, y: 2 }\n"
+        );
+    }
+
+    #[test]
+    fn check_format_enum_value() {
+        let value = TraceValue {
+            binary: None,
+            data: None,
+            width: None,
+            elements: None,
+            members: Some(vec![TraceStructMember { name: "case".to_string(), value: scalar("5") }]),
+        };
+        assert_eq!(format_value(&value, None, &context()), "variant #5");
+
+        let mode = Irep::just_id(IrepId::StructTag)
+            .with_named_sub(IrepId::Identifier, Irep::just_string_id("tag-Mode"));
+        let mut symbols = SymbolTable::new();
+        symbols.insert(variable_symbol("_RNvCs1_4test5check::1::var_1::mode", mode));
+        let enum_map = BTreeMap::from([(
+            "tag-Mode".to_string(),
+            BTreeMap::from([
+                ("0".to_string(), "Off".to_string()),
+                ("5".to_string(), "On".to_string()),
+            ]),
+        )]);
+        let context = TraceContext::with_symbols(PathBuf::from("/kani/library"), symbols, enum_map);
+        let trace = vec![
+            assignment("check::1::var_1::mode", location("src/lib.rs", "check", "3"), value),
+            assignment(
+                "check::1::var_1::mode.case",
+                location("src/lib.rs", "check", "4"),
+                scalar("0"),
+            ),
+        ];
+        assert_eq!(
+            format_trace(&trace, &context),
+            " Trace:\n  1: src/lib.rs:3 in function check\n     mode = On\n  \
+            2: src/lib.rs:4 in function check\n     mode = Off\n"
+        );
+    }
+
+    #[test]
+    fn check_kani_library_location() {
+        let context = context();
+        assert!(!context.is_user_location(&location("/kani/library/kani/src/lib.rs", "any", "1")));
+        assert!(!context.is_user_location(&location("/kani/library/std/src/lib.rs", "f", "1")));
+        assert!(context.is_user_location(&location(
+            "/home/user/library/kani/src/lib.rs",
+            "f",
+            "1"
+        )));
+    }
+
+    #[test]
+    fn check_variable_type() {
+        let u8_type = Irep::just_id(IrepId::Unsignedbv);
+        let bool_type = Irep::just_id(IrepId::CBool);
+        let mut symbols = SymbolTable::new();
+        symbols.insert(variable_symbol("_RNvCs1_4test5check::1::var_1::x", u8_type.clone()));
+        symbols.insert(variable_symbol("_RNvCs1_4test5other::1::var_1::x", u8_type.clone()));
+        symbols.insert(variable_symbol("_RNvCs1_4test5check::1::var_2::flag", bool_type.clone()));
+        symbols.insert(variable_symbol("_RNvCs1_4test5other::1::var_2::flag", u8_type.clone()));
+        let context = TraceContext::with_symbols(PathBuf::new(), symbols, BTreeMap::new());
+        assert_eq!(context.variable_type("x"), Some(&u8_type));
+        assert_eq!(context.variable_type("check::1::var_2::flag"), Some(&bool_type));
+        assert_eq!(context.variable_type("flag"), None);
+        assert_eq!(context.variable_type("unknown"), None);
+    }
+}
@@ -0,0 +1,461 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Select the harnesses that are affected by a code change, so they are the only ones verified.
+//!
+//! The compiler records the items that are reachable from each harness, together with their
+//! source location and a fingerprint of their MIR. A harness is affected by a change if:
+//! - Compared to a fingerprint file saved by a previous run: any of its reachable items was
+//!   added, removed or had its MIR modified, or its attributes changed.
+//! - Compared to a git revision: any of the lines that changed since that revision overlaps with
+//!   one of its reachable items, or is in a file with one of its reachable items but outside of
+//!   the items recorded for any harness (e.g., a type definition, a constant or an attribute).
+//!
+//! Changes to the manifest, the lock file or to the build script may affect every harness,
+//! so every harness is selected in this case.
+
+use anyhow::{Context, Result, bail};
+use kani_metadata::{HarnessMetadata, ReachableItem};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::session::KaniSession;
+use crate::version::KANI_VERSION;
+
+/// Key used to store the fingerprint of the harness attributes alongside its reachable items.
+const HARNESS_ATTRIBUTES_KEY: &str = "<harness attributes>";
+
+/// Files that may affect the compilation of every item in the workspace.
+const GLOBAL_FILES: &[&str] =
+    &["Cargo.toml", "Cargo.lock", "build.rs", "rust-toolchain", "rust-toolchain.toml"];
+
+/// The fingerprints of the items reachable from each harness, as stored in a fingerprint file.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Fingerprints {
+    kani_version: String,
+    /// Map each harness to the fingerprint of its reachable items, keyed on their mangled name.
+    harnesses: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl Fingerprints {
+    fn from_harnesses(harnesses: &[&HarnessMetadata]) -> Result<Fingerprints> {
+        let harnesses = harnesses
+            .iter()
+            .map(|harness| Ok((harness_key(harness), harness_fingerprints(harness)?)))
+            .collect::<Result<_>>()?;
+        Ok(Fingerprints { kani_version: KANI_VERSION.to_string(), harnesses })
+    }
+
+    fn load(path: &Path) -> Result<Fingerprints> {
+        let file = File::open(path)
+            .with_context(|| format!("Failed to open fingerprint file `{}`", path.display()))?;
+        serde_json::from_reader(BufReader::new(file))
+            .with_context(|| format!("Failed to parse fingerprint file `{}`", path.display()))
+    }
+
+    /// Return why the given harness is affected by the changes, or `None` if it isn't.
+    fn change_reason(&self, harness: &HarnessMetadata) -> Result<Option<String>> {
+        if self.kani_version != KANI_VERSION {
+            return Ok(Some(format!("fingerprints were saved by Kani {}", self.kani_version)));
+        }
+        let Some(previous) = self.harnesses.get(&harness_key(harness)) else {
+            return Ok(Some("new harness".to_string()));
+        };
+        let current = harness_fingerprints(harness)?;
+        if let Some((key, _)) =
+            current.iter().find(|(key, fingerprint)| previous.get(*key) != Some(*fingerprint))
+        {
+            return Ok(Some(if key == HARNESS_ATTRIBUTES_KEY {
+                "harness attributes changed".to_string()
+            } else {
+                format!("`{}` changed", item_name(harness, key))
+            }));
+        }
+        Ok(previous
+            .keys()
+            .find(|key| !current.contains_key(*key))
+            .map(|key| format!("`{key}` is no longer reachable")))
+    }
+}
+
+/// The lines that changed in the working tree since a given git revision.
+#[derive(Debug, Default)]
+struct GitChanges {
+    /// Whether a file that may affect every harness changed.
+    global_change: Option<PathBuf>,
+    /// The ranges of lines that changed in each file. Files that are not tracked by git are
+    /// considered to be changed entirely.
+    changed_lines: HashMap<PathBuf, Vec<RangeInclusive<usize>>>,
+    /// The files with changes outside of the known items, which may affect any item of the file.
+    changed_outside_items: HashSet<PathBuf>,
+}
+
+static DIFF_FILE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\+\+\+ b/(.*)$").unwrap());
+static DIFF_HUNK: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^@@ -\d+(?:,\d+)? \+(\d+)(?:,(\d+))? @@").unwrap());
+
+impl GitChanges {
+    fn since(rev: &str) -> Result<GitChanges> {
+        let root = PathBuf::from(git(&["rev-parse", "--show-toplevel"])?.trim());
+        let diff = git(&[
+            "diff",
+            "--unified=0",
+            "--no-color",
+            "--no-ext-diff",
+            "--src-prefix=a/",
+            "--dst-prefix=b/",
+            rev,
+            "--",
+        ])?;
+        let mut changes = GitChanges::from_diff(&root, &diff);
+        for file in git(&["ls-files", "--others", "--exclude-standard", "--full-name"])?.lines() {
+            changes.add_file(&root, file, vec![0..=usize::MAX]);
+        }
+        Ok(changes)
+    }
+
+    /// Collect the lines of the new version of each file that were modified by the given diff.
+    fn from_diff(root: &Path, diff: &str) -> GitChanges {
+        let mut changes = GitChanges::default();
+        let mut current_file: Option<String> = None;
+        let mut ranges = vec![];
+        for line in diff.lines() {
+            if line.starts_with("+++ ") {
+                let ranges = std::mem::take(&mut ranges);
+                if let Some(file) = current_file.take() {
+                    changes.add_file(root, &file, ranges);
+                }
+                // Deleted files (`+++ /dev/null`) are ignored, since none of their items can be
+                // reachable anymore.
+                current_file = DIFF_FILE.captures(line).map(|captures| captures[1].to_string());
+            } else if let Some(captures) = DIFF_HUNK.captures(line) {
+                let start: usize = captures[1].parse().unwrap();
+                let count: usize =
+                    captures.get(2).map_or(1, |count| count.as_str().parse().unwrap());
+                // A hunk without lines in the new version is a deletion after line `start`,
+                // which may affect the items on either side of it.
+                let end = if count == 0 { start + 1 } else { start + count - 1 };
+                ranges.push(start..=end);
+            }
+        }
+        if let Some(file) = current_file {
+            changes.add_file(root, &file, ranges);
+        }
+        changes
+    }
+
+    fn add_file(&mut self, root: &Path, file: &str, ranges: Vec<RangeInclusive<usize>>) {
+        let path = root.join(file);
+        if self.global_change.is_none()
+            && path
+                .file_name()
+                .is_some_and(|name| GLOBAL_FILES.iter().any(|global| name == *global))
+        {
+            self.global_change = Some(path.clone());
+        }
+        let path = path.canonicalize().unwrap_or(path);
+        self.changed_lines.entry(path).or_default().extend(ranges);
+    }
+
+    /// Record the files with changed lines that are not within one of the given items.
+    ///
+    /// The items only cover the bodies of the functions reachable from some harness, so such a
+    /// change may be to a type definition, a constant, a trait implementation, or to the
+    /// attributes of a harness, and it may affect every item in the file.
+    fn find_changes_outside<'a>(&mut self, items: impl Iterator<Item = &'a ReachableItem>) {
+        let mut items_by_file: HashMap<&Path, Vec<RangeInclusive<usize>>> = HashMap::new();
+        for item in items {
+            items_by_file
+                .entry(Path::new(&item.file))
+                .or_default()
+                .push(item.start_line..=item.end_line);
+        }
+        for (file, ranges) in &self.changed_lines {
+            let items = items_by_file.get(file.as_path()).map_or(&[][..], Vec::as_slice);
+            let outside = ranges.iter().any(|range| {
+                !items
+                    .iter()
+                    .any(|item| *item.start() <= *range.start() && *range.end() <= *item.end())
+            });
+            if outside {
+                self.changed_outside_items.insert(file.clone());
+            }
+        }
+    }
+
+    fn changed(&self, item: &ReachableItem) -> bool {
+        self.changed_lines.get(Path::new(&item.file)).is_some_and(|ranges| {
+            ranges
+                .iter()
+                .any(|range| *range.start() <= item.end_line && item.start_line <= *range.end())
+        })
+    }
+
+    /// Return why the given harness is affected by the changes, or `None` if it isn't.
+    fn change_reason(&self, harness: &HarnessMetadata) -> Option<String> {
+        if let Some(file) = &self.global_change {
+            return Some(format!("`{}` changed", file.display()));
+        }
+        if harness.reachable_items.is_empty() {
+            return Some("no reachable items were recorded".to_string());
+        }
+        if let Some(item) = harness.reachable_items.iter().find(|item| self.changed(item)) {
+            return Some(format!("`{}` changed", item.name));
+        }
+        harness
+            .reachable_items
+            .iter()
+            .find(|item| self.changed_outside_items.contains(Path::new(&item.file)))
+            .map(|item| format!("`{}` changed outside of the function bodies", item.file))
+    }
+}
+
+/// The harnesses selected for verification according to the changes in the code.
+pub struct ChangedHarnesses<'a> {
+    /// The harnesses affected by the changes, which should be verified.
+    pub affected: Vec<&'a HarnessMetadata>,
+    /// The harnesses that are not affected by the changes since a fingerprint file, and so passed
+    /// verification when it was saved.
+    pub verified: Vec<&'a HarnessMetadata>,
+}
+
+/// What the current version of the code should be compared with.
+enum Baseline {
+    Fingerprints(Fingerprints),
+    Git(GitChanges),
+}
+
+impl KaniSession {
+    /// Whether the compiler should record the items reachable from each harness.
+    pub fn needs_reachable_items(&self) -> bool {
+        self.args.changed_since.is_some()
+            || self.args.changed_since_fingerprints.is_some()
+            || self.args.save_fingerprints.is_some()
+    }
+
+    /// Save the fingerprints of the given harnesses, so a later run can use them with
+    /// `--changed-since-fingerprints`.
+    ///
+    /// Only the harnesses that are known to pass verification should be saved, since the
+    /// harnesses that are not affected by later changes are not verified again.
+    pub fn save_fingerprints(&self, harnesses: &[&HarnessMetadata], path: &Path) -> Result<()> {
+        let fingerprints = Fingerprints::from_harnesses(harnesses)?;
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(&mut writer, &fingerprints)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Split the harnesses between the ones that are affected by the changes since the baseline
+    /// given with `--changed-since` or `--changed-since-fingerprints`, and the ones that aren't.
+    /// Every harness is affected if no baseline was given.
+    pub fn select_changed_harnesses<'a>(
+        &self,
+        harnesses: Vec<&'a HarnessMetadata>,
+    ) -> Result<ChangedHarnesses<'a>> {
+        let baseline = if let Some(rev) = &self.args.changed_since {
+            let mut changes = GitChanges::since(rev)?;
+            changes.find_changes_outside(
+                harnesses.iter().flat_map(|harness| harness.reachable_items.iter()),
+            );
+            Baseline::Git(changes)
+        } else if let Some(path) = &self.args.changed_since_fingerprints {
+            Baseline::Fingerprints(Fingerprints::load(path)?)
+        } else {
+            return Ok(ChangedHarnesses { affected: harnesses, verified: vec![] });
+        };
+        let total = harnesses.len();
+        let mut changed = ChangedHarnesses { affected: vec![], verified: vec![] };
+        for harness in harnesses {
+            let reason = match &baseline {
+                Baseline::Fingerprints(fingerprints) => fingerprints.change_reason(harness)?,
+                Baseline::Git(changes) => changes.change_reason(harness),
+            };
+            if let Some(reason) = reason {
+                if self.args.common_args.verbose {
+                    println!("Selected harness {}: {reason}", harness.pretty_name);
+                }
+                changed.affected.push(harness);
+            } else if matches!(baseline, Baseline::Fingerprints(_)) {
+                changed.verified.push(harness);
+            }
+        }
+        if !self.args.common_args.quiet {
+            println!(
+                "Selected {} out of {total} harnesses affected by the changes.",
+                changed.affected.len()
+            );
+        }
+        Ok(changed)
+    }
+}
+
+/// Uniquely identify a harness across the crates of a workspace.
+fn harness_key(harness: &HarnessMetadata) -> String {
+    format!("{}::{}", harness.crate_name, harness.pretty_name)
+}
+
+/// The fingerprints of the items reachable from the harness, and of its attributes.
+fn harness_fingerprints(harness: &HarnessMetadata) -> Result<BTreeMap<String, String>> {
+    let mut fingerprints: BTreeMap<_, _> = harness
+        .reachable_items
+        .iter()
+        .map(|item| (item.mangled_name.clone(), item.fingerprint.clone()))
+        .collect();
+    let attributes = serde_json::to_vec(&harness.attributes)?;
+    let attributes_hash = Sha256::digest(attributes);
+    fingerprints.insert(
+        HARNESS_ATTRIBUTES_KEY.to_string(),
+        attributes_hash.iter().map(|byte| format!("{byte:02x}")).collect(),
+    );
+    Ok(fingerprints)
+}
+
+/// The user-friendly name of a reachable item of the harness.
+fn item_name<'a>(harness: &'a HarnessMetadata, mangled_name: &'a str) -> &'a str {
+    harness
+        .reachable_items
+        .iter()
+        .find(|item| item.mangled_name == mangled_name)
+        .map_or(mangled_name, |item| &item.name)
+}
+
+/// Run git with the given arguments, and return its standard output.
+fn git(args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .context("Failed to invoke git. `--changed-since` requires git to be installed")?;
+    if !output.status.success() {
+        bail!(
+            "`git {}` failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::tests::mock_proof_harness;
+
+    fn mock_item(name: &str, file: &str, lines: RangeInclusive<usize>) -> ReachableItem {
+        ReachableItem {
+            name: name.to_string(),
+            mangled_name: format!("_R{name}"),
+            file: file.to_string(),
+            start_line: *lines.start(),
+            end_line: *lines.end(),
+            fingerprint: format!("{name}-v1"),
+        }
+    }
+
+    #[test]
+    fn check_git_changes_from_diff() {
+        let diff = "\
+diff --git a/src/lib.rs b/src/lib.rs
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -10,2 +10,3 @@ fn foo() {
+@@ -30 +31,0 @@ fn bar() {
+diff --git a/src/old.rs b/src/old.rs
+--- a/src/old.rs
++++ /dev/null
+@@ -1,5 +0,0 @@
++++ b/src/new.rs
+@@ -0,0 +1,2 @@
+";
+        let changes = GitChanges::from_diff(Path::new("/ws"), diff);
+        assert!(changes.global_change.is_none());
+        assert_eq!(changes.changed_lines[Path::new("/ws/src/lib.rs")], vec![10..=12, 31..=32]);
+        assert!(!changes.changed_lines.contains_key(Path::new("/ws/src/old.rs")));
+        assert_eq!(changes.changed_lines[Path::new("/ws/src/new.rs")], vec![1..=2]);
+
+        assert!(changes.changed(&mock_item("foo", "/ws/src/lib.rs", 5..=11)));
+        assert!(changes.changed(&mock_item("bar", "/ws/src/lib.rs", 32..=40)));
+        assert!(!changes.changed(&mock_item("baz", "/ws/src/lib.rs", 13..=30)));
+        assert!(!changes.changed(&mock_item("qux", "/ws/src/other.rs", 10..=12)));
+    }
+
+    #[test]
+    fn check_git_changes_outside_items() {
+        let diff =
+            "+++ b/src/lib.rs\n@@ -3 +3 @@\n@@ -10 +10 @@\n+++ b/src/types.rs\n@@ -2 +2 @@\n";
+        let mut changes = GitChanges::from_diff(Path::new("/ws"), diff);
+        let foo = mock_item("foo", "/ws/src/lib.rs", 8..=12);
+        let bar = mock_item("bar", "/ws/src/types.rs", 5..=7);
+        changes.find_changes_outside([&foo, &bar].into_iter());
+        // Line 3 of `lib.rs` is outside of `foo`, e.g. in the definition of a constant.
+        assert!(changes.changed_outside_items.contains(Path::new("/ws/src/lib.rs")));
+        assert!(changes.changed_outside_items.contains(Path::new("/ws/src/types.rs")));
+
+        let mut harness = mock_proof_harness("check_bar", None, None, None);
+        harness.reachable_items = vec![bar];
+        assert_eq!(
+            changes.change_reason(&harness),
+            Some("`/ws/src/types.rs` changed outside of the function bodies".to_string())
+        );
+
+        let mut inside =
+            GitChanges::from_diff(Path::new("/ws"), "+++ b/src/lib.rs\n@@ -10 +10 @@\n");
+        inside.find_changes_outside([&foo].into_iter());
+        assert!(inside.changed_outside_items.is_empty());
+    }
+
+    #[test]
+    fn check_git_changes_manifest() {
+        let diff = "+++ b/crates/foo/Cargo.toml\n@@ -3 +3 @@\n";
+        let changes = GitChanges::from_diff(Path::new("/ws"), diff);
+        let harness = mock_proof_harness("check_foo", None, None, None);
+        assert_eq!(
+            changes.change_reason(&harness),
+            Some("`/ws/crates/foo/Cargo.toml` changed".to_string())
+        );
+    }
+
+    #[test]
+    fn check_fingerprint_changes() {
+        let mut harness = mock_proof_harness("check_foo", None, None, None);
+        harness.reachable_items = vec![
+            mock_item("check_foo", "/ws/src/lib.rs", 1..=5),
+            mock_item("foo", "/ws/src/lib.rs", 7..=9),
+        ];
+        let fingerprints = Fingerprints::from_harnesses(&[&harness]).unwrap();
+        assert_eq!(fingerprints.change_reason(&harness).unwrap(), None);
+
+        let mut changed = harness.clone();
+        changed.reachable_items[1].fingerprint = "foo-v2".to_string();
+        assert_eq!(
+            fingerprints.change_reason(&changed).unwrap(),
+            Some("`foo` changed".to_string())
+        );
+
+        let mut removed = harness.clone();
+        removed.reachable_items.pop();
+        assert_eq!(
+            fingerprints.change_reason(&removed).unwrap(),
+            Some("`_Rfoo` is no longer reachable".to_string())
+        );
+
+        let mut attributes = harness.clone();
+        attributes.attributes.unwind_value = Some(3);
+        assert_eq!(
+            fingerprints.change_reason(&attributes).unwrap(),
+            Some("harness attributes changed".to_string())
+        );
+
+        let other = mock_proof_harness("check_bar", None, None, None);
+        assert_eq!(fingerprints.change_reason(&other).unwrap(), Some("new harness".to_string()));
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Export the concrete values found by Kani as a fuzzing corpus.
+//!
+//! Each counterexample is saved as a file that contains the bytes of every `kani::any()` value,
+//! concatenated in the order of the calls. This is the format read by `kani::fuzz_playback_run`,
+//! and the layout is the one used by `cargo fuzz`, i.e., one directory per fuzz target.
+
+use crate::concrete_playback::test_generator::FIXTURES_DIR;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The concrete values of a failing check or a satisfied cover, for one harness.
+#[derive(Debug, PartialEq)]
+struct Counterexample {
+    /// The unqualified name of the harness, which names its corpus.
+    harness: String,
+    /// The hash that Kani used to name the test or fixture, which names the corpus entry.
+    name: String,
+    /// The byte representation of each value.
+    values: Vec<Vec<u8>>,
+}
+
+/// The part of a fixture generated by `--concrete-playback=fixture` that we need.
+#[derive(Deserialize)]
+struct Fixture {
+    harness: String,
+    values: Vec<Vec<u8>>,
+}
+
+/// Export the counterexamples of the playback tests in `sources` and of the fixtures in
+/// `fixtures` to `out_dir`.
+pub fn export_corpus(
+    sources: &[PathBuf],
+    fixtures: &[PathBuf],
+    out_dir: &Path,
+    quiet: bool,
+) -> Result<()> {
+    let mut counterexamples = vec![];
+    for source in sources {
+        let content = fs::read_to_string(source)
+            .with_context(|| format!("Failed to read `{}`", source.display()))?;
+        counterexamples.extend(read_unit_tests(&content));
+    }
+    for fixture in fixtures {
+        counterexamples.push(read_fixture(fixture)?);
+    }
+    if counterexamples.is_empty() {
+        println!("WARNING: Kani could not find any concrete playback test or fixture to export.");
+        return Ok(());
+    }
+
+    for counterexample in &counterexamples {
+        let dir = out_dir.join(&counterexample.harness);
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create `{}`", dir.display()))?;
+        let path = dir.join(&counterexample.name);
+        fs::write(&path, counterexample.values.concat())
+            .with_context(|| format!("Failed to write `{}`", path.display()))?;
+    }
+    if !quiet {
+        println!(
+            "INFO: Exported {} input(s) to the corpus in `{}`.",
+            counterexamples.len(),
+            out_dir.display()
+        );
+    }
+    Ok(())
+}
+
+/// Whether `path` is a fixture generated by `--concrete-playback=fixture`.
+pub fn is_fixture(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "json")
+        && path.components().any(|component| component.as_os_str() == FIXTURES_DIR)
+}
+
+fn read_fixture(path: &Path) -> Result<Counterexample> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read `{}`", path.display()))?;
+    let fixture: Fixture = serde_json::from_str(&content)
+        .with_context(|| format!("Invalid concrete playback fixture `{}`", path.display()))?;
+    Ok(Counterexample {
+        harness: fixture.harness.rsplit("::").next().unwrap().to_string(),
+        name: path.file_stem().unwrap().to_string_lossy().to_string(),
+        values: fixture.values,
+    })
+}
+
+/// Extract the counterexamples of the concrete playback unit tests in a source file.
+///
+/// The tests may have been formatted by rustfmt, so we don't rely on their exact layout.
+fn read_unit_tests(content: &str) -> Vec<Counterexample> {
+    let mut counterexamples = vec![];
+    let mut lines = content.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        let Some(test_name) =
+            line.strip_prefix("fn kani_concrete_playback_").and_then(|l| l.strip_suffix("() {"))
+        else {
+            continue;
+        };
+        // Join the body of the test, without the comments with the interpreted values.
+        let body = lines
+            .by_ref()
+            .take_while(|line| *line != "}")
+            .filter(|line| !line.starts_with("//"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        if let Some(counterexample) = parse_unit_test(test_name, &body) {
+            counterexamples.push(counterexample);
+        }
+    }
+    counterexamples
+}
+
+/// Parse the body of the unit test `kani_concrete_playback_{test_name}`.
+/// Tests that don't call `kani::concrete_playback_run` are ignored.
+fn parse_unit_test(test_name: &str, body: &str) -> Option<Counterexample> {
+    let (_, run) = body.split_once("kani::concrete_playback_run(concrete_vals,")?;
+    let (harness, _) = run.split_once(')')?;
+    let (_, vals) = body.split_once("vec![")?;
+    let (vals, _) = vals.split_once("];")?;
+    let values = vals
+        .split("vec![")
+        .skip(1)
+        .map(|val| {
+            let (bytes, _) = val.split_once(']')?;
+            bytes
+                .split(',')
+                .map(str::trim)
+                .filter(|byte| !byte.is_empty())
+                .map(|byte| byte.parse().ok())
+                .collect::<Option<Vec<u8>>>()
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let (_, name) = test_name.rsplit_once('_')?;
+    Some(Counterexample { harness: harness.trim().to_string(), name: name.to_string(), values })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_read_unit_tests() {
+        let content = r#"
+#[kani::proof]
+fn check_two() {}
+
+/// Test generated for harness `check_two`
+#[test]
+fn kani_concrete_playback_check_two_1234() {
+    let concrete_vals: Vec<Vec<u8>> = vec![
+        // 1
+        vec![1, 0],
+        // []
+        vec![],
+        // 65535
+        vec![
+            255, 255,
+        ],
+    ];
+    kani::concrete_playback_run(concrete_vals, check_two);
+}
+
+#[test]
+fn kani_concrete_playback_fixtures_check_two() {
+    kani::concrete_playback_run_fixtures("kani-counterexamples/check_two", check_two);
+}
+"#;
+        assert_eq!(
+            read_unit_tests(content),
+            vec![Counterexample {
+                harness: "check_two".to_string(),
+                name: "1234".to_string(),
+                values: vec![vec![1, 0], vec![], vec![255, 255]],
+            }]
+        );
+    }
+}
@@ -2,5 +2,6 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 //! Implements the logic related to concrete playback
 
+pub mod corpus;
 pub mod playback;
 pub mod test_generator;
@@ -4,23 +4,51 @@
 //! This can be achieved with <kani|cargo kani> playback --test <test_name>
 
 use crate::args::common::Verbosity;
-use crate::args::playback_args::{CargoPlaybackArgs, KaniPlaybackArgs, MessageFormat};
+use crate::args::playback_args::{
+    CargoPlaybackArgs, Debugger, KaniPlaybackArgs, MessageFormat, PlaybackArgs,
+};
 use crate::call_cargo::cargo_config_args;
 use crate::call_single_file::{LibConfig, base_rustc_flags};
+use crate::concrete_playback::corpus::{export_corpus, is_fixture};
+use crate::concrete_playback::test_generator::{
+    ANY_VALUE_DOC, CHECK_LOCATION_DOC, FIXTURES_DIR, fixtures_root,
+};
 use crate::session::{InstallType, lib_playback_folder, setup_cargo_command};
 use crate::util::args::{CargoArg, CommandWrapper, PassTo, RustcArg};
 use crate::{session, util};
-use anyhow::Result;
+use anyhow::{Context, Result, bail};
+use cargo_metadata::Message;
+use std::env::current_dir;
+use std::fs;
+use std::io::BufReader;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use tracing::debug;
 
 pub fn playback_cargo(args: CargoPlaybackArgs) -> Result<()> {
+    if let Some(out_dir) = &args.playback.export_corpus {
+        let mut sources = vec![];
+        find_files(&current_dir()?, &mut sources)?;
+        let fixtures = sources.iter().filter(|f| is_fixture(f)).cloned().collect::<Vec<_>>();
+        sources.retain(|f| f.extension().is_some_and(|ext| ext == "rs"));
+        return export_corpus(&sources, &fixtures, out_dir, args.playback.common_opts.quiet);
+    }
     cargo_test(args)
 }
 
 pub fn playback_standalone(args: KaniPlaybackArgs) -> Result<()> {
+    if let Some(out_dir) = &args.playback.export_corpus {
+        let mut fixtures = vec![];
+        let fixtures_dir = fixtures_root(&args.input).join(FIXTURES_DIR);
+        if fixtures_dir.is_dir() {
+            find_files(&fixtures_dir, &mut fixtures)?;
+        }
+        fixtures.retain(|f| is_fixture(f));
+        let sources = std::slice::from_ref(&args.input);
+        return export_corpus(sources, &fixtures, out_dir, args.playback.common_opts.quiet);
+    }
+
     let install = InstallType::new()?;
     let artifact = build_test(&install, &args)?;
     debug!(?artifact, "playback_standalone");
@@ -29,7 +57,9 @@ pub fn playback_standalone(args: KaniPlaybackArgs) -> Result<()> {
         print_artifact(&artifact, args.playback.message_format)
     }
 
-    if !args.playback.only_codegen {
+    if let Some(debugger) = args.playback.debugger {
+        debug_test(&artifact, std::slice::from_ref(&args.input), &args.playback, debugger)?;
+    } else if !args.playback.only_codegen {
         run_test(&artifact, &args)?;
     }
 
@@ -59,6 +89,12 @@ fn run_test(exe: &Path, args: &KaniPlaybackArgs) -> Result<()> {
 
     cmd.args(&args.playback.test_args);
 
+    // Fixture tests read their fixtures relative to the package root, like under `cargo test`.
+    let root = fixtures_root(&args.input);
+    if root.join(FIXTURES_DIR).is_dir() {
+        cmd.current_dir(root);
+    }
+
     session::run_terminal(&args.playback.common_opts, cmd)?;
     Ok(())
 }
@@ -70,7 +106,7 @@ fn build_test(install: &InstallType, args: &KaniPlaybackArgs) -> Result<PathBuf>
         util::info_operation("Building", args.input.to_string_lossy().deref());
     }
 
-    let mut rustc_args = base_rustc_flags(LibConfig::new(lib_playback_folder()?));
+    let mut rustc_args = playback_rustc_flags()?;
     rustc_args.push("--test".into());
     rustc_args.push(RustcArg::from(&args.input));
     rustc_args.push(format!("--crate-name={TEST_BIN_NAME}").into());
@@ -83,6 +119,10 @@ fn build_test(install: &InstallType, args: &KaniPlaybackArgs) -> Result<PathBuf>
         rustc_args.push("--error-format=json".into());
     }
 
+    if args.playback.debugger.is_some() {
+        rustc_args.push("-Cdebuginfo=2".into());
+    }
+
     let mut cmd = Command::new(install.kani_compiler()?);
     cmd.pass_rustc_args(&rustc_args, PassTo::OnlyLocalCrate);
 
@@ -91,12 +131,21 @@ fn build_test(install: &InstallType, args: &KaniPlaybackArgs) -> Result<PathBuf>
     Ok(PathBuf::from(TEST_BIN_NAME).canonicalize()?)
 }
 
+/// The flags used to compile playback tests with the Kani compiler.
+fn playback_rustc_flags() -> Result<Vec<RustcArg>> {
+    let mut flags = base_rustc_flags(LibConfig::new(lib_playback_folder()?));
+    // This argument makes the compiler apply the stubs and contracts of the harnesses to their
+    // playback tests. It will be removed before rustc driver is invoked.
+    flags.push("--kani-playback".into());
+    Ok(flags)
+}
+
 /// Invokes cargo test using Kani compiler and the provided arguments.
 fn cargo_test(args: CargoPlaybackArgs) -> Result<()> {
     let install = InstallType::new()?;
     let mut cmd = setup_cargo_command()?;
 
-    let rustc_args = base_rustc_flags(LibConfig::new(lib_playback_folder()?));
+    let rustc_args = playback_rustc_flags()?;
     let mut cargo_args: Vec<CargoArg> = vec!["test".into()];
 
     if args.playback.common_opts.verbose() {
@@ -105,11 +154,12 @@ fn cargo_test(args: CargoPlaybackArgs) -> Result<()> {
         cargo_args.push("--quiet".into())
     }
 
-    if args.playback.message_format == MessageFormat::Json {
+    // We need the path of the test executables to run them under a debugger.
+    if args.playback.message_format == MessageFormat::Json || args.playback.debugger.is_some() {
         cargo_args.push("--message-format=json".into());
     }
 
-    if args.playback.only_codegen {
+    if args.playback.only_codegen || args.playback.debugger.is_some() {
         cargo_args.push("--no-run".into());
     }
 
@@ -117,7 +167,7 @@ fn cargo_test(args: CargoPlaybackArgs) -> Result<()> {
     cargo_args.append(&mut cargo_config_args());
 
     // These have to be the last arguments to cargo test.
-    if !args.playback.test_args.is_empty() {
+    if !args.playback.test_args.is_empty() && args.playback.debugger.is_none() {
         cargo_args.push("--".into());
         cargo_args.extend(args.playback.test_args.iter().map(|arg| arg.into()));
     }
@@ -128,6 +178,181 @@ fn cargo_test(args: CargoPlaybackArgs) -> Result<()> {
         .pass_rustc_args(&rustc_args, PassTo::AllCrates)
         .env("CARGO_TERM_PROGRESS_WHEN", "never");
 
+    if let Some(debugger) = args.playback.debugger {
+        cmd.env("CARGO_PROFILE_TEST_DEBUG", "true");
+        let executables = test_executables(&args.playback, cmd)?;
+        let test = &args.playback.test_args[0];
+        let Some(executable) = executables.iter().find(|exe| find_test(exe, test).is_ok()) else {
+            bail!("Failed to find the test `{test}` in the test executables of this package.");
+        };
+        let mut sources = vec![];
+        find_files(&current_dir()?, &mut sources)?;
+        sources.retain(|f| f.extension().is_some_and(|ext| ext == "rs"));
+        return debug_test(executable, &sources, &args.playback, debugger);
+    }
+
     session::run_terminal(&args.playback.common_opts, cmd)?;
     Ok(())
 }
+
+/// Run `cargo test --no-run` and return the test executables it built.
+fn test_executables(args: &PlaybackArgs, cmd: Command) -> Result<Vec<PathBuf>> {
+    let mut cargo_process = session::run_piped(&args.common_opts, cmd)?;
+    let reader = BufReader::new(cargo_process.stdout.take().unwrap());
+    let mut executables = vec![];
+    for message in Message::parse_stream(reader) {
+        match message? {
+            Message::CompilerMessage(msg) => {
+                if let Some(rendered) = &msg.message.rendered {
+                    print!("{rendered}");
+                }
+            }
+            Message::CompilerArtifact(artifact) if artifact.profile.test => {
+                executables.extend(artifact.executable.map(|exe| exe.into_std_path_buf()));
+            }
+            _ => {}
+        }
+    }
+    let status = cargo_process.wait()?;
+    if !status.success() {
+        bail!("Failed to build the playback tests ({status}).");
+    }
+    Ok(executables)
+}
+
+/// Find the full name of the test `name` in the test executable `exe`.
+fn find_test(exe: &Path, name: &str) -> Result<String> {
+    let output = Command::new(exe)
+        .args(["--list", "--format=terse"])
+        .output()
+        .context(format!("Failed to invoke {}", exe.display()))?;
+    let tests = String::from_utf8_lossy(&output.stdout);
+    tests
+        .lines()
+        .filter_map(|line| line.strip_suffix(": test"))
+        .find(|test| *test == name || test.ends_with(&format!("::{name}")))
+        .map(str::to_string)
+        .context(format!("Failed to find the test `{name}` in {}", exe.display()))
+}
+
+/// Run the playback test `args.test_args[0]` of the executable `exe` under `debugger`.
+/// The remaining test arguments are passed to the test executable.
+///
+/// The doc comment of the test in `sources` tells which check failed, and which value each
+/// `kani::any()` call returns.
+fn debug_test(
+    exe: &Path,
+    sources: &[PathBuf],
+    args: &PlaybackArgs,
+    debugger: Debugger,
+) -> Result<()> {
+    let test = find_test(exe, &args.test_args[0])?;
+    let fn_name = test.rsplit("::").next().unwrap();
+    let doc = sources.iter().find_map(|source| read_test_doc(source, fn_name));
+    let location = doc.as_ref().and_then(|doc| doc.location.clone());
+    if !args.common_opts.quiet {
+        match &doc {
+            Some(doc) if !doc.values.is_empty() => {
+                println!("Values of `kani::any()` in `{test}`:");
+                for value in &doc.values {
+                    println!("  {value}");
+                }
+            }
+            _ => println!("WARNING: Could not find the values of `kani::any()` in `{test}`."),
+        }
+    }
+
+    let mut test_args = vec![test.clone(), "--exact".into(), "--nocapture".into()];
+    // Keep the test on the main thread of the executable.
+    test_args.push("--test-threads=1".into());
+    // The test harness rejects options that are given twice.
+    test_args.extend(
+        args.test_args[1..]
+            .iter()
+            .filter(|arg| !test_args.contains(arg) && !arg.starts_with("--test-threads"))
+            .cloned()
+            .collect::<Vec<_>>(),
+    );
+    let cmd = match debugger {
+        Debugger::Gdb => {
+            let mut cmd = Command::new("gdb");
+            cmd.arg("-q").args(gdb_breakpoint(&location)).args(["-ex", "run", "--args"]);
+            cmd.arg(exe).args(&test_args);
+            cmd
+        }
+        Debugger::Lldb => {
+            let breakpoint = match &location {
+                Some(location) => {
+                    let (file, line) = location.rsplit_once(':').unwrap();
+                    format!("breakpoint set --file {file} --line {line}")
+                }
+                None => "breakpoint set --name rust_panic".to_string(),
+            };
+            let mut cmd = Command::new("lldb");
+            cmd.args(["-o", &breakpoint, "-o", "run", "--"]).arg(exe).args(&test_args);
+            cmd
+        }
+        Debugger::Rr => {
+            // The test is expected to fail, so we don't check the status of the recording.
+            let mut record = Command::new("rr");
+            record.arg("record").arg(exe).args(&test_args);
+            record.status().context("Failed to invoke rr")?;
+            let mut cmd = Command::new("rr");
+            cmd.args(["replay", "--"]).args(gdb_breakpoint(&location)).args(["-ex", "continue"]);
+            cmd
+        }
+    };
+    session::run_terminal(&args.common_opts, cmd)
+}
+
+/// The `gdb` arguments that set a breakpoint at `location`, or at the panic handler if the
+/// location of the failing check is unknown.
+fn gdb_breakpoint(location: &Option<String>) -> [String; 2] {
+    let location = location.as_deref().unwrap_or("rust_panic");
+    ["-ex".to_string(), format!("break {location}")]
+}
+
+/// The information that Kani recorded in the doc comment of a playback test.
+struct TestDoc {
+    /// The location of the check that the test reproduces, as `file:line`.
+    location: Option<String>,
+    /// The call site and the value of each `kani::any()` call.
+    values: Vec<String>,
+}
+
+/// Read the doc comment of the function `fn_name` in the file `source`, if it is defined there.
+fn read_test_doc(source: &Path, fn_name: &str) -> Option<TestDoc> {
+    let content = fs::read_to_string(source).ok()?;
+    let lines = content.lines().map(str::trim).collect::<Vec<_>>();
+    let fn_line = lines.iter().position(|line| *line == format!("fn {fn_name}() {{"))?;
+    let doc = lines[..fn_line]
+        .iter()
+        .rev()
+        .skip_while(|line| line.starts_with("#["))
+        .take_while(|line| line.starts_with("///"))
+        .collect::<Vec<_>>();
+    let location = doc.iter().find_map(|line| {
+        line.strip_prefix(CHECK_LOCATION_DOC).map(|location| location.trim_matches('`').to_string())
+    });
+    let values = doc
+        .iter()
+        .rev()
+        .filter_map(|line| line.strip_prefix(ANY_VALUE_DOC).map(str::to_string))
+        .collect();
+    Some(TestDoc { location, values })
+}
+
+/// Collect the Rust files and the JSON files in `dir` and its subdirectories, skipping build
+/// outputs.
+fn find_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let hidden = path.file_name().is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        if path.is_dir() && !hidden && !path.ends_with("target") {
+            find_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs" || ext == "json") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
@@ -6,17 +6,20 @@
 
 use crate::args::ConcretePlaybackMode;
 use crate::call_cbmc::VerificationResult;
-use crate::cbmc_output_parser::Property;
+use crate::cbmc_output_parser::{Property, SourceLocation};
 use crate::session::KaniSession;
 use anyhow::{Context, Result};
-use concrete_vals_extractor::{ConcreteItem, PrimitiveConcreteVal, extract_harness_values};
+use concrete_vals_extractor::{
+    ConcreteItem, PrimitiveConcreteVal, extract_call_sites, extract_harness_values,
+};
 use kani_metadata::{HarnessKind, HarnessMetadata};
+use serde::Serialize;
 use std::collections::hash_map::DefaultHasher;
 use std::ffi::OsString;
 use std::fs::{File, read_to_string};
 use std::hash::{Hash, Hasher};
 use std::io::{BufRead, BufReader, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use tempfile::NamedTempFile;
 
@@ -46,7 +49,8 @@ pub fn gen_and_add_concrete_playback(
                     .iter()
                     .map(|(prop, concrete_items)| {
                         let pretty_name = harness.get_harness_name_unqualified();
-                        format_unit_test(pretty_name, concrete_items, gen_test_doc(harness, prop))
+                        let doc = gen_test_doc(harness, prop, concrete_items);
+                        format_unit_test(pretty_name, concrete_items, doc)
                     })
                     .collect();
                 unit_tests.dedup_by(|a, b| a.name == b.name);
@@ -67,6 +71,14 @@ pub fn gen_and_add_concrete_playback(
                             );
                         }
                     }
+                    ConcretePlaybackMode::Fixture => {
+                        self.add_fixtures(harness, &harness_values).unwrap_or_else(|err| {
+                            panic!(
+                                "Failed to add concrete playback fixtures for `{}`: {err}",
+                                &harness.pretty_name
+                            )
+                        });
+                    }
                     ConcretePlaybackMode::InPlace => {
                         if !self.args.common_args.quiet && !unit_tests.is_empty() {
                             println!(
@@ -100,6 +112,44 @@ pub fn gen_and_add_concrete_playback(
         Ok(())
     }
 
+    /// Save each counterexample as a fixture file, and add a test that plays back every fixture
+    /// of the harness to the user's source code, unless it is already present.
+    fn add_fixtures(
+        &self,
+        harness: &HarnessMetadata,
+        harness_values: &[(&Property, Vec<ConcreteItem>)],
+    ) -> Result<()> {
+        let dir_name = fixtures_dir_name(harness);
+        let fixtures_dir =
+            fixtures_root(Path::new(&harness.original_file)).join(FIXTURES_DIR).join(&dir_name);
+        std::fs::create_dir_all(&fixtures_dir)
+            .with_context(|| format!("Failed to create `{}`", fixtures_dir.display()))?;
+        let harness_name = harness.get_harness_name_unqualified();
+        let mut paths = vec![];
+        for (property, concrete_items) in harness_values {
+            let hash = concrete_items_hash(harness_name, concrete_items);
+            let path = fixtures_dir.join(format!("{hash}.json"));
+            let fixture = Fixture {
+                harness: &harness.pretty_name,
+                check: property.property_class(),
+                description: &property.description,
+                location: file_line(&property.source_location),
+                values: concrete_items.iter().flat_map(ConcreteItem::byte_arrays).collect(),
+            };
+            std::fs::write(&path, serde_json::to_string_pretty(&fixture)?)
+                .with_context(|| format!("Failed to write `{}`", path.display()))?;
+            paths.push(path);
+        }
+        if !self.args.common_args.quiet {
+            println!(
+                "INFO: Now writing the concrete playback fixture(s):{}",
+                paths.iter().map(|path| format!("\n  - {}", path.display())).collect::<String>()
+            );
+        }
+        let unit_test = format_fixture_test(harness, &dir_name);
+        self.modify_src_code(&harness.original_file, harness.original_end_line, vec![unit_test])
+    }
+
     /// Add the unit test to the user's source code, format it, and short circuit if code already present.
     fn modify_src_code(
         &self,
@@ -237,7 +287,17 @@ fn run_rustfmt(
     }
 }
 
-fn gen_test_doc(harness: &HarnessMetadata, property: &Property) -> String {
+/// The prefix of the doc line that records the location of the check reproduced by the test.
+pub const CHECK_LOCATION_DOC: &str = "/// Check location: ";
+
+/// The prefix of the doc lines that record the value of each `kani::any()` call.
+pub const ANY_VALUE_DOC: &str = "/// - ";
+
+fn gen_test_doc(
+    harness: &HarnessMetadata,
+    property: &Property,
+    concrete_items: &[ConcreteItem],
+) -> String {
     let mut doc_str = match &harness.attributes.kind {
         HarnessKind::Proof => {
             format!("/// Test generated for harness `{}` \n", harness.pretty_name)
@@ -258,7 +318,28 @@ fn gen_test_doc(harness: &HarnessMetadata, property: &Property) -> String {
         property.property_class(),
         property.description
     ));
-    if !harness.attributes.stubs.is_empty() {
+    if let Some(location) = file_line(&property.source_location) {
+        doc_str.push_str(&format!("{CHECK_LOCATION_DOC}`{location}`\n"));
+    }
+    if !concrete_items.is_empty() {
+        // Record where each value comes from, so `kani playback --debugger` can show it.
+        doc_str.push_str("///\n/// Values of `kani::any()`:\n");
+        for (item, call_site) in concrete_items.iter().zip(extract_call_sites(property)) {
+            let value = match item {
+                ConcreteItem::Primitive(val) => val.interp_val.clone(),
+                ConcreteItem::Array(vals) => format!(
+                    "[{}]",
+                    vals.iter().map(|val| val.interp_val.as_str()).collect::<Vec<_>>().join(", ")
+                ),
+            };
+            let call_site = call_site.unwrap_or_else(|| "unknown location".to_string());
+            doc_str.push_str(&format!("{ANY_VALUE_DOC}`{call_site}`: {value}\n"));
+        }
+    }
+    if !harness.attributes.stubs.is_empty()
+        || !harness.attributes.verified_stubs.is_empty()
+        || matches!(harness.attributes.kind, HarnessKind::ProofForContract { .. })
+    {
         doc_str.push_str(
             r#"///
 /// # Warning
@@ -266,28 +347,30 @@ fn gen_test_doc(harness: &HarnessMetadata, property: &Property) -> String {
 /// Concrete playback tests combined with stubs or contracts is highly
 /// experimental, and subject to change.
 ///
-/// The original harness has stubs which are not applied to this test.
-/// This may cause a mismatch of non-deterministic values if the stub
-/// creates any non-deterministic value.
-/// The execution path may also differ, which can be used to refine the stub
-/// logic.
+/// The stubs and contracts of the original harness are only applied to this
+/// test when it is run with `kani playback`. They are applied to every test
+/// in the same crate.
 "#,
         );
     }
     doc_str
 }
 
+/// Hash the concrete values along with the proof harness name.
+fn concrete_items_hash(harness_name: &str, concrete_items: &[ConcreteItem]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    harness_name.hash(&mut hasher);
+    concrete_items.hash(&mut hasher);
+    hasher.finish()
+}
+
 /// Generate a formatted unit test from a list of concrete values.
 fn format_unit_test(
     harness_name: &str,
     concrete_items: &[ConcreteItem],
     doc_str: String,
 ) -> UnitTest {
-    // Hash the concrete values along with the proof harness name.
-    let mut hasher = DefaultHasher::new();
-    harness_name.hash(&mut hasher);
-    concrete_items.hash(&mut hasher);
-    let hash = hasher.finish();
+    let hash = concrete_items_hash(harness_name, concrete_items);
     let func_name = format!("kani_concrete_playback_{harness_name}_{hash}");
 
     let func_before_concrete_vals = [
@@ -313,6 +396,59 @@ fn format_unit_test(
     UnitTest { code: full_func, name: func_name }
 }
 
+/// Generate the unit test that plays back every fixture saved for `harness` in `dir_name`.
+fn format_fixture_test(harness: &HarnessMetadata, dir_name: &str) -> UnitTest {
+    let harness_name = harness.get_harness_name_unqualified();
+    let func_name = format!("kani_concrete_playback_fixtures_{harness_name}");
+    let code = vec![
+        format!("/// Test generated for harness `{}`", harness.pretty_name),
+        "///".to_string(),
+        format!("/// Play back every counterexample saved in `{FIXTURES_DIR}/{dir_name}`."),
+        "#[test]".to_string(),
+        format!("fn {func_name}() {{"),
+        format!(
+            "{:<4}kani::concrete_playback_run_fixtures(\"{FIXTURES_DIR}/{dir_name}\", {harness_name});",
+            " "
+        ),
+        "}".to_string(),
+    ];
+    UnitTest { code, name: func_name }
+}
+
+/// The directory where `--concrete-playback=fixture` saves counterexamples.
+pub const FIXTURES_DIR: &str = "kani-counterexamples";
+
+/// The directory that contains the fixtures of the source file `src_path`.
+///
+/// This is the root of the package, which is the working directory of tests run by `cargo test`.
+/// For files outside a package, this is the directory of the file.
+pub fn fixtures_root(src_path: &Path) -> PathBuf {
+    let src_dir =
+        src_path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    src_dir
+        .ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file())
+        .unwrap_or(src_dir)
+        .to_path_buf()
+}
+
+/// The fixtures of each harness are saved in a directory named after its full path.
+fn fixtures_dir_name(harness: &HarnessMetadata) -> String {
+    harness.pretty_name.replace("::", "_")
+}
+
+/// A counterexample saved by `--concrete-playback=fixture`.
+/// It is played back by `kani::concrete_playback_run_fixtures`, which only reads `values`.
+#[derive(Serialize)]
+struct Fixture<'a> {
+    harness: &'a str,
+    check: String,
+    description: &'a str,
+    location: Option<String>,
+    /// The byte representation of each value, flattened like in the unit tests.
+    values: Vec<Vec<u8>>,
+}
+
 /// Format concrete items as strings--these make up the body of the concrete test.
 fn format_concrete_items(concrete_items: &[ConcreteItem]) -> impl Iterator<Item = String> + '_ {
     // Note that ConcreteItem::Arrays are flattened, e.g., given: concrete_items = [ConcreteItem::Array(val1, val2), ConcreteItem::Primitive(val3)],
@@ -348,6 +484,11 @@ fn format_concrete_vals(
     })
 }
 
+/// Format a source location as `file:line`, which debuggers accept as a breakpoint location.
+fn file_line(location: &SourceLocation) -> Option<String> {
+    Some(format!("{}:{}", location.file.as_ref()?, location.line.as_ref()?))
+}
+
 /// Suppose `src_path` was `/path/to/file.txt`. This function extracts this into `/path/to` and `file.txt`.
 fn extract_parent_dir_and_src_file(src_path: &Path) -> Result<(String, String)> {
     let parent_dir_as_path = src_path.parent().unwrap();
@@ -390,6 +531,16 @@ pub enum ConcreteItem {
         Array(Vec<PrimitiveConcreteVal>),
     }
 
+    impl ConcreteItem {
+        /// The byte arrays of this item, which are flattened for arrays.
+        pub fn byte_arrays(&self) -> Vec<Vec<u8>> {
+            match self {
+                ConcreteItem::Primitive(val) => vec![val.byte_arr.clone()],
+                ConcreteItem::Array(vals) => vals.iter().map(|val| val.byte_arr.clone()).collect(),
+            }
+        }
+    }
+
     /// Represents the concrete value of a primitive type--its byte representation and the intepreted value.
     /// E.g., a u16 with decimal value 65280 would be PrimitiveConcreteVal { byte_arr: vec![0, 255], interp_val: "65280" }.
     #[derive(Hash)]
@@ -424,6 +575,36 @@ pub fn extract_harness_values(
             .collect()
     }
 
+    /// Extract the source location of the `kani::any()` call that produced each of the values
+    /// returned by [extract_harness_values] for `property`.
+    ///
+    /// The call site of a value is the last function call made from the user code before the
+    /// value is assigned, since the calls made inside Kani's `Arbitrary` implementations are
+    /// skipped.
+    pub fn extract_call_sites(property: &Property) -> Vec<Option<String>> {
+        let mut call_site = None;
+        let mut call_sites = vec![];
+        for trace_item in property.trace.iter().flatten() {
+            if trace_item.step_type == "function-call"
+                && let Some(location) = &trace_item.source_location
+                && location.function.as_ref().is_some_and(|func| !is_library_fn(func))
+            {
+                call_site = super::file_line(location);
+            }
+            if extract_from_trace_item(trace_item).is_some() {
+                call_sites.push(call_site.clone());
+            }
+        }
+        call_sites
+    }
+
+    /// Whether `func` belongs to Kani or to the standard library, rather than to the user code.
+    fn is_library_fn(func: &str) -> bool {
+        let func = func.trim_start_matches('<');
+        ["kani::", "core::", "std::", "alloc::"].iter().any(|krate| func.starts_with(krate))
+            || func.contains(" as kani::")
+    }
+
     /// Extracts individual bytes from a TraceValue for a primitive type
     /// to produce a PrimitiveConcreteVal representing that value.
     fn extract_primitive_value(value: &TraceValue) -> Option<PrimitiveConcreteVal> {
@@ -760,6 +941,7 @@ fn check_concrete_vals_extractor_primitive() {
                     data: Some(TraceData::NonBool("385".to_string())),
                     width: Some(16),
                     elements: None,
+                    members: None,
                 }),
             }]),
         }];
@@ -773,6 +955,60 @@ fn check_concrete_vals_extractor_primitive() {
         }
     }
 
+    /// The call site of a value is the last call made from the user code before it is assigned.
+    #[test]
+    fn check_extract_call_sites() {
+        let location = |function: &str, line: &str| {
+            Some(SourceLocation {
+                column: None,
+                file: Some("main.rs".to_string()),
+                function: Some(function.to_string()),
+                line: Some(line.to_string()),
+            })
+        };
+        let call = |function: &str, line: &str| TraceItem {
+            step_type: "function-call".to_string(),
+            lhs: None,
+            source_location: location(function, line),
+            value: None,
+        };
+        let value = TraceItem {
+            step_type: "assignment".to_string(),
+            lhs: Some("goto_symex$$return_value".to_string()),
+            source_location: location("kani::any_raw_internal::<u8>", "1"),
+            value: Some(TraceValue {
+                binary: Some("00000001".to_string()),
+                data: Some(TraceData::NonBool("1".to_string())),
+                width: Some(8),
+                elements: None,
+                members: None,
+            }),
+        };
+        let property = Property {
+            description: "".to_string(),
+            property_id: PropertyId {
+                fn_name: Some("".to_string()),
+                class: "assertion".to_string(),
+                id: 1,
+            },
+            status: CheckStatus::Failure,
+            reach: None,
+            source_location: location("harness", "12").unwrap(),
+            trace: Some(vec![
+                call("harness", "10"),
+                call("kani::any::<u8>", "100"),
+                call("<u8 as kani::Arbitrary>::any", "200"),
+                value.clone(),
+                call("harness", "11"),
+                value,
+            ]),
+        };
+        assert_eq!(
+            extract_call_sites(&property),
+            [Some("main.rs:10".to_string()), Some("main.rs:11".to_string())]
+        );
+    }
+
     /// Test util functions which extract the counter example values from a property for arrays.
     #[test]
     fn check_concrete_vals_extractor_array() {
@@ -812,6 +1048,7 @@ fn check_concrete_vals_extractor_array() {
                                     data: Some(TraceData::NonBool("4294967295".to_string())),
                                     width: Some(32),
                                     elements: None,
+                                    members: None,
                                 },
                             },
                             TraceArrayValue {
@@ -820,6 +1057,7 @@ fn check_concrete_vals_extractor_array() {
                                     data: Some(TraceData::NonBool("2147483648".to_string())),
                                     width: Some(32),
                                     elements: None,
+                                    members: None,
                                 },
                             },
                             TraceArrayValue {
@@ -828,6 +1066,7 @@ fn check_concrete_vals_extractor_array() {
                                     data: Some(TraceData::NonBool("4294967295".to_string())),
                                     width: Some(32),
                                     elements: None,
+                                    members: None,
                                 },
                             },
                             TraceArrayValue {
@@ -836,9 +1075,11 @@ fn check_concrete_vals_extractor_array() {
                                     data: Some(TraceData::NonBool("7".to_string())),
                                     width: Some(32),
                                     elements: None,
+                                    members: None,
                                 },
                             },
                         ]),
+                        members: None,
                     }),
                 },
                 // Since the array is of size 4, there are also TraceItems for each element of the array, which extract_harness_value should ignore.
@@ -856,6 +1097,7 @@ fn check_concrete_vals_extractor_array() {
                         data: Some(TraceData::NonBool("4294967295".to_string())),
                         width: Some(32),
                         elements: None,
+                        members: None,
                     }),
                 },
                 TraceItem {
@@ -872,6 +1114,7 @@ fn check_concrete_vals_extractor_array() {
                         data: Some(TraceData::NonBool("2147483648".to_string())),
                         width: Some(32),
                         elements: None,
+                        members: None,
                     }),
                 },
                 TraceItem {
@@ -888,6 +1131,7 @@ fn check_concrete_vals_extractor_array() {
                         data: Some(TraceData::NonBool("4294967295".to_string())),
                         width: Some(32),
                         elements: None,
+                        members: None,
                     }),
                 },
                 TraceItem {
@@ -904,6 +1148,7 @@ fn check_concrete_vals_extractor_array() {
                         data: Some(TraceData::NonBool("7".to_string())),
                         width: Some(32),
                         elements: None,
+                        members: None,
                     }),
                 },
             ]),
@@ -0,0 +1,601 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Implements the `goto-inspect` subcommand, which answers queries about the goto model of a
+//! harness, e.g., the `.out` file kept by `--keep-temps`.
+//!
+//! The model is read with `cprover_bindings`, and the harness configuration, such as its stubs
+//! and unwind value, comes from the Kani metadata in the same directory, if any.
+
+use crate::args::goto_inspect_args::{Format, GotoInspectArgs, Query};
+use crate::metadata::from_json;
+use crate::version::KANI_VERSION;
+use anyhow::{Context, Result};
+use cbmc::InternedString;
+use cbmc::irep::goto_binary_serde::read_goto_binary_file;
+use cbmc::irep::{GotoFunction, GotoModel, Instruction, InstructionKind, Irep, IrepId};
+use comfy_table::Table as PrettyTable;
+use kani_metadata::artifact::convert_type;
+use kani_metadata::{ArtifactType, HarnessMetadata, KaniMetadata};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+// Represents the version of our JSON output format.
+// Increment this version (according to semantic versioning rules) whenever the JSON output format changes.
+const FILE_VERSION: &str = "0.1";
+/// The entry point of goto models that were linked by Kani.
+const CBMC_ENTRY_POINT: &str = "__CPROVER__start";
+/// The property class of the assertions added by `codegen_unimplemented`.
+const UNSUPPORTED_CONSTRUCT: &str = "unsupported_construct";
+
+/// Answer the queries about the goto model in `args.input`.
+pub fn goto_inspect(args: GotoInspectArgs) -> Result<()> {
+    let model = read_goto_binary_file(&args.input)
+        .with_context(|| format!("Failed to read goto binary `{}`", args.input.display()))?;
+    let harness = find_harness(&args.input);
+    let entry = match (&args.entry, &harness) {
+        (Some(entry), _) => entry.clone(),
+        (None, Some(harness)) => harness.mangled_name.clone(),
+        (None, None) => CBMC_ENTRY_POINT.to_string(),
+    };
+    if !model.symbol_table.symbol_table.contains_key(&InternedString::from(&entry)) {
+        anyhow::bail!("Cannot find the entry function `{entry}` in `{}`", args.input.display());
+    }
+
+    let queries = if args.queries.is_empty() {
+        vec![Query::Reachable, Query::Stubs, Query::Loops, Query::Sizes, Query::Unsupported]
+    } else {
+        args.queries
+    };
+    let unwind =
+        Unwind { default: args.default_unwind, loops: args.unwindset.into_iter().collect() };
+    let report = inspect(&model, &entry, harness.as_ref(), &queries, &unwind);
+    match args.format {
+        Format::Pretty => pretty(&report),
+        Format::Json => json(&report)?,
+    }
+    Ok(())
+}
+
+/// The answers to the queries about a goto model. Queries that were not asked are `None`.
+#[derive(Debug, Default, Serialize)]
+struct InspectReport {
+    entry: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reachable: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stubs: Option<Vec<StubInfo>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    undefined: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    loops: Option<Vec<LoopInfo>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sizes: Option<Vec<SizeInfo>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unsupported: Option<Vec<UnsupportedInfo>>,
+}
+
+/// The unwind bounds given on the command line, as they would be given to verify the harness.
+#[derive(Debug, Default)]
+struct Unwind {
+    /// The bound of the loops of harnesses without an unwind attribute.
+    default: Option<u32>,
+    /// The bound of specific loops, which takes precedence over the other bounds.
+    loops: HashMap<String, u32>,
+}
+
+/// A function replaced by the harness.
+#[derive(Debug, PartialEq, Serialize)]
+struct StubInfo {
+    original: String,
+    /// The stub, or `None` if the function is replaced by its contract.
+    replacement: Option<String>,
+}
+
+/// A loop of a reachable function, named as CBMC does, e.g., in `--unwindset`.
+#[derive(Debug, PartialEq, Serialize)]
+struct LoopInfo {
+    name: String,
+    location: String,
+    /// Whether the loop has a loop contract, in which case it doesn't need to be unwound.
+    has_invariant: bool,
+    /// The unwind bound of the loop, if any.
+    unwind: Option<u32>,
+}
+
+impl LoopInfo {
+    fn is_bounded(&self) -> bool {
+        self.has_invariant || self.unwind.is_some()
+    }
+}
+
+/// The size of a symbol, which is a good proxy for the work that CBMC needs to do with it.
+#[derive(Debug, PartialEq, Serialize)]
+struct SizeInfo {
+    symbol: String,
+    /// The number of instructions of the function, if it has a body.
+    instructions: usize,
+    /// The number of irep nodes of the symbol type, value and body.
+    irep_nodes: usize,
+}
+
+/// A construct that Kani cannot verify, which fails verification if it is reachable.
+#[derive(Debug, PartialEq, Serialize)]
+struct UnsupportedInfo {
+    function: String,
+    location: String,
+    description: String,
+}
+
+/// Find the harness that was compiled to `input` in the Kani metadata in its directory.
+fn find_harness(input: &Path) -> Option<HarnessMetadata> {
+    let dir = input.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.to_string_lossy().ends_with(".kani-metadata.json"))
+        .filter_map(|path| from_json::<KaniMetadata>(&path).ok())
+        .flat_map(|metadata| metadata.proof_harnesses.into_iter().chain(metadata.test_harnesses))
+        .find(|harness| {
+            harness.goto_file.as_ref().is_some_and(|goto_file| {
+                let goto = convert_type(goto_file, ArtifactType::SymTabGoto, ArtifactType::Goto);
+                goto.file_name() == input.file_name()
+            })
+        })
+}
+
+fn inspect(
+    model: &GotoModel,
+    entry: &str,
+    harness: Option<&HarnessMetadata>,
+    queries: &[Query],
+    unwind: &Unwind,
+) -> InspectReport {
+    let reachable = reachable_functions(model, entry);
+    let mut report = InspectReport { entry: entry.to_string(), ..Default::default() };
+    for query in queries {
+        match query {
+            Query::Reachable => {
+                report.reachable = Some(reachable.iter().map(ToString::to_string).collect())
+            }
+            Query::Stubs => {
+                report.stubs = Some(stubs(harness));
+                report.undefined = Some(undefined_functions(model, &reachable));
+            }
+            Query::Loops => {
+                report.loops = Some(loops(model, &reachable, harness, unwind));
+            }
+            Query::Sizes => report.sizes = Some(sizes(model)),
+            Query::Unsupported => report.unsupported = Some(unsupported(model, &reachable)),
+        }
+    }
+    report
+}
+
+/// The functions that are referenced, directly or not, by the entry function, including through
+/// the value of global variables, e.g., vtables.
+///
+/// This is an over-approximation, since every function whose address is taken is considered to
+/// be called. The functions are sorted by name.
+fn reachable_functions(model: &GotoModel, entry: &str) -> Vec<InternedString> {
+    let mut visited = HashSet::new();
+    let mut queue = vec![InternedString::from(entry)];
+    while let Some(name) = queue.pop() {
+        let Some(symbol) = model.symbol_table.symbol_table.get(&name) else { continue };
+        if symbol.is_type || !visited.insert(name) {
+            continue;
+        }
+        let mut visit = |irep: &Irep| {
+            visit_ireps(irep, &mut |irep| {
+                if irep.id == IrepId::Symbol
+                    && let Some(identifier) = irep.lookup(IrepId::Identifier)
+                {
+                    queue.push(identifier.id.to_string().into());
+                }
+            })
+        };
+        if let Some(body) = model.functions.get(&name) {
+            for instruction in &body.instructions {
+                visit(&instruction.code);
+                visit(&instruction.condition);
+            }
+        } else {
+            visit(&symbol.value);
+        }
+    }
+    let mut reachable =
+        visited.into_iter().filter(|name| is_function(model, *name)).collect::<Vec<_>>();
+    reachable.sort_by_key(ToString::to_string);
+    reachable
+}
+
+fn is_function(model: &GotoModel, name: InternedString) -> bool {
+    model.symbol_table.symbol_table.get(&name).is_some_and(|symbol| symbol.typ.id == IrepId::Code)
+}
+
+/// The functions in `names` that have a body in the model, with their body.
+fn bodies<'a>(
+    model: &'a GotoModel,
+    names: &'a [InternedString],
+) -> impl Iterator<Item = (&'a InternedString, &'a GotoFunction)> {
+    names.iter().filter_map(|name| model.functions.get(name).map(|body| (name, body)))
+}
+
+fn has_body(model: &GotoModel, name: InternedString) -> bool {
+    model.functions.contains_key(&name) || !model.symbol_table.symbol_table[&name].value.is_nil()
+}
+
+/// The functions replaced by the harness, according to its metadata.
+fn stubs(harness: Option<&HarnessMetadata>) -> Vec<StubInfo> {
+    let Some(harness) = harness else { return vec![] };
+    let stubs = harness.attributes.stubs.iter().map(|stub| StubInfo {
+        original: stub.original.clone(),
+        replacement: Some(stub.replacement.clone()),
+    });
+    let contracts = harness
+        .attributes
+        .verified_stubs
+        .iter()
+        .map(|original| StubInfo { original: original.clone(), replacement: None });
+    stubs.chain(contracts).collect()
+}
+
+/// The reachable functions without a body, which are havocked by CBMC, or replaced by an
+/// `assert(false)` unless `--no-undefined-function-checks` is used.
+/// Note that CBMC built-in functions are not included.
+fn undefined_functions(model: &GotoModel, reachable: &[InternedString]) -> Vec<String> {
+    reachable
+        .iter()
+        .filter(|function| !has_body(model, **function))
+        .map(ToString::to_string)
+        .filter(|function| !function.starts_with("__CPROVER_"))
+        .collect()
+}
+
+/// The loops of the reachable functions, i.e., their backward jumps, numbered as CBMC does.
+///
+/// The bound of each loop is chosen as when verifying the harness: the bound of the loop in
+/// `--unwindset`, or the unwind attribute of the harness, or `--default-unwind`.
+fn loops(
+    model: &GotoModel,
+    reachable: &[InternedString],
+    harness: Option<&HarnessMetadata>,
+    unwind: &Unwind,
+) -> Vec<LoopInfo> {
+    let harness_unwind =
+        harness.and_then(|harness| harness.attributes.unwind_value).or(unwind.default);
+    let mut loops = vec![];
+    for (function, body) in bodies(model, reachable) {
+        let positions: HashMap<usize, usize> = body
+            .instructions
+            .iter()
+            .enumerate()
+            .filter(|(_, instruction)| instruction.target_number != Instruction::NIL_TARGET)
+            .map(|(idx, instruction)| (instruction.target_number, idx))
+            .collect();
+        let backward_jumps = body.instructions.iter().enumerate().filter(|(idx, instruction)| {
+            instruction.kind == InstructionKind::Goto
+                && instruction.targets.iter().any(|target| positions.get(target) <= Some(idx))
+        });
+        for (number, (_, instruction)) in backward_jumps.enumerate() {
+            let has_invariant = [&instruction.code, &instruction.condition]
+                .iter()
+                .any(|irep| irep.lookup(IrepId::CSpecLoopInvariant).is_some());
+            let name = format!("{function}.{number}");
+            let unwind = unwind.loops.get(&name).copied().or(harness_unwind);
+            loops.push(LoopInfo {
+                name,
+                location: location(&instruction.location),
+                has_invariant,
+                unwind,
+            });
+        }
+    }
+    loops
+}
+
+/// The size of every symbol that isn't a type, from the largest to the smallest.
+fn sizes(model: &GotoModel) -> Vec<SizeInfo> {
+    let mut sizes = model
+        .symbol_table
+        .symbol_table
+        .values()
+        .filter(|symbol| !symbol.is_type)
+        .map(|symbol| {
+            let mut irep_nodes = irep_size(&symbol.typ) + irep_size(&symbol.value);
+            let mut instructions = 0;
+            if let Some(body) = model.functions.get(&symbol.name) {
+                instructions = body.instructions.len();
+                irep_nodes += body
+                    .instructions
+                    .iter()
+                    .map(|instruction| {
+                        irep_size(&instruction.code) + irep_size(&instruction.condition)
+                    })
+                    .sum::<usize>();
+            }
+            SizeInfo { symbol: symbol.name.to_string(), instructions, irep_nodes }
+        })
+        .collect::<Vec<_>>();
+    sizes.sort_by(|a, b| b.irep_nodes.cmp(&a.irep_nodes).then_with(|| a.symbol.cmp(&b.symbol)));
+    sizes
+}
+
+/// The assertions added by `codegen_unimplemented` in the reachable functions.
+fn unsupported(model: &GotoModel, reachable: &[InternedString]) -> Vec<UnsupportedInfo> {
+    bodies(model, reachable)
+        .flat_map(|(function, body)| {
+            body.instructions
+                .iter()
+                .filter(|instruction| {
+                    instruction.kind == InstructionKind::Assert
+                        && instruction.location.lookup_as_string(IrepId::PropertyClass).as_deref()
+                            == Some(UNSUPPORTED_CONSTRUCT)
+                })
+                .map(move |instruction| UnsupportedInfo {
+                    function: function.to_string(),
+                    location: location(&instruction.location),
+                    description: instruction
+                        .location
+                        .lookup_as_string(IrepId::Comment)
+                        .unwrap_or_default(),
+                })
+        })
+        .collect()
+}
+
+/// Format a source location irep as `file:line:column`.
+fn location(location: &Irep) -> String {
+    let Some(file) = location.lookup_as_string(IrepId::File) else {
+        return "<unknown>".to_string();
+    };
+    [IrepId::Line, IrepId::Column]
+        .into_iter()
+        .map_while(|id| location.lookup_as_string(id))
+        .fold(file, |location, part| format!("{location}:{part}"))
+}
+
+fn visit_ireps(irep: &Irep, visitor: &mut impl FnMut(&Irep)) {
+    visitor(irep);
+    for sub in irep.sub.iter().chain(irep.named_sub.values()) {
+        visit_ireps(sub, visitor);
+    }
+}
+
+fn irep_size(irep: &Irep) -> usize {
+    let mut size = 0;
+    visit_ireps(irep, &mut |_| size += 1);
+    size
+}
+
+fn pretty_table(header: &[&str], rows: Vec<Vec<String>>) -> PrettyTable {
+    let mut table = PrettyTable::new();
+    table.set_header(header.to_vec()).add_rows(rows);
+    table
+}
+
+fn pretty(report: &InspectReport) {
+    println!("Entry function: {}", report.entry);
+    if let Some(reachable) = &report.reachable {
+        println!("\nReachable functions ({}):", reachable.len());
+        let rows = reachable.iter().map(|function| vec![function.clone()]).collect();
+        println!("{}", pretty_table(&["Function"], rows));
+    }
+    if let Some(stubs) = &report.stubs {
+        println!("\nStubs ({}):", stubs.len());
+        let rows = stubs
+            .iter()
+            .map(|stub| {
+                let replacement = stub.replacement.clone().unwrap_or("<contract>".to_string());
+                vec![stub.original.clone(), replacement]
+            })
+            .collect();
+        println!("{}", pretty_table(&["Function", "Replacement"], rows));
+    }
+    if let Some(undefined) = &report.undefined {
+        println!("\nUndefined functions ({}):", undefined.len());
+        let rows = undefined.iter().map(|function| vec![function.clone()]).collect();
+        println!("{}", pretty_table(&["Function"], rows));
+    }
+    if let Some(loops) = &report.loops {
+        let unbounded = loops.iter().filter(|info| !info.is_bounded()).count();
+        println!("\nLoops ({}, {unbounded} without an unwind bound or invariant):", loops.len());
+        let rows = loops
+            .iter()
+            .map(|info| {
+                let bound = match (info.has_invariant, info.unwind) {
+                    (true, _) => "invariant".to_string(),
+                    (false, Some(unwind)) => format!("unwind {unwind}"),
+                    (false, None) => "none".to_string(),
+                };
+                vec![info.name.clone(), info.location.clone(), bound]
+            })
+            .collect();
+        println!("{}", pretty_table(&["Loop", "Location", "Bound"], rows));
+    }
+    if let Some(sizes) = &report.sizes {
+        println!("\nSymbol sizes ({}):", sizes.len());
+        let rows = sizes
+            .iter()
+            .map(|size| {
+                vec![
+                    size.symbol.clone(),
+                    size.instructions.to_string(),
+                    size.irep_nodes.to_string(),
+                ]
+            })
+            .collect();
+        println!("{}", pretty_table(&["Symbol", "Instructions", "Irep nodes"], rows));
+    }
+    if let Some(unsupported) = &report.unsupported {
+        println!("\nUnsupported constructs ({}):", unsupported.len());
+        let rows = unsupported
+            .iter()
+            .map(|info| {
+                vec![info.function.clone(), info.location.clone(), info.description.clone()]
+            })
+            .collect();
+        println!("{}", pretty_table(&["Function", "Location", "Description"], rows));
+    }
+}
+
+fn json(report: &InspectReport) -> Result<()> {
+    let mut value = serde_json::to_value(report)?;
+    value["kani-version"] = KANI_VERSION.into();
+    value["file-version"] = FILE_VERSION.into();
+    println!("{}", serde_json::to_string_pretty(&value)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::tests::mock_proof_harness;
+    use cbmc::irep::{Symbol, SymbolTable};
+    use std::collections::BTreeMap;
+
+    fn function_symbol(name: &str) -> Symbol {
+        Symbol {
+            typ: Irep::just_id(IrepId::Code),
+            value: Irep::nil(),
+            location: Irep::nil(),
+            name: name.into(),
+            module: "".into(),
+            base_name: name.into(),
+            pretty_name: name.into(),
+            mode: "C".into(),
+            is_type: false,
+            is_macro: false,
+            is_exported: false,
+            is_input: false,
+            is_output: false,
+            is_state_var: false,
+            is_property: false,
+            is_static_lifetime: false,
+            is_thread_local: false,
+            is_lvalue: true,
+            is_file_local: false,
+            is_extern: false,
+            is_volatile: false,
+            is_parameter: false,
+            is_auxiliary: false,
+            is_weak: false,
+        }
+    }
+
+    fn instruction(kind: InstructionKind, code: Irep) -> Instruction {
+        Instruction {
+            code,
+            location: Irep::nil(),
+            kind,
+            condition: Irep::just_id(IrepId::True),
+            target_number: Instruction::NIL_TARGET,
+            targets: vec![],
+            labels: vec![],
+        }
+    }
+
+    fn call(function: &str) -> Irep {
+        let symbol = Irep::just_id(IrepId::Symbol)
+            .with_named_sub(IrepId::Identifier, Irep::just_string_id(function));
+        Irep { id: IrepId::Code, sub: vec![symbol], named_sub: Default::default() }
+    }
+
+    /// A model where `harness` calls `callee` in a loop, and `callee` calls `undefined` and
+    /// `method` through the global `vtable`.
+    fn model() -> GotoModel {
+        let mut symbol_table = SymbolTable::new();
+        for name in ["harness", "callee", "undefined", "unused", "method"] {
+            symbol_table.insert(function_symbol(name));
+        }
+        let mut vtable = function_symbol("vtable");
+        vtable.typ = Irep::just_id(IrepId::Struct);
+        vtable.value = Irep::just_id(IrepId::AddressOf).with_named_sub(
+            IrepId::Identifier,
+            Irep::just_id(IrepId::Symbol)
+                .with_named_sub(IrepId::Identifier, Irep::just_string_id("method")),
+        );
+        symbol_table.insert(vtable);
+        let mut head = instruction(InstructionKind::FunctionCall, call("callee"));
+        head.target_number = 1;
+        let mut back_edge = instruction(InstructionKind::Goto, Irep::nil());
+        back_edge.targets = vec![1];
+        let mut unsupported = instruction(InstructionKind::Assert, Irep::nil());
+        unsupported.location = Irep::just_id(IrepId::EmptyString)
+            .with_named_sub(IrepId::File, Irep::just_string_id("lib.rs"))
+            .with_named_sub(IrepId::Line, Irep::just_int_id(10))
+            .with_named_sub(IrepId::Comment, Irep::just_string_id("asm is not currently supported"))
+            .with_named_sub(IrepId::PropertyClass, Irep::just_string_id(UNSUPPORTED_CONSTRUCT));
+        let functions = BTreeMap::from([
+            (
+                "harness".into(),
+                GotoFunction {
+                    instructions: vec![
+                        head,
+                        back_edge,
+                        instruction(InstructionKind::EndFunction, Irep::nil()),
+                    ],
+                },
+            ),
+            (
+                "callee".into(),
+                GotoFunction {
+                    instructions: vec![
+                        instruction(InstructionKind::FunctionCall, call("undefined")),
+                        instruction(InstructionKind::FunctionCall, call("vtable")),
+                        unsupported,
+                    ],
+                },
+            ),
+            ("unused".into(), GotoFunction { instructions: vec![] }),
+        ]);
+        GotoModel { symbol_table, functions }
+    }
+
+    #[test]
+    fn check_inspect() {
+        let model = model();
+        let all = [Query::Reachable, Query::Stubs, Query::Loops, Query::Sizes, Query::Unsupported];
+        let report = inspect(&model, "harness", None, &all, &Unwind::default());
+        assert_eq!(report.reachable.unwrap(), ["callee", "harness", "method", "undefined"]);
+        assert_eq!(report.stubs.unwrap(), []);
+        assert_eq!(report.undefined.unwrap(), ["method", "undefined"]);
+        assert_eq!(
+            report.loops.unwrap(),
+            [LoopInfo {
+                name: "harness.0".to_string(),
+                location: "<unknown>".to_string(),
+                has_invariant: false,
+                unwind: None,
+            }]
+        );
+        assert_eq!(report.sizes.unwrap().len(), 6);
+        assert_eq!(
+            report.unsupported.unwrap(),
+            [UnsupportedInfo {
+                function: "callee".to_string(),
+                location: "lib.rs:10".to_string(),
+                description: "asm is not currently supported".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn check_loop_unwind() {
+        let model = model();
+        let unwind_of = |harness: Option<&HarnessMetadata>, unwind: &Unwind| {
+            let report = inspect(&model, "harness", harness, &[Query::Loops], unwind);
+            report.loops.unwrap()[0].unwind
+        };
+        let default = Unwind { default: Some(2), ..Default::default() };
+        assert_eq!(unwind_of(None, &default), Some(2));
+
+        let harness = mock_proof_harness("harness", Some(5), None, None);
+        assert_eq!(unwind_of(Some(&harness), &default), Some(5));
+
+        let unwindset =
+            Unwind { default: Some(2), loops: HashMap::from([("harness.0".to_string(), 7)]) };
+        assert_eq!(unwind_of(Some(&harness), &unwindset), Some(7));
+    }
+}
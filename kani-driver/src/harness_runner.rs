@@ -2,16 +2,23 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use anyhow::{Error, Result, bail};
+use comfy_table::Table as PrettyTable;
+use kani_metadata::artifact::convert_type;
 use kani_metadata::{ArtifactType, HarnessKind, HarnessMetadata};
 use rayon::prelude::*;
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
+use std::time::Duration;
 
 use crate::args::{NumThreads, OutputFormat};
 use crate::call_cbmc::{VerificationResult, VerificationStatus};
+use crate::cbmc_property_renderer::TraceContext;
 use crate::project::Project;
+use crate::resource_usage::format_bytes;
 use crate::session::{BUG_REPORT_URL, KaniSession};
+use crate::util::warning;
+use crate::verification_cache::VerificationCache;
 
 use std::env::current_dir;
 use std::path::PathBuf;
@@ -58,6 +65,10 @@ pub(crate) fn check_all_harnesses(
         self.check_stubbing(harnesses)?;
 
         let sorted_harnesses = crate::metadata::sort_harnesses_by_loc(harnesses);
+        let cache = self
+            .sess
+            .use_verification_cache()
+            .then(|| VerificationCache::new(&self.project.outdir));
         let pool = {
             let mut builder = rayon::ThreadPoolBuilder::new();
             match self.sess.args.jobs() {
@@ -87,7 +98,7 @@ pub(crate) fn check_all_harnesses(
                         self.sess.synthesize_loop_contracts(goto_file, goto_file, harness)?;
                     }
 
-                    let result = self.sess.check_harness(goto_file, harness)?;
+                    let result = self.sess.check_harness(goto_file, harness, cache.as_ref())?;
                     if self.sess.args.fail_fast && result.status == VerificationStatus::Failure {
                         Err(Error::new(FailFastHarnessInfo {
                             index_to_failing_harness: idx,
@@ -147,15 +158,21 @@ impl KaniSession {
     fn process_output(
         &self,
         result: &VerificationResult,
+        binary: &Path,
         harness: &HarnessMetadata,
         thread_index: usize,
     ) {
         if self.should_print_output() {
+            let trace_context = self.trace_context(result, binary, harness);
             if self.args.output_into_files {
-                self.write_output_to_file(result, harness, thread_index);
+                self.write_output_to_file(result, harness, thread_index, trace_context.as_ref());
             }
 
-            let output = result.render(&self.args.output_format, harness.attributes.should_panic);
+            let output = result.render(
+                &self.args.output_format,
+                harness.attributes.should_panic,
+                trace_context.as_ref(),
+            );
             if rayon::current_num_threads() > 1 {
                 println!("Thread {thread_index}: {output}");
             } else {
@@ -168,11 +185,30 @@ fn should_print_output(&self) -> bool {
         !self.args.common_args.quiet && self.args.output_format != OutputFormat::Old
     }
 
+    /// Load the information needed to render the counterexample traces of a failed harness, if
+    /// the user requested them.
+    fn trace_context(
+        &self,
+        result: &VerificationResult,
+        binary: &Path,
+        harness: &HarnessMetadata,
+    ) -> Option<TraceContext> {
+        if !self.args.trace || result.status != VerificationStatus::Failure {
+            return None;
+        }
+        let kani_library = self.kani_lib_c.parent().and_then(Path::parent)?.to_path_buf();
+        let enum_map = harness.goto_file.as_ref().map(|goto_file| {
+            convert_type(goto_file, ArtifactType::SymTabGoto, ArtifactType::EnumMap)
+        });
+        Some(TraceContext::new(kani_library, binary, enum_map.as_deref()))
+    }
+
     fn write_output_to_file(
         &self,
         result: &VerificationResult,
         harness: &HarnessMetadata,
         thread_index: usize,
+        trace_context: Option<&TraceContext>,
     ) {
         let target_dir = self.result_output_dir().unwrap();
         let file_name = target_dir.join(harness.pretty_name.clone());
@@ -182,7 +218,7 @@ fn write_output_to_file(
         std::fs::create_dir_all(prefix).unwrap();
         let mut file = File::create(&file_name).unwrap();
         let mut file_output =
-            result.render(&OutputFormat::Regular, harness.attributes.should_panic);
+            result.render(&OutputFormat::Regular, harness.attributes.should_panic, trace_context);
         if rayon::current_num_threads() > 1 {
             file_output = format!("Thread {thread_index}:\n{file_output}");
         }
@@ -201,11 +237,13 @@ fn result_output_dir(&self) -> Result<PathBuf> {
         Ok(target_dir.join("result_output_dir")) //Hardcode output to result_output_dir, may want to make it adjustable?
     }
 
-    /// Run the verification process for a single harness
+    /// Run the verification process for a single harness.
+    /// If a verification cache is provided, CBMC is skipped for harnesses with a stored result.
     pub(crate) fn check_harness(
         &self,
         binary: &Path,
         harness: &HarnessMetadata,
+        cache: Option<&VerificationCache>,
     ) -> Result<VerificationResult> {
         let thread_index = rayon::current_thread_index().unwrap_or_default();
         if !self.args.common_args.quiet {
@@ -233,9 +271,33 @@ pub(crate) fn check_harness(
             println!("{msg}");
         }
 
-        let mut result = self.with_timer(|| self.run_cbmc(binary, harness), "run_cbmc")?;
+        let cache_key = match cache {
+            Some(_) => Some(self.verification_cache_key(binary, harness)?),
+            None => None,
+        };
+        let cached_result =
+            cache.zip(cache_key.as_deref()).and_then(|(cache, key)| cache.load(key, harness));
+        let mut result = if let Some(result) = cached_result {
+            if !self.args.common_args.quiet {
+                let msg = format!("Using cached verification result for {}", harness.pretty_name);
+                if rayon::current_num_threads() > 1 {
+                    println!("Thread {thread_index}: {msg}");
+                } else {
+                    println!("{msg}");
+                }
+            }
+            result
+        } else {
+            let result = self.with_timer(|| self.run_cbmc(binary, harness), "run_cbmc")?;
+            if let Some((cache, key)) = cache.zip(cache_key.as_deref())
+                && let Err(err) = cache.store(key, harness, &result)
+            {
+                warning(&format!("Failed to store verification result in the cache: {err}"));
+            }
+            result
+        };
 
-        self.process_output(&result, harness, thread_index);
+        self.process_output(&result, binary, harness, thread_index);
         self.gen_and_add_concrete_playback(harness, &mut result)?;
         Ok(result)
     }
@@ -284,6 +346,14 @@ pub(crate) fn print_final_summary(self, results: &[HarnessResult<'_>]) -> Result
             );
         } else {
             match self.args.harnesses.as_slice() {
+                [] if self.args.shard.is_some() => {
+                    println!("No proof harnesses were assigned to this shard.")
+                }
+                [] if self.args.changed_since.is_some()
+                    || self.args.changed_since_fingerprints.is_some() =>
+                {
+                    println!("No proof harnesses are affected by the changes.")
+                }
                 [] =>
                 // TODO: This could use a better message, possibly with links to Kani documentation.
                 // New users may encounter this and could use a pointer to how to write proof harnesses.
@@ -301,6 +371,10 @@ pub(crate) fn print_final_summary(self, results: &[HarnessResult<'_>]) -> Result
             };
         }
 
+        if let Some(count) = self.args.slowest_harnesses {
+            print_slowest_harnesses(results, count);
+        }
+
         if self.args.coverage {
             self.show_coverage_summary()?;
         }
@@ -327,3 +401,38 @@ fn show_coverage_summary(&self) -> Result<()> {
         Ok(())
     }
 }
+
+/// Print the `count` harnesses that took the longest to verify, with the resources CBMC consumed
+/// to verify each of them. Statistics that CBMC didn't report are shown as `-`.
+fn print_slowest_harnesses(results: &[HarnessResult<'_>], count: usize) {
+    let mut slowest: Vec<_> = results.iter().collect();
+    slowest.sort_by(|a, b| b.result.runtime.cmp(&a.result.runtime));
+
+    let mut table = PrettyTable::new();
+    table.set_header(vec![
+        "Harness",
+        "Time",
+        "Solver Time",
+        "Peak Memory",
+        "Steps",
+        "VCCs",
+        "Variables",
+        "Clauses",
+    ]);
+    let secs = |time: Option<Duration>| time.map(|time| format!("{:.2}s", time.as_secs_f64()));
+    let known = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+    for result in slowest.into_iter().take(count) {
+        let resources = &result.result.resources;
+        table.add_row(vec![
+            result.harness.pretty_name.clone(),
+            known(secs(Some(result.result.runtime))),
+            known(secs(resources.solver_time)),
+            known(resources.peak_memory.map(format_bytes)),
+            known(resources.program_steps.map(|steps| steps.to_string())),
+            known(resources.vccs.map(|vccs| vccs.to_string())),
+            known(resources.solver_variables.map(|variables| variables.to_string())),
+            known(resources.solver_clauses.map(|clauses| clauses.to_string())),
+        ]);
+    }
+    println!("Slowest Harnesses:\n{table}");
+}
@@ -11,17 +11,22 @@
 use args_toml::join_args;
 
 use crate::args::StandaloneSubcommand;
+use crate::call_cbmc::VerificationStatus;
+use crate::changed_harnesses::ChangedHarnesses;
 use crate::concrete_playback::playback::{playback_cargo, playback_standalone};
 use crate::list::collect_metadata::{list_cargo, list_standalone};
+use crate::mutate::{mutate_cargo, mutate_standalone};
 use crate::project::Project;
 use crate::session::KaniSession;
 use crate::version::print_kani_version;
 use clap::Parser;
+use kani_metadata::UnstableFeature;
 use tracing::debug;
 
 mod args;
 mod args_toml;
 mod autoharness;
+mod call_aeneas;
 mod call_cargo;
 mod call_cbmc;
 mod call_goto_cc;
@@ -30,14 +35,20 @@
 mod call_single_file;
 mod cbmc_output_parser;
 mod cbmc_property_renderer;
+mod changed_harnesses;
 mod concrete_playback;
 mod coverage;
+mod goto_inspect;
 mod harness_runner;
 mod list;
 mod metadata;
+mod mutate;
 mod project;
+mod report;
+mod resource_usage;
 mod session;
 mod util;
+mod verification_cache;
 mod version;
 
 /// The main function for the `kani-driver`.
@@ -72,9 +83,18 @@ fn cargokani_main(input_args: Vec<OsString>) -> Result<()> {
         Some(CargoKaniSubcommand::Autoharness(autoharness_args)) => {
             return autoharness_cargo(*autoharness_args);
         }
+        Some(CargoKaniSubcommand::GotoInspect(args)) => {
+            return goto_inspect::goto_inspect(*args);
+        }
         Some(CargoKaniSubcommand::List(list_args)) => {
             return list_cargo(*list_args, args.verify_opts);
         }
+        Some(CargoKaniSubcommand::MergeResults(args)) => {
+            return report::merge_results(*args);
+        }
+        Some(CargoKaniSubcommand::Mutate(args)) => {
+            return mutate_cargo(*args);
+        }
         Some(CargoKaniSubcommand::Playback(args)) => {
             return playback_cargo(*args);
         }
@@ -98,10 +118,13 @@ fn standalone_main() -> Result<()> {
         Some(StandaloneSubcommand::Autoharness(args)) => {
             return autoharness_standalone(*args);
         }
+        Some(StandaloneSubcommand::GotoInspect(args)) => return goto_inspect::goto_inspect(*args),
         Some(StandaloneSubcommand::Playback(args)) => return playback_standalone(*args),
         Some(StandaloneSubcommand::List(list_args)) => {
             return list_standalone(*list_args, args.verify_opts);
         }
+        Some(StandaloneSubcommand::MergeResults(args)) => return report::merge_results(*args),
+        Some(StandaloneSubcommand::Mutate(args)) => return mutate_standalone(*args),
         Some(StandaloneSubcommand::VerifyStd(args)) => {
             let session = KaniSession::new(args.verify_opts)?;
             if !session.args.common_args.quiet {
@@ -129,8 +152,18 @@ fn standalone_main() -> Result<()> {
 fn verify_project(project: Project, session: KaniSession) -> Result<()> {
     debug!(?project, "verify_project");
     let harnesses = session.determine_targets(project.get_all_harnesses())?;
+    let ChangedHarnesses { affected, verified } = session.select_changed_harnesses(harnesses)?;
+    let harnesses = match session.args.shard {
+        Some(shard) => session.select_shard(&affected, shard),
+        None => affected,
+    };
     debug!(n = harnesses.len(), ?harnesses, "verify_project");
 
+    if session.args.common_args.unstable_features.contains(UnstableFeature::Lean) {
+        // The Lean backend hands the harnesses to Aeneas instead of verifying them.
+        return session.generate_lean_project(&project, &harnesses);
+    }
+
     // Verification
     let runner = harness_runner::HarnessRunner { sess: &session, project: &project };
     let results = runner.check_all_harnesses(&harnesses)?;
@@ -152,6 +185,17 @@ fn verify_project(project: Project, session: KaniSession) -> Result<()> {
         session.save_coverage_results(&project, &results, &timestamp)?;
     }
 
+    if let Some(path) = &session.args.save_fingerprints {
+        let passed: Vec<_> = results
+            .iter()
+            .filter(|result| result.result.status == VerificationStatus::Success)
+            .map(|result| result.harness)
+            .chain(verified.iter().copied())
+            .collect();
+        session.save_fingerprints(&passed, path)?;
+    }
+
+    session.write_reports(&project, &results)?;
     session.print_final_summary(&results)
 }
 
@@ -11,6 +11,7 @@
 use std::fs::File;
 use std::io::{BufReader, BufWriter};
 
+use crate::args::Shard;
 use crate::session::KaniSession;
 use serde::Deserialize;
 
@@ -146,6 +147,40 @@ pub fn sort_harnesses_by_loc<'a>(harnesses: &[&'a HarnessMetadata]) -> Vec<&'a H
     harnesses_clone
 }
 
+impl KaniSession {
+    /// Select the harnesses assigned to the given shard.
+    ///
+    /// Harnesses are assigned to the shards in a round-robin fashion after being sorted by
+    /// location, so every shard gets a similar number of harnesses from each file, and the
+    /// assignment only depends on the set of harnesses.
+    pub fn select_shard<'a>(
+        &self,
+        harnesses: &[&'a HarnessMetadata],
+        shard: Shard,
+    ) -> Vec<&'a HarnessMetadata> {
+        let selected = shard_harnesses(harnesses, shard);
+        if !self.args.common_args.quiet {
+            println!(
+                "Selected {} out of {} harnesses for shard {shard}.",
+                selected.len(),
+                harnesses.len()
+            );
+        }
+        selected
+    }
+}
+
+fn shard_harnesses<'a>(
+    harnesses: &[&'a HarnessMetadata],
+    shard: Shard,
+) -> Vec<&'a HarnessMetadata> {
+    sort_harnesses_by_loc(harnesses)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(idx, harness)| (idx % shard.count == shard.index - 1).then_some(harness))
+        .collect()
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -172,6 +207,8 @@ pub fn mock_proof_harness(
             contract: Default::default(),
             has_loop_contracts: false,
             is_automatically_generated: false,
+            reachable_items: vec![],
+            mutants: vec![],
         }
     }
 
@@ -260,4 +297,25 @@ fn check_find_proof_harness_with_exact() {
             "module::not_check_three"
         );
     }
+
+    #[test]
+    fn check_shard_harnesses() {
+        let harnesses: Vec<_> = (0..5)
+            .map(|line| {
+                let mut harness = mock_proof_harness(&format!("check_{line}"), None, None, None);
+                harness.original_start_line = line;
+                harness
+            })
+            .collect();
+        let ref_harnesses: Vec<_> = harnesses.iter().collect();
+        let names = |index| {
+            shard_harnesses(&ref_harnesses, Shard { index, count: 2 })
+                .iter()
+                .map(|harness| harness.pretty_name.as_str())
+                .collect::<Vec<_>>()
+        };
+        // Harnesses in the same file are sorted by descending line.
+        assert_eq!(names(1), ["check_4", "check_2", "check_0"]);
+        assert_eq!(names(2), ["check_3", "check_1"]);
+    }
 }
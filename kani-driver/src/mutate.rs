@@ -0,0 +1,239 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Implements the `mutate` subcommand, which measures how strong the proof harnesses are.
+//!
+//! The compiler enumerates the mutants of the local functions reachable from each harness, e.g.
+//! a comparison operator that was flipped or a call that was removed. We then rebuild the crate
+//! once per mutant and verify the harnesses that reach it. A mutant that doesn't make any harness
+//! fail is reported as surviving, since the harnesses don't check the behavior that was changed.
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::args::mutate_args::{CargoMutateArgs, StandaloneMutateArgs};
+use crate::call_cbmc::VerificationStatus;
+use crate::harness_runner::HarnessRunner;
+use crate::project::{Project, standalone_project};
+use crate::session::KaniSession;
+use crate::util::warning;
+use crate::{InvocationType, print_kani_version, project};
+use anyhow::Result;
+use kani_metadata::{HarnessMetadata, Mutant};
+
+pub fn mutate_cargo(args: CargoMutateArgs) -> Result<()> {
+    let session = KaniSession::new(args.verify_opts)?;
+    if !session.args.common_args.quiet {
+        print_kani_version(InvocationType::CargoKani(vec![]));
+    }
+    run_mutants(session, |session| project::cargo_project(session, false))
+}
+
+pub fn mutate_standalone(args: StandaloneMutateArgs) -> Result<()> {
+    let session = KaniSession::new(args.verify_opts)?;
+    if !session.args.common_args.quiet {
+        print_kani_version(InvocationType::Standalone);
+    }
+    run_mutants(session, |session| {
+        standalone_project(&args.input, args.crate_name.clone(), session)
+    })
+}
+
+/// The outcome of verifying the harnesses that reach a mutant.
+#[derive(Debug)]
+struct MutantOutcome {
+    mutant: Mutant,
+    /// The harnesses that reach the mutated function.
+    harnesses: BTreeSet<String>,
+    /// The harnesses that failed with the mutation applied.
+    killed_by: BTreeSet<String>,
+    /// Whether the mutant could not be built.
+    unviable: bool,
+}
+
+impl MutantOutcome {
+    fn survived(&self) -> bool {
+        !self.unviable && self.killed_by.is_empty()
+    }
+}
+
+/// Verify the harnesses without mutations first, then once per mutant.
+fn run_mutants(
+    mut session: KaniSession,
+    mut build: impl FnMut(&mut KaniSession) -> Result<Project>,
+) -> Result<()> {
+    session.mutation_compiler_flags = vec!["--list-mutants".to_string()];
+    let project = build(&mut session)?;
+    let harnesses = session.determine_targets(project.get_all_harnesses())?;
+    let runner = HarnessRunner { sess: &session, project: &project };
+    let results = runner.check_all_harnesses(&harnesses)?;
+
+    // Mutants can only be detected by harnesses that succeed without them.
+    let (passing, failing): (Vec<_>, Vec<_>) =
+        results.iter().partition(|result| result.result.status == VerificationStatus::Success);
+    let failing: Vec<_> = failing.iter().map(|result| result.harness.pretty_name.clone()).collect();
+    if !failing.is_empty() {
+        warning(&format!("Skipping harnesses that fail without mutations: {}", failing.join(", ")));
+    }
+    let passing: Vec<_> = passing.iter().map(|result| result.harness).collect();
+    let mut outcomes = collect_mutants(&passing);
+    let passing: BTreeSet<_> = passing.iter().map(|harness| harness.pretty_name.clone()).collect();
+
+    let quiet = session.args.common_args.quiet;
+    session.args.common_args.quiet = true;
+    let total = outcomes.len();
+    for (idx, outcome) in outcomes.iter_mut().enumerate() {
+        let mutant = &outcome.mutant;
+        if !quiet {
+            println!(
+                "Checking mutant {}/{total}: {}:{} in `{}`: {}...",
+                idx + 1,
+                mutant.file,
+                mutant.line,
+                mutant.function,
+                mutant.description
+            );
+        }
+        session.mutation_compiler_flags = vec![format!("--mutant {}", mutant.id)];
+        let project = match build(&mut session) {
+            Ok(project) => project,
+            Err(err) => {
+                warning(&format!("Failed to build mutant `{}`: {err:#}", mutant.id));
+                outcome.unviable = true;
+                continue;
+            }
+        };
+        let harnesses: Vec<_> = project
+            .get_all_harnesses()
+            .into_iter()
+            .filter(|harness| outcome.harnesses.contains(&harness.pretty_name))
+            .collect();
+        let runner = HarnessRunner { sess: &session, project: &project };
+        outcome.killed_by = runner
+            .check_all_harnesses(&harnesses)?
+            .into_iter()
+            .filter(|result| result.result.status == VerificationStatus::Failure)
+            .map(|result| result.harness.pretty_name.clone())
+            .collect();
+    }
+
+    if !quiet {
+        print!("{}", mutation_summary(&passing, &outcomes));
+    }
+    Ok(())
+}
+
+/// Collect the mutants reached by the given harnesses, in the order they were first reported.
+fn collect_mutants(harnesses: &[&HarnessMetadata]) -> Vec<MutantOutcome> {
+    let mut outcomes: Vec<MutantOutcome> = vec![];
+    let mut indices = HashMap::new();
+    for harness in harnesses {
+        for mutant in &harness.mutants {
+            let idx = *indices.entry(mutant.id.clone()).or_insert_with(|| {
+                outcomes.push(MutantOutcome {
+                    mutant: mutant.clone(),
+                    harnesses: BTreeSet::new(),
+                    killed_by: BTreeSet::new(),
+                    unviable: false,
+                });
+                outcomes.len() - 1
+            });
+            outcomes[idx].harnesses.insert(harness.pretty_name.clone());
+        }
+    }
+    outcomes
+}
+
+/// Summarize the mutants that were killed and the ones that survived each harness.
+fn mutation_summary(harnesses: &BTreeSet<String>, outcomes: &[MutantOutcome]) -> String {
+    let mut summary = String::from("\nMutation Summary:\n");
+    for harness in harnesses {
+        let reached: Vec<_> = outcomes
+            .iter()
+            .filter(|outcome| !outcome.unviable && outcome.harnesses.contains(harness))
+            .collect();
+        if reached.is_empty() {
+            summary.push_str(&format!("Harness `{harness}` does not reach any mutants.\n"));
+            continue;
+        }
+        let surviving: Vec<_> =
+            reached.iter().filter(|outcome| !outcome.killed_by.contains(harness)).collect();
+        summary.push_str(&format!(
+            "Harness `{harness}` killed {} of {} mutants.\n",
+            reached.len() - surviving.len(),
+            reached.len()
+        ));
+        for outcome in surviving {
+            let mutant = &outcome.mutant;
+            summary.push_str(&format!(
+                " - Surviving mutant {}:{} in `{}`: {}\n",
+                mutant.file, mutant.line, mutant.function, mutant.description
+            ));
+        }
+    }
+
+    let unviable = outcomes.iter().filter(|outcome| outcome.unviable).count();
+    let surviving = outcomes.iter().filter(|outcome| outcome.survived()).count();
+    let killed = outcomes.len() - unviable - surviving;
+    summary.push_str(&format!(
+        "Complete - {killed} mutants killed, {surviving} survived, {unviable} failed to build.\n"
+    ));
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::tests::mock_proof_harness;
+
+    fn mock_mutant(index: usize, description: &str) -> Mutant {
+        Mutant {
+            id: format!("_RNvCs_5krate3abs#{index}"),
+            function: "abs".to_string(),
+            description: description.to_string(),
+            file: "src/lib.rs".to_string(),
+            line: 10 + index,
+        }
+    }
+
+    #[test]
+    fn check_collect_mutants() {
+        let mut first = mock_proof_harness("check_first", None, None, None);
+        first.mutants = vec![mock_mutant(0, "replace `<` with `<=`")];
+        let mut second = mock_proof_harness("check_second", None, None, None);
+        second.mutants =
+            vec![mock_mutant(1, "remove assignment"), mock_mutant(0, "replace `<` with `<=`")];
+
+        let outcomes = collect_mutants(&[&first, &second]);
+        let ids: Vec<_> = outcomes.iter().map(|outcome| outcome.mutant.id.as_str()).collect();
+        assert_eq!(ids, ["_RNvCs_5krate3abs#0", "_RNvCs_5krate3abs#1"]);
+        assert_eq!(
+            outcomes[0].harnesses,
+            BTreeSet::from(["check_first".into(), "check_second".into()])
+        );
+        assert_eq!(outcomes[1].harnesses, BTreeSet::from(["check_second".into()]));
+    }
+
+    #[test]
+    fn check_mutation_summary() {
+        let mut first = mock_proof_harness("check_first", None, None, None);
+        first.mutants =
+            vec![mock_mutant(0, "replace `<` with `<=`"), mock_mutant(1, "replace `0` with `1`")];
+        let mut second = mock_proof_harness("check_second", None, None, None);
+        second.mutants = vec![mock_mutant(1, "replace `0` with `1`")];
+        let third = mock_proof_harness("check_third", None, None, None);
+
+        let mut outcomes = collect_mutants(&[&first, &second, &third]);
+        outcomes[0].killed_by.insert("check_first".into());
+        let harnesses =
+            BTreeSet::from(["check_first".into(), "check_second".into(), "check_third".into()]);
+        assert_eq!(
+            mutation_summary(&harnesses, &outcomes),
+            "\nMutation Summary:\n\
+            Harness `check_first` killed 1 of 2 mutants.\n \
+            - Surviving mutant src/lib.rs:11 in `abs`: replace `0` with `1`\n\
+            Harness `check_second` killed 0 of 1 mutants.\n \
+            - Surviving mutant src/lib.rs:11 in `abs`: replace `0` with `1`\n\
+            Harness `check_third` does not reach any mutants.\n\
+            Complete - 1 mutants killed, 1 survived, 0 failed to build.\n"
+        );
+    }
+}
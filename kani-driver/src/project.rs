@@ -9,7 +9,8 @@
 use crate::util::{crate_name, info_operation};
 use anyhow::{Context, Result};
 use kani_metadata::{
-    ArtifactType, ArtifactType::*, HarnessMetadata, KaniMetadata, artifact::convert_type,
+    ArtifactType, ArtifactType::*, HarnessMetadata, KaniMetadata, UnstableFeature,
+    artifact::convert_type,
 };
 use std::env::current_dir;
 use std::fs;
@@ -96,10 +97,17 @@ fn try_new(
             for harness_metadata in
                 crate_metadata.test_harnesses.iter().chain(crate_metadata.proof_harnesses.iter())
             {
-                let symtab_out = Artifact::try_new(
-                    harness_metadata.goto_file.as_ref().expect("Expected a model file"),
-                    SymTabGoto,
-                )?;
+                let model_file =
+                    harness_metadata.goto_file.as_ref().expect("Expected a model file");
+                if session.args.common_args.unstable_features.contains(UnstableFeature::Lean) {
+                    // The Lean backend only generates the LLBC file of each harness.
+                    artifacts.push(Artifact::try_new(
+                        &convert_type(model_file, SymTabGoto, Llbc),
+                        Llbc,
+                    )?);
+                    continue;
+                }
+                let symtab_out = Artifact::try_new(model_file, SymTabGoto)?;
                 let goto_path = convert_type(&symtab_out.path, symtab_out.typ, Goto);
 
                 // Link
@@ -108,10 +116,12 @@ fn try_new(
 
                 // All other harness artifacts that may have been generated as part of the build.
                 artifacts.extend(
-                    [SymTab, TypeMap, VTableRestriction, PrettyNameMap].iter().filter_map(|typ| {
-                        let artifact = Artifact::try_from(&symtab_out, *typ).ok()?;
-                        Some(artifact)
-                    }),
+                    [SymTab, TypeMap, EnumMap, VTableRestriction, PrettyNameMap].iter().filter_map(
+                        |typ| {
+                            let artifact = Artifact::try_from(&symtab_out, *typ).ok()?;
+                            Some(artifact)
+                        },
+                    ),
                 );
                 artifacts.push(symtab_out);
                 artifacts.push(goto);
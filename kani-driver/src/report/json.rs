@@ -0,0 +1,119 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Generate a JSON report with the outcome and the resources consumed by each harness, so proof
+//! budgets can be tracked across runs. These reports can also be merged with the
+//! `merge-results` subcommand, e.g. to combine the results of several shards.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::{Finding, findings};
+use crate::args::Shard;
+use crate::harness_runner::HarnessResult;
+use crate::version::KANI_VERSION;
+
+/// Version of the report format. Increase it whenever a field is changed or removed.
+pub const REPORT_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonReport {
+    pub kani_version: String,
+    pub report_version: u32,
+    /// The shard that produced this report, if the harnesses were split with `--shard`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard: Option<Shard>,
+    pub harnesses: Vec<HarnessReport>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarnessReport {
+    pub name: String,
+    #[serde(rename = "crate")]
+    pub crate_name: String,
+    pub file: String,
+    pub line: usize,
+    pub status: HarnessStatus,
+    pub verification_time: f64,
+    pub resources: Map<String, Value>,
+    pub failures: Vec<FailureReport>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HarnessStatus {
+    Success,
+    Failure,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FailureReport {
+    pub class: String,
+    pub message: String,
+    pub location: Option<String>,
+}
+
+/// Generate the JSON report for the given results.
+pub fn report(results: &[HarnessResult<'_>], shard: Option<Shard>) -> Result<String> {
+    let report = JsonReport {
+        kani_version: KANI_VERSION.to_string(),
+        report_version: REPORT_VERSION,
+        shard,
+        harnesses: results.iter().map(harness_report).collect(),
+    };
+    Ok(serde_json::to_string_pretty(&report)?)
+}
+
+fn harness_report(result: &HarnessResult<'_>) -> HarnessReport {
+    let harness = result.harness;
+    let failures: Vec<_> = findings(result).iter().map(failure_report).collect();
+    HarnessReport {
+        name: harness.pretty_name.clone(),
+        crate_name: harness.crate_name.clone(),
+        file: harness.original_file.clone(),
+        line: harness.original_start_line,
+        status: if failures.is_empty() { HarnessStatus::Success } else { HarnessStatus::Failure },
+        verification_time: result.result.runtime.as_secs_f64(),
+        resources: result.result.resources.to_json(),
+        failures,
+    }
+}
+
+fn failure_report(finding: &Finding<'_>) -> FailureReport {
+    FailureReport {
+        class: finding.class.clone(),
+        message: finding.message.clone(),
+        location: finding.location.map(|location| location.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::call_cbmc::VerificationResult;
+    use crate::metadata::tests::mock_proof_harness;
+    use crate::resource_usage::ResourceUsage;
+    use serde_json::json;
+
+    #[test]
+    fn check_json_report() {
+        let harness = mock_proof_harness("check_resources", None, Some("krate"), None);
+        let mut result =
+            VerificationResult::from_properties(vec![], &harness.attributes, Default::default());
+        result.resources =
+            ResourceUsage { vccs: Some(3), peak_memory: Some(4096), ..Default::default() };
+        let shard = Some(Shard { index: 2, count: 3 });
+        let report: Value = serde_json::from_str(
+            &super::report(&[HarnessResult { harness: &harness, result }], shard).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(report["shard"], json!({ "index": 2, "count": 3 }));
+        let harness_report = &report["harnesses"][0];
+        assert_eq!(harness_report["name"], "check_resources");
+        assert_eq!(harness_report["status"], "success");
+        assert_eq!(harness_report["resources"], json!({ "vccs": 3, "peakMemory": 4096 }));
+        assert_eq!(harness_report["failures"], json!([]));
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Generate reports in the JUnit XML format. Each crate is represented by a test suite and each
+//! harness by a test case.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use super::{Finding, findings};
+use crate::harness_runner::HarnessResult;
+
+/// Generate the JUnit XML report for the given results.
+pub fn report(results: &[HarnessResult<'_>]) -> String {
+    let mut suites: BTreeMap<&str, Vec<&HarnessResult<'_>>> = BTreeMap::new();
+    for result in results {
+        suites.entry(result.harness.crate_name.as_str()).or_default().push(result);
+    }
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let (failures, time) = summary(results);
+    writeln!(
+        out,
+        "<testsuites name=\"kani\" tests=\"{}\" failures=\"{failures}\" time=\"{time:.3}\">",
+        results.len()
+    )
+    .unwrap();
+    for (crate_name, results) in suites {
+        let (failures, time) = summary(results.iter().copied());
+        writeln!(
+            out,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{failures}\" time=\"{time:.3}\">",
+            escape(crate_name),
+            results.len()
+        )
+        .unwrap();
+        for result in results {
+            write_test_case(&mut out, result);
+        }
+        writeln!(out, "  </testsuite>").unwrap();
+    }
+    writeln!(out, "</testsuites>").unwrap();
+    out
+}
+
+/// Return the number of failed harnesses and the total verification time in seconds.
+fn summary<'a>(results: impl IntoIterator<Item = &'a HarnessResult<'a>>) -> (usize, f32) {
+    results.into_iter().fold((0, 0.0), |(failures, time), result| {
+        let failed = !findings(result).is_empty();
+        (failures + usize::from(failed), time + result.result.runtime.as_secs_f32())
+    })
+}
+
+fn write_test_case(out: &mut String, result: &HarnessResult<'_>) {
+    let harness = result.harness;
+    write!(
+        out,
+        "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\" file=\"{}\" line=\"{}\"",
+        escape(&harness.pretty_name),
+        escape(&harness.crate_name),
+        result.result.runtime.as_secs_f32(),
+        escape(&harness.original_file),
+        harness.original_start_line,
+    )
+    .unwrap();
+    let findings = findings(result);
+    let resources = result.result.resources.to_json();
+    if findings.is_empty() && resources.is_empty() {
+        writeln!(out, "/>").unwrap();
+        return;
+    }
+    writeln!(out, ">").unwrap();
+    if !resources.is_empty() {
+        writeln!(out, "      <properties>").unwrap();
+        for (name, value) in resources {
+            writeln!(out, "        <property name=\"{name}\" value=\"{value}\"/>").unwrap();
+        }
+        writeln!(out, "      </properties>").unwrap();
+    }
+    if findings.is_empty() {
+        writeln!(out, "    </testcase>").unwrap();
+        return;
+    }
+    let message = match findings.as_slice() {
+        [finding] => finding.message.clone(),
+        _ => format!("{} failed properties", findings.len()),
+    };
+    let details: Vec<_> = findings.iter().map(format_finding).collect();
+    writeln!(
+        out,
+        "      <failure message=\"{}\" type=\"VERIFICATION:FAILED\">{}</failure>",
+        escape(&message),
+        escape(&details.join("\n"))
+    )
+    .unwrap();
+    writeln!(out, "    </testcase>").unwrap();
+}
+
+fn format_finding(finding: &Finding<'_>) -> String {
+    match finding.location {
+        Some(location) => format!("{}: {} at {location}", finding.class, finding.message),
+        None => format!("{}: {}", finding.class, finding.message),
+    }
+}
+
+/// Escape the characters that cannot appear verbatim in XML attributes and text.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::call_cbmc::VerificationResult;
+    use crate::cbmc_output_parser::{CheckStatus, Property, PropertyId, SourceLocation};
+    use crate::metadata::tests::mock_proof_harness;
+    use crate::resource_usage::ResourceUsage;
+
+    fn mock_property(description: &str, status: CheckStatus) -> Property {
+        Property {
+            description: description.to_string(),
+            property_id: PropertyId {
+                fn_name: Some("check".to_string()),
+                class: "assertion".to_string(),
+                id: 1,
+            },
+            source_location: SourceLocation {
+                column: Some("5".to_string()),
+                file: Some("src/lib.rs".to_string()),
+                function: None,
+                line: Some("10".to_string()),
+            },
+            status,
+            reach: None,
+            trace: None,
+        }
+    }
+
+    #[test]
+    fn check_junit_report() {
+        let success = mock_proof_harness("check_success", None, Some("krate"), None);
+        let failure = mock_proof_harness("check_failure", None, Some("krate"), None);
+        let results = [
+            HarnessResult {
+                harness: &success,
+                result: VerificationResult {
+                    resources: ResourceUsage { solver_clauses: Some(1062), ..Default::default() },
+                    ..VerificationResult::from_properties(
+                        vec![mock_property("x > 0", CheckStatus::Success)],
+                        &success.attributes,
+                        Default::default(),
+                    )
+                },
+            },
+            HarnessResult {
+                harness: &failure,
+                result: VerificationResult::from_properties(
+                    vec![mock_property("a < \"b\"", CheckStatus::Failure)],
+                    &failure.attributes,
+                    Default::default(),
+                ),
+            },
+        ];
+        let report = report(&results);
+        assert!(report.contains(
+            "<testsuites name=\"kani\" tests=\"2\" failures=\"1\" time=\"0.000\">\n  \
+            <testsuite name=\"krate\" tests=\"2\" failures=\"1\" time=\"0.000\">"
+        ));
+        assert!(report.contains("<testcase name=\"check_success\" classname=\"krate\""));
+        assert!(report.contains("<property name=\"solverClauses\" value=\"1062\"/>"));
+        assert!(report.contains(
+            "<failure message=\"a &lt; &quot;b&quot;\" type=\"VERIFICATION:FAILED\">\
+            assertion: a &lt; &quot;b&quot; at src/lib.rs:10:5</failure>"
+        ));
+    }
+}
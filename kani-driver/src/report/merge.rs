@@ -0,0 +1,172 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Implements the `merge-results` subcommand, which combines the JSON reports of several
+//! verification runs (e.g. one per shard) into a single summary and exit code.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+
+use super::json::{HarnessReport, HarnessStatus, JsonReport, REPORT_VERSION};
+use crate::args::Shard;
+use crate::args::merge_results_args::MergeResultsArgs;
+use crate::util::warning;
+use crate::version::KANI_VERSION;
+
+/// Merge the given reports and print the final summary.
+/// Exit with a failure code if any of the harnesses failed.
+pub fn merge_results(args: MergeResultsArgs) -> Result<()> {
+    let reports = args.reports.iter().map(|path| load_report(path)).collect::<Result<Vec<_>>>()?;
+    check_shards(&reports)?;
+    for version in reports.iter().map(|report| &report.kani_version).collect::<BTreeSet<_>>() {
+        if version != KANI_VERSION {
+            warning(&format!("merging a report generated by Kani {version}"));
+        }
+    }
+    let harnesses = merge_harnesses(reports)?;
+    let failures: Vec<_> =
+        harnesses.iter().filter(|harness| harness.status == HarnessStatus::Failure).collect();
+
+    if !args.common_args.quiet {
+        println!("Merged Harness Summary:");
+        for failure in &failures {
+            println!("Verification failed for - {}", failure.name);
+        }
+        println!(
+            "Complete - {} successfully verified harnesses, {} failures, {} total.",
+            harnesses.len() - failures.len(),
+            failures.len(),
+            harnesses.len()
+        );
+    }
+
+    if !failures.is_empty() {
+        // Failure exit code without additional error message
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn load_report(path: &Path) -> Result<JsonReport> {
+    let file =
+        File::open(path).with_context(|| format!("Failed to open report `{}`", path.display()))?;
+    let report: JsonReport = serde_json::from_reader(BufReader::new(file))
+        .with_context(|| format!("Failed to parse report `{}`", path.display()))?;
+    if report.report_version != REPORT_VERSION {
+        bail!(
+            "Report `{}` has version {}, but only version {REPORT_VERSION} is supported",
+            path.display(),
+            report.report_version
+        );
+    }
+    Ok(report)
+}
+
+/// Check that the reports of sharded runs cover every shard exactly once.
+fn check_shards(reports: &[JsonReport]) -> Result<()> {
+    let shards: Vec<Shard> = reports.iter().filter_map(|report| report.shard).collect();
+    let Some(count) = shards.first().map(|shard| shard.count) else {
+        return Ok(());
+    };
+    if shards.len() != reports.len() {
+        bail!("Cannot merge the reports of sharded and non-sharded runs");
+    }
+    if let Some(shard) = shards.iter().find(|shard| shard.count != count) {
+        bail!("Cannot merge the reports of shard {shard} with the reports of {count} shards");
+    }
+    let mut seen = BTreeSet::new();
+    for shard in &shards {
+        if !seen.insert(shard.index) {
+            bail!("The results of shard {shard} were provided more than once");
+        }
+    }
+    let missing: Vec<_> = (1..=count)
+        .filter(|index| !seen.contains(index))
+        .map(|index| Shard { index, count }.to_string())
+        .collect();
+    if !missing.is_empty() {
+        bail!("Missing the results of shard(s) {}", missing.join(", "));
+    }
+    Ok(())
+}
+
+/// Collect the harnesses of all reports, sorted by crate and name.
+fn merge_harnesses(reports: Vec<JsonReport>) -> Result<Vec<HarnessReport>> {
+    let mut harnesses = BTreeMap::new();
+    for harness in reports.into_iter().flat_map(|report| report.harnesses) {
+        let key = (harness.crate_name.clone(), harness.name.clone());
+        if let Some(duplicate) = harnesses.insert(key, harness) {
+            bail!(
+                "Harness `{}` from crate `{}` appears in more than one report",
+                duplicate.name,
+                duplicate.crate_name
+            );
+        }
+    }
+    Ok(harnesses.into_values().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_report(shard: Option<Shard>, harnesses: &[(&str, HarnessStatus)]) -> JsonReport {
+        JsonReport {
+            kani_version: KANI_VERSION.to_string(),
+            report_version: REPORT_VERSION,
+            shard,
+            harnesses: harnesses
+                .iter()
+                .map(|(name, status)| HarnessReport {
+                    name: name.to_string(),
+                    crate_name: "krate".to_string(),
+                    file: "src/lib.rs".to_string(),
+                    line: 1,
+                    status: *status,
+                    verification_time: 0.0,
+                    resources: Default::default(),
+                    failures: vec![],
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn check_shards_coverage() {
+        let shard = |index| Some(Shard { index, count: 3 });
+        let reports = [mock_report(shard(1), &[]), mock_report(shard(3), &[])];
+        let err = check_shards(&reports).unwrap_err();
+        assert_eq!(err.to_string(), "Missing the results of shard(s) 2/3");
+
+        let reports = [mock_report(shard(1), &[]), mock_report(shard(1), &[])];
+        assert!(check_shards(&reports).is_err());
+
+        let reports = [mock_report(shard(1), &[]), mock_report(None, &[])];
+        assert!(check_shards(&reports).is_err());
+
+        let reports =
+            [mock_report(shard(2), &[]), mock_report(shard(1), &[]), mock_report(shard(3), &[])];
+        assert!(check_shards(&reports).is_ok());
+        assert!(check_shards(&[mock_report(None, &[])]).is_ok());
+    }
+
+    #[test]
+    fn check_merge_harnesses() {
+        let reports = vec![
+            mock_report(None, &[("check_b", HarnessStatus::Failure)]),
+            mock_report(None, &[("check_a", HarnessStatus::Success)]),
+        ];
+        let harnesses = merge_harnesses(reports).unwrap();
+        let names: Vec<_> = harnesses.iter().map(|harness| harness.name.as_str()).collect();
+        assert_eq!(names, ["check_a", "check_b"]);
+
+        let reports = vec![
+            mock_report(None, &[("check_a", HarnessStatus::Failure)]),
+            mock_report(None, &[("check_a", HarnessStatus::Success)]),
+        ];
+        assert!(merge_harnesses(reports).is_err());
+    }
+}
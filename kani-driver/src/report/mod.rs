@@ -0,0 +1,148 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! This module writes machine-readable reports of the verification results and of the resources
+//! consumed by each harness, so they can be consumed by CI dashboards and code scanning tools.
+
+mod json;
+mod junit;
+mod merge;
+mod sarif;
+
+pub use merge::merge_results;
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::args::ReportFormat;
+use crate::call_cbmc::{ExitStatus, VerificationStatus};
+use crate::cbmc_output_parser::{CheckStatus, Property, SourceLocation};
+use crate::harness_runner::HarnessResult;
+use crate::project::Project;
+use crate::session::KaniSession;
+
+const OUTPUT_FILENAME: &str = "kani-report";
+
+impl KaniSession {
+    /// Write a report of the given results in each of the formats requested by the user.
+    pub fn write_reports(&self, project: &Project, results: &[HarnessResult<'_>]) -> Result<()> {
+        if self.args.report_formats.is_empty() {
+            return Ok(());
+        }
+        let out_dir = self.report_dir(project);
+        std::fs::create_dir_all(&out_dir)?;
+        for format in &self.args.report_formats {
+            let (extension, content) = match format {
+                ReportFormat::Json => ("json", json::report(results, self.args.shard)?),
+                ReportFormat::Junit => ("xml", junit::report(results)),
+                ReportFormat::Sarif => ("sarif", sarif::report(results, &source_root(project)?)?),
+            };
+            let out_path = out_dir.join(OUTPUT_FILENAME).with_extension(extension);
+            let mut writer = BufWriter::new(File::create(&out_path)?);
+            writer.write_all(content.as_bytes())?;
+            writer.flush()?;
+            if !self.args.common_args.quiet {
+                println!(
+                    "Wrote {format} report to {}",
+                    std::fs::canonicalize(&out_path)?.display()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// The directory where the reports are written: the `kani` folder of the cargo target
+    /// directory, or the output directory of a standalone project.
+    fn report_dir(&self, project: &Project) -> PathBuf {
+        match &project.cargo_metadata {
+            Some(metadata) => self
+                .args
+                .target_dir
+                .clone()
+                .unwrap_or_else(|| metadata.target_directory.clone().into())
+                .join("kani"),
+            None => project.outdir.clone(),
+        }
+    }
+}
+
+/// The directory that the source locations of the reports are relative to: the cargo workspace
+/// root, or the directory of the input file of a standalone project.
+fn source_root(project: &Project) -> Result<PathBuf> {
+    let root = match (&project.cargo_metadata, &project.input) {
+        (Some(metadata), _) => metadata.workspace_root.clone().into(),
+        (None, Some(input)) => {
+            input.canonicalize()?.parent().map(Path::to_path_buf).unwrap_or_default()
+        }
+        (None, None) => std::env::current_dir()?,
+    };
+    Ok(root)
+}
+
+/// A reason why a harness failed to verify.
+struct Finding<'a> {
+    /// The property class (e.g. `assertion`), used to classify the finding.
+    class: String,
+    message: String,
+    /// The location of the failure. `None` if the failure isn't associated with a property.
+    location: Option<&'a SourceLocation>,
+}
+
+/// Collect the reasons why a harness failed.
+///
+/// Failed properties are only reported if the harness failed, since a harness with
+/// `#[kani::should_panic]` is expected to have failed properties.
+fn findings<'a>(result: &'a HarnessResult<'_>) -> Vec<Finding<'a>> {
+    if result.result.status == VerificationStatus::Success {
+        return vec![];
+    }
+    match &result.result.results {
+        Ok(properties) => {
+            let findings: Vec<_> = properties
+                .iter()
+                .filter(|property| property.status == CheckStatus::Failure)
+                .map(|property| Finding {
+                    class: property.property_class(),
+                    message: property.description.clone(),
+                    location: property_location(property),
+                })
+                .collect();
+            if findings.is_empty() {
+                // This only happens if the harness was expected to panic, but it didn't.
+                vec![Finding {
+                    class: "should_panic".to_string(),
+                    message: "The harness was expected to panic, but no panic was found"
+                        .to_string(),
+                    location: None,
+                }]
+            } else {
+                findings
+            }
+        }
+        Err(exit_status) => {
+            let message = match exit_status {
+                ExitStatus::Timeout => "CBMC timed out".to_string(),
+                ExitStatus::OutOfMemory => "CBMC ran out of memory".to_string(),
+                ExitStatus::Other(status) => format!("CBMC failed with status {status}"),
+            };
+            vec![Finding { class: "cbmc".to_string(), message, location: None }]
+        }
+    }
+}
+
+/// The location of a failed property. If CBMC didn't report a location for the property itself,
+/// use the last location in its trace.
+fn property_location(property: &Property) -> Option<&SourceLocation> {
+    if !property.source_location.is_missing() {
+        return Some(&property.source_location);
+    }
+    property
+        .trace
+        .iter()
+        .flatten()
+        .rev()
+        .filter_map(|step| step.source_location.as_ref())
+        .find(|location| !location.is_missing())
+}
@@ -0,0 +1,230 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Generate reports in the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+//! format. Each failed property is reported as a result, and each property class as a rule.
+//!
+//! Source files under the project root are referenced relative to the `%SRCROOT%` base id, so the
+//! report can be uploaded from a different checkout of the project.
+
+use std::collections::BTreeSet;
+use std::path::{Component, Path};
+
+use anyhow::Result;
+use serde_json::{Value, json};
+
+use super::{Finding, findings};
+use crate::harness_runner::HarnessResult;
+use crate::version::KANI_VERSION;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const SRCROOT: &str = "%SRCROOT%";
+
+/// Generate the SARIF report for the given results, where `src_root` is the root of the project
+/// that the `%SRCROOT%` base id refers to.
+pub fn report(results: &[HarnessResult<'_>], src_root: &Path) -> Result<String> {
+    let mut rules = BTreeSet::new();
+    let mut sarif_results = vec![];
+    for result in results {
+        for finding in findings(result) {
+            sarif_results.push(sarif_result(result, &finding, src_root));
+            rules.insert(finding.class);
+        }
+    }
+    let rules: Vec<_> = rules.into_iter().map(|class| json!({ "id": class })).collect();
+
+    let report = json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "Kani",
+                    "version": KANI_VERSION,
+                    "informationUri": "https://github.com/model-checking/kani",
+                    "rules": rules,
+                }
+            },
+            "originalUriBaseIds": {
+                SRCROOT: { "uri": format!("{}/", file_uri(src_root).trim_end_matches('/')) },
+            },
+            "results": sarif_results,
+        }]
+    });
+    Ok(serde_json::to_string_pretty(&report)?)
+}
+
+fn sarif_result(result: &HarnessResult<'_>, finding: &Finding<'_>, src_root: &Path) -> Value {
+    let harness = result.harness;
+    // Findings that aren't associated with a property are reported at the harness itself.
+    let (file, line, column) = match finding.location {
+        Some(location) => (
+            location.file.clone().unwrap_or_default(),
+            location.line.as_ref().and_then(|line| line.parse::<usize>().ok()),
+            location.column.as_ref().and_then(|column| column.parse::<usize>().ok()),
+        ),
+        None => (harness.original_file.clone(), Some(harness.original_start_line), None),
+    };
+    let mut region = serde_json::Map::new();
+    if let Some(line) = line {
+        region.insert("startLine".to_string(), line.into());
+    }
+    if let Some(column) = column {
+        region.insert("startColumn".to_string(), column.into());
+    }
+    json!({
+        "ruleId": finding.class,
+        "level": "error",
+        "message": { "text": format!("{} (harness `{}`)", finding.message, harness.pretty_name) },
+        "locations": [{
+            "physicalLocation": {
+                "artifactLocation": artifact_location(&file, src_root),
+                "region": region,
+            }
+        }],
+        "properties": {
+            "harness": harness.pretty_name,
+            "verificationTime": result.result.runtime.as_secs_f32(),
+            "resources": result.result.resources.to_json(),
+        }
+    })
+}
+
+/// The location of a source file: relative to `%SRCROOT%` if the file is in the project, or an
+/// absolute `file` URI otherwise (e.g. for the standard library).
+fn artifact_location(file: &str, src_root: &Path) -> Value {
+    let path = Path::new(file);
+    let path = if path.is_relative() {
+        std::env::current_dir().map(|dir| dir.join(path)).unwrap_or_else(|_| path.to_path_buf())
+    } else {
+        path.to_path_buf()
+    };
+    match path.strip_prefix(src_root) {
+        Ok(relative) if relative.components().all(|c| matches!(c, Component::Normal(_))) => {
+            json!({ "uri": encode_path(relative), "uriBaseId": SRCROOT })
+        }
+        _ => json!({ "uri": file_uri(&path) }),
+    }
+}
+
+/// The `file` URI of an absolute path.
+fn file_uri(path: &Path) -> String {
+    let encoded = encode_path(path);
+    if encoded.starts_with('/') {
+        format!("file://{encoded}")
+    } else {
+        format!("file:///{encoded}")
+    }
+}
+
+/// Percent-encode the components of a path, separated by `/`.
+fn encode_path(path: &Path) -> String {
+    let mut uri = String::new();
+    for (i, component) in path.components().enumerate() {
+        match component {
+            Component::RootDir => {
+                uri.push('/');
+                continue;
+            }
+            Component::Prefix(prefix) => {
+                uri.push_str(&prefix.as_os_str().to_string_lossy().replace('\\', "/"));
+                continue;
+            }
+            _ => {}
+        }
+        if i > 0 && !uri.ends_with('/') {
+            uri.push('/');
+        }
+        for byte in component.as_os_str().to_string_lossy().bytes() {
+            if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+                uri.push(byte as char);
+            } else {
+                uri.push_str(&format!("%{byte:02X}"));
+            }
+        }
+    }
+    uri
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::call_cbmc::VerificationResult;
+    use crate::cbmc_output_parser::{CheckStatus, Property, PropertyId, SourceLocation};
+    use crate::metadata::tests::mock_proof_harness;
+
+    fn mock_property(file: &str, status: CheckStatus) -> Property {
+        Property {
+            description: "assertion failed: x > 0".to_string(),
+            property_id: PropertyId {
+                fn_name: Some("check".to_string()),
+                class: "assertion".to_string(),
+                id: 1,
+            },
+            source_location: SourceLocation {
+                column: Some("5".to_string()),
+                file: Some(file.to_string()),
+                function: None,
+                line: Some("10".to_string()),
+            },
+            status,
+            reach: None,
+            trace: None,
+        }
+    }
+
+    /// Check the properties of the report that are required by the SARIF 2.1.0 schema, and the
+    /// locations relative to `%SRCROOT%`.
+    #[test]
+    fn check_sarif_report() {
+        let root = Path::new("/work/my crate");
+        let success = mock_proof_harness("check_success", None, Some("krate"), None);
+        let failure = mock_proof_harness("check_failure", None, Some("krate"), None);
+        let results = [
+            HarnessResult {
+                harness: &success,
+                result: VerificationResult::from_properties(
+                    vec![mock_property("/work/my crate/src/lib.rs", CheckStatus::Success)],
+                    &success.attributes,
+                    Default::default(),
+                ),
+            },
+            HarnessResult {
+                harness: &failure,
+                result: VerificationResult::from_properties(
+                    vec![
+                        mock_property("/work/my crate/src/lib.rs", CheckStatus::Failure),
+                        mock_property("/rustc/library/core/src/num.rs", CheckStatus::Failure),
+                    ],
+                    &failure.attributes,
+                    Default::default(),
+                ),
+            },
+        ];
+        let report: Value = serde_json::from_str(&report(&results, root).unwrap()).unwrap();
+        assert_eq!(report["$schema"], SARIF_SCHEMA);
+        assert_eq!(report["version"], "2.1.0");
+        let runs = report["runs"].as_array().unwrap();
+        assert_eq!(runs.len(), 1);
+        let run = &runs[0];
+        assert_eq!(run["tool"]["driver"]["name"], "Kani");
+        assert_eq!(run["tool"]["driver"]["rules"], json!([{ "id": "assertion" }]));
+        assert_eq!(run["originalUriBaseIds"][SRCROOT]["uri"], "file:///work/my%20crate/");
+
+        let sarif_results = run["results"].as_array().unwrap();
+        assert_eq!(sarif_results.len(), 2);
+        for result in sarif_results {
+            assert_eq!(result["ruleId"], "assertion");
+            assert!(result["message"]["text"].is_string());
+            assert_eq!(result["locations"][0]["physicalLocation"]["region"]["startLine"], 10);
+        }
+        assert_eq!(
+            sarif_results[0]["locations"][0]["physicalLocation"]["artifactLocation"],
+            json!({ "uri": "src/lib.rs", "uriBaseId": SRCROOT })
+        );
+        assert_eq!(
+            sarif_results[1]["locations"][0]["physicalLocation"]["artifactLocation"],
+            json!({ "uri": "file:///rustc/library/core/src/num.rs" })
+        );
+    }
+}
@@ -0,0 +1,246 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Collect the resources consumed by CBMC while verifying a harness, so users can find out
+//! why a harness is slow.
+//!
+//! Most of the statistics are extracted from the messages that CBMC emits, while the peak
+//! memory usage is sampled from the operating system while CBMC runs.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::{Map, Value};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::cbmc_output_parser::ParserItem;
+
+/// How often the memory usage of CBMC is sampled.
+const SAMPLING_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The resources consumed by a single CBMC invocation.
+/// Each statistic is `None` if CBMC didn't report it (e.g. if it timed out).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResourceUsage {
+    /// The size of the program expression generated by symbolic execution, in steps.
+    pub program_steps: Option<u64>,
+    /// The number of verification conditions generated by symbolic execution.
+    pub vccs: Option<u64>,
+    /// The number of verification conditions left after simplification.
+    pub vccs_remaining: Option<u64>,
+    /// The number of variables of the largest formula sent to the solver.
+    pub solver_variables: Option<u64>,
+    /// The number of clauses of the largest formula sent to the solver.
+    pub solver_clauses: Option<u64>,
+    /// The time spent in symbolic execution.
+    pub symex_time: Option<Duration>,
+    /// The total time spent in the solver.
+    pub solver_time: Option<Duration>,
+    /// The peak resident set size of the CBMC process, in bytes.
+    pub peak_memory: Option<u64>,
+}
+
+static PROGRAM_STEPS: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^size of program expression: (\d+) steps").unwrap());
+static VCCS: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^Generated (\d+) VCC\(s\), (\d+) remaining").unwrap());
+static FORMULA_SIZE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(\d+) variables, (\d+) clauses").unwrap());
+static SYMEX_TIME: Lazy<Regex> = Lazy::new(|| Regex::new(r"^Runtime Symex: ([0-9.e-]+)s").unwrap());
+static SOLVER_TIME: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^Runtime Solver: ([0-9.e-]+)s").unwrap());
+
+impl ResourceUsage {
+    /// Extract the statistics reported in CBMC's messages.
+    ///
+    /// CBMC may invoke the solver more than once (e.g. to check cover properties), so we keep
+    /// the size of the largest formula and accumulate the time spent in the solver.
+    pub fn from_messages(items: &[ParserItem]) -> ResourceUsage {
+        let mut usage = ResourceUsage::default();
+        for item in items {
+            let ParserItem::Message { message_text, .. } = item else {
+                continue;
+            };
+            if let Some(captures) = PROGRAM_STEPS.captures(message_text) {
+                usage.program_steps = captures[1].parse().ok();
+            } else if let Some(captures) = VCCS.captures(message_text) {
+                usage.vccs = captures[1].parse().ok();
+                usage.vccs_remaining = captures[2].parse().ok();
+            } else if let Some(captures) = FORMULA_SIZE.captures(message_text) {
+                usage.solver_variables = usage.solver_variables.max(captures[1].parse().ok());
+                usage.solver_clauses = usage.solver_clauses.max(captures[2].parse().ok());
+            } else if let Some(captures) = SYMEX_TIME.captures(message_text) {
+                usage.symex_time = add(usage.symex_time, parse_duration(&captures[1]));
+            } else if let Some(captures) = SOLVER_TIME.captures(message_text) {
+                usage.solver_time = add(usage.solver_time, parse_duration(&captures[1]));
+            }
+        }
+        usage
+    }
+
+    /// The statistics that are known, as a JSON object used by the machine-readable reports.
+    /// Times are reported in seconds and memory in bytes.
+    pub fn to_json(&self) -> Map<String, Value> {
+        let counts = [
+            ("programSteps", self.program_steps),
+            ("vccs", self.vccs),
+            ("vccsRemaining", self.vccs_remaining),
+            ("solverVariables", self.solver_variables),
+            ("solverClauses", self.solver_clauses),
+            ("peakMemory", self.peak_memory),
+        ];
+        let times = [("symexTime", self.symex_time), ("solverTime", self.solver_time)];
+        let mut json = Map::new();
+        for (name, count) in counts {
+            if let Some(count) = count {
+                json.insert(name.to_string(), count.into());
+            }
+        }
+        for (name, time) in times {
+            if let Some(time) = time {
+                json.insert(name.to_string(), time.as_secs_f64().into());
+            }
+        }
+        json
+    }
+}
+
+/// Format a number of bytes in a human readable way, e.g. `12.5 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 { format!("{bytes} B") } else { format!("{value:.1} {}", UNITS[unit]) }
+}
+
+fn add(current: Option<Duration>, new: Option<Duration>) -> Option<Duration> {
+    match (current, new) {
+        (Some(current), Some(new)) => Some(current + new),
+        (current, new) => current.or(new),
+    }
+}
+
+fn parse_duration(secs: &str) -> Option<Duration> {
+    secs.parse::<f64>().ok().and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+}
+
+/// Samples the peak memory usage of a process in a background thread.
+///
+/// We rely on the `VmHWM` entry of `/proc/<pid>/status`, which is the high water mark of the
+/// process resident set size. So we only miss the memory allocated during the last sampling
+/// interval. On systems without `/proc`, no measurement is reported.
+pub struct PeakMemoryMonitor {
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<Option<u64>>,
+}
+
+impl PeakMemoryMonitor {
+    /// Start sampling the memory usage of the process with the given id.
+    pub fn start(pid: u32) -> PeakMemoryMonitor {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_thread = stop.clone();
+        let handle = std::thread::spawn(move || {
+            let status_file = format!("/proc/{pid}/status");
+            let mut peak = None;
+            while !stop_thread.load(Ordering::Relaxed) {
+                // The file disappears (or stops reporting memory) once the process exits.
+                let Some(high_water_mark) = std::fs::read_to_string(&status_file)
+                    .ok()
+                    .and_then(|status| parse_high_water_mark(&status))
+                else {
+                    break;
+                };
+                peak = peak.max(Some(high_water_mark));
+                std::thread::sleep(SAMPLING_INTERVAL);
+            }
+            peak
+        });
+        PeakMemoryMonitor { stop, handle }
+    }
+
+    /// Stop sampling and return the peak memory usage in bytes, if it could be measured.
+    pub fn stop(self) -> Option<u64> {
+        self.stop.store(true, Ordering::Relaxed);
+        self.handle.join().ok().flatten()
+    }
+}
+
+/// Parse the `VmHWM:    1234 kB` line of a `/proc/<pid>/status` file into bytes.
+fn parse_high_water_mark(status: &str) -> Option<u64> {
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kilobytes: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kilobytes * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(text: &str) -> ParserItem {
+        ParserItem::Message {
+            message_text: text.to_string(),
+            message_type: "STATUS-MESSAGE".to_string(),
+        }
+    }
+
+    #[test]
+    fn check_resource_usage_from_messages() {
+        let items = vec![
+            message("size of program expression: 142 steps"),
+            message("Runtime Symex: 0.25s"),
+            message("Generated 5 VCC(s), 3 remaining after simplification"),
+            message("493 variables, 1062 clauses"),
+            message("Runtime Solver: 0.5s"),
+            message("12 variables, 20 clauses"),
+            message("Runtime Solver: 0.25s"),
+        ];
+        let usage = ResourceUsage::from_messages(&items);
+        assert_eq!(
+            usage,
+            ResourceUsage {
+                program_steps: Some(142),
+                vccs: Some(5),
+                vccs_remaining: Some(3),
+                solver_variables: Some(493),
+                solver_clauses: Some(1062),
+                symex_time: Some(Duration::from_millis(250)),
+                solver_time: Some(Duration::from_millis(750)),
+                peak_memory: None,
+            }
+        );
+    }
+
+    #[test]
+    fn check_parse_high_water_mark() {
+        let status = "Name:\tcbmc\nVmPeak:\t  20000 kB\nVmHWM:\t    1234 kB\nVmRSS:\t    1000 kB\n";
+        assert_eq!(parse_high_water_mark(status), Some(1234 * 1024));
+        assert_eq!(parse_high_water_mark("Name:\tcbmc\n"), None);
+    }
+
+    #[test]
+    fn check_resource_usage_to_json() {
+        let usage = ResourceUsage {
+            program_steps: Some(142),
+            solver_time: Some(Duration::from_millis(500)),
+            ..Default::default()
+        };
+        assert_eq!(
+            Value::Object(usage.to_json()),
+            serde_json::json!({ "programSteps": 142, "solverTime": 0.5 })
+        );
+        assert!(ResourceUsage::default().to_json().is_empty());
+    }
+
+    #[test]
+    fn check_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(300 * 1024 * 1024), "300.0 MiB");
+    }
+}
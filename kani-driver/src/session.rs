@@ -39,6 +39,9 @@ pub struct KaniSession {
     /// Invariant: this field is_some() iff the autoharness subcommand is enabled.
     pub autoharness_compiler_flags: Option<Vec<String>>,
 
+    /// The mutate-specific compiler arguments, which select the mutant to build.
+    pub mutation_compiler_flags: Vec<String>,
+
     /// The location we found the 'kani_rustc' command
     pub kani_compiler: PathBuf,
     /// The location we found 'kani_lib.c'
@@ -69,6 +72,7 @@ pub fn new(args: VerificationArgs) -> Result<Self> {
         Ok(KaniSession {
             args,
             autoharness_compiler_flags: None,
+            mutation_compiler_flags: vec![],
             kani_compiler: install.kani_compiler()?,
             kani_lib_c: install.kani_lib_c()?,
             temporaries: Mutex::new(vec![]),
@@ -0,0 +1,231 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Persistent cache of verification results.
+//!
+//! Running CBMC is by far the most expensive step of verifying a harness, and its result only
+//! depends on the specialized goto binary and on the flags passed to CBMC. So we store the
+//! properties reported for each harness in the target directory, keyed on a hash of both.
+//! Subsequent runs that produce the same goto binary and flags can skip CBMC altogether.
+
+use anyhow::Result;
+use kani_metadata::HarnessMetadata;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::{self, File};
+use std::io::{BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use tempfile::NamedTempFile;
+
+use crate::args::OutputFormat;
+use crate::call_cbmc::VerificationResult;
+use crate::cbmc_output_parser::{CheckStatus, Property, PropertyId, SourceLocation};
+use crate::session::KaniSession;
+use crate::version::KANI_VERSION;
+
+/// Name of the directory (inside the project output directory) where results are stored.
+const CACHE_DIR_NAME: &str = "kani-verification-cache";
+
+/// Placeholder used instead of the goto binary path when hashing the CBMC flags. The binary
+/// content is hashed separately, so its location shouldn't affect the key.
+const GOTO_BINARY_PLACEHOLDER: &str = "<goto-binary>";
+
+/// A handle to the verification cache of a project.
+pub struct VerificationCache {
+    dir: PathBuf,
+}
+
+/// The content of a cache entry.
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    /// The harness that produced this entry. Only used to make entries easier to inspect.
+    harness: String,
+    properties: Vec<CachedProperty>,
+}
+
+/// The serializable version of a CBMC [`Property`].
+///
+/// We cannot serialize properties directly since their `property_id` is only deserialized from
+/// CBMC's `<function>.<class>.<counter>` format. Traces are never stored, since the flows that
+/// request them don't use the cache.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedProperty {
+    description: String,
+    fn_name: Option<String>,
+    class: String,
+    id: u32,
+    source_location: SourceLocation,
+    status: CheckStatus,
+    reach: Option<CheckStatus>,
+}
+
+impl From<&Property> for CachedProperty {
+    fn from(property: &Property) -> Self {
+        CachedProperty {
+            description: property.description.clone(),
+            fn_name: property.property_id.fn_name.clone(),
+            class: property.property_id.class.clone(),
+            id: property.property_id.id,
+            source_location: property.source_location.clone(),
+            status: property.status,
+            reach: property.reach,
+        }
+    }
+}
+
+impl From<CachedProperty> for Property {
+    fn from(cached: CachedProperty) -> Self {
+        Property {
+            description: cached.description,
+            property_id: PropertyId { fn_name: cached.fn_name, class: cached.class, id: cached.id },
+            source_location: cached.source_location,
+            status: cached.status,
+            reach: cached.reach,
+            trace: None,
+        }
+    }
+}
+
+impl VerificationCache {
+    /// Create a handle to the cache stored in the given output directory.
+    pub fn new(outdir: &Path) -> Self {
+        VerificationCache { dir: outdir.join(CACHE_DIR_NAME) }
+    }
+
+    /// Path to the file that stores the entry for the given key.
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(key).with_extension("json")
+    }
+
+    /// Retrieve the result stored for the given key, if any.
+    ///
+    /// Entries that cannot be read or parsed are treated as missing, so they simply get
+    /// overwritten after CBMC runs again.
+    pub fn load(&self, key: &str, harness: &HarnessMetadata) -> Option<VerificationResult> {
+        let start_time = Instant::now();
+        let file = File::open(self.entry_path(key)).ok()?;
+        let entry: CacheEntry = serde_json::from_reader(BufReader::new(file)).ok()?;
+        let properties = entry.properties.into_iter().map(Property::from).collect();
+        Some(VerificationResult::from_properties(
+            properties,
+            &harness.attributes,
+            start_time.elapsed(),
+        ))
+    }
+
+    /// Store the given result under the given key.
+    ///
+    /// Only results that include the properties reported by CBMC are stored. Timeouts and CBMC
+    /// crashes are never cached, since they may not be reproducible.
+    pub fn store(
+        &self,
+        key: &str,
+        harness: &HarnessMetadata,
+        result: &VerificationResult,
+    ) -> Result<()> {
+        let Ok(properties) = &result.results else {
+            return Ok(());
+        };
+        let entry = CacheEntry {
+            harness: harness.pretty_name.clone(),
+            properties: properties.iter().map(CachedProperty::from).collect(),
+        };
+        fs::create_dir_all(&self.dir)?;
+        // Write to a temporary file first, so concurrent runs never observe a partial entry.
+        let mut tmp_file = NamedTempFile::new_in(&self.dir)?;
+        tmp_file.write_all(&serde_json::to_vec(&entry)?)?;
+        tmp_file.persist(self.entry_path(key))?;
+        Ok(())
+    }
+}
+
+impl KaniSession {
+    /// Whether results should be read from and written to the verification cache.
+    ///
+    /// The concrete playback flow and `--trace` need the counterexample traces, which are not
+    /// cached, and the old output format doesn't produce any property to be stored.
+    pub fn use_verification_cache(&self) -> bool {
+        self.args.verification_cache
+            && self.args.concrete_playback.is_none()
+            && !self.args.trace
+            && self.args.output_format != OutputFormat::Old
+    }
+
+    /// Compute the cache key of a harness: a hash of the Kani version, the CBMC flags used to
+    /// verify the harness and the content of its specialized goto binary.
+    pub fn verification_cache_key(
+        &self,
+        binary: &Path,
+        harness: &HarnessMetadata,
+    ) -> Result<String> {
+        let mut hasher = Sha256::new();
+        hasher.update(KANI_VERSION.as_bytes());
+        for flag in self.cbmc_flags(Path::new(GOTO_BINARY_PLACEHOLDER), harness)? {
+            hasher.update([0]);
+            hasher.update(flag.as_encoded_bytes());
+        }
+        hasher.update([0]);
+        hasher.update(fs::read(binary)?);
+        Ok(hasher.finalize().iter().map(|byte| format!("{byte:02x}")).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::call_cbmc::VerificationStatus;
+    use crate::metadata::tests::mock_proof_harness;
+
+    fn mock_harness(should_panic: bool) -> HarnessMetadata {
+        let mut harness = mock_proof_harness("check_cache", None, None, None);
+        harness.attributes.should_panic = should_panic;
+        harness
+    }
+
+    fn mock_property(status: CheckStatus) -> Property {
+        Property {
+            description: "assertion failed: x > 0".to_string(),
+            property_id: PropertyId {
+                fn_name: Some("check_cache".to_string()),
+                class: "assertion".to_string(),
+                id: 1,
+            },
+            source_location: SourceLocation {
+                column: Some("5".to_string()),
+                file: Some("cache.rs".to_string()),
+                function: Some("check_cache".to_string()),
+                line: Some("2".to_string()),
+            },
+            status,
+            reach: None,
+            trace: None,
+        }
+    }
+
+    #[test]
+    fn check_cache_round_trip() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let cache = VerificationCache::new(tmp_dir.path());
+        let harness = mock_harness(false);
+        assert!(cache.load("key", &harness).is_none());
+
+        let result = VerificationResult::from_properties(
+            vec![mock_property(CheckStatus::Failure)],
+            &harness.attributes,
+            Default::default(),
+        );
+        cache.store("key", &harness, &result).unwrap();
+
+        let cached = cache.load("key", &harness).unwrap();
+        assert_eq!(cached.status, VerificationStatus::Failure);
+        let properties = cached.results.unwrap();
+        assert_eq!(properties.len(), 1);
+        assert_eq!(properties[0].property_name(), "check_cache.assertion.1");
+        assert_eq!(properties[0].status, CheckStatus::Failure);
+
+        // The outcome is recomputed, so the same entry can serve a `should_panic` harness.
+        let cached = cache.load("key", &mock_harness(true)).unwrap();
+        assert_eq!(cached.status, VerificationStatus::Success);
+    }
+}
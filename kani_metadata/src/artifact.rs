@@ -19,12 +19,17 @@ pub enum ArtifactType {
     SymTabGoto,
     /// A `json` file that has a map of mangled name to pretty name for goto types.
     TypeMap,
+    /// A `json` file that maps the goto type of each enum to the names of its variants, indexed
+    /// by their discriminant.
+    EnumMap,
     /// A `json` file that has information about the function pointer restrictions derived from
     /// vtable generation.
     VTableRestriction,
     /// A `json` file that stores the name to prettyName mapping for symbols
     /// (used to demangle names from the C dump).
     PrettyNameMap,
+    /// The LLBC file generated by the Lean backend.
+    Llbc,
 }
 
 impl ArtifactType {
@@ -35,8 +40,10 @@ const fn extension(&self) -> &'static str {
             ArtifactType::SymTab => "symtab.json",
             ArtifactType::SymTabGoto => "symtab.out",
             ArtifactType::TypeMap => "type_map.json",
+            ArtifactType::EnumMap => "enum_map.json",
             ArtifactType::VTableRestriction => "restrictions.json",
             ArtifactType::PrettyNameMap => "pretty_name_map.json",
+            ArtifactType::Llbc => "llbc",
         }
     }
 }
@@ -55,7 +62,7 @@ pub fn convert_type(path: &Path, from: ArtifactType, to: ArtifactType) -> PathBu
     // Strip current extensions and replace by the new one.
     match from {
         // Artifact types that has only one extension.
-        ArtifactType::Goto => {
+        ArtifactType::Goto | ArtifactType::Llbc => {
             result.set_extension(to);
         }
         // Artifact types that has two extensions.
@@ -63,6 +70,7 @@ pub fn convert_type(path: &Path, from: ArtifactType, to: ArtifactType) -> PathBu
         | ArtifactType::SymTab
         | ArtifactType::SymTabGoto
         | ArtifactType::TypeMap
+        | ArtifactType::EnumMap
         | ArtifactType::VTableRestriction
         | ArtifactType::PrettyNameMap => {
             result.set_extension("");
@@ -42,6 +42,47 @@ pub struct HarnessMetadata {
     pub has_loop_contracts: bool,
     /// If the harness was automatically generated or manually written.
     pub is_automatically_generated: bool,
+    /// The items reachable from this harness that are defined in local source files.
+    /// This is only collected if requested by the driver, e.g. to select the harnesses affected
+    /// by a code change.
+    #[serde(default)]
+    pub reachable_items: Vec<ReachableItem>,
+    /// The mutants of the functions reachable from this harness.
+    /// This is only collected by the `mutate` subcommand.
+    #[serde(default)]
+    pub mutants: Vec<Mutant>,
+}
+
+/// An item that is reachable from a harness, with enough information to detect whether it changed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReachableItem {
+    /// The user-friendly name of the item.
+    pub name: String,
+    /// The mangled name of the item, which uniquely identifies it across compilations.
+    pub mangled_name: String,
+    /// The canonical path to the file where the item is defined.
+    pub file: String,
+    /// The line in that file where the item body begins.
+    pub start_line: usize,
+    /// The line in that file where the item body ends.
+    pub end_line: usize,
+    /// A hash of the item MIR body.
+    pub fingerprint: String,
+}
+
+/// A mutation of a function reachable from a harness.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Mutant {
+    /// The identifier used to select this mutant, in the format `<mangled_name>#<index>`.
+    pub id: String,
+    /// The user-friendly name of the mutated function.
+    pub function: String,
+    /// A description of the mutation.
+    pub description: String,
+    /// The file where the mutation was applied.
+    pub file: String,
+    /// The line in that file where the mutation was applied.
+    pub line: usize,
 }
 
 /// The attributes added by the user to control how a harness is executed.
@@ -51,6 +92,8 @@ pub struct HarnessAttributes {
     pub kind: HarnessKind,
     /// Whether the harness is expected to panic or not.
     pub should_panic: bool,
+    /// The message that every panic is expected to contain, if the harness is expected to panic.
+    pub expected_panic: Option<String>,
     /// Optional data to store solver.
     pub solver: Option<CbmcSolver>,
     /// Optional data to store unwind value.
@@ -80,6 +123,7 @@ pub fn new(kind: HarnessKind) -> HarnessAttributes {
         HarnessAttributes {
             kind,
             should_panic: false,
+            expected_panic: None,
             solver: None,
             unwind_value: None,
             stubs: vec![],
@@ -112,6 +156,13 @@ pub fn get_harness_name_unqualified(&self) -> &str {
             &self.pretty_name
         }
     }
+
+    /// The name of the crate in the LLBC file of this harness, which Aeneas uses as the Lean
+    /// namespace of the generated code. Each harness is translated separately, so each one gets
+    /// its own namespace to keep the declarations of different harnesses apart.
+    pub fn lean_namespace(&self) -> String {
+        format!("{}_{}", self.crate_name, self.pretty_name.replace("::", "_"))
+    }
 }
 
 /// Search for a proof harness with a particular name.
@@ -71,6 +71,9 @@ pub enum AutoHarnessSkipReason {
     /// The function doesn't match the user's provided filters.
     #[strum(serialize = "Did not match provided filters")]
     UserFilter,
+    /// The function is generic, and none of the provided type substitutions satisfy its trait bounds.
+    #[strum(serialize = "No provided generic type satisfies the function's trait bounds")]
+    NoValidInstantiation,
 }
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Ord)]
 pub struct ContractedFunction {
@@ -80,6 +83,20 @@ pub struct ContractedFunction {
     pub file: String,
     /// The pretty names of the proof harnesses (`#[kani::proof_for_contract]`) for this function
     pub harnesses: Vec<String>,
+    /// The signature and clauses of the contract, only collected for the Lean backend.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spec: Option<ContractSpec>,
+}
+
+/// What the Lean backend needs to state the theorem of a contract.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Ord)]
+pub struct ContractSpec {
+    /// The (name, type) pairs of the function's arguments.
+    pub args: Vec<(String, String)>,
+    /// The text of each `#[kani::requires]` clause.
+    pub requires: Vec<String>,
+    /// The text of each `#[kani::ensures]` clause.
+    pub ensures: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,3 +60,46 @@ fn bounded_any<const N: usize>() -> Self {
         hash_set
     }
 }
+
+impl<K, V> BoundedArbitrary for std::collections::BTreeMap<K, V>
+where
+    K: Arbitrary + Ord,
+    V: Arbitrary,
+{
+    fn bounded_any<const N: usize>() -> Self {
+        let mut btree_map = std::collections::BTreeMap::new();
+        for _ in 0..N {
+            if bool::any() {
+                btree_map.insert(K::any(), V::any());
+            }
+        }
+        btree_map
+    }
+}
+
+impl<V> BoundedArbitrary for std::collections::BTreeSet<V>
+where
+    V: Arbitrary + Ord,
+{
+    fn bounded_any<const N: usize>() -> Self {
+        let mut btree_set = std::collections::BTreeSet::new();
+        for _ in 0..N {
+            if bool::any() {
+                btree_set.insert(V::any());
+            }
+        }
+        btree_set
+    }
+}
+
+impl<T: Arbitrary> BoundedArbitrary for std::collections::VecDeque<T> {
+    fn bounded_any<const N: usize>() -> Self {
+        Vec::<T>::bounded_any::<N>().into()
+    }
+}
+
+impl<T: Arbitrary + Ord> BoundedArbitrary for std::collections::BinaryHeap<T> {
+    fn bounded_any<const N: usize>() -> Self {
+        Vec::<T>::bounded_any::<N>().into()
+    }
+}
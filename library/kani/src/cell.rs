@@ -0,0 +1,81 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module contains a model of `std::cell::UnsafeCell` that detects data races between the
+//! threads of [`crate::thread`].
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The access state of an [`UnsafeCell`] that is being written.
+const WRITING: usize = usize::MAX;
+
+/// A replacement for [`std::cell::UnsafeCell`] that checks that the accesses to its value don't
+/// race, i.e., that no access happens while the value is being written.
+///
+/// The value can only be accessed through [`UnsafeCell::with`] and [`UnsafeCell::with_mut`],
+/// like in [loom](https://docs.rs/loom). Each call is an access that lasts until the closure
+/// returns.
+#[derive(Debug)]
+pub struct UnsafeCell<T: ?Sized> {
+    /// The number of ongoing reads, or [`WRITING`].
+    access: AtomicUsize,
+    data: std::cell::UnsafeCell<T>,
+}
+
+impl<T> UnsafeCell<T> {
+    /// Constructs a new instance of `UnsafeCell` which will wrap the specified value.
+    #[crate::unstable(
+        feature = "concurrency",
+        issue = "none",
+        reason = "experimental thread model"
+    )]
+    pub const fn new(data: T) -> UnsafeCell<T> {
+        UnsafeCell { access: AtomicUsize::new(0), data: std::cell::UnsafeCell::new(data) }
+    }
+
+    /// Unwraps the value.
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+impl<T: ?Sized> UnsafeCell<T> {
+    /// Get an immutable pointer to the wrapped value.
+    ///
+    /// Kani reports a data race if the value is being written by another thread.
+    pub fn with<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(*const T) -> R,
+    {
+        let previous = self.access.fetch_add(1, Ordering::SeqCst);
+        crate::assert(previous != WRITING, "data race: read during a write to an `UnsafeCell`");
+        let result = f(self.data.get());
+        self.access.fetch_sub(1, Ordering::SeqCst);
+        result
+    }
+
+    /// Get a mutable pointer to the wrapped value.
+    ///
+    /// Kani reports a data race if the value is being read or written by another thread.
+    pub fn with_mut<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(*mut T) -> R,
+    {
+        let previous = self.access.swap(WRITING, Ordering::SeqCst);
+        crate::assert(previous == 0, "data race: write during an access to an `UnsafeCell`");
+        let result = f(self.data.get());
+        self.access.store(0, Ordering::SeqCst);
+        result
+    }
+
+    /// Returns a mutable reference to the underlying data, which cannot race.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+}
+
+impl<T: Default> Default for UnsafeCell<T> {
+    fn default() -> UnsafeCell<T> {
+        UnsafeCell::new(T::default())
+    }
+}
@@ -3,15 +3,30 @@
 
 //! Helper code for concrete playback.
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::panic::{AssertUnwindSafe, catch_unwind, panic_any, resume_unwind, set_hook, take_hook};
+use std::path::PathBuf;
+use std::sync::Once;
 
 thread_local! {
     /// thread_local! gives us a separate CONCRETE_VALS instance for each thread.
     /// This allows us to run concrete playback unit tests in parallel.
     /// RefCell is necessary for mut statics.
     static CONCRETE_VALS: RefCell<Vec<Vec<u8>>> = RefCell::new(Vec::new());
+
+    /// The bytes left in the input played back by `fuzz_playback_run`, if any.
+    static FUZZ_INPUT: RefCell<Option<VecDeque<u8>>> = const { RefCell::new(None) };
+
+    /// The id that the compiler assigned to the harness being played back, which selects the
+    /// stubs of this harness. Zero means that no harness with stubs is being played back.
+    static PLAYBACK_HARNESS: Cell<u32> = const { Cell::new(0) };
 }
 
+/// The panic payload used to stop the playback of a fuzzer input that is not a valid input of the
+/// proof harness.
+struct InvalidInput;
+
 /// This function sets concrete values and plays back the user's proof harness.
 pub fn concrete_playback_run<F: Fn()>(mut local_concrete_vals: Vec<Vec<u8>>, proof_harness: F) {
     // Det vals in the user test case should be in the same order as the order of kani::any() calls.
@@ -40,6 +55,356 @@ pub fn concrete_playback_run<F: Fn()>(mut local_concrete_vals: Vec<Vec<u8>>, pro
     });
 }
 
+/// This function plays back the user's proof harness with the concrete values of every fixture
+/// in `dir`. Fixtures are the JSON files generated by `--concrete-playback=fixture`.
+///
+/// Every fixture is played back, even if an earlier one fails, and the failing fixtures are
+/// reported at the end.
+pub fn concrete_playback_run_fixtures<F: Fn()>(dir: &str, proof_harness: F) {
+    let mut fixtures: Vec<PathBuf> = std::fs::read_dir(dir)
+        .unwrap_or_else(|err| panic!("Failed to read the fixtures in `{dir}`: {err}"))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    fixtures.sort();
+    assert!(!fixtures.is_empty(), "No concrete playback fixtures found in `{dir}`");
+    let failures: Vec<_> = fixtures
+        .iter()
+        .filter(|fixture| {
+            let content = std::fs::read_to_string(fixture).unwrap();
+            let concrete_vals = parse_fixture_values(&content)
+                .unwrap_or_else(|| panic!("Invalid fixture `{}`", fixture.display()));
+            println!("Playing back fixture `{}`", fixture.display());
+            catch_unwind(AssertUnwindSafe(|| concrete_playback_run(concrete_vals, &proof_harness)))
+                .is_err()
+        })
+        .map(|fixture| fixture.display().to_string())
+        .collect();
+    assert!(failures.is_empty(), "Concrete playback failed for the fixtures {failures:?}");
+}
+
+/// Play back the harness with the id `harness_id` with [concrete_playback_run].
+///
+/// The compiler replaces the calls to [concrete_playback_run] of the harnesses that use stubs by
+/// calls to this function, and the calls to a stubbed function by a dispatch on
+/// [playback_harness].
+#[doc(hidden)]
+pub fn concrete_playback_run_harness<F: Fn()>(
+    local_concrete_vals: Vec<Vec<u8>>,
+    proof_harness: F,
+    harness_id: u32,
+) {
+    with_playback_harness(harness_id, || concrete_playback_run(local_concrete_vals, proof_harness))
+}
+
+/// Play back the harness with the id `harness_id` with [concrete_playback_run_fixtures].
+#[doc(hidden)]
+pub fn concrete_playback_run_fixtures_harness<F: Fn()>(
+    dir: &str,
+    proof_harness: F,
+    harness_id: u32,
+) {
+    with_playback_harness(harness_id, || concrete_playback_run_fixtures(dir, proof_harness))
+}
+
+/// The id of the harness being played back by the current thread.
+#[doc(hidden)]
+pub fn playback_harness() -> u32 {
+    PLAYBACK_HARNESS.with(Cell::get)
+}
+
+/// Run `playback` with `harness_id` as the harness being played back.
+fn with_playback_harness(harness_id: u32, playback: impl FnOnce()) {
+    /// Restore the previous harness, even if the playback fails.
+    struct Restore(u32);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            PLAYBACK_HARNESS.with(|harness| harness.set(self.0));
+        }
+    }
+    let _restore = Restore(PLAYBACK_HARNESS.with(|harness| harness.replace(harness_id)));
+    playback();
+}
+
+/// This function plays back the user's proof harness with values read from an arbitrary byte
+/// slice, so the harness can be used as a fuzz target. Each `kani::any()` call consumes the bytes
+/// of its value from the start of `data`, which is the format of the corpus exported by
+/// `kani playback --export-corpus`.
+///
+/// Inputs that are too short or that violate a `kani::assume` are ignored, since a fuzzer is
+/// expected to generate them.
+pub fn fuzz_playback_run<F: Fn()>(data: &[u8], proof_harness: F) {
+    static IGNORE_INVALID_INPUTS: Once = Once::new();
+    IGNORE_INVALID_INPUTS.call_once(|| {
+        // Keep the hook installed by the fuzzer, which usually aborts, for any other panic.
+        let hook = take_hook();
+        set_hook(Box::new(move |info| {
+            if !info.payload().is::<InvalidInput>() {
+                hook(info)
+            }
+        }));
+    });
+    FUZZ_INPUT.with(|input| *input.borrow_mut() = Some(data.iter().copied().collect()));
+    let result = catch_unwind(AssertUnwindSafe(proof_harness));
+    FUZZ_INPUT.with(|input| *input.borrow_mut() = None);
+    if let Err(payload) = result
+        && !payload.is::<InvalidInput>()
+    {
+        resume_unwind(payload)
+    }
+}
+
+/// Concrete playback implementation of a `kani::assume` that does not hold.
+///
+/// Fuzzer inputs are discarded, but the values of a concrete playback test should satisfy every
+/// assumption.
+pub(crate) fn assume_failed() -> ! {
+    if FUZZ_INPUT.with(|input| input.borrow().is_some()) {
+        panic_any(InvalidInput)
+    }
+    panic!("`kani::assume` should always hold")
+}
+
+/// Parse the `values` of a fixture, which are stored as a JSON array of byte arrays.
+///
+/// The fixture must be a valid JSON object. Its other fields are ignored.
+fn parse_fixture_values(fixture: &str) -> Option<Vec<Vec<u8>>> {
+    let mut parser = JsonParser { input: fixture, pos: 0 };
+    let json = parser.parse_value()?;
+    parser.skip_whitespace();
+    if parser.pos != fixture.len() {
+        return None;
+    }
+    let Json::Object(fields) = json else { return None };
+    let (_, values) = fields.iter().rev().find(|(key, _)| key == "values")?;
+    let Json::Array(values) = values else { return None };
+    values
+        .iter()
+        .map(|value| match value {
+            Json::Array(bytes) => bytes
+                .iter()
+                .map(|byte| match byte {
+                    Json::Number(number) => number.parse().ok(),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A JSON value. Numbers are kept as written, so they can be parsed to the expected type.
+#[derive(Debug, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+/// A parser for the JSON files written by Kani. The Kani library doesn't depend on a JSON
+/// library, since it is linked to the playback tests of the user's crate.
+struct JsonParser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl JsonParser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| matches!(c, ' ' | '\t' | '\n' | '\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Option<()> {
+        self.skip_whitespace();
+        (self.bump()? == expected).then_some(())
+    }
+
+    fn parse_value(&mut self) -> Option<Json> {
+        self.skip_whitespace();
+        match self.peek()? {
+            '{' => self.parse_object(),
+            '[' => self.parse_array(),
+            '"' => self.parse_string().map(Json::String),
+            '-' | '0'..='9' => self.parse_number(),
+            _ => self.parse_literal(),
+        }
+    }
+
+    fn parse_object(&mut self) -> Option<Json> {
+        self.expect('{')?;
+        let mut fields = vec![];
+        self.skip_whitespace();
+        if self.peek()? == '}' {
+            self.pos += 1;
+            return Some(Json::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.expect(':')?;
+            fields.push((key, self.parse_value()?));
+            self.skip_whitespace();
+            match self.bump()? {
+                ',' => {}
+                '}' => return Some(Json::Object(fields)),
+                _ => return None,
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Option<Json> {
+        self.expect('[')?;
+        let mut elements = vec![];
+        self.skip_whitespace();
+        if self.peek()? == ']' {
+            self.pos += 1;
+            return Some(Json::Array(elements));
+        }
+        loop {
+            elements.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.bump()? {
+                ',' => {}
+                ']' => return Some(Json::Array(elements)),
+                _ => return None,
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Option<String> {
+        if self.bump()? != '"' {
+            return None;
+        }
+        let mut string = String::new();
+        loop {
+            match self.bump()? {
+                '"' => return Some(string),
+                '\\' => match self.bump()? {
+                    '"' => string.push('"'),
+                    '\\' => string.push('\\'),
+                    '/' => string.push('/'),
+                    'b' => string.push('\u{8}'),
+                    'f' => string.push('\u{c}'),
+                    'n' => string.push('\n'),
+                    'r' => string.push('\r'),
+                    't' => string.push('\t'),
+                    'u' => {
+                        let high = self.parse_hex()?;
+                        let code = if (0xD800..0xDC00).contains(&high) {
+                            // A surrogate pair.
+                            if self.bump()? != '\\' || self.bump()? != 'u' {
+                                return None;
+                            }
+                            let low = self.parse_hex()?;
+                            0x10000 + ((high - 0xD800) << 10) + low.checked_sub(0xDC00)?
+                        } else {
+                            high
+                        };
+                        string.push(char::from_u32(code)?);
+                    }
+                    _ => return None,
+                },
+                c if c.is_control() => return None,
+                c => string.push(c),
+            }
+        }
+    }
+
+    fn parse_hex(&mut self) -> Option<u32> {
+        let digits = self.input.get(self.pos..self.pos + 4)?;
+        self.pos += 4;
+        u32::from_str_radix(digits, 16).ok()
+    }
+
+    fn parse_number(&mut self) -> Option<Json> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| matches!(c, '-' | '+' | '.' | 'e' | 'E' | '0'..='9')) {
+            self.pos += 1;
+        }
+        let number = &self.input[start..self.pos];
+        number.parse::<f64>().ok()?;
+        Some(Json::Number(number.to_string()))
+    }
+
+    fn parse_literal(&mut self) -> Option<Json> {
+        let rest = &self.input[self.pos..];
+        let (literal, value) =
+            [("null", Json::Null), ("true", Json::Bool(true)), ("false", Json::Bool(false))]
+                .into_iter()
+                .find(|(literal, _)| rest.starts_with(literal))?;
+        self.pos += literal.len();
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_fixture() {
+        let fixture = r#"{
+  "harness": "check_values",
+  "check": "assertion",
+  "description": "assertion failed: \"[x, y]\" != \"{}\", values: [1, 2]",
+  "location": null,
+  "values": [
+    [
+      101
+    ],
+    [],
+    [0, 255]
+  ]
+}"#;
+        assert_eq!(parse_fixture_values(fixture), Some(vec![vec![101], vec![], vec![0, 255]]));
+    }
+
+    #[test]
+    fn test_parse_fixture_ignores_other_fields() {
+        let fixture = r#"{"values": [[1]], "extra": {"values": [[2]], "flag": true}}"#;
+        assert_eq!(parse_fixture_values(fixture), Some(vec![vec![1]]));
+    }
+
+    #[test]
+    fn test_parse_fixture_escapes() {
+        let mut parser = JsonParser { input: r#""a\"b\\c\u00e9\ud83d\ude00\n""#, pos: 0 };
+        assert_eq!(parser.parse_value(), Some(Json::String("a\"b\\c\u{e9}\u{1f600}\n".into())));
+    }
+
+    #[test]
+    fn test_parse_invalid_fixture() {
+        for fixture in [
+            "",
+            "[[1]]",
+            r#"{"harness": "check"}"#,
+            r#"{"values": [[256]]}"#,
+            r#"{"values": [[-1]]}"#,
+            r#"{"values": [[1.5]]}"#,
+            r#"{"values": [1]}"#,
+            r#"{"values": [[1]]"#,
+            r#"{"values": [[1]]} trailing"#,
+            r#"{"values": [[1],]}"#,
+            r#"{"values" [[1]]}"#,
+        ] {
+            assert_eq!(parse_fixture_values(fixture), None, "{fixture}");
+        }
+    }
+}
+
 /// Iterate over `any_raw_internal` since CBMC produces assignment per element.
 pub(crate) unsafe fn any_raw_array<T: Copy, const N: usize>() -> [T; N] {
     unsafe { [(); N].map(|_| crate::any_raw_internal::<T>()) }
@@ -54,6 +419,16 @@ pub fn concrete_playback_run<F: Fn()>(mut local_concrete_vals: Vec<Vec<u8>>, pro
 /// The semantics of this function require that SIZE_T equals the size of type T.
 pub(crate) unsafe fn any_raw_internal<T: Copy>() -> T {
     let sz = size_of::<T>();
+    let fuzz_val: Option<Option<Vec<u8>>> = FUZZ_INPUT.with(|input| {
+        let mut input = input.borrow_mut();
+        let input = input.as_mut()?;
+        Some((input.len() >= sz).then(|| input.drain(..sz).collect()))
+    });
+    match fuzz_val {
+        Some(Some(val)) => return unsafe { std::ptr::read_unaligned(val.as_ptr() as *const T) },
+        Some(None) => panic_any(InvalidInput),
+        None => {}
+    }
     let mut next_concrete_val: Vec<u8> = Vec::new();
     CONCRETE_VALS.with(|glob_concrete_vals| {
         let mut_ref_glob_concrete_vals = &mut *glob_concrete_vals.borrow_mut();
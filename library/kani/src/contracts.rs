@@ -225,6 +225,25 @@
 //! [#2823](https://github.com/model-checking/kani/issues/2823) to enable
 //! opt-out of inductive verification.
 //!
+//! Inductive verification only proves partial correctness, i.e., that the
+//! contract holds if the function terminates. A recursive function may also
+//! declare a termination measure with the [`decreases`](macro@decreases)
+//! attribute:
+//!
+//! ```
+//! #[kani::ensures(|result| *result == n)]
+//! #[kani::decreases(n)]
+//! #[kani::recursion]
+//! fn count(n: u64) -> u64 {
+//!     if n == 0 { 0 } else { 1 + count(n - 1) }
+//! }
+//! ```
+//!
+//! Kani then asserts that every recursive call has a strictly smaller
+//! measure than the call being verified, which proves that the function
+//! terminates. The measure may be a tuple, which is compared
+//! lexicographically. Mutual recursion is not supported.
+//!
 //! ## Write Sets
 //!
 //! The [`modifies`](macro@modifies) attribute is used to describe which
@@ -275,4 +294,4 @@
 //! Here, the value stored in `a` is precomputed and remembered after the function
 //! is called, even though the contents of `a` changed during the function execution.
 //!
-pub use super::{ensures, modifies, proof_for_contract, requires, stub_verified};
+pub use super::{decreases, ensures, modifies, proof_for_contract, requires, stub_verified};
@@ -4,8 +4,14 @@
 //! This module contains functions to work with futures (and async/.await) in Kani.
 
 use std::{
+    cell::UnsafeCell,
+    fmt,
     future::Future,
     pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
     task::{Context, RawWaker, RawWakerVTable, Waker},
 };
 
@@ -68,6 +74,7 @@ pub enum SchedulingAssumption {
 /// Nondeterministic scheduling strategies can be very slow to verify because they require Kani to check a large number of permutations of tasks.
 /// So if you want to verify a harness that uses `spawn`, but don't care about concurrency issues, you can simply use a deterministic scheduling strategy,
 /// such as [`RoundRobin`], which polls each task in turn.
+/// To verify the possible interleavings of the tasks, use [`NondetScheduling`] instead.
 ///
 /// Finally, you have the option of providing your own scheduling strategy by implementing this trait.
 /// This can be useful, for example, if you want to verify that things work correctly for a very specific task ordering.
@@ -104,6 +111,52 @@ fn pick_task(&mut self, num_tasks: usize) -> (usize, SchedulingAssumption) {
     }
 }
 
+/// Picks the next task nondeterministically, so Kani verifies every interleaving of the tasks
+/// with at most `max_context_switches` context switches.
+///
+/// A context switch happens when the picked task is not the one that was polled last.
+/// Once all the context switches are used, the remaining tasks are polled in round-robin order,
+/// so that every task can still complete.
+///
+/// Each poll is a loop iteration of the scheduler, so the harness needs an unwind bound that
+/// covers the number of polls of all tasks. Smaller bounds on the number of context switches
+/// make verification faster, at the cost of missing interleavings that need more switches.
+pub struct NondetScheduling {
+    max_context_switches: usize,
+    context_switches: usize,
+    last: Option<usize>,
+    round_robin: RoundRobin,
+}
+
+impl NondetScheduling {
+    /// Creates a strategy that explores the interleavings with up to `max_context_switches`
+    /// context switches.
+    pub const fn new(max_context_switches: usize) -> NondetScheduling {
+        NondetScheduling {
+            max_context_switches,
+            context_switches: 0,
+            last: None,
+            round_robin: RoundRobin { index: 0 },
+        }
+    }
+}
+
+impl SchedulingStrategy for NondetScheduling {
+    fn pick_task(&mut self, num_tasks: usize) -> (usize, SchedulingAssumption) {
+        if self.context_switches >= self.max_context_switches {
+            return self.round_robin.pick_task(num_tasks);
+        }
+        let index: usize = crate::any();
+        crate::assume(index < num_tasks);
+        if self.last.is_some_and(|last| last != index) {
+            self.context_switches += 1;
+        }
+        self.last = Some(index);
+        self.round_robin.index = index;
+        (index, SchedulingAssumption::CanAssumeRunning)
+    }
+}
+
 pub(crate) struct Scheduler {
     tasks: Vec<Option<BoxFuture>>,
     num_running: usize,
@@ -117,11 +170,19 @@ pub(crate) const fn new() -> Scheduler {
     }
 
     /// Adds a future to the scheduler's task list, returning a JoinHandle
-    pub(crate) fn spawn<F: Future<Output = ()> + Sync + 'static>(&mut self, fut: F) -> JoinHandle {
-        let index = self.tasks.len();
-        self.tasks.push(Some(Box::pin(fut)));
+    pub(crate) fn spawn<F>(&mut self, fut: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Sync + 'static,
+        F::Output: 'static,
+    {
+        let packet = Arc::new(Packet {
+            result: UnsafeCell::new(None),
+            finished: AtomicBool::new(false),
+            aborted: AtomicBool::new(false),
+        });
+        self.tasks.push(Some(Box::pin(Task { fut: Box::pin(fut), packet: packet.clone() })));
         self.num_running += 1;
-        JoinHandle { index }
+        JoinHandle { packet }
     }
 
     /// Runs the scheduler with the given scheduling plan until all tasks have completed
@@ -130,14 +191,11 @@ fn run(&mut self, mut scheduling_plan: impl SchedulingStrategy) {
         let cx = &mut Context::from_waker(&waker);
         while self.num_running > 0 {
             let (index, assumption) = scheduling_plan.pick_task(self.tasks.len());
-            let task = &mut self.tasks[index];
-            if let Some(fut) = task.as_mut() {
+            // The task is taken out of the list while it is polled, since it may spawn new tasks.
+            if let Some(mut fut) = self.tasks[index].take() {
                 match fut.as_mut().poll(cx) {
-                    std::task::Poll::Ready(()) => {
-                        self.num_running -= 1;
-                        let _prev = task.take();
-                    }
-                    std::task::Poll::Pending => (),
+                    std::task::Poll::Ready(()) => self.num_running -= 1,
+                    std::task::Poll::Pending => self.tasks[index] = Some(fut),
                 }
             } else if let SchedulingAssumption::CanAssumeRunning = assumption {
                 crate::assume(false); // useful so that we can assume that a nondeterministically picked task is still running
@@ -156,33 +214,119 @@ fn block_on<F: Future<Output = ()> + Sync + 'static>(
     }
 }
 
+/// The result of a task, shared by the task and its [`JoinHandle`].
+struct Packet<T> {
+    result: UnsafeCell<Option<T>>,
+    /// Whether the task completed. Unlike `result`, this stays set once the output is taken.
+    finished: AtomicBool,
+    aborted: AtomicBool,
+}
+
+// SAFETY: The scheduler runs all the tasks in the same thread.
+unsafe impl<T> Send for Packet<T> {}
+unsafe impl<T> Sync for Packet<T> {}
+
+/// A spawned future, which stores its output for its [`JoinHandle`].
+struct Task<F: Future> {
+    fut: Pin<Box<F>>,
+    packet: Arc<Packet<F::Output>>,
+}
+
+impl<F: Future> Future for Task<F> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> std::task::Poll<Self::Output> {
+        let task = self.get_mut();
+        if task.packet.aborted.load(Ordering::Relaxed) {
+            // The future is dropped with the task.
+            return std::task::Poll::Ready(());
+        }
+        match task.fut.as_mut().poll(cx) {
+            std::task::Poll::Ready(result) => {
+                // SAFETY: See `Packet`.
+                unsafe { *task.packet.result.get() = Some(result) };
+                task.packet.finished.store(true, Ordering::Relaxed);
+                std::task::Poll::Ready(())
+            }
+            std::task::Poll::Pending => std::task::Poll::Pending,
+        }
+    }
+}
+
 /// Result of spawning a task.
 ///
-/// If you `.await` a JoinHandle, this will wait for the spawned task to complete.
-pub struct JoinHandle {
-    index: usize,
+/// If you `.await` a JoinHandle, this will wait for the spawned task to complete, and return its
+/// output, or an error if the task was aborted.
+pub struct JoinHandle<T = ()> {
+    packet: Arc<Packet<T>>,
 }
 
-#[allow(static_mut_refs)]
-impl Future for JoinHandle {
-    type Output = ();
+impl<T> JoinHandle<T> {
+    /// Aborts the task. Its future is dropped the next time the task is scheduled, instead of
+    /// being polled, and awaiting this handle returns an error.
+    ///
+    /// This has no effect if the task already completed.
+    pub fn abort(&self) {
+        if !self.is_finished() {
+            self.packet.aborted.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Checks if the task completed, even if its output was already returned by awaiting this
+    /// handle.
+    pub fn is_finished(&self) -> bool {
+        self.packet.finished.load(Ordering::Relaxed)
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, JoinError>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> std::task::Poll<Self::Output> {
-        if unsafe { GLOBAL_EXECUTOR.as_mut().unwrap().tasks[self.index].is_some() } {
-            std::task::Poll::Pending
-        } else {
+        // SAFETY: See `Packet`.
+        if let Some(result) = unsafe { (*self.packet.result.get()).take() } {
             cx.waker().wake_by_ref(); // For completeness. But Kani currently ignores wakers.
-            std::task::Poll::Ready(())
+            std::task::Poll::Ready(Ok(result))
+        } else if self.packet.aborted.load(Ordering::Relaxed) {
+            std::task::Poll::Ready(Err(JoinError { _private: () }))
+        } else {
+            std::task::Poll::Pending
         }
     }
 }
 
+/// The error returned when awaiting the [`JoinHandle`] of a task that was aborted.
+#[derive(Debug)]
+pub struct JoinError {
+    _private: (),
+}
+
+impl JoinError {
+    /// Whether the task was cancelled, which is always the case since panics are verification
+    /// failures.
+    pub fn is_cancelled(&self) -> bool {
+        true
+    }
+}
+
+impl fmt::Display for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "task was cancelled")
+    }
+}
+
+impl std::error::Error for JoinError {}
+
 /// Spawns a task on the current global executor (which is set by [`block_on_with_spawn`])
 ///
 /// This function can only be called inside a future passed to [`block_on_with_spawn`].
 #[crate::unstable(feature = "async-lib", issue = 2559, reason = "experimental async support")]
 #[allow(static_mut_refs)]
-pub fn spawn<F: Future<Output = ()> + Sync + 'static>(fut: F) -> JoinHandle {
+pub fn spawn<F>(fut: F) -> JoinHandle<F::Output>
+where
+    F: Future + Sync + 'static,
+    F::Output: 'static,
+{
     unsafe {
         if let Some(executor) = GLOBAL_EXECUTOR.as_mut() {
             executor.spawn(fut)
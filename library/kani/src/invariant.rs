@@ -94,6 +94,17 @@ pub trait Invariant
     fn is_safe(&self) -> bool;
 }
 
+/// Check the safety invariant of `value`.
+///
+/// The compiler uses this model to find out whether a type implements `Invariant` when injecting
+/// type invariants into function contracts.
+#[doc(hidden)]
+#[kanitool::fn_marker = "InvariantIsSafeModel"]
+#[inline(never)]
+pub fn is_safe<T: Invariant>(value: &T) -> bool {
+    value.is_safe()
+}
+
 /// Any value is considered safe for the type
 macro_rules! trivial_invariant {
     ( $type: ty ) => {
@@ -28,17 +28,27 @@
 
 pub mod arbitrary;
 pub mod bounded_arbitrary;
+pub mod cell;
 #[cfg(feature = "concrete_playback")]
 mod concrete_playback;
 pub mod futures;
 pub mod invariant;
 pub mod shadow;
+pub mod sync;
+pub mod thread;
 pub mod vec;
 
 mod models;
 
 #[cfg(feature = "concrete_playback")]
-pub use concrete_playback::concrete_playback_run;
+pub use concrete_playback::{
+    concrete_playback_run, concrete_playback_run_fixtures, fuzz_playback_run,
+};
+#[cfg(feature = "concrete_playback")]
+#[doc(hidden)]
+pub use concrete_playback::{
+    concrete_playback_run_fixtures_harness, concrete_playback_run_harness, playback_harness,
+};
 pub use invariant::Invariant;
 
 #[cfg(not(feature = "concrete_playback"))]
@@ -47,7 +57,19 @@ pub fn concrete_playback_run<F: Fn()>(_: Vec<Vec<u8>>, _: F) {
     unreachable!("Concrete playback does not work during verification")
 }
 
-pub use futures::{RoundRobin, block_on, block_on_with_spawn, spawn, yield_now};
+#[cfg(not(feature = "concrete_playback"))]
+/// NOP `concrete_playback_run_fixtures` for type checking during verification mode.
+pub fn concrete_playback_run_fixtures<F: Fn()>(_: &str, _: F) {
+    unreachable!("Concrete playback does not work during verification")
+}
+
+#[cfg(not(feature = "concrete_playback"))]
+/// NOP `fuzz_playback_run` for type checking during verification mode.
+pub fn fuzz_playback_run<F: Fn()>(_: &[u8], _: F) {
+    unreachable!("Concrete playback does not work during verification")
+}
+
+pub use futures::{NondetScheduling, RoundRobin, block_on, block_on_with_spawn, spawn, yield_now};
 
 // Kani proc macros must be in a separate crate
 pub use kani_macros::*;
@@ -14,7 +14,7 @@
 //! use kani::shadow::ShadowMem;
 //! use std::alloc::{alloc, Layout};
 //!
-//! let mut sm = ShadowMem::new(false);
+//! let mut sm: ShadowMem<bool> = ShadowMem::new(false);
 //!
 //! unsafe {
 //!     let ptr = alloc(Layout::new::<u8>());
@@ -26,31 +26,54 @@
 //!     sm.set(ptr, true);
 //! }
 //! ```
+//!
+//! The shadow memory is backed by the shadow memory of the model checker, which tracks a value
+//! for every byte of every object, so it has no limit on the number or the size of the objects.
+//! The model checker stores one byte per field, so a map uses `size_of::<T>()` fields.
+//! A harness can use several maps by giving them different ids:
+//!
+//! ```no_run
+//! use kani::shadow::ShadowMem;
+//!
+//! static mut INITIALIZED: ShadowMem<bool, 0> = ShadowMem::new(false);
+//! static mut TAINTED: ShadowMem<bool, 1> = ShadowMem::new(false);
+//! ```
+//!
+//! Each id identifies a single map, so maps are always independent.
+//!
+//! # Breaking changes
+//!
+//! Unlike the previous array-based implementation, a map is a property of the whole harness
+//! rather than of a `ShadowMem` value:
+//! - Kani reports a compilation error if two `ShadowMem` use the same id.
+//! - A `ShadowMem::new` call executed at runtime, i.e., outside the initializer of a static,
+//!   can only run once: running it again, e.g., in a loop, fails verification instead of
+//!   creating a fresh map.
+//! - The initial value given to such a call must be a constant of a primitive type, since the
+//!   model checker needs it before the harness runs. Other values are a compilation error.
 
-const MAX_NUM_OBJECTS: usize = 1024;
-const MAX_OBJECT_SIZE: usize = 64;
-
-const MAX_NUM_OBJECTS_ASSERT_MSG: &str = "The number of objects exceeds the maximum number supported by Kani's shadow memory model (1024)";
-const MAX_OBJECT_SIZE_ASSERT_MSG: &str =
-    "The object size exceeds the maximum size supported by Kani's shadow memory model (64)";
-
-/// A shadow memory data structure that contains a two-dimensional array of a
-/// generic type `T`.
-/// Each element of the outer array represents an object, and each element of
-/// the inner array represents a byte in the object.
-pub struct ShadowMem<T: Copy> {
-    mem: [[T; MAX_OBJECT_SIZE]; MAX_NUM_OBJECTS],
+/// A shadow memory map that stores a value of type `T` for each byte of memory.
+///
+/// `MAP` identifies the map, and must be different for each `ShadowMem` of a harness.
+pub struct ShadowMem<T: Copy, const MAP: usize = 0> {
+    /// The value of the locations that were never set.
+    ///
+    /// This is only read by the compiler, which declares the shadow memory of the map with it.
+    #[allow(dead_code)]
+    init: T,
 }
 
-impl<T: Copy> ShadowMem<T> {
+impl<T: Copy, const MAP: usize> ShadowMem<T, MAP> {
     /// Create a new shadow memory instance initialized with the given value
     #[crate::unstable(
         feature = "ghost-state",
         issue = 3184,
         reason = "experimental ghost state/shadow memory API"
     )]
+    #[inline(never)]
+    #[kanitool::fn_marker = "ShadowNewHook"]
     pub const fn new(val: T) -> Self {
-        Self { mem: [[val; MAX_OBJECT_SIZE]; MAX_NUM_OBJECTS] }
+        Self { init: val }
     }
 
     /// Get the shadow memory value of the given pointer
@@ -60,11 +83,7 @@ pub const fn new(val: T) -> Self {
         reason = "experimental ghost state/shadow memory API"
     )]
     pub fn get<U>(&self, ptr: *const U) -> T {
-        let obj = crate::mem::pointer_object(ptr);
-        let offset = crate::mem::pointer_offset(ptr);
-        crate::assert(obj < MAX_NUM_OBJECTS, MAX_NUM_OBJECTS_ASSERT_MSG);
-        crate::assert(offset < MAX_OBJECT_SIZE, MAX_OBJECT_SIZE_ASSERT_MSG);
-        self.mem[obj][offset]
+        crate::internal::shadow_get::<MAP, T>(ptr as *const u8)
     }
 
     /// Set the shadow memory value of the given pointer
@@ -74,10 +93,6 @@ pub fn get<U>(&self, ptr: *const U) -> T {
         reason = "experimental ghost state/shadow memory API"
     )]
     pub fn set<U>(&mut self, ptr: *const U, val: T) {
-        let obj = crate::mem::pointer_object(ptr);
-        let offset = crate::mem::pointer_offset(ptr);
-        crate::assert(obj < MAX_NUM_OBJECTS, MAX_NUM_OBJECTS_ASSERT_MSG);
-        crate::assert(offset < MAX_OBJECT_SIZE, MAX_OBJECT_SIZE_ASSERT_MSG);
-        self.mem[obj][offset] = val;
+        crate::internal::shadow_set::<MAP, T>(ptr as *const u8, val);
     }
 }
@@ -0,0 +1,336 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module contains a model of the synchronization primitives of `std::sync`, to be used with
+//! the threads of [`crate::thread`].
+//!
+//! Locks are never poisoned, since a panic is a verification failure.
+
+use crate::thread::{self, NO_THREAD, ThreadId};
+use std::cell::UnsafeCell;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{LockResult, TryLockError, TryLockResult};
+
+pub use std::sync::{Arc, atomic};
+
+/// A mutual exclusion primitive useful for protecting shared data.
+///
+/// This is a drop-in replacement for [`std::sync::Mutex`].
+/// Locking a mutex that is already held by the current thread is reported as a deadlock.
+pub struct Mutex<T: ?Sized> {
+    owner: AtomicUsize,
+    data: UnsafeCell<T>,
+}
+
+// SAFETY: The data is only accessed by the thread that holds the lock.
+unsafe impl<T: ?Sized + Send> Send for Mutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
+
+impl<T> Mutex<T> {
+    /// Creates a new mutex in an unlocked state.
+    #[crate::unstable(
+        feature = "concurrency",
+        issue = "none",
+        reason = "experimental thread model"
+    )]
+    pub const fn new(value: T) -> Mutex<T> {
+        Mutex { owner: AtomicUsize::new(NO_THREAD), data: UnsafeCell::new(value) }
+    }
+
+    /// Consumes this mutex, returning the underlying data.
+    pub fn into_inner(self) -> LockResult<T> {
+        Ok(self.data.into_inner())
+    }
+}
+
+impl<T: ?Sized> Mutex<T> {
+    /// Acquires the mutex, blocking the current thread until it is able to do so.
+    pub fn lock(&self) -> LockResult<MutexGuard<'_, T>> {
+        let me = thread::current();
+        let acquired = self.acquire(me);
+        thread::block_until(acquired, self.owner.load(Ordering::SeqCst));
+        Ok(MutexGuard { mutex: self, _not_send: PhantomData })
+    }
+
+    /// Attempts to acquire the mutex without blocking.
+    pub fn try_lock(&self) -> TryLockResult<MutexGuard<'_, T>> {
+        if self.acquire(thread::current()) {
+            Ok(MutexGuard { mutex: self, _not_send: PhantomData })
+        } else {
+            Err(TryLockError::WouldBlock)
+        }
+    }
+
+    /// Returns a mutable reference to the underlying data.
+    pub fn get_mut(&mut self) -> LockResult<&mut T> {
+        Ok(self.data.get_mut())
+    }
+
+    fn acquire(&self, me: ThreadId) -> bool {
+        self.owner.compare_exchange(NO_THREAD, me, Ordering::SeqCst, Ordering::SeqCst).is_ok()
+    }
+}
+
+impl<T: Default> Default for Mutex<T> {
+    fn default() -> Mutex<T> {
+        Mutex::new(T::default())
+    }
+}
+
+/// An RAII guard of a [`Mutex`]. The mutex is unlocked when the guard is dropped.
+pub struct MutexGuard<'a, T: ?Sized + 'a> {
+    mutex: &'a Mutex<T>,
+    _not_send: PhantomData<*const ()>,
+}
+
+// SAFETY: Same as `std::sync::MutexGuard`.
+unsafe impl<T: ?Sized + Sync> Sync for MutexGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for MutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: The guard holds the lock.
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for MutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: The guard holds the lock.
+        unsafe { &mut *self.mutex.data.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for MutexGuard<'_, T> {
+    fn drop(&mut self) {
+        self.mutex.owner.store(NO_THREAD, Ordering::SeqCst);
+    }
+}
+
+/// The state of a [`RwLock`] that is held by a writer.
+const WRITE_LOCKED: usize = usize::MAX;
+
+/// A reader-writer lock.
+///
+/// This is a drop-in replacement for [`std::sync::RwLock`].
+/// Deadlocks are only detected when a thread waits for a lock held by a writer.
+pub struct RwLock<T: ?Sized> {
+    /// The number of readers, or [`WRITE_LOCKED`].
+    state: AtomicUsize,
+    writer: AtomicUsize,
+    data: UnsafeCell<T>,
+}
+
+// SAFETY: Same as `std::sync::RwLock`.
+unsafe impl<T: ?Sized + Send> Send for RwLock<T> {}
+unsafe impl<T: ?Sized + Send + Sync> Sync for RwLock<T> {}
+
+impl<T> RwLock<T> {
+    /// Creates a new instance of an `RwLock<T>` which is unlocked.
+    #[crate::unstable(
+        feature = "concurrency",
+        issue = "none",
+        reason = "experimental thread model"
+    )]
+    pub const fn new(value: T) -> RwLock<T> {
+        RwLock {
+            state: AtomicUsize::new(0),
+            writer: AtomicUsize::new(NO_THREAD),
+            data: UnsafeCell::new(value),
+        }
+    }
+
+    /// Consumes this lock, returning the underlying data.
+    pub fn into_inner(self) -> LockResult<T> {
+        Ok(self.data.into_inner())
+    }
+}
+
+impl<T: ?Sized> RwLock<T> {
+    /// Locks this lock with shared read access, blocking the current thread until it can be
+    /// acquired.
+    pub fn read(&self) -> LockResult<RwLockReadGuard<'_, T>> {
+        let acquired = self.acquire_read();
+        thread::block_until(acquired, self.writer.load(Ordering::SeqCst));
+        Ok(RwLockReadGuard { lock: self, _not_send: PhantomData })
+    }
+
+    /// Attempts to acquire this lock with shared read access without blocking.
+    pub fn try_read(&self) -> TryLockResult<RwLockReadGuard<'_, T>> {
+        if self.acquire_read() {
+            Ok(RwLockReadGuard { lock: self, _not_send: PhantomData })
+        } else {
+            Err(TryLockError::WouldBlock)
+        }
+    }
+
+    /// Locks this lock with exclusive write access, blocking the current thread until it can be
+    /// acquired.
+    pub fn write(&self) -> LockResult<RwLockWriteGuard<'_, T>> {
+        let acquired = self.acquire_write();
+        thread::block_until(acquired, self.writer.load(Ordering::SeqCst));
+        Ok(RwLockWriteGuard { lock: self, _not_send: PhantomData })
+    }
+
+    /// Attempts to lock this lock with exclusive write access without blocking.
+    pub fn try_write(&self) -> TryLockResult<RwLockWriteGuard<'_, T>> {
+        if self.acquire_write() {
+            Ok(RwLockWriteGuard { lock: self, _not_send: PhantomData })
+        } else {
+            Err(TryLockError::WouldBlock)
+        }
+    }
+
+    /// Returns a mutable reference to the underlying data.
+    pub fn get_mut(&mut self) -> LockResult<&mut T> {
+        Ok(self.data.get_mut())
+    }
+
+    fn acquire_read(&self) -> bool {
+        let readers = self.state.load(Ordering::SeqCst);
+        readers != WRITE_LOCKED
+            && self
+                .state
+                .compare_exchange(readers, readers + 1, Ordering::SeqCst, Ordering::SeqCst)
+                .is_ok()
+    }
+
+    fn acquire_write(&self) -> bool {
+        let acquired = self
+            .state
+            .compare_exchange(0, WRITE_LOCKED, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok();
+        if acquired {
+            self.writer.store(thread::current(), Ordering::SeqCst);
+        }
+        acquired
+    }
+}
+
+impl<T: Default> Default for RwLock<T> {
+    fn default() -> RwLock<T> {
+        RwLock::new(T::default())
+    }
+}
+
+/// An RAII guard of the shared read access of a [`RwLock`].
+pub struct RwLockReadGuard<'a, T: ?Sized + 'a> {
+    lock: &'a RwLock<T>,
+    _not_send: PhantomData<*const ()>,
+}
+
+// SAFETY: Same as `std::sync::RwLockReadGuard`.
+unsafe impl<T: ?Sized + Sync> Sync for RwLockReadGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for RwLockReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: The guard holds a read lock.
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for RwLockReadGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.state.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// An RAII guard of the exclusive write access of a [`RwLock`].
+pub struct RwLockWriteGuard<'a, T: ?Sized + 'a> {
+    lock: &'a RwLock<T>,
+    _not_send: PhantomData<*const ()>,
+}
+
+// SAFETY: Same as `std::sync::RwLockWriteGuard`.
+unsafe impl<T: ?Sized + Sync> Sync for RwLockWriteGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for RwLockWriteGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: The guard holds the write lock.
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for RwLockWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: The guard holds the write lock.
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for RwLockWriteGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.writer.store(NO_THREAD, Ordering::SeqCst);
+        self.lock.state.store(0, Ordering::SeqCst);
+    }
+}
+
+/// A condition variable, which blocks a thread until it is notified.
+///
+/// This is a drop-in replacement for [`std::sync::Condvar`]. Spurious wakeups are not modeled,
+/// but `notify_one` may wake up all the waiting threads.
+pub struct Condvar {
+    notifications: AtomicUsize,
+}
+
+impl Condvar {
+    /// Creates a new condition variable.
+    #[crate::unstable(
+        feature = "concurrency",
+        issue = "none",
+        reason = "experimental thread model"
+    )]
+    pub const fn new() -> Condvar {
+        Condvar { notifications: AtomicUsize::new(0) }
+    }
+
+    /// Blocks the current thread until this condition variable receives a notification.
+    ///
+    /// The mutex of `guard` is unlocked while waiting, and locked again before returning.
+    pub fn wait<'a, T: ?Sized>(&self, guard: MutexGuard<'a, T>) -> LockResult<MutexGuard<'a, T>> {
+        let mutex = guard.mutex;
+        let seen = self.notifications.load(Ordering::SeqCst);
+        drop(guard);
+        thread::block_until(self.notifications.load(Ordering::SeqCst) != seen, NO_THREAD);
+        mutex.lock()
+    }
+
+    /// Blocks the current thread while `condition` returns `true`.
+    pub fn wait_while<'a, T, F>(
+        &self,
+        mut guard: MutexGuard<'a, T>,
+        mut condition: F,
+    ) -> LockResult<MutexGuard<'a, T>>
+    where
+        T: ?Sized,
+        F: FnMut(&mut T) -> bool,
+    {
+        while condition(&mut *guard) {
+            guard = self.wait(guard)?;
+        }
+        Ok(guard)
+    }
+
+    /// Wakes up one blocked thread on this condition variable.
+    pub fn notify_one(&self) {
+        self.notifications.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Wakes up all blocked threads on this condition variable.
+    pub fn notify_all(&self) {
+        self.notifications.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+impl Default for Condvar {
+    fn default() -> Condvar {
+        Condvar::new()
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This module contains a model of `std::thread` to verify concurrent code in Kani.
+//!
+//! This model requires `-Z concurrency`, with which Kani uses CBMC's thread support to consider
+//! every interleaving of the threads of the harness, where atomic operations and the primitives
+//! of [`crate::sync`] are sequentially consistent. In concrete playback, threads spawned with
+//! [`spawn`] run in place, i.e., sequentially.
+//!
+//! Blocking operations, such as [`JoinHandle::join`] or [`crate::sync::Mutex::lock`], are
+//! modeled with assumptions: a schedule where a thread blocks forever is simply not continued.
+//! Kani reports a deadlock if a thread blocks on a resource held by a thread that is itself
+//! waiting, directly or not, for the first one.
+//!
+//! Like [loom](https://docs.rs/loom), this model is meant to replace the standard library in the
+//! code being verified, e.g.:
+//! ```ignore
+//! #[cfg(kani)]
+//! use kani::{sync::Mutex, thread};
+//! #[cfg(not(kani))]
+//! use std::{sync::Mutex, thread};
+//! ```
+//!
+//! The standard library is not redirected to this model, and Kani reports a compilation error if
+//! `std::thread::spawn`, or another function that spawns a thread of the standard library, is
+//! reachable with `-Z concurrency`.
+
+use std::cell::{Cell, UnsafeCell};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// The maximum number of threads of a harness, including the thread that runs the harness.
+pub const MAX_THREADS: usize = 4;
+
+/// Identifies a thread. The thread that runs the harness is `0`.
+pub(crate) type ThreadId = usize;
+
+/// Represents the absence of a thread, e.g., the owner of a lock that is not held.
+pub(crate) const NO_THREAD: ThreadId = usize::MAX;
+
+thread_local! {
+    static CURRENT: Cell<ThreadId> = const { Cell::new(0) };
+}
+
+/// The number of threads that were created by the harness.
+static NUM_THREADS: AtomicUsize = AtomicUsize::new(1);
+
+/// The thread that each thread is blocked on, if any.
+static WAITING_FOR: [AtomicUsize; MAX_THREADS] =
+    [const { AtomicUsize::new(NO_THREAD) }; MAX_THREADS];
+
+/// The id of the thread that executes this function.
+pub(crate) fn current() -> ThreadId {
+    CURRENT.with(Cell::get)
+}
+
+/// Block the current thread until `ready` is true, where `owner` is the thread that must
+/// release the resource that the current thread needs, if known.
+///
+/// `ready` should be the result of an atomic operation that acquires the resource if it is
+/// available. Blocking and acquiring the resource later is modeled by acquiring it later in a
+/// different interleaving, so the current schedule is not continued if `ready` is false.
+///
+/// Without `-Z concurrency`, threads run to completion when they are spawned, so a resource that
+/// isn't available never will be, and blocking is reported as a failure.
+pub(crate) fn block_until(ready: bool, owner: ThreadId) {
+    if !ready {
+        crate::assert(crate::internal::is_concurrent(), "thread would block; use -Z concurrency");
+        let me = current();
+        if me < MAX_THREADS {
+            WAITING_FOR[me].store(owner, Ordering::SeqCst);
+        }
+        crate::assert(
+            !is_waiting_for(owner, me),
+            "deadlock: the threads are waiting for each other",
+        );
+    }
+    crate::assume(ready);
+}
+
+/// Whether `thread` is `target`, or is waiting for it, directly or not.
+///
+/// The chain is followed without a loop, so this check does not depend on the unwind bound of
+/// the harness. Chains are at most [`MAX_THREADS`] long, since the threads are distinct.
+fn is_waiting_for(thread: ThreadId, target: ThreadId) -> bool {
+    let mut thread = thread;
+    macro_rules! step {
+        () => {
+            if thread == target {
+                return true;
+            }
+            if thread >= MAX_THREADS {
+                return false;
+            }
+            thread = WAITING_FOR[thread].load(Ordering::SeqCst);
+        };
+    }
+    step!();
+    step!();
+    step!();
+    step!();
+    thread == target
+}
+
+/// The result of a thread, shared by the thread and its [`JoinHandle`].
+struct Packet<T> {
+    done: AtomicBool,
+    result: UnsafeCell<Option<T>>,
+}
+
+// SAFETY: The result is written by the thread before `done` is set, and only read by the
+// `JoinHandle` after `done` is set.
+unsafe impl<T: Send> Sync for Packet<T> {}
+
+/// The closure run by a new thread, and where to store its result.
+struct Start<F, T> {
+    id: ThreadId,
+    f: F,
+    packet: Arc<Packet<T>>,
+}
+
+/// The entry point of the threads created by [`spawn`].
+fn run<F: FnOnce() -> T, T>(arg: *mut u8) {
+    // SAFETY: `arg` was created by `spawn` from a `Box<Start<F, T>>`.
+    let start = unsafe { Box::from_raw(arg as *mut Start<F, T>) };
+    // Restoring the parent only matters when the thread runs in place.
+    let parent = CURRENT.with(|current| current.replace(start.id));
+    let result = (start.f)();
+    // SAFETY: See `Packet`.
+    unsafe { *start.packet.result.get() = Some(result) };
+    start.packet.done.store(true, Ordering::Release);
+    CURRENT.with(|current| current.set(parent));
+}
+
+/// Spawns a new thread, returning a [`JoinHandle`] for it.
+///
+/// This is a drop-in replacement for [`std::thread::spawn`], which requires `-Z concurrency`.
+/// A harness can create at most [`MAX_THREADS`] threads, including its own.
+#[crate::unstable(feature = "concurrency", issue = "none", reason = "experimental thread model")]
+pub fn spawn<F, T>(f: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let id = NUM_THREADS.fetch_add(1, Ordering::SeqCst);
+    #[cfg(not(feature = "concrete_playback"))]
+    crate::assert(id < MAX_THREADS, "kani::thread::spawn: too many threads");
+    let packet = Arc::new(Packet { done: AtomicBool::new(false), result: UnsafeCell::new(None) });
+    let start = Box::new(Start { id, f, packet: packet.clone() });
+    crate::internal::start_thread(run::<F, T>, Box::into_raw(start) as *mut u8);
+    JoinHandle { id, packet }
+}
+
+/// An owned permission to join on a thread, i.e., to block on its termination.
+///
+/// This is a drop-in replacement for [`std::thread::JoinHandle`].
+pub struct JoinHandle<T> {
+    id: ThreadId,
+    packet: Arc<Packet<T>>,
+}
+
+impl<T> JoinHandle<T> {
+    /// Waits for the thread to finish, and returns its result.
+    ///
+    /// Since panics are verification failures, this never returns an error.
+    pub fn join(self) -> std::thread::Result<T> {
+        block_until(self.packet.done.load(Ordering::Acquire), self.id);
+        // SAFETY: See `Packet`.
+        Ok(unsafe { (*self.packet.result.get()).take().unwrap() })
+    }
+
+    /// Checks if the thread has finished running its closure.
+    pub fn is_finished(&self) -> bool {
+        self.packet.done.load(Ordering::Acquire)
+    }
+}
+
+/// Cooperatively gives up a timeslice to the scheduler.
+///
+/// This is a no-op, since Kani already considers every interleaving of the threads.
+pub fn yield_now() {}
@@ -167,7 +167,9 @@ pub fn assume(cond: bool) {
         #[kanitool::fn_marker = "AssumeHook"]
         #[cfg(feature = "concrete_playback")]
         pub fn assume(cond: bool) {
-            assert!(cond, "`kani::assume` should always hold");
+            if !cond {
+                concrete_playback::assume_failed()
+            }
         }
 
         /// Creates an assertion of the specified condition and message.
@@ -488,8 +490,9 @@ pub fn automatic_harness() {
             #[inline(never)]
             #[doc(hidden)]
             #[kanitool::fn_marker = "UntrackedDerefHook"]
-            pub fn untracked_deref<T>(_: &T) -> T {
-                todo!()
+            pub fn untracked_deref<T>(value: &T) -> T {
+                // This is only executed by concrete playback.
+                unsafe { ptr::read(value) }
             }
 
             /// CBMC contracts currently has a limitation where `free` has to be in scope.
@@ -506,6 +509,45 @@ pub fn untracked_deref<T>(_: &T) -> T {
             #[kanitool::fn_marker = "InitContractsHook"]
             pub fn init_contracts() {}
 
+            /// Run `run(arg)` in a new thread. This is the building block of `kani::thread::spawn`.
+            ///
+            /// The thread is only interleaved with the others when `-Z concurrency` is enabled.
+            /// Otherwise, and in concrete playback, it runs to completion before this returns.
+            #[inline(never)]
+            #[doc(hidden)]
+            #[kanitool::fn_marker = "StartThreadHook"]
+            pub fn start_thread(run: fn(*mut u8), arg: *mut u8) {
+                run(arg)
+            }
+
+            /// Whether the threads started by `start_thread` are interleaved, i.e., whether
+            /// `-Z concurrency` is enabled. This is always false in concrete playback.
+            #[inline(never)]
+            #[doc(hidden)]
+            #[kanitool::fn_marker = "IsConcurrentHook"]
+            pub fn is_concurrent() -> bool {
+                false
+            }
+
+            /// Get the value stored for `ptr` by the shadow memory map with id `MAP`.
+            ///
+            /// This is the building block of `kani::shadow::ShadowMem`, which is backed by the
+            /// shadow memory of CBMC.
+            #[inline(never)]
+            #[doc(hidden)]
+            #[kanitool::fn_marker = "ShadowGetHook"]
+            pub fn shadow_get<const MAP: usize, T: Copy>(_ptr: *const u8) -> T {
+                super::kani_intrinsic()
+            }
+
+            /// Set the value stored for `ptr` by the shadow memory map with id `MAP`.
+            #[inline(never)]
+            #[doc(hidden)]
+            #[kanitool::fn_marker = "ShadowSetHook"]
+            pub fn shadow_set<const MAP: usize, T: Copy>(_ptr: *const u8, _value: T) {
+                super::kani_intrinsic()
+            }
+
             /// This should only be used within contracts. The intent is to
             /// perform type inference on a closure's argument
             #[doc(hidden)]
@@ -513,6 +555,90 @@ pub fn apply_closure<T, U: Fn(&T) -> bool>(f: U, x: &T) -> bool {
                 f(x)
             }
 
+            /// Assume the safety invariant of `value` when type invariants are injected into
+            /// contracts (i.e., `--contract-invariants`). References are checked against the
+            /// invariant of the type they point to.
+            ///
+            /// The contract macros only emit calls to this function if the option is enabled.
+            /// This is a no-op if the type does not implement `kani::Invariant`.
+            /// Only for use within function contracts.
+            #[doc(hidden)]
+            #[kanitool::fn_marker = "AssumeInvariantIntrinsic"]
+            #[inline(never)]
+            #[cfg(not(feature = "concrete_playback"))]
+            pub fn assume_invariant<T: ?Sized>(_value: &T) {
+                super::kani_intrinsic()
+            }
+
+            #[doc(hidden)]
+            #[kanitool::fn_marker = "AssumeInvariantIntrinsic"]
+            #[inline(never)]
+            #[cfg(feature = "concrete_playback")]
+            pub fn assume_invariant<T: ?Sized>(_value: &T) {}
+
+            /// Assert the safety invariant of `value` when type invariants are injected into
+            /// contracts. See [assume_invariant] for more details.
+            #[doc(hidden)]
+            #[kanitool::fn_marker = "AssertInvariantIntrinsic"]
+            #[inline(never)]
+            #[cfg(not(feature = "concrete_playback"))]
+            pub fn assert_invariant<T: ?Sized>(_value: &T, _msg: &'static str) {
+                super::kani_intrinsic()
+            }
+
+            #[doc(hidden)]
+            #[kanitool::fn_marker = "AssertInvariantIntrinsic"]
+            #[inline(never)]
+            #[cfg(feature = "concrete_playback")]
+            pub fn assert_invariant<T: ?Sized>(_value: &T, _msg: &'static str) {}
+
+            /// Check that the termination `measure` of a recursive call is strictly smaller than
+            /// the measure of the call being verified, which is stored in `outer`.
+            ///
+            /// # Safety
+            ///
+            /// `outer` must point to a live value of type `T`.
+            #[doc(hidden)]
+            pub unsafe fn decreases<T: Measure>(measure: &T, outer: *const ()) -> bool {
+                measure < unsafe { &*(outer as *const T) }
+            }
+
+            /// Check that the termination `measure` of a loop at the end of an iteration is
+            /// strictly smaller than its measure at the beginning of the iteration.
+            #[doc(hidden)]
+            pub fn loop_decreases<T: Measure>(measure: T, previous: T) -> bool {
+                measure < previous
+            }
+
+            /// A termination measure of a `decreases` clause.
+            ///
+            /// A measure that strictly decreases at every step can only do so a finite number of
+            /// times if its type has a finite number of values. This is the case for integers,
+            /// and for tuples of measures, which are compared lexicographically.
+            #[doc(hidden)]
+            #[diagnostic::on_unimplemented(
+                message = "`{Self}` cannot be used as a termination measure",
+                note = "termination measures must be integers or tuples of integers"
+            )]
+            pub trait Measure: PartialOrd {}
+
+            impl Measure for u8 {}
+            impl Measure for u16 {}
+            impl Measure for u32 {}
+            impl Measure for u64 {}
+            impl Measure for u128 {}
+            impl Measure for usize {}
+            impl Measure for i8 {}
+            impl Measure for i16 {}
+            impl Measure for i32 {}
+            impl Measure for i64 {}
+            impl Measure for i128 {}
+            impl Measure for isize {}
+            impl<A: Measure> Measure for (A,) {}
+            impl<A: Measure, B: Measure> Measure for (A, B) {}
+            impl<A: Measure, B: Measure, C: Measure> Measure for (A, B, C) {}
+            impl<A: Measure, B: Measure, C: Measure, D: Measure> Measure for (A, B, C, D) {}
+
             /// Recieves a reference to a pointer-like object and assigns kani::any_modifies to that object.
             /// Only for use within function contracts and will not be replaced if the recursive or function stub
             /// replace contracts are not used.
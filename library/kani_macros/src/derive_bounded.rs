@@ -12,22 +12,21 @@
 /// Fields of the given type marked with `#[bounded]` will use
 /// `BoundedArbitrary::bounded_any()` while other fields fall back to `kani::any()`
 ///
-/// Current limitation: Generic bounds are restricted to `T: kani::Arbitrary` rather than
-/// `T: kani::BoundedArbitrary`. This is the right thing to do when the generic is
-/// used in some other container that you want to be bounded; like in the following
-/// example:
+/// Generic parameters are bound by `T: kani::Arbitrary`, and the type of each bounded field
+/// is required to implement `kani::BoundedArbitrary`. The latter propagates any extra bound
+/// required by the field type, e.g. `K: Ord` for the following example:
 ///
 /// ```rust
 /// #[derive(BoundedArbitrary)]
-/// struct MyVec<T> {
+/// struct MyMap<K, V> {
 ///     #[bounded]
-///     vec: Vec<T>,
+///     map: BTreeMap<K, V>,
 ///     cap: usize
 /// }
 /// ```
 ///
-/// However, if you use the generic raw in a field and want it to be bounded, this
-/// won't work. The following doesn't compile:
+/// Current limitation: Since every generic must implement `kani::Arbitrary`, using the
+/// generic raw in a bounded field requires it to implement both traits:
 ///
 /// ```rust
 /// #[derive(BoundedArbitrary)]
@@ -37,8 +36,7 @@
 /// }
 /// ```
 ///
-/// TODO: have the generic bound change based on how it's used if we can detect this
-/// automatically, otherwise support an attribute on the generic.
+/// TODO: only add the `T: kani::Arbitrary` bound for generics used in unbounded fields.
 pub(crate) fn expand_derive_bounded_arbitrary(
     item: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
@@ -52,8 +50,8 @@ pub(crate) fn expand_derive_bounded_arbitrary(
         syn::Data::Union(data_union) => union_constructor(&parsed.ident, data_union),
     };
 
-    // add `T: Arbitrary` bounds for generics
-    let clauses = quote_generics(&parsed.generics);
+    // add `T: Arbitrary` bounds for generics and `Field: BoundedArbitrary` for bounded fields
+    let clauses = quote_generics(&parsed.generics, &bounded_field_types(&parsed.data));
     let (impl_generics, ty_generics, _) = parsed.generics.split_for_impl();
     let name = &parsed.ident;
 
@@ -145,21 +143,40 @@ fn union_constructor(ident: &syn::Ident, _data_union: &syn::DataUnion) -> TokenS
 /// where
 ///     A: Arbitrary
 ///     B: Arbitrary
+///     Vec<B>: BoundedArbitrary
 /// {
 ///     ...
 /// }
 /// ```
-fn quote_generics(generics: &syn::Generics) -> TokenStream {
+fn quote_generics(generics: &syn::Generics, bounded_types: &[&syn::Type]) -> TokenStream {
+    // Without generics, the constructor is enough to check that every bounded field is valid.
+    if generics.type_params().count() == 0 {
+        return Default::default();
+    }
     let kani_path = kani_path();
-    let where_clauses = generics.type_params().map(|param| {
+    let generic_clauses = generics.type_params().map(|param| {
         let ident = &param.ident;
         quote!(#ident : #kani_path::Arbitrary)
     });
-    if generics.type_params().count() > 0 {
-        quote!(where #(#where_clauses),*)
-    } else {
-        Default::default()
-    }
+    let bounded_clauses = bounded_types.iter().map(|ty| quote!(#ty : #kani_path::BoundedArbitrary));
+    let where_clauses = generic_clauses.chain(bounded_clauses);
+    quote!(where #(#where_clauses),*)
+}
+
+/// Collect the types of all fields marked with `#[bounded]`.
+fn bounded_field_types(data: &syn::Data) -> Vec<&syn::Type> {
+    let fields: Box<dyn Iterator<Item = &syn::Field>> = match data {
+        syn::Data::Struct(data_struct) => Box::new(data_struct.fields.iter()),
+        syn::Data::Enum(data_enum) => {
+            Box::new(data_enum.variants.iter().flat_map(|variant| variant.fields.iter()))
+        }
+        syn::Data::Union(_) => Box::new(std::iter::empty()),
+    };
+    fields.filter(|field| is_bounded(field)).map(|field| &field.ty).collect()
+}
+
+fn is_bounded(field: &syn::Field) -> bool {
+    field.attrs.iter().any(|attr| attr.path().is_ident("bounded"))
 }
 
 /// Generates a symbolic value based on whether the field has the `#[bounded]`
@@ -168,7 +185,7 @@ fn quote_generics(generics: &syn::Generics) -> TokenStream {
 fn generate_any_call(field: &syn::Field) -> TokenStream {
     let ty = &field.ty;
     let kani_path = kani_path();
-    let any_call = if field.attrs.iter().any(|attr| attr.path().is_ident("bounded")) {
+    let any_call = if is_bounded(field) {
         quote!(#kani_path::bounded_any::<#ty, N>())
     } else {
         quote!(#kani_path::any::<#ty>())
@@ -44,10 +44,25 @@ pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// allows users to exercise [negative testing](https://en.wikipedia.org/wiki/Negative_testing)
 /// for Rust unit tests.
 ///
+/// The `#[kani::should_panic]` attribute verifies that there are one or more failed checks related to panics,
+/// and that all failed checks are related to panics.
+///
+/// Similar to libtest, `#[kani::should_panic(expected = "<message>")]` also verifies that the message of
+/// every panic contains the given string, e.g.:
+///
+/// ```ignore
+/// #[kani::proof]
+/// #[kani::should_panic(expected = "index out of bounds")]
+/// fn check_out_of_bounds() {
+///     let v = vec![1, 2, 3];
+///     let _ = v[kani::any::<usize>()];
+/// }
+/// ```
+///
 /// # Limitations
 ///
-/// The `#[kani::should_panic]` attribute verifies that there are one or more failed checks related to panics.
-/// At the moment, it's not possible to pin it down to specific panics.
+/// For panics with a formatted message, e.g. `panic!("invalid value: {}", x)`, the message
+/// is matched against the format string, since the formatted message is not known statically.
 #[proc_macro_attribute]
 pub fn should_panic(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::should_panic(attr, item)
@@ -94,6 +109,17 @@ pub fn solver(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::solver(attr, item)
 }
 
+/// Select the types that the `autoharness` subcommand instantiates this generic function with.
+///
+/// The attribute `#[kani::autoharness_types(T1, T2, ...)]` can only be used on generic functions.
+/// Kani instantiates each type parameter of the function with every listed type, and generates one
+/// automatic harness per instantiation that satisfies the function's trait bounds.
+/// These types take precedence over the ones provided via `--generic-type`.
+#[proc_macro_attribute]
+pub fn autoharness_types(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr_impl::autoharness_types(attr, item)
+}
+
 /// Mark an API as unstable. This should only be used inside the Kani sysroot.
 /// See https://model-checking.github.io/kani/rfc/rfcs/0006-unstable-api.html for more details.
 #[doc(hidden)]
@@ -376,6 +402,24 @@ pub fn ensures(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::ensures(attr, item)
 }
 
+/// Add a termination measure to this recursive function.
+///
+/// This is part of the function contract API, for more general information see
+/// the [module-level documentation](../kani/contracts/index.html).
+///
+/// The contents of the attribute is an expression over the input values to the
+/// annotated function, e.g. `n` or `(a, b)` for a lexicographic order. Its type
+/// must be an integer or a tuple of integers. The function must also be
+/// annotated with [`recursion`][macro@recursion], since Kani checks the
+/// measure while checking the contract inductively: it asserts that the measure
+/// of every recursive call is strictly smaller than the measure of the call
+/// being verified. Since the measure has a finite number of values, a verified
+/// contract then also proves that the function terminates.
+#[proc_macro_attribute]
+pub fn decreases(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr_impl::decreases(attr, item)
+}
+
 /// Designates this function as a harness to check a function contract.
 ///
 /// The argument to this macro is the relative path (e.g. `foo` or
@@ -444,6 +488,17 @@ pub fn loop_invariant(attr: TokenStream, item: TokenStream) -> TokenStream {
 pub fn loop_modifies(attr: TokenStream, item: TokenStream) -> TokenStream {
     attr_impl::loop_modifies(attr, item)
 }
+
+/// Add a termination measure to this loop.
+///
+/// The contents of the attribute is a comma separated list of expressions that
+/// are compared lexicographically, and that must be strictly smaller at the end
+/// of every iteration than at its beginning. The loop must also have a
+/// [`loop_invariant`][macro@loop_invariant], which this measure is checked with.
+#[proc_macro_attribute]
+pub fn loop_decreases(attr: TokenStream, item: TokenStream) -> TokenStream {
+    attr_impl::loop_decreases(attr, item)
+}
 /// This module implements Kani attributes in a way that only Kani's compiler can understand.
 /// This code should only be activated when pre-building Kani's sysroot.
 #[cfg(kani_sysroot)]
@@ -453,8 +508,10 @@ mod sysroot {
     mod contracts;
     mod loop_contracts;
 
-    pub use contracts::{ensures, modifies, proof_for_contract, requires, stub_verified};
-    pub use loop_contracts::{loop_invariant, loop_modifies};
+    pub use contracts::{
+        decreases, ensures, modifies, proof_for_contract, requires, stub_verified,
+    };
+    pub use loop_contracts::{loop_decreases, loop_invariant, loop_modifies};
 
     use super::*;
 
@@ -588,8 +645,9 @@ pub fn proof(attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     }
 
-    kani_attribute!(should_panic, no_args);
+    kani_attribute!(should_panic);
     kani_attribute!(recursion, no_args);
+    kani_attribute!(autoharness_types);
     kani_attribute!(solver);
     kani_attribute!(stub);
     kani_attribute!(unstable);
@@ -623,15 +681,18 @@ pub fn proof(_attr: TokenStream, item: TokenStream) -> TokenStream {
 
     no_op!(should_panic);
     no_op!(recursion);
+    no_op!(autoharness_types);
     no_op!(solver);
     no_op!(stub);
     no_op!(unstable);
     no_op!(unwind);
     no_op!(requires);
     no_op!(ensures);
+    no_op!(decreases);
     no_op!(modifies);
     no_op!(proof_for_contract);
     no_op!(stub_verified);
     no_op!(loop_invariant);
     no_op!(loop_modifies);
+    no_op!(loop_decreases);
 }
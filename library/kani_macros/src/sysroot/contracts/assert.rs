@@ -48,11 +48,16 @@ fn initial_assert_stmts(&self) -> Vec<Stmt> {
         let stmts = &self.annotated_fn.block.stmts;
         let result = Ident::new(INTERNAL_RESULT_IDENT, Span::call_site());
 
+        let entry_invariants = self.invariants_on_entry(ContractMode::Assert);
+        let exit_invariants = self.invariants_on_exit(ContractMode::Assert);
+
         parse_quote!(
+            #entry_invariants
             let mut body_wrapper = kani_force_fn_once(|| #output {
                 #(#stmts)*
             });
             let #result : #return_type = #body_wrapper_ident();
+            #exit_invariants
             #result
         )
     }
@@ -89,7 +94,7 @@ fn make_assert_body(&self, mut body_stmts: Vec<Stmt>) -> TokenStream2 {
                     #return_expr
                 })
             }
-            ContractConditionsData::Modifies { .. } => {
+            ContractConditionsData::Modifies { .. } | ContractConditionsData::Decreases { .. } => {
                 quote!({#(#body_stmts)*})
             }
         }
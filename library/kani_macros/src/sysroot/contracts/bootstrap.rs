@@ -5,10 +5,11 @@
 //! function.
 
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
-use syn::{Expr, ItemFn, Stmt};
+use quote::{format_ident, quote};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use syn::{Block, Expr, ItemFn, Stmt, parse_quote};
 
-use super::{ContractConditionsHandler, INTERNAL_RESULT_IDENT, helpers::*};
+use super::{ContractConditionsData, ContractConditionsHandler, INTERNAL_RESULT_IDENT, helpers::*};
 
 impl<'a> ContractConditionsHandler<'a> {
     /// Generate initial contract.
@@ -24,7 +25,12 @@ pub fn handle_untouched(&mut self) {
 
         let replace_closure = self.replace_closure();
         let check_closure = self.check_closure();
-        let recursion_closure = self.new_recursion_closure(&replace_closure, &check_closure);
+        let mut recursion_closure = self.new_recursion_closure(&replace_closure, &check_closure);
+        if let ContractConditionsData::Decreases { attr } = &self.condition_type {
+            let mut closure = syn::parse2(recursion_closure).unwrap();
+            self.add_decreases(&mut closure, attr);
+            recursion_closure = quote!(#closure);
+        }
         let assert_closure = self.assert_closure();
 
         let span = Span::call_site();
@@ -176,5 +182,59 @@ fn expand_recursion(&self, closure: &mut Stmt) {
         let Expr::Block(else_block) = else_branch.1.as_mut() else { unreachable!() };
         let check_closure = expect_closure(&mut else_block.block.stmts, "check");
         self.expand_check(check_closure);
+
+        if let ContractConditionsData::Decreases { attr } = &self.condition_type {
+            self.add_decreases(closure, attr);
+        }
+    }
+
+    /// Check that the termination measure decreases on every recursive call.
+    ///
+    /// The measure of the call being verified is stored before calling the check closure, and
+    /// every recursive call, which is replaced by the contract, asserts that its measure is
+    /// strictly smaller:
+    ///
+    /// ```ignore
+    /// static mut DECREASES_<hash>: *const () = core::ptr::null();
+    /// if unsafe { REENTRY } {
+    ///     kani::assert(unsafe { kani::internal::decreases(&(measure), DECREASES_<hash>) }, ..);
+    ///     /* replace */
+    /// } else {
+    ///     let __kani_decreases_<hash> = measure;
+    ///     unsafe { DECREASES_<hash> = core::ptr::addr_of!(__kani_decreases_<hash>) as *const () };
+    ///     /* check */
+    /// }
+    /// ```
+    fn add_decreases(&self, closure: &mut Stmt, attr: &Expr) {
+        let mut hasher = DefaultHasher::new();
+        attr.hash(&mut hasher);
+        let hash = hasher.finish();
+        let outer = format_ident!("DECREASES_{hash:X}");
+        let measure = format_ident!("__kani_decreases_{hash:x}");
+        let attr_copy = &self.attr_copy;
+
+        let body = closure_body(closure);
+        let stmts = &mut body.block.stmts;
+        stmts.insert(0, parse_quote!(static mut #outer: *const () = core::ptr::null();));
+        let if_reentry = stmts
+            .iter_mut()
+            .find_map(|stmt| {
+                if let Stmt::Expr(Expr::If(if_expr), ..) = stmt { Some(if_expr) } else { None }
+            })
+            .unwrap();
+        if_reentry.then_branch.stmts.insert(
+            0,
+            parse_quote!(kani::assert(
+                unsafe { kani::internal::decreases(&(#attr), #outer) },
+                concat!("recursive call decreases ", stringify!(#attr_copy)),
+            );),
+        );
+        let else_branch = if_reentry.else_branch.as_mut().unwrap();
+        let Expr::Block(else_block) = else_branch.1.as_mut() else { unreachable!() };
+        let store: Block = parse_quote!({
+            let #measure = #attr;
+            unsafe { #outer = core::ptr::addr_of!(#measure) as *const () };
+        });
+        else_block.block.stmts.splice(0..0, store.stmts);
     }
 }
@@ -4,9 +4,12 @@
 //! Logic used for generating the code that checks a contract.
 
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
-use quote::quote;
+use quote::{format_ident, quote};
 use std::mem;
-use syn::{Block, Expr, FnArg, Local, LocalInit, Pat, PatIdent, ReturnType, Stmt, parse_quote};
+use syn::{
+    Block, Expr, FnArg, Local, LocalInit, Pat, PatIdent, ReturnType, Stmt, Type, TypeReference,
+    parse_quote,
+};
 
 use super::{
     ContractConditionsData, ContractConditionsHandler, ContractMode, INTERNAL_RESULT_IDENT,
@@ -51,6 +54,8 @@ pub fn make_check_body(&self, mut body_stmts: Vec<Stmt>) -> TokenStream2 {
                     #return_expr
                 })
             }
+            // The measure is checked by the recursion closure.
+            ContractConditionsData::Decreases { .. } => quote!({#(#body_stmts)*}),
             ContractConditionsData::Modifies { attr } => {
                 let wrapper_arg_ident = Ident::new(WRAPPER_ARG, Span::call_site());
                 let wrapper_tuple = body_stmts.iter_mut().find_map(|stmt| {
@@ -92,10 +97,14 @@ fn initial_check_stmts(&self) -> Vec<syn::Stmt> {
             redefs_mut_only,
         );
         let result = Ident::new(INTERNAL_RESULT_IDENT, Span::call_site());
+        let entry_invariants = self.invariants_on_entry(ContractMode::SimpleCheck);
+        let exit_invariants = self.invariants_on_exit(ContractMode::SimpleCheck);
         parse_quote!(
+            #entry_invariants
             let #wrapper_arg_ident = (#mut_recv);
             #modifies_closure
             let #result : #return_type = #modifies_ident(#wrapper_arg_ident);
+            #exit_invariants
             #result
         )
     }
@@ -199,6 +208,79 @@ pub fn arg_redefinitions(&self, redefine_only_mut: bool) -> TokenStream2 {
         result
     }
 
+    /// Generate the statements that check the type invariants of the arguments on entry.
+    ///
+    /// The invariants are checked by `kani::internal::{assume, assert}_invariant`. These
+    /// statements are only compiled if the user enabled `--contract-invariants`, which sets
+    /// `--cfg=kani_contract_invariants`. They are assumed when checking the
+    /// contract and asserted otherwise.
+    ///
+    /// We also keep a pointer to the target of each mutable reference argument, since the
+    /// reference itself may be moved into the function body before we check it on exit.
+    pub fn invariants_on_entry(&self, mode: ContractMode) -> TokenStream2 {
+        let receiver =
+            self.annotated_fn.sig.receiver().map(|_| Ident::new("self", Span::call_site()));
+        let args = receiver.into_iter().chain(self.arg_bindings().map(|(_, ident)| ident.clone()));
+        let checks = args.map(|ident| {
+            let msg = format!("type invariant of `{ident}`");
+            invariant_check(mode == ContractMode::SimpleCheck, quote!(&#ident), msg)
+        });
+        let pointers = self.mut_ref_args().into_iter().map(|ident| {
+            let ptr = invariant_ptr(&ident);
+            quote!(
+                #[cfg(kani_contract_invariants)]
+                let #ptr = core::ptr::addr_of!(*#ident);
+            )
+        });
+        quote!(#(#checks)* #(#pointers)*)
+    }
+
+    /// Generate the statements that check the type invariants of the result and of the targets
+    /// of mutable reference arguments on exit.
+    ///
+    /// They are assumed when replacing the function by its contract and asserted otherwise.
+    pub fn invariants_on_exit(&self, mode: ContractMode) -> TokenStream2 {
+        let assume = mode == ContractMode::Replace;
+        let result = Ident::new(INTERNAL_RESULT_IDENT, Span::call_site());
+        // Functions without a return type return `()`, which has no invariant worth checking.
+        let result_check =
+            matches!(self.annotated_fn.sig.output, ReturnType::Type(..)).then(|| {
+                invariant_check(
+                    assume,
+                    quote!(&#result),
+                    "type invariant of the result".to_string(),
+                )
+            });
+        let arg_checks = self.mut_ref_args().into_iter().map(|ident| {
+            let ptr = invariant_ptr(&ident);
+            let msg = format!("type invariant of `{ident}` on exit");
+            invariant_check(assume, quote!(unsafe { &*#ptr }), msg)
+        });
+        quote!(#result_check #(#arg_checks)*)
+    }
+
+    /// Return the arguments that are mutable references, including a `&mut self` receiver.
+    fn mut_ref_args(&self) -> Vec<Ident> {
+        let is_mut_ref =
+            |ty: &Type| matches!(ty, Type::Reference(TypeReference { mutability: Some(_), .. }));
+        self.annotated_fn
+            .sig
+            .inputs
+            .iter()
+            .filter_map(|arg| match arg {
+                FnArg::Receiver(receiver) => {
+                    is_mut_ref(&receiver.ty).then(|| Ident::new("self", Span::call_site()))
+                }
+                FnArg::Typed(typed) => match typed.pat.as_ref() {
+                    Pat::Ident(PatIdent { ident, subpat: None, .. }) if is_mut_ref(&typed.ty) => {
+                        Some(ident.clone())
+                    }
+                    _ => None,
+                },
+            })
+            .collect()
+    }
+
     /// Extract all arguments bindings and their mutability.
     fn arg_bindings(&self) -> impl Iterator<Item = (MutBinding, &Ident)> {
         self.annotated_fn.sig.inputs.iter().flat_map(|arg| match arg {
@@ -207,3 +289,24 @@ fn arg_bindings(&self) -> impl Iterator<Item = (MutBinding, &Ident)> {
         })
     }
 }
+
+/// Generate a statement that assumes or asserts the type invariant of `value`.
+fn invariant_check(assume: bool, value: TokenStream2, msg: String) -> TokenStream2 {
+    if assume {
+        quote!(
+            #[cfg(kani_contract_invariants)]
+            kani::internal::assume_invariant(#value);
+        )
+    } else {
+        quote!(
+            #[cfg(kani_contract_invariants)]
+            kani::internal::assert_invariant(#value, #msg);
+        )
+    }
+}
+
+/// Name of the pointer used to check the type invariant of the target of a mutable reference
+/// argument on exit.
+fn invariant_ptr(arg: &Ident) -> Ident {
+    format_ident!("__kani_invariant_{arg}")
+}
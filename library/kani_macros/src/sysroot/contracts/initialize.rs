@@ -67,6 +67,9 @@ pub fn new(
             ContractConditionsType::Modifies => {
                 ContractConditionsData::new_modifies(attr, &mut output)
             }
+            ContractConditionsType::Decreases => {
+                ContractConditionsData::Decreases { attr: syn::parse(attr)? }
+            }
         };
 
         let fn_name = &annotated_fn.sig.ident;
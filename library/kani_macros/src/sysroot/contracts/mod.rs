@@ -138,6 +138,17 @@
 //!
 //! We register this closure as `#[kanitool::asserted_with = "__kani_assert_..."]`
 //!
+//! ## Trait method declarations
+//!
+//! A trait method declaration has no body to instrument. The first contract
+//! attribute on it moves all the contract attributes to a hidden provided method
+//! `__kani_trait_contract_<fn_name>` that calls the declared method, and marks
+//! the declaration with `#[kanitool::trait_contract = "__kani_trait_contract_..."]`.
+//! The provided method is then expanded as described above. Since every
+//! implementation of the trait inherits it, the compiler can use it as the
+//! contract of any implementation that doesn't have a contract of its own.
+//! See [`trait_decl`] for an example.
+//!
 //! # Complete example
 //!
 //! ```
@@ -516,7 +527,7 @@
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use quote::quote;
 use strum_macros::Display;
-use syn::{Error, Expr, ExprClosure, ItemFn, TraitItemFn, parse_macro_input, parse_quote};
+use syn::{Expr, ExprClosure, ItemFn, TraitItemFn, parse_macro_input, parse_quote};
 
 mod assert;
 mod bootstrap;
@@ -526,6 +537,7 @@
 mod initialize;
 mod replace;
 mod shared;
+mod trait_decl;
 
 const INTERNAL_RESULT_IDENT: &str = "result_kani_internal";
 
@@ -541,6 +553,10 @@ pub fn modifies(attr: TokenStream, item: TokenStream) -> TokenStream {
     contract_main(attr, item, ContractConditionsType::Modifies)
 }
 
+pub fn decreases(attr: TokenStream, item: TokenStream) -> TokenStream {
+    contract_main(attr, item, ContractConditionsType::Decreases)
+}
+
 /// This is very similar to the kani_attribute macro, but it instead creates
 /// key-value style attributes which I find a little easier to parse.
 macro_rules! passthrough {
@@ -620,6 +636,7 @@ enum ContractConditionsType {
     Requires,
     Ensures,
     Modifies,
+    Decreases,
 }
 
 /// Clause-specific information mostly generated by parsing the attribute.
@@ -637,6 +654,10 @@ enum ContractConditionsData {
     Modifies {
         attr: Vec<Expr>,
     },
+    Decreases {
+        /// The contents of the attribute.
+        attr: Expr,
+    },
 }
 
 /// Enumeration that stores (some of) the contract mode values.
@@ -669,25 +690,44 @@ fn contract_main(
     item: TokenStream,
     contract_typ: ContractConditionsType,
 ) -> TokenStream {
-    // Contract expansion edits the body of the function,
-    // so we can't allow applying contract attributes to method decls without bodies.
+    // Contract expansion edits the body of the function, so declarations without a body are
+    // expanded into a provided method that every implementation inherits.
     if let Ok(trait_fn) = syn::parse::<TraitItemFn>(item.clone())
         && trait_fn.default.is_none()
     {
-        let error_msg = format!(
-            "#[kani::{contract_typ}] cannot be applied to trait function declarations without default bodies.\n\
-             help: Apply this attribute to the implementation of the function instead.",
-        );
-        return Error::new(Span::call_site(), error_msg).into_compile_error().into();
+        return trait_decl::expand_trait_decl(attr.into(), trait_fn, contract_typ).into();
     }
     let attr_copy = TokenStream2::from(attr.clone());
     let mut item_fn = parse_macro_input!(item as ItemFn);
+    // The measure is checked by the recursion closure, which is only used with `recursion`.
+    if contract_typ == ContractConditionsType::Decreases
+        && !item_fn.attrs.iter().any(|attr| {
+            attr.path().segments.last().is_some_and(|segment| segment.ident == "recursion")
+        })
+    {
+        return syn::Error::new(
+            Span::call_site(),
+            "`#[kani::decreases]` requires the function to be annotated with `#[kani::recursion]`",
+        )
+        .into_compile_error()
+        .into();
+    }
     let function_state = ContractFunctionState::from_attributes(&item_fn.attrs);
+    // Record the text of the pre- and postconditions, which the Lean backend quotes in the theorem
+    // stub of the contract.
+    let clause = match contract_typ {
+        ContractConditionsType::Requires | ContractConditionsType::Ensures => {
+            let name = Ident::new(&format!("contract_{contract_typ}"), Span::call_site());
+            quote!(#[kanitool::#name = stringify!(#attr_copy)])
+        }
+        ContractConditionsType::Modifies | ContractConditionsType::Decreases => quote!(),
+    };
     let handler = match ContractConditionsHandler::new(contract_typ, attr, &mut item_fn, attr_copy)
     {
         Ok(handler) => handler,
         Err(e) => return e.into_compile_error().into(),
     };
 
-    handler.dispatch_on(function_state).into()
+    let output = handler.dispatch_on(function_state);
+    quote!(#clause #output).into()
 }
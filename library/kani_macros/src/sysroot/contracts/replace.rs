@@ -24,7 +24,10 @@ fn initial_replace_stmts(&self) -> Vec<syn::Stmt> {
         // https://github.com/model-checking/kani/issues/3667
         let redefs = self.arg_redefinitions(false);
         let redefs_block: Block = syn::parse_quote!({#redefs});
+        let entry_invariants = self.invariants_on_entry(ContractMode::Replace);
+        let entry_block: Block = syn::parse_quote!({#entry_invariants});
         [
+            entry_block.stmts,
             vec![syn::parse_quote!(
                 let #result : #return_type = kani::any_modifies();
             )],
@@ -107,6 +110,15 @@ fn expand_replace_body(&self, before: &[Stmt], after: &[Stmt]) -> TokenStream {
                     #result
                 })
             }
+            // The measure is checked by the recursion closure.
+            ContractConditionsData::Decreases { .. } => {
+                let result = Ident::new(INTERNAL_RESULT_IDENT, Span::call_site());
+                quote!({
+                    #(#before)*
+                    #(#after)*
+                    #result
+                })
+            }
             ContractConditionsData::Modifies { attr } => {
                 let result = Ident::new(INTERNAL_RESULT_IDENT, Span::call_site());
                 quote!({
@@ -128,7 +140,9 @@ pub fn replace_closure(&self) -> TokenStream {
         let sig = &self.annotated_fn.sig;
         let output = &sig.output;
         let before = self.initial_replace_stmts();
-        let body = self.expand_replace_body(&before, &[]);
+        let exit_invariants = self.invariants_on_exit(ContractMode::Replace);
+        let after: Block = syn::parse_quote!({#exit_invariants});
+        let body = self.expand_replace_body(&before, &after.stmts);
 
         quote!(
             #[kanitool::is_contract_generated(replace)]
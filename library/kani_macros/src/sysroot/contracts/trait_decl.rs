@@ -0,0 +1,114 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Expansion of contracts attached to trait method declarations.
+//!
+//! A method declaration has no body that we could instrument. Instead, we move the contract
+//! attributes to a provided method that simply calls the declared one, which is then expanded like
+//! any other function with a contract. Every implementation of the trait inherits this method, and
+//! the compiler redirects the calls of implementations without a contract of their own to it.
+
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{Attribute, FnArg, Pat, TraitItemFn, parse_quote};
+
+use super::ContractConditionsType;
+use super::helpers::matches_path;
+
+/// Expand the first contract attribute found on a trait method declaration.
+///
+/// For example, the declaration:
+///
+/// ```ignore
+/// #[kani::requires(buf.len() >= 8)]
+/// #[kani::ensures(|result| *result <= buf.len())]
+/// fn encode(&self, buf: &mut [u8]) -> usize;
+/// ```
+///
+/// expands to:
+///
+/// ```ignore
+/// #[kanitool::trait_contract = "__kani_trait_contract_encode"]
+/// fn encode(&self, buf: &mut [u8]) -> usize;
+///
+/// #[doc(hidden)]
+/// #[kanitool::is_contract_generated(trait_contract)]
+/// #[kani::requires(buf.len() >= 8)]
+/// #[kani::ensures(|result| *result <= buf.len())]
+/// fn __kani_trait_contract_encode(&self, buf: &mut [u8]) -> usize {
+///     Self::encode(self, buf)
+/// }
+/// ```
+pub fn expand_trait_decl(
+    attr: TokenStream2,
+    mut decl: TraitItemFn,
+    contract_typ: ContractConditionsType,
+) -> TokenStream2 {
+    let (contract_attrs, other_attrs): (Vec<Attribute>, Vec<Attribute>) =
+        decl.attrs.drain(..).partition(is_contract_attribute);
+    decl.attrs = other_attrs;
+
+    let fn_name = &decl.sig.ident;
+    let wrapper_name = format_ident!("__kani_trait_contract_{fn_name}");
+    let mut wrapper_sig = decl.sig.clone();
+    wrapper_sig.ident = wrapper_name.clone();
+    let args: Vec<Ident> = wrapper_sig
+        .inputs
+        .iter_mut()
+        .enumerate()
+        .map(|(idx, arg)| match arg {
+            FnArg::Receiver(receiver) => {
+                // A method taking `self` by value can only have a body if `Self` is sized.
+                if receiver.reference.is_none() && receiver.colon_token.is_none() {
+                    wrapper_sig
+                        .generics
+                        .make_where_clause()
+                        .predicates
+                        .push(parse_quote!(Self: Sized));
+                }
+                Ident::new("self", receiver.self_token.span)
+            }
+            FnArg::Typed(pat_type) => match pat_type.pat.as_ref() {
+                Pat::Ident(pat_ident) => pat_ident.ident.clone(),
+                _ => {
+                    // Declarations can only bind an argument to a name or to `_`.
+                    let ident = format_ident!("arg_{idx}");
+                    *pat_type.pat = parse_quote!(#ident);
+                    ident
+                }
+            },
+        })
+        .collect();
+    let call = if wrapper_sig.unsafety.is_some() {
+        quote!(unsafe { Self::#fn_name(#(#args),*) })
+    } else {
+        quote!(Self::#fn_name(#(#args),*))
+    };
+    let contract_name = Ident::new(&contract_typ.to_string(), proc_macro2::Span::call_site());
+
+    quote!(
+        #[kanitool::trait_contract = stringify!(#wrapper_name)]
+        #decl
+
+        #[doc(hidden)]
+        #[kanitool::is_contract_generated(trait_contract)]
+        #[kani::#contract_name(#attr)]
+        #(#contract_attrs)*
+        #wrapper_sig {
+            #call
+        }
+    )
+}
+
+/// Is this one of the attributes that define the contract of a function?
+///
+/// They have to be moved together to the generated method, since each of them is expanded
+/// separately. The `recursion` attribute may have been expanded already, so we also accept its
+/// `kanitool` version.
+fn is_contract_attribute(attr: &Attribute) -> bool {
+    let path = attr.path();
+    ["requires", "ensures", "modifies", "recursion"]
+        .into_iter()
+        .any(|name| matches_path(path, &["kani", name]) || matches_path(path, &[name]))
+        || matches_path(path, &["kanitool", "recursion"])
+}
@@ -5,13 +5,13 @@
 //!
 
 use proc_macro::TokenStream;
-use proc_macro_error2::abort_call_site;
+use proc_macro_error2::{abort, abort_call_site};
 use quote::{format_ident, quote};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::AndAnd;
 use syn::{
-    BinOp, Block, Expr, ExprBinary, Ident, Stmt, Token, parse_macro_input, parse_quote,
+    Attribute, BinOp, Block, Expr, ExprBinary, Ident, Stmt, Token, parse_macro_input, parse_quote,
     visit_mut::VisitMut,
 };
 
@@ -245,6 +245,14 @@ pub fn loop_invariant(attr: TokenStream, item: TokenStream) -> TokenStream {
     // expr of the loop invariant
     let mut inv_expr: Expr = syn::parse(attr).unwrap();
 
+    // The termination measure is checked as part of the invariant, see [loop_decreases].
+    let decreases = take_loop_decreases(&mut loop_stmt);
+    if let Some(measure) = &decreases {
+        inv_expr = parse_quote!(
+            (#inv_expr) && kani::internal::loop_decreases(#measure, prev(#measure))
+        );
+    }
+
     // adding on_entry variables
     let mut onentry_var_prefix: String = "__kani_onentry_var".to_owned();
     onentry_var_prefix.push_str(&loop_id);
@@ -281,8 +289,14 @@ pub fn loop_invariant(attr: TokenStream, item: TokenStream) -> TokenStream {
     let mut loop_body_closure_ret_2_name: String = "__kani_loop_body_closure_ret_2".to_owned();
     loop_body_closure_ret_2_name.push_str(&loop_id);
     let loop_body_closure_ret_2 = format_ident!("{}", loop_body_closure_ret_2_name);
+    let mut first_iter_inv = inv_expr.clone();
     if has_prev {
         inv_expr = transform_inv.transformed_expr.clone();
+        // The measure doesn't need to decrease if the loop exits during the first iteration.
+        first_iter_inv = match (&decreases, &inv_expr) {
+            (Some(_), Expr::Binary(ExprBinary { left, .. })) => left.as_ref().clone(),
+            _ => inv_expr.clone(),
+        };
         match loop_stmt {
             Stmt::Expr(ref mut e, _) => match e {
                 Expr::While(ew) => ew.body.stmts = assign_stms.clone(),
@@ -357,7 +371,7 @@ const fn #register_ident<F: Fn() -> bool>(_f: &F, _transformed: usize) -> bool {
             #loop_stmt
         }
         else {
-            assert!(#inv_expr);
+            assert!(#first_iter_inv);
         };
         }
         })
@@ -392,6 +406,62 @@ fn generate_unique_id_from_span(stmt: &Stmt) -> String {
     format!("_{:?}_{:?}_{:?}_{:?}", start.line(), start.column(), end.line(), end.column())
 }
 
+/// Return the attributes of the loop in this statement.
+fn loop_attrs(loop_stmt: &mut Stmt) -> Option<&mut Vec<Attribute>> {
+    match loop_stmt {
+        Stmt::Expr(Expr::While(ew), _) => Some(&mut ew.attrs),
+        Stmt::Expr(Expr::Loop(el), _) => Some(&mut el.attrs),
+        _ => None,
+    }
+}
+
+/// Whether this attribute is the Kani loop contract attribute with the given name.
+fn is_loop_attr(attr: &Attribute, name: &str) -> bool {
+    attr.path().segments.last().is_some_and(|segment| segment.ident == name)
+}
+
+/// Remove the `loop_decreases` attribute of the loop, if any, and return its measure as a tuple.
+fn take_loop_decreases(loop_stmt: &mut Stmt) -> Option<Expr> {
+    let attrs = loop_attrs(loop_stmt)?;
+    let pos = attrs.iter().position(|attr| is_loop_attr(attr, "loop_decreases"))?;
+    let attr = attrs.remove(pos);
+    let measures = attr
+        .parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+        .unwrap_or_else(|err| abort!(err.span(), "{}", err));
+    Some(parse_quote!((#measures)))
+}
+
+/*
+    A loop with a termination measure
+    #[kani::loop_invariant(inv)]
+    #[kani::loop_decreases(m1, m2)]
+    while(loop_guard) { ... }
+
+    is checked with the loop invariant
+    #[kani::loop_invariant((inv) && kani::internal::loop_decreases((m1, m2), prev((m1, m2))))]
+
+    i.e., the measure at the end of each iteration must be lexicographically smaller than the
+    measure at its beginning. `loop_decreases` only accepts integers and tuples of integers, so
+    the measure can only take a finite number of values, which proves that the loop terminates.
+
+    The measure is handled by the `loop_invariant` expansion, so if `loop_decreases` is expanded
+    first, it just moves itself after the `loop_invariant` attribute.
+*/
+pub fn loop_decreases(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr = proc_macro2::TokenStream::from(attr);
+    let mut loop_stmt: Stmt = syn::parse(item).unwrap();
+    let Some(attrs) = loop_attrs(&mut loop_stmt) else {
+        abort_call_site!("`#[kani::loop_decreases]` is only supported for loops.")
+    };
+    if !attrs.iter().any(|attr| is_loop_attr(attr, "loop_invariant")) {
+        abort_call_site!("`#[kani::loop_decreases]` requires a `#[kani::loop_invariant]`.";
+            note = "the measure is checked together with the loop invariant.";
+        )
+    }
+    attrs.push(parse_quote!(#[kani::loop_decreases(#attr)]));
+    quote!(#loop_stmt).into()
+}
+
 pub fn loop_modifies(attr: TokenStream, item: TokenStream) -> TokenStream {
     let assigns = parse_macro_input!(attr with Punctuated::<Expr, Token![,]>::parse_terminated)
         .into_iter()
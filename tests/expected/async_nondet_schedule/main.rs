@@ -0,0 +1,32 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// compile-flags: --edition 2018
+// kani-flags: -Z async-lib
+
+//! Check that `kani::NondetScheduling` explores the interleavings of the tasks, i.e., that the
+//! main task may observe the spawned task before it runs, in the middle, and after it completes.
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicI64, Ordering},
+};
+
+#[kani::proof(schedule = kani::NondetScheduling::new(2))]
+#[kani::unwind(6)]
+async fn nondet_schedule() {
+    let x = Arc::new(AtomicI64::new(0));
+    let x2 = x.clone();
+    let handle = kani::spawn(async move {
+        x2.fetch_add(1, Ordering::Relaxed);
+        kani::yield_now().await;
+        x2.fetch_add(1, Ordering::Relaxed);
+    });
+    kani::yield_now().await;
+    let seen = x.load(Ordering::Relaxed);
+    kani::cover!(seen == 0);
+    kani::cover!(seen == 1);
+    kani::cover!(seen == 2);
+    handle.await.unwrap();
+    assert_eq!(x.load(Ordering::Relaxed), 2);
+}
@@ -0,0 +1,52 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This file tests whether we can generate bounded collections that have any possible size
+//! between 0-BOUND
+
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, VecDeque};
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_btree_map() {
+    const BOUND: usize = 2;
+    let map: BTreeMap<u8, bool> = kani::bounded_any::<_, BOUND>();
+    kani::cover!(map.len() == 0);
+    kani::cover!(map.len() == 1);
+    kani::cover!(map.len() == 2);
+    assert!(map.len() <= BOUND);
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_btree_set() {
+    const BOUND: usize = 2;
+    let set: BTreeSet<u8> = kani::bounded_any::<_, BOUND>();
+    kani::cover!(set.len() == 0);
+    kani::cover!(set.len() == 1);
+    kani::cover!(set.len() == 2);
+    assert!(set.len() <= BOUND);
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_vec_deque() {
+    const BOUND: usize = 2;
+    let deque: VecDeque<u8> = kani::bounded_any::<_, BOUND>();
+    kani::cover!(deque.len() == 0);
+    kani::cover!(deque.len() == 2);
+    assert!(deque.len() <= BOUND);
+}
+
+#[kani::proof]
+#[kani::unwind(5)]
+fn check_binary_heap() {
+    const BOUND: usize = 2;
+    let heap: BinaryHeap<u8> = kani::bounded_any::<_, BOUND>();
+    kani::cover!(heap.len() == 0);
+    kani::cover!(heap.len() == 2);
+    assert!(heap.len() <= BOUND);
+    if let Some(max) = heap.peek() {
+        assert!(heap.iter().all(|elem| elem <= max));
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z concurrency
+
+//! Test that Kani reports a data race when a thread reads a value while it is being written.
+
+use kani::cell::UnsafeCell;
+use kani::sync::Arc;
+use kani::thread;
+
+/// A cell that is wrongly declared as thread safe.
+struct SharedCell(UnsafeCell<u8>);
+
+// SAFETY: Not safe, which is what this test checks.
+unsafe impl Sync for SharedCell {}
+
+#[kani::proof]
+fn check_unsynchronized_write() {
+    let cell = Arc::new(SharedCell(UnsafeCell::new(0)));
+    let writer = {
+        let cell = cell.clone();
+        thread::spawn(move || cell.0.with_mut(|value| unsafe { *value = 1 }))
+    };
+    let value = cell.0.with(|value| unsafe { *value });
+    assert!(value == 0 || value == 1);
+    writer.join().unwrap();
+}
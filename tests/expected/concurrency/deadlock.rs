@@ -0,0 +1,27 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z concurrency
+
+//! Test that Kani reports a deadlock when two threads lock two mutexes in a different order.
+
+use kani::sync::{Arc, Mutex};
+use kani::thread;
+
+#[kani::proof]
+fn check_lock_order() {
+    let a = Arc::new(Mutex::new(0));
+    let b = Arc::new(Mutex::new(0));
+    let handle = {
+        let (a, b) = (a.clone(), b.clone());
+        thread::spawn(move || {
+            let _b = b.lock().unwrap();
+            *a.lock().unwrap() += 1;
+        })
+    };
+    {
+        let _a = a.lock().unwrap();
+        *b.lock().unwrap() += 1;
+    }
+    handle.join().unwrap();
+}
@@ -0,0 +1,13 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z concurrency
+
+//! Test that Kani rejects the threads of the standard library with `-Z concurrency`, since they
+//! are not interleaved by the thread model.
+
+#[kani::proof]
+fn check_std_spawn() {
+    let handle = std::thread::spawn(|| 1);
+    assert_eq!(handle.join().unwrap(), 1);
+}
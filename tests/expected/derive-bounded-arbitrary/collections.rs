@@ -0,0 +1,27 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the derive BoundedArbitrary macro supports bounded fields of standard
+//! collections, including generic ones that require extra bounds (e.g. `K: Ord`).
+
+extern crate kani;
+use kani::BoundedArbitrary;
+use std::collections::{BTreeMap, VecDeque};
+
+#[derive(BoundedArbitrary)]
+#[allow(unused)]
+struct Inventory<K> {
+    #[bounded]
+    items: BTreeMap<K, u8>,
+    #[bounded]
+    pending: VecDeque<u8>,
+}
+
+#[kani::proof]
+#[kani::unwind(4)]
+fn check_inventory() {
+    let inventory: Inventory<u8> = kani::bounded_any::<_, 1>();
+    kani::cover!(inventory.items.len() == 0);
+    kani::cover!(inventory.items.len() == 1);
+    kani::cover!(inventory.pending.len() == 1);
+}
@@ -0,0 +1,18 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that Kani reports recursive calls that do not decrease the termination measure, even
+//! though the contract holds if the function terminates.
+
+#[kani::ensures(|result| *result == 0)]
+#[kani::decreases(n)]
+#[kani::recursion]
+fn zero(n: u8) -> u8 {
+    if n == 0 { 0 } else { zero(n) }
+}
+
+#[kani::proof_for_contract(zero)]
+fn check_zero() {
+    zero(kani::any());
+}
@@ -0,0 +1,18 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that termination measures must have a finite number of values. A float can decrease
+//! forever, e.g. by halving, so it is rejected.
+
+#[kani::ensures(|result| *result == 0)]
+#[kani::decreases(x)]
+#[kani::recursion]
+fn halve(x: f32) -> u8 {
+    if x < 1.0 { 0 } else { halve(x / 2.0) }
+}
+
+#[kani::proof_for_contract(halve)]
+fn check_halve() {
+    halve(kani::any());
+}
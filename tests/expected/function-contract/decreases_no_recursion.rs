@@ -0,0 +1,17 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that a termination measure requires the function to be annotated with
+//! `#[kani::recursion]`, since the measure is only checked on recursive calls.
+
+#[kani::ensures(|result| *result == n)]
+#[kani::decreases(n)]
+fn count(n: u64) -> u64 {
+    if n == 0 { 0 } else { 1 + count(n - 1) }
+}
+
+#[kani::proof_for_contract(count)]
+fn check_count() {
+    count(kani::any_where(|n| *n < 5));
+}
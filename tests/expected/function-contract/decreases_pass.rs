@@ -0,0 +1,43 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+
+//! Check that the termination measure of a recursive function is checked on every recursive
+//! call, and that it does not affect the contract replacement.
+
+#[kani::ensures(|result| *result == n)]
+#[kani::decreases(n)]
+#[kani::recursion]
+fn count(n: u64) -> u64 {
+    if n == 0 { 0 } else { 1 + count(n - 1) }
+}
+
+/// Terminates by a lexicographic order on its arguments.
+#[kani::ensures(|result| *result == 0)]
+#[kani::decreases((i, j))]
+#[kani::recursion]
+fn drain(i: u8, j: u8) -> u8 {
+    if j > 0 {
+        drain(i, j - 1)
+    } else if i > 0 {
+        drain(i - 1, u8::MAX)
+    } else {
+        0
+    }
+}
+
+#[kani::proof_for_contract(count)]
+fn check_count() {
+    count(kani::any());
+}
+
+#[kani::proof_for_contract(drain)]
+fn check_drain() {
+    drain(kani::any(), kani::any());
+}
+
+#[kani::proof]
+#[kani::stub_verified(count)]
+fn check_stub() {
+    assert!(count(5) == 5);
+}
@@ -0,0 +1,70 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+//! Test that a contract on a trait method declaration is inherited by every implementation,
+//! both when checking the contract and when replacing statically and dynamically dispatched calls.
+
+trait Counter {
+    #[kani::requires(*count < 100)]
+    #[kani::ensures(|result| *result > 0 && *count == old(*count) + *result)]
+    #[kani::modifies(count)]
+    fn bump(&self, count: &mut u32) -> u32;
+}
+
+struct One;
+
+struct Step(u32);
+
+impl Counter for One {
+    fn bump(&self, count: &mut u32) -> u32 {
+        *count += 1;
+        1
+    }
+}
+
+impl Counter for Step {
+    fn bump(&self, count: &mut u32) -> u32 {
+        let step = if self.0 == 0 { 1 } else { self.0 };
+        *count += step;
+        step
+    }
+}
+
+fn bump_twice<C: Counter>(counter: &C, count: &mut u32) {
+    counter.bump(count);
+    counter.bump(count);
+}
+
+fn bump_all(counters: &[&dyn Counter], count: &mut u32) {
+    for counter in counters {
+        counter.bump(count);
+    }
+}
+
+#[kani::proof_for_contract(<One as Counter>::bump)]
+fn check_one() {
+    let mut count = kani::any();
+    One.bump(&mut count);
+}
+
+#[kani::proof_for_contract(<Step as Counter>::bump)]
+fn check_step() {
+    let mut count = kani::any();
+    Step(kani::any_where(|step| *step < 10)).bump(&mut count);
+}
+
+#[kani::proof]
+#[kani::stub_verified(<Step as Counter>::bump)]
+fn check_bump_twice() {
+    let mut count = kani::any_where(|count| *count < 50);
+    bump_twice(&Step(kani::any()), &mut count);
+    assert!(count >= 2);
+}
+
+#[kani::proof]
+#[kani::stub_verified(Counter::bump)]
+fn check_bump_all() {
+    let mut count = 0;
+    bump_all(&[&One, &Step(5)], &mut count);
+    assert!(count >= 2);
+}
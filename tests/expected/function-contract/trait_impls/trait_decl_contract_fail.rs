@@ -0,0 +1,37 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts
+//! Test that an implementation that violates the contract of its trait method declaration is
+//! caught, while an implementation with its own contract is checked against that one instead.
+
+trait Sizer {
+    #[kani::ensures(|result| *result > 0)]
+    fn size(&self) -> usize;
+}
+
+struct Empty;
+
+struct Unit;
+
+impl Sizer for Empty {
+    fn size(&self) -> usize {
+        0
+    }
+}
+
+impl Sizer for Unit {
+    #[kani::ensures(|result| *result == 0)]
+    fn size(&self) -> usize {
+        0
+    }
+}
+
+#[kani::proof_for_contract(<Empty as Sizer>::size)]
+fn check_empty() {
+    Empty.size();
+}
+
+#[kani::proof_for_contract(<Unit as Sizer>::size)]
+fn check_unit() {
+    Unit.size();
+}
@@ -0,0 +1,41 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts --contract-invariants
+
+//! Check that `--contract-invariants` asserts the type invariant of the result and of `&mut`
+//! arguments on exit, and of the arguments passed to a verified stub.
+
+#[derive(Clone, Copy, kani::Arbitrary)]
+struct Percent(u8);
+
+impl kani::Invariant for Percent {
+    fn is_safe(&self) -> bool {
+        self.0 <= 100
+    }
+}
+
+#[kani::ensures(|_| true)]
+fn double(p: Percent) -> Percent {
+    Percent(p.0.saturating_mul(2))
+}
+
+#[kani::modifies(p)]
+fn increment(p: &mut Percent) {
+    p.0 += 1;
+}
+
+#[kani::proof_for_contract(double)]
+fn check_double() {
+    double(kani::any());
+}
+
+#[kani::proof_for_contract(increment)]
+fn check_increment() {
+    increment(&mut kani::any());
+}
+
+#[kani::proof]
+#[kani::stub_verified(double)]
+fn check_invalid_arg() {
+    double(Percent(101));
+}
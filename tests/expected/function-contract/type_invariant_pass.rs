@@ -0,0 +1,51 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zfunction-contracts --contract-invariants
+
+//! Check that `--contract-invariants` assumes the type invariant of the arguments, and that
+//! the stub of a verified function assumes the invariant of its result and `&mut` arguments.
+
+#[derive(Clone, Copy, kani::Arbitrary)]
+struct Percent(u8);
+
+impl kani::Invariant for Percent {
+    fn is_safe(&self) -> bool {
+        self.0 <= 100
+    }
+}
+
+/// Would overflow without the invariant of `p`.
+#[kani::ensures(|result| result.0 <= 100)]
+fn complement(p: Percent) -> Percent {
+    Percent(100 - p.0)
+}
+
+#[kani::modifies(p)]
+fn halve(p: &mut Percent) {
+    p.0 /= 2;
+}
+
+fn quarter_complement(p: Percent) -> u8 {
+    let mut q = complement(p);
+    halve(&mut q);
+    halve(&mut q);
+    q.0
+}
+
+#[kani::proof_for_contract(complement)]
+fn check_complement() {
+    complement(kani::any());
+}
+
+#[kani::proof_for_contract(halve)]
+fn check_halve() {
+    halve(&mut kani::any());
+}
+
+#[kani::proof]
+#[kani::stub_verified(complement)]
+#[kani::stub_verified(halve)]
+fn check_quarter_complement() {
+    let p = Percent(kani::any_where(|x| *x <= 100));
+    assert!(quarter_complement(p) <= 100);
+}
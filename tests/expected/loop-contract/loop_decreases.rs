@@ -0,0 +1,38 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// kani-flags: -Z loop-contracts
+
+//! Check that loops with a termination measure are verified, regardless of the order of the
+//! loop contract attributes.
+
+#![feature(stmt_expr_attributes)]
+#![feature(proc_macro_hygiene)]
+
+#[kani::proof]
+fn count_down() {
+    let mut x: u8 = kani::any_where(|i| *i >= 1);
+    #[kani::loop_invariant(x >= 1)]
+    #[kani::loop_decreases(x)]
+    while x > 1 {
+        x = x - 1;
+    }
+    assert!(x == 1);
+}
+
+#[kani::proof]
+fn lexicographic() {
+    let mut i: u8 = kani::any_where(|i| *i <= 10);
+    let mut j: u8 = 0;
+    #[kani::loop_decreases(i, j)]
+    #[kani::loop_invariant(i <= 10 && j <= 10)]
+    while i > 0 {
+        if j == 0 {
+            i -= 1;
+            j = 10;
+        } else {
+            j -= 1;
+        }
+    }
+    assert!(i == 0);
+}
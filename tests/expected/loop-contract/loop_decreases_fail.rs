@@ -0,0 +1,20 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// kani-flags: -Z loop-contracts
+
+//! Check that Kani reports loops whose termination measure does not decrease.
+
+#![feature(stmt_expr_attributes)]
+#![feature(proc_macro_hygiene)]
+
+#[kani::proof]
+fn no_progress() {
+    let x: u8 = kani::any_where(|i| *i <= 100);
+    let mut steps: u8 = 0;
+    #[kani::loop_invariant(x <= 100 && steps <= 100)]
+    #[kani::loop_decreases(x)]
+    while x > 0 && steps < 100 {
+        steps += 1;
+    }
+}
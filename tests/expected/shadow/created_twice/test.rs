@@ -0,0 +1,21 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zghost-state
+
+// This test checks that Kani reports a failure if the same shadow memory map is created more
+// than once at runtime, since the instances would share their values.
+
+use kani::shadow::ShadowMem;
+
+fn new_map() -> ShadowMem<u8, 3> {
+    ShadowMem::new(0)
+}
+
+#[kani::proof]
+fn check_created_twice() {
+    let x = 0u8;
+    let mut first = new_map();
+    first.set(&x as *const u8, 1);
+    let second = new_map();
+    assert_eq!(second.get(&x as *const u8), 1);
+}
@@ -0,0 +1,20 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zghost-state
+
+// This test checks that Kani rejects two shadow memory maps with the same id, which would
+// otherwise share their values.
+
+use kani::shadow::ShadowMem;
+
+static mut INITIALIZED: ShadowMem<bool, 0> = ShadowMem::new(false);
+static mut TAINTED: ShadowMem<bool, 0> = ShadowMem::new(false);
+
+#[kani::proof]
+fn check_same_id() {
+    let x = 0u8;
+    unsafe {
+        INITIALIZED.set(&x as *const u8, true);
+        assert!(!TAINTED.get(&x as *const u8));
+    }
+}
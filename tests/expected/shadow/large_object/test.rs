@@ -0,0 +1,28 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zghost-state
+
+// This test checks that Kani's shadow memory supports objects larger than a
+// cache line, which used to be the maximum object size (64)
+
+static mut SM: kani::shadow::ShadowMem<bool> = kani::shadow::ShadowMem::new(false);
+
+fn check_object_size<const N: usize>() {
+    let arr: [u8; N] = [0; N];
+    let last = &arr[N - 1];
+    unsafe {
+        SM.set(last as *const u8, true);
+        assert!(SM.get(last as *const u8));
+        assert!(!SM.get(&arr[0] as *const u8));
+    }
+}
+
+#[kani::proof]
+fn check_object_size_64() {
+    check_object_size::<64>();
+}
+
+#[kani::proof]
+fn check_object_size_4096() {
+    check_object_size::<4096>();
+}
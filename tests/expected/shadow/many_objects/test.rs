@@ -0,0 +1,34 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zghost-state
+
+// This test checks that Kani's shadow memory supports more objects than the
+// maximum number it used to support (1024)
+
+static mut SM: kani::shadow::ShadowMem<bool> = kani::shadow::ShadowMem::new(false);
+
+fn check_objects<const N: usize>() {
+    let mut i = 0;
+    // A dummy loop that creates `N` objects.
+    let mut have_42 = false;
+    while i < N {
+        let x: Box<usize> = Box::new(kani::any());
+        if *x == 42 {
+            have_42 = true;
+        }
+        i += 1;
+    }
+
+    // create a new object after the `N` objects of the loop
+    let x: i32 = have_42 as i32;
+    assert_eq!(x, have_42 as i32);
+    unsafe {
+        SM.set(&x as *const i32, true);
+        assert!(SM.get(&x as *const i32));
+    }
+}
+
+#[kani::proof]
+fn check_many_objects() {
+    check_objects::<1100>();
+}
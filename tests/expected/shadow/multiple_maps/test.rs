@@ -0,0 +1,33 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zghost-state
+
+// This test checks that shadow memory maps with different ids are independent,
+// and that each of them starts with its own initial value, including maps created at runtime.
+
+use kani::shadow::ShadowMem;
+
+static mut INITIALIZED: ShadowMem<bool, 0> = ShadowMem::new(false);
+static mut LABEL: ShadowMem<u8, 1> = ShadowMem::new(u8::MAX);
+
+#[kani::proof]
+fn check_independent_maps() {
+    let buf = [0u8; 4];
+    let index: usize = kani::any_where(|i| *i < 4);
+    unsafe {
+        INITIALIZED.set(&buf[1] as *const u8, true);
+        LABEL.set(&buf[2] as *const u8, 7);
+        assert_eq!(INITIALIZED.get(&buf[index] as *const u8), index == 1);
+        assert_eq!(LABEL.get(&buf[index] as *const u8), if index == 2 { 7 } else { u8::MAX });
+    }
+}
+
+#[kani::proof]
+fn check_local_map() {
+    let x = 0u8;
+    let mut local: ShadowMem<i8, 2> = ShadowMem::new(-1);
+    assert_eq!(local.get(&x as *const u8), -1);
+    local.set(&x as *const u8, 3);
+    assert_eq!(local.get(&x as *const u8), 3);
+    unsafe { assert!(!INITIALIZED.get(&x as *const u8)) };
+}
@@ -0,0 +1,42 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zghost-state
+
+// This test checks that shadow memory maps store values wider than a byte, and that each of them
+// starts with every byte of its initial value.
+
+use kani::shadow::ShadowMem;
+
+static mut OWNER: ShadowMem<u32, 0> = ShadowMem::new(0xdead_beef);
+static mut RANGE: ShadowMem<(u8, u16), 1> = ShadowMem::new((1, 1000));
+
+#[kani::proof]
+fn check_u32_map() {
+    let buf = [0u8; 4];
+    let index: usize = kani::any_where(|i| *i < 4);
+    let owner: u32 = kani::any();
+    unsafe {
+        OWNER.set(&buf[1] as *const u8, owner);
+        let expected = if index == 1 { owner } else { 0xdead_beef };
+        assert_eq!(OWNER.get(&buf[index] as *const u8), expected);
+    }
+}
+
+#[kani::proof]
+fn check_tuple_map() {
+    let x = 0u64;
+    unsafe {
+        assert_eq!(RANGE.get(&x as *const u64), (1, 1000));
+        RANGE.set(&x as *const u64, (u8::MAX, u16::MAX));
+        assert_eq!(RANGE.get(&x as *const u64), (u8::MAX, u16::MAX));
+    }
+}
+
+#[kani::proof]
+fn check_local_i64_map() {
+    let x = 0u8;
+    let mut local: ShadowMem<i64, 2> = ShadowMem::new(-2);
+    assert_eq!(local.get(&x as *const u8), -2);
+    local.set(&x as *const u8, i64::MAX);
+    assert_eq!(local.get(&x as *const u8), i64::MAX);
+}
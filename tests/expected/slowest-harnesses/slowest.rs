@@ -0,0 +1,13 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z unstable-options --slowest-harnesses 1
+//! Test that `--slowest-harnesses` prints a table with the resources consumed by the harnesses.
+
+#[kani::proof]
+fn check_multiplication() {
+    let x: u32 = kani::any();
+    let y: u32 = kani::any();
+    kani::assume(x < 1000 && y < 1000);
+    assert_eq!(x * y, y * x);
+}
@@ -0,0 +1,30 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// kani-flags: -Z unstable-options --trace
+//! Test that `--trace` prints the values assigned to user variables in the failing execution.
+
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+#[kani::proof]
+fn check_trace() {
+    let x: u8 = kani::any();
+    kani::assume(x == 7);
+    let point = Point { x, y: 2 };
+    assert!(point.x + point.y != 9);
+}
+
+#[derive(PartialEq)]
+enum Mode {
+    Off,
+    On = 5,
+}
+
+#[kani::proof]
+fn check_enum_trace() {
+    let mode = if kani::any() { Mode::On } else { Mode::Off };
+    assert!(mode != Mode::On);
+}
@@ -22,7 +22,7 @@ async fn round_robin_schedule() {
     });
     kani::yield_now().await;
     x2.fetch_add(1, Ordering::Relaxed);
-    handle.await;
+    handle.await.unwrap();
     assert_eq!(x.load(Ordering::Relaxed), 2);
 }
 
@@ -46,3 +46,44 @@ fn round_robin_schedule_manual() {
     );
     assert_eq!(x.load(Ordering::Relaxed), 2);
 }
+
+#[kani::proof(schedule = kani::RoundRobin::default())]
+#[kani::unwind(4)]
+async fn join_handle_result() {
+    let handle = kani::spawn(async {
+        kani::yield_now().await;
+        40 + 2
+    });
+    assert!(!handle.is_finished());
+    assert_eq!(handle.await.unwrap(), 42);
+}
+
+#[kani::proof(schedule = kani::RoundRobin::default())]
+#[kani::unwind(4)]
+async fn join_handle_abort() {
+    let x = Arc::new(AtomicI64::new(0));
+    let x2 = x.clone();
+    let handle = kani::spawn(async move {
+        x2.fetch_add(1, Ordering::Relaxed);
+    });
+    handle.abort();
+    assert!(handle.await.unwrap_err().is_cancelled());
+    assert_eq!(x.load(Ordering::Relaxed), 0);
+}
+
+#[kani::proof(schedule = kani::RoundRobin::default())]
+#[kani::unwind(4)]
+async fn join_handle_after_completion() {
+    let mut handle = kani::spawn(async { 40 + 2 });
+    kani::yield_now().await;
+    assert!(handle.is_finished());
+    assert_eq!((&mut handle).await.unwrap(), 42);
+    // The task stays finished once its output is taken, so aborting it has no effect.
+    assert!(handle.is_finished());
+    handle.abort();
+    assert!(handle.is_finished());
+    let handle = kani::spawn(async { 40 + 2 });
+    kani::yield_now().await;
+    handle.abort();
+    assert_eq!(handle.await.unwrap(), 42);
+}
@@ -0,0 +1,21 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: -Zlean --print-llbc
+
+//! This test checks that Kani's LLBC backend handles array repeat expressions,
+//! raw pointers and calls through function pointers
+
+fn first(a: [u8; 4]) -> u8 {
+    a[0]
+}
+
+fn apply(f: fn([u8; 4]) -> u8, a: [u8; 4]) -> u8 {
+    f(a)
+}
+
+#[kani::proof]
+fn main() {
+    let mut a = [0; 4];
+    let _p = &raw mut a;
+    let _ = apply(first, a);
+}
@@ -0,0 +1,55 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// kani-flags: autoharness -Z autoharness
+
+//! Check that autoharness generates harnesses for methods with `&self` and `&mut self` receivers,
+//! including when the receiver type cannot derive `Arbitrary` but implements it manually.
+
+#![allow(dead_code)]
+
+/// A stack that cannot derive `Arbitrary` because of its `Vec` field.
+pub struct Stack {
+    items: Vec<u8>,
+}
+
+#[cfg(kani)]
+impl kani::Arbitrary for Stack {
+    fn any() -> Self {
+        Stack { items: kani::vec::any_vec::<u8, 2>() }
+    }
+}
+
+impl Stack {
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    fn peek(&self) -> Option<&u8> {
+        self.items.last()
+    }
+
+    fn pop(&mut self) -> Option<u8> {
+        self.items.pop()
+    }
+
+    fn increment_top(&mut self) {
+        if let Some(top) = self.items.last_mut() {
+            *top += 1;
+        }
+    }
+}
+
+/// A wrapper that derives `Arbitrary` but whose field only implements it manually.
+pub struct Wrapper {
+    stack: Stack,
+}
+
+impl Wrapper {
+    fn is_empty(&self) -> bool {
+        self.stack.len() == 0
+    }
+}
+
+fn top_plus(stack: &Stack, offset: u8) -> u8 {
+    stack.peek().copied().unwrap_or(0) + offset
+}
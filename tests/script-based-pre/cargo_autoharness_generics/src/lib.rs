@@ -0,0 +1,30 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Test that the autoharness subcommand instantiates generic functions with the types provided
+//! on the command line, in the Cargo.toml, and through the `kani::autoharness_types` attribute.
+
+#![allow(dead_code)]
+
+use std::ops::Add;
+
+/// Instantiated with `u8` (command line) and `i8` (Cargo.toml).
+fn double<T: Copy + Add<Output = T>>(x: T) -> T {
+    x + x
+}
+
+/// The attribute takes precedence over the other types.
+#[cfg_attr(kani, kani::autoharness_types(char, u32))]
+fn pick<T: PartialEq>(x: T, y: T) -> T {
+    if x == y { x } else { y }
+}
+
+/// None of the provided types implement `Iterator`.
+fn count_items<T: Iterator>(iter: T) -> usize {
+    iter.count()
+}
+
+/// Kani only substitutes types, so functions with const generic parameters are skipped.
+fn first<const N: usize>(arr: [u8; N]) -> Option<u8> {
+    arr.first().copied()
+}
@@ -0,0 +1,18 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+set +e
+
+# Replace `gdb` by a script that prints how Kani invokes it.
+export PATH="$(pwd)/fake_bin:${PATH}"
+
+pushd sample_crate > /dev/null
+cargo clean
+
+echo "[TEST] Debug test..."
+cargo kani playback -Z concrete-playback --debugger -- \
+    kani_concrete_playback_harness_9281930173612376470 --show-output
+
+cargo clean
+popd > /dev/null
@@ -0,0 +1,30 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This test is used to test the playback `--debugger` option on a unit test generated by the
+//! concrete playback.
+
+#[cfg(kani)]
+mod harnesses {
+    #[kani::proof]
+    fn harness() {
+        let x: u8 = kani::any();
+        assert!(x != 101);
+    }
+
+    /// Test generated for harness `harnesses::harness`
+    ///
+    /// Check for `assertion`: "assertion failed: x != 101"
+    /// Check location: `src/lib.rs:12`
+    ///
+    /// Values of `kani::any()`:
+    /// - `src/lib.rs:11:21`: 101
+    #[test]
+    fn kani_concrete_playback_harness_9281930173612376470() {
+        let concrete_vals: Vec<Vec<u8>> = vec![
+            // 101
+            vec![101],
+        ];
+        kani::concrete_playback_run(concrete_vals, harness);
+    }
+}
@@ -0,0 +1,14 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+set +e
+
+pushd sample_crate > /dev/null
+cargo clean
+
+echo "[TEST] Play back harnesses with different stubs..."
+cargo kani playback -Z concrete-playback -- --test-threads=1
+
+cargo clean
+popd > /dev/null
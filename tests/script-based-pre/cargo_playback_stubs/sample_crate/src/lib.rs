@@ -0,0 +1,78 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Check that the playback tests of harnesses that stub the same function differently each use
+//! the stub of their own harness, and that the other tests call the original function.
+
+pub fn magic() -> u32 {
+    0
+}
+
+pub fn wrapper() -> u32 {
+    magic()
+}
+
+#[cfg(kani)]
+mod harnesses {
+    use super::*;
+
+    fn one() -> u32 {
+        1
+    }
+
+    fn two() -> u32 {
+        2
+    }
+
+    #[kani::proof]
+    #[kani::stub(magic, one)]
+    fn check_one() {
+        let x: u8 = kani::any();
+        assert_eq!(wrapper() + x as u32, 1);
+    }
+
+    #[kani::proof]
+    #[kani::stub(magic, two)]
+    fn check_two() {
+        let x: u8 = kani::any();
+        assert_eq!(wrapper() + x as u32, 2);
+    }
+
+    #[kani::proof]
+    fn check_original() {
+        let x: u8 = kani::any();
+        assert_eq!(wrapper() + x as u32, 0);
+    }
+
+    #[test]
+    fn kani_concrete_playback_check_one_1() {
+        let concrete_vals: Vec<Vec<u8>> = vec![
+            // 0
+            vec![0],
+        ];
+        kani::concrete_playback_run(concrete_vals, check_one);
+    }
+
+    #[test]
+    fn kani_concrete_playback_check_two_1() {
+        let concrete_vals: Vec<Vec<u8>> = vec![
+            // 0
+            vec![0],
+        ];
+        kani::concrete_playback_run(concrete_vals, check_two);
+    }
+
+    #[test]
+    fn kani_concrete_playback_check_original_1() {
+        let concrete_vals: Vec<Vec<u8>> = vec![
+            // 0
+            vec![0],
+        ];
+        kani::concrete_playback_run(concrete_vals, check_original);
+    }
+
+    #[test]
+    fn check_without_playback() {
+        assert_eq!(wrapper(), 0);
+    }
+}
@@ -0,0 +1,22 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+fn double(x: u8) -> u16 {
+    x as u16 * 2
+}
+
+fn negate(x: i8) -> i16 {
+    -(x as i16)
+}
+
+#[kani::proof]
+fn check_double() {
+    let x: u8 = kani::any();
+    assert!(double(x) >= x as u16);
+}
+
+#[kani::proof]
+fn check_negate() {
+    let x: i8 = kani::any();
+    assert_eq!(negate(negate(x)), x as i16);
+}
@@ -0,0 +1,47 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Checks that `--changed-since-fingerprints` only verifies the harnesses that can reach an item
+# whose MIR changed since the fingerprints were saved, and that `--save-fingerprints` only saves
+# the harnesses that are known to pass.
+
+OUT_DIR=target
+FINGERPRINTS=${OUT_DIR}/fingerprints.json
+SOURCE=${OUT_DIR}/changed_since.rs
+
+# Expects two arguments: "extra kani arguments" "output_file"
+function check_kani {
+    local args=$1
+    local log_file="${OUT_DIR}/$2"
+    kani ${SOURCE} --target-dir "${OUT_DIR}" -Z unstable-options ${args} > "${log_file}" 2>&1
+
+    grep "Checking harness" -H ${log_file}
+    grep "Selected" -H ${log_file}
+    grep -E "successfully verified harnesses|No proof harnesses" -H ${log_file}
+}
+
+# Ensure output folder is clean
+rm -rf ${OUT_DIR}
+mkdir -p ${OUT_DIR}
+cp changed_since.rs ${SOURCE}
+
+echo "Save fingerprints"
+check_kani "--save-fingerprints ${FINGERPRINTS}" initial.log
+
+echo "No changes"
+check_kani "--changed-since-fingerprints ${FINGERPRINTS}" unchanged.log
+
+echo "Change negate"
+sed -i.bak 's/-(x as i16)/0 - x as i16/' ${SOURCE}
+check_kani "--changed-since-fingerprints ${FINGERPRINTS} --verbose" changed.log
+
+echo "Break negate"
+sed -i.bak 's/0 - x as i16/x as i16 * 2/' ${SOURCE}
+check_kani "--changed-since-fingerprints ${FINGERPRINTS} --save-fingerprints ${FINGERPRINTS}" broken.log
+
+echo "Failed harness is verified again"
+check_kani "--changed-since-fingerprints ${FINGERPRINTS} --verbose" failed_again.log
+
+# Try to leave a clean output folder at the end
+rm -rf ${OUT_DIR}
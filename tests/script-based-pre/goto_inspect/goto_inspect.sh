@@ -0,0 +1,32 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+#
+# Check that `kani goto-inspect` can inspect the model file of a harness kept by `--keep-temps`.
+
+set -eu
+
+cd $(dirname $0)
+rm -f inspect_* loops.json
+
+kani --keep-temps --only-codegen inspect.rs --harness check_inspect >& kani.log || \
+    { ret=$?; echo "== Failed to run Kani"; cat kani.log; rm kani.log; exit 1; }
+rm -f kani.log
+
+MODEL=$(ls inspect_*check_inspect.out)
+
+echo "[TEST] Reachable functions"
+# The implementation of the trait method is only referenced by the vtable of `Triangle`.
+kani goto-inspect -Z unstable-options --query reachable --format json ${MODEL} \
+    | grep -o 'Triangle.*sides' | sort -u
+
+echo "[TEST] Loops"
+# Without other bounds, the loops are bounded by the unwind attribute of the harness.
+kani goto-inspect -Z unstable-options --query loops --format json ${MODEL} > loops.json
+grep -o '"unwind": [0-9]*' loops.json | sort -u
+# The bound of a loop in `--unwindset` takes precedence.
+LOOP=$(grep -o '"name": "[^"]*total_sides[^"]*"' loops.json | cut -d'"' -f4)
+kani goto-inspect -Z unstable-options --query loops --format json --default-unwind 5 \
+    --unwindset "${LOOP}:7" ${MODEL} | grep -A4 "${LOOP}" | grep -o '"unwind": [0-9]*'
+
+rm -f inspect_* loops.json
@@ -0,0 +1,32 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Harness inspected by `goto_inspect.sh` from the model file kept by `--keep-temps`.
+
+trait Shape {
+    fn sides(&self) -> u8;
+}
+
+struct Triangle;
+
+impl Shape for Triangle {
+    /// Only called through the vtable of `Triangle`.
+    fn sides(&self) -> u8 {
+        3
+    }
+}
+
+fn total_sides(shapes: &[&dyn Shape]) -> u8 {
+    let mut total = 0;
+    for shape in shapes {
+        total += shape.sides();
+    }
+    total
+}
+
+#[kani::proof]
+#[kani::unwind(3)]
+fn check_inspect() {
+    let triangle = Triangle;
+    assert_eq!(total_sides(&[&triangle, &triangle]), 6);
+}
@@ -0,0 +1,20 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! A function with a contract, whose harness is translated to Lean.
+
+#[kani::requires(x != 0 && y != 0)]
+#[kani::ensures(|result| *result != 0)]
+fn gcd(x: u8, y: u8) -> u8 {
+    let (mut a, mut b) = (x, y);
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+#[kani::proof_for_contract(gcd)]
+fn check_gcd() {
+    gcd(kani::any(), kani::any());
+}
@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+# Check the Lean project that Kani generates, using a fake `aeneas` that records its arguments.
+
+set -eu
+
+export PATH="$(pwd)/fake_bin:${PATH}"
+export AENEAS_LOG="$(pwd)/aeneas.log"
+rm -rf lean "${AENEAS_LOG}"
+spec=lean/Proofs/gcd/check_gcd/Spec.lean
+
+kani gcd.rs -Z lean -Z function-contracts
+
+echo "[TEST] Aeneas arguments"
+sed -E "s|$(pwd)/||g; s|[^ ]*\.llbc$|<LLBC>|" "${AENEAS_LOG}"
+
+echo "[TEST] lakefile.lean"
+cat lean/lakefile.lean
+
+echo "[TEST] Proofs.lean"
+cat lean/Proofs.lean
+
+echo "[TEST] Spec.lean"
+cat ${spec}
+
+echo "[TEST] Second run"
+echo "-- Proved by hand" >> ${spec}
+kani gcd.rs -Z lean -Z function-contracts
+echo "Aeneas runs: $(wc -l < "${AENEAS_LOG}")"
+echo "Last line of Spec.lean: $(tail -n 1 ${spec})"
+echo "Generated module: $(sed -E 's|[^ ]*\.llbc|<LLBC>|' lean/Proofs/gcd/check_gcd/Gcd.lean)"
+
+rm -rf lean "${AENEAS_LOG}"
@@ -0,0 +1,19 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Check that mutants of `is_even` are killed by a harness that checks its result, but survive a
+//! harness that ignores it.
+
+fn is_even(x: u32) -> bool {
+    x % 2 == 0
+}
+
+#[kani::proof]
+fn check_is_even() {
+    let x: u32 = kani::any();
+    assert_eq!(is_even(x), x & 1 == 0);
+}
+
+#[kani::proof]
+fn check_is_even_weak() {
+    let _ = is_even(kani::any());
+}
@@ -0,0 +1,17 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Checks that `kani mutate` reports the mutants that survive each harness.
+
+OUT_DIR=target
+
+# Ensure output folder is clean
+rm -rf ${OUT_DIR}
+mkdir -p ${OUT_DIR}
+
+kani mutate -Z unstable-options is_even.rs --target-dir ${OUT_DIR} | grep -E "Mutation Summary|Harness|Surviving|Complete"
+echo "Exit code: ${PIPESTATUS[0]}"
+
+# Try to leave a clean output folder at the end
+rm -rf ${OUT_DIR}
@@ -13,8 +13,7 @@ run() {
     -Z concrete-playback --concrete-playback=inplace \
     -Z function-contracts -Z stubbing --output-format terse
 
-  # Note that today one of the tests will succeed since the contract pre-conditions are not inserted by Kani.
-  # Hopefully this will change with https://github.com/model-checking/kani/issues/3326
+  # The stubs and contracts of the harnesses are applied to the tests, so all of them should fail.
   echo "[TEST] Run test for ${input_rs}..."
   summary=$(kani playback -Z concrete-playback "${input_rs}" -- kani_concrete_playback | grep "test result")
   echo "Result for ${input_rs}: ${summary}"
@@ -0,0 +1,30 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+set +e
+
+OUT_DIR=tmp_sample_crate
+
+# Ensure output folder is clean
+rm -rf ${OUT_DIR}
+
+# Move the original source to the output folder since it will be modified
+cp -r sample_crate ${OUT_DIR}
+pushd $OUT_DIR
+
+echo "[TEST] Generate fixture..."
+cargo kani
+
+echo "[TEST] Fixture content..."
+cat kani-counterexamples/verify_check_div/*.json
+
+echo "[TEST] Play back fixture..."
+cargo kani playback -Z concrete-playback -- kani_concrete_playback_fixtures_check_div
+
+echo "[TEST] Play back invalid fixture..."
+echo '{"values": [[100], [2]' > kani-counterexamples/verify_check_div/invalid.json
+cargo kani playback -Z concrete-playback -- kani_concrete_playback_fixtures_check_div
+
+popd
+rm -rf ${OUT_DIR}
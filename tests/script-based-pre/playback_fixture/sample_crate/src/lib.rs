@@ -0,0 +1,22 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! This test checks that `--concrete-playback=fixture` saves the counterexamples as fixtures,
+//! and that the generated test plays back each of them.
+
+pub fn checked_div(a: u8, b: u8) -> u8 {
+    if b == 0 { 0 } else { a / b }
+}
+
+#[cfg(kani)]
+mod verify {
+    use super::*;
+
+    #[kani::proof]
+    fn check_div() {
+        let a: u8 = kani::any();
+        let b: u8 = kani::any();
+        kani::assume(a == 100);
+        assert!(checked_div(a, b) != 50, "result is 50");
+    }
+}
@@ -0,0 +1,37 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Checks that `--report-format` writes the reports to the target directory, and that the
+# locations of the SARIF report are relative to the `%SRCROOT%` base id.
+
+OUT_DIR=target
+
+# Ensure output folder is clean
+rm -rf ${OUT_DIR}
+mkdir -p ${OUT_DIR}
+
+kani report.rs --target-dir ${OUT_DIR} -Z unstable-options --report-format sarif \
+    --report-format junit --report-format json > ${OUT_DIR}/kani.log 2>&1
+echo "Exit code: $?"
+
+for report in kani-report.sarif kani-report.xml kani-report.json; do
+    if [ -f ${OUT_DIR}/${report} ]; then echo "Found ${report}"; fi
+    if [ -f ${report} ]; then echo "Unexpected ${report} in the current directory"; fi
+done
+
+python3 - ${OUT_DIR}/kani-report.sarif <<'PY'
+import json, sys
+report = json.load(open(sys.argv[1]))
+assert report["version"] == "2.1.0", report["version"]
+run, = report["runs"]
+assert run["originalUriBaseIds"]["%SRCROOT%"]["uri"].startswith("file:///")
+for result in run["results"]:
+    location = result["locations"][0]["physicalLocation"]
+    print(result["ruleId"], result["message"]["text"], location["artifactLocation"])
+PY
+
+grep -o '<testcase name="[a-z_]*"' ${OUT_DIR}/kani-report.xml | sort
+
+# Try to leave a clean output folder at the end
+rm -rf ${OUT_DIR}
@@ -0,0 +1,19 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[kani::proof]
+fn check_one() {
+    assert!(kani::any::<u8>() as u16 <= 255);
+}
+
+#[kani::proof]
+fn check_two() {
+    let x: u8 = kani::any();
+    assert!(x < 255, "fails for 255");
+}
+
+#[kani::proof]
+fn check_three() {
+    let x: u8 = kani::any();
+    assert_eq!(x.wrapping_add(1).wrapping_sub(1), x);
+}
@@ -0,0 +1,37 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Checks that `--shard` splits the harnesses among shards, and that `merge-results` combines the
+# JSON reports of every shard into a single summary and exit code.
+
+OUT_DIR=target
+SOURCE=$(pwd)/shard.rs
+
+# Expects one argument: "shard"
+function check_shard {
+    local dir="${OUT_DIR}/shard_${1%/*}"
+    mkdir -p ${dir}
+    kani ${SOURCE} --target-dir ${dir} -Z unstable-options --shard $1 --report-format json \
+        > ${dir}/kani.log 2>&1
+    grep -E "Selected|Checking harness" ${dir}/kani.log
+}
+
+# Ensure output folder is clean
+rm -rf ${OUT_DIR}
+mkdir -p ${OUT_DIR}
+
+check_shard 1/2
+check_shard 2/2
+
+echo "Merge all shards"
+kani merge-results -Z unstable-options ${OUT_DIR}/shard_1/kani-report.json \
+    ${OUT_DIR}/shard_2/kani-report.json
+echo "Exit code: $?"
+
+echo "Merge with a missing shard"
+kani merge-results -Z unstable-options ${OUT_DIR}/shard_1/kani-report.json
+echo "Exit code: $?"
+
+# Try to leave a clean output folder at the end
+rm -rf ${OUT_DIR}
@@ -0,0 +1,16 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Harnesses used to check that verification results are reused across runs.
+
+#[kani::proof]
+fn check_success() {
+    let x: u8 = kani::any();
+    assert_eq!(x.wrapping_add(1).wrapping_sub(1), x);
+}
+
+#[kani::proof]
+fn check_failure() {
+    let x: u8 = kani::any();
+    assert!(x < 100);
+}
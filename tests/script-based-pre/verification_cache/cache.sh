@@ -0,0 +1,39 @@
+#!/usr/bin/env bash
+# Copyright Kani Contributors
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+# Checks that `--verification-cache` reuses results for harnesses whose goto binary and CBMC
+# flags are unchanged, and that a change in the CBMC flags invalidates the cached results.
+
+OUT_DIR=target
+
+# Expects two arguments: "extra kani arguments" "output_file"
+function check_kani {
+    local args=$1
+    local log_file="${OUT_DIR}/$2"
+    kani cache.rs --target-dir "${OUT_DIR}" -Z unstable-options --verification-cache ${args} \
+        > "${log_file}" 2>&1
+
+    # Check which harnesses were verified and which ones were reused
+    grep "Checking harness" -H ${log_file}
+    grep "Using cached verification result" -H ${log_file} || echo "${log_file}:No cached result"
+
+    # Check the verification summary
+    grep "successfully verified harnesses" -H ${log_file}
+}
+
+# Ensure output folder is clean
+rm -rf ${OUT_DIR}
+mkdir -p ${OUT_DIR}
+
+echo "Initial run"
+check_kani "" initial.log
+
+echo "Re-execute the same command"
+check_kani "" same.log
+
+echo "Run with a new cbmc option"
+check_kani "--default-unwind 2" cbmc_opt.log
+
+# Try to leave a clean output folder at the end
+rm -rf ${OUT_DIR}
@@ -3,8 +3,8 @@
 //
 // kani-flags: -Z function-contracts
 //
-//! This tests that we emit a nice error message when a user tries
-//! to apply a contract to a default trait fn without a body.
+//! This tests that we emit a nice error message when a user tries to check the contract
+//! of a trait method declaration instead of one of its implementations.
 
 struct Bar;
 
@@ -19,5 +19,7 @@ fn foo(&self) -> bool {
     }
 }
 
-#[kani::proof]
-fn harness() {}
+#[kani::proof_for_contract(Foo::foo)]
+fn harness() {
+    Bar.foo();
+}
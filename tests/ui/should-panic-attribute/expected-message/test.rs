@@ -0,0 +1,15 @@
+// Copyright Kani Contributors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks that verification passes when `#[kani::should_panic(expected = ...)]` is used and all
+//! failures encountered are panics whose message contains the expected string.
+
+#[kani::proof]
+#[kani::should_panic(expected = "invalid index")]
+fn check() {
+    if kani::any() {
+        panic!("invalid index: too large");
+    } else {
+        panic!("invalid index: too small");
+    }
+}